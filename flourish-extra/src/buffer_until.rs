@@ -0,0 +1,95 @@
+use std::{
+	mem,
+	sync::{Arc, Mutex},
+};
+
+use flourish::{
+	unmanaged::UnmanagedSignal, Signal, SignalArc, SignalDyn, SignalsRuntimeRef, Subscription,
+};
+
+/// Which values [`buffer_until`] emits on each flush.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BufferMode {
+	/// Emit only the current value, so that any number of changes between flushes collapse into it.
+	///
+	/// The value function runs detached and only when flushing.
+	#[default]
+	Latest,
+	/// Emit each value the value function produced since the previous flush, oldest first.
+	///
+	/// The value function runs in an internal subscription, so it's evaluated eagerly on changes.
+	All,
+}
+
+/// Buffers the results of `value_fn_pin` into a [`SignalArc`] that only changes when `flush_trigger` does.
+///
+/// The returned signal holds the values emitted by the latest flush (see [`BufferMode`]),
+/// starting with a flush on its first evaluation. Only `flush_trigger` is recorded as dependency,
+/// which makes this suitable for applying pending edits on save.
+///
+/// The returned signal uses the runtime of `flush_trigger`.
+///
+/// ```
+/// # {
+/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+/// use flourish::GlobalSignalsRuntime;
+/// use flourish_extra::{buffer_until, BufferMode};
+///
+/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+///
+/// let draft = Signal::cell("a");
+/// let save = Signal::cell(());
+///
+/// let saved = buffer_until(|| draft.get(), save.as_dyn(), BufferMode::Latest);
+/// assert_eq!(saved.get_clone(), ["a"]);
+///
+/// draft.set("ab");
+/// draft.set("abc");
+/// assert_eq!(saved.get_clone(), ["a"]);
+///
+/// save.set(());
+/// assert_eq!(saved.get_clone(), ["abc"]);
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// With [`BufferMode::All`], flushing panics iff the internal buffer was poisoned by a panic while collecting.
+pub fn buffer_until<'a, T: 'a + Send, SR: 'a + SignalsRuntimeRef>(
+	mut value_fn_pin: impl 'a + Send + FnMut() -> T,
+	flush_trigger: &SignalDyn<'a, (), SR>,
+	mode: BufferMode,
+) -> SignalArc<Vec<T>, impl 'a + Sized + UnmanagedSignal<Vec<T>, SR>, SR> {
+	let flush_trigger = flush_trigger.to_owned();
+	let runtime = flush_trigger.clone_runtime_ref();
+	let mut flush: Box<dyn 'a + Send + FnMut() -> Vec<T>> = match mode {
+		BufferMode::Latest => {
+			let runtime = runtime.clone();
+			Box::new(move || vec![runtime.run_detached(&mut value_fn_pin)])
+		}
+		BufferMode::All => {
+			let buffer = Arc::new(Mutex::new(Vec::new()));
+			let collector = Subscription::computed_with_runtime(
+				{
+					let buffer = Arc::clone(&buffer);
+					move || {
+						let value = value_fn_pin();
+						buffer.lock().unwrap().push(value);
+					}
+				},
+				runtime.clone(),
+			);
+			Box::new(move || {
+				let _collector = &collector;
+				mem::take(&mut *buffer.lock().unwrap())
+			})
+		}
+	};
+	Signal::computed_with_runtime(
+		move || {
+			flush_trigger.touch();
+			flush()
+		},
+		runtime,
+	)
+}
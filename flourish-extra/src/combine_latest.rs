@@ -0,0 +1,105 @@
+use flourish::{unmanaged::UnmanagedSignal, Signal, SignalArc, SignalsRuntimeRef, Subscription};
+
+/// Combines up to eight signals into a [`SignalArc`] of the tuple of their current values.
+///
+/// The inputs may each have a distinct value type, but must all share one signals runtime type.
+/// The combined signal uses the runtime of the first input.
+///
+/// All inputs are read within one computation, so a change of any of them refreshes the whole tuple once.
+/// Values are obtained through [`Signal::get_clone_exclusive`].
+///
+/// Inputs are moved into the combined signal. Each may be a [`SignalArc`], a [`Subscription`]
+/// or a reference to either or to a [`Signal`].
+///
+/// ```
+/// # {
+/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+/// use flourish::GlobalSignalsRuntime;
+/// use flourish_extra::combine_latest;
+///
+/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+///
+/// let a = Signal::cell(1);
+/// let b = Signal::cell("b");
+/// let c = Signal::computed(|| 'c');
+///
+/// let combined = combine_latest!(a.clone(), &b, c);
+/// assert_eq!(combined.get(), (1, "b", 'c'));
+///
+/// a.set(2);
+/// assert_eq!(combined.get(), (2, "b", 'c'));
+/// # }
+/// ```
+///
+/// Inputs using different signals runtime types are rejected at compile-time:
+///
+/// ```compile_fail
+/// use flourish::{SignalArcDyn, SignalsRuntimeRef};
+/// use flourish_extra::combine_latest;
+///
+/// fn combine<SR1: SignalsRuntimeRef, SR2: SignalsRuntimeRef>(
+///     a: SignalArcDyn<'static, i32, SR1>,
+///     b: SignalArcDyn<'static, i32, SR2>,
+/// ) {
+///     let _ = combine_latest!(a, b);
+/// }
+/// ```
+#[macro_export]
+macro_rules! combine_latest {
+	($($input:expr),+ $(,)?) => {
+		$crate::__combine_latest!([$($input),+] [a0 a1 a2 a3 a4 a5 a6 a7] [])
+	};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __combine_latest {
+	([$input:expr $(, $inputs:expr)*] [$name:ident $($names:ident)*] [$($bound:tt)*]) => {
+		$crate::__combine_latest!([$($inputs),*] [$($names)*] [$($bound)* ($name $input)])
+	};
+	([$($inputs:expr),+] [] [$($bound:tt)*]) => {
+		::core::compile_error!("`combine_latest!` accepts at most 8 inputs.")
+	};
+	([] [$($names:ident)*] [($first:ident $first_input:expr) $(($name:ident $input:expr))*]) => {{
+		let $first = $first_input;
+		$(let $name = $input;)*
+		let runtime = $first.clone_runtime_ref();
+		$($crate::__::assert_same_runtime(&runtime, &$name);)*
+		$crate::__::Signal::computed_with_runtime(
+			move || ($first.get_clone_exclusive(), $($name.get_clone_exclusive(),)*),
+			runtime,
+		)
+	}};
+}
+
+/// Implemented by signal handles that use the signals runtime `SR`.
+#[diagnostic::on_unimplemented(
+	message = "`{Self}` does not use the signals runtime `{SR}`",
+	label = "this input's signals runtime differs from that of the first input",
+	note = "all inputs of `combine_latest!` must share one signals runtime type"
+)]
+pub trait InRuntime<SR: SignalsRuntimeRef> {}
+
+impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: SignalsRuntimeRef> InRuntime<SR>
+	for Signal<T, S, SR>
+{
+}
+
+impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: SignalsRuntimeRef> InRuntime<SR>
+	for SignalArc<T, S, SR>
+{
+}
+
+impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: SignalsRuntimeRef> InRuntime<SR>
+	for Subscription<T, S, SR>
+{
+}
+
+impl<X: ?Sized + InRuntime<SR>, SR: SignalsRuntimeRef> InRuntime<SR> for &X {}
+
+/// Type-checks that `input` uses the signals runtime type `SR`.
+pub fn assert_same_runtime<SR: SignalsRuntimeRef, X: ?Sized + InRuntime<SR>>(
+	_runtime: &SR,
+	_input: &X,
+) {
+}
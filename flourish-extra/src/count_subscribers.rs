@@ -0,0 +1,65 @@
+use flourish::{
+	unmanaged::{inert_cell, UnmanagedSignal},
+	CallbackTableTypes, Signal, SignalArc, SignalDyn, SignalsRuntimeRef, SubscriberCount,
+};
+
+/// Returns a pass-through `tap` of `target` and a signal counting the subscribers of that `tap`.
+///
+/// The signals runtime only reports subscription changes to the signal that's subscribed to,
+/// so subscribe to (or depend on) `tap` rather than `target` to be counted.  
+/// The count is derived from the runtime's [`SubscribedStatus`](`CallbackTableTypes::SubscribedStatus`).
+/// For [`GlobalSignalsRuntime`](`flourish::GlobalSignalsRuntime`), that's a [`bool`],
+/// so the count only toggles between `0` and `1`.
+/// [`GlobalCountingSignalsRuntime`](`flourish::GlobalCountingSignalsRuntime`) reports the total.
+///
+/// The count is updated asynchronously (via [`set`](`Signal::set`)), as the runtime notifies about
+/// subscription changes.
+///
+/// Both signals use the runtime of `target`.
+///
+/// ```
+/// # {
+/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+/// use flourish::GlobalSignalsRuntime;
+/// use flourish_extra::count_subscribers;
+///
+/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+/// type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+///
+/// let target = Signal::cell(1);
+/// let (tap, count) = count_subscribers(target.as_dyn());
+/// assert_eq!(count.get(), 0);
+///
+/// let sub = Subscription::computed(|| tap.get());
+/// assert_eq!(count.get(), 1);
+///
+/// drop(sub);
+/// assert_eq!(count.get(), 0);
+/// # }
+/// ```
+pub fn count_subscribers<'a, T: 'a + Send + Clone, SR: 'a + SignalsRuntimeRef>(
+	target: &SignalDyn<'a, T, SR>,
+) -> (
+	SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>,
+	SignalArc<usize, impl 'a + Sized + UnmanagedSignal<usize, SR>, SR>,
+)
+where
+	<SR::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus: SubscriberCount,
+{
+	let target = target.to_owned();
+	let runtime = target.clone_runtime_ref();
+
+	let count = Signal::new(inert_cell(0, runtime.clone()));
+	let tap = Signal::computed_reactive_with_runtime(
+		move || target.get_clone_exclusive(),
+		{
+			let count = count.clone();
+			move |status: <SR::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus| {
+				count.set(usize::try_from(status.subscriber_count()).unwrap_or(usize::MAX));
+			}
+		},
+		runtime,
+	);
+
+	(tap, count)
+}
@@ -0,0 +1,139 @@
+use std::{
+	future::Future,
+	sync::{
+		atomic::{AtomicBool, Ordering},
+		Arc,
+	},
+	time::Duration,
+};
+
+use flourish::{
+	unmanaged::{inert_cell, UnmanagedSignal},
+	Propagation, Signal, SignalArc, SignalsRuntimeRef, Timer,
+};
+
+/// Like [`Signal::distinct`], but additionally throttles propagation of *different* values to at most
+/// one per `min_interval`.
+///
+/// Each propagated change opens a window of `min_interval`. Changes during that window are held back,
+/// and once it closes, the latest result of `fn_pin` (if different) is emitted and opens the next window.  
+/// The first value always propagates immediately.
+///
+/// `sleeper` is called with `min_interval` whenever a window opens and **should** return a [`Future`]
+/// that completes once that time has passed. It's polled directly from its [`Waker`](`core::task::Waker`),
+/// so no executor is necessary, but it's up to the sleeper to wake it eventually.
+///
+/// Built on [`Signal::reduced`]. Uses the default signals runtime; see [`distinct_window_with_runtime`] to choose one.
+///
+/// ```
+/// # {
+/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+/// use std::time::Duration;
+///
+/// use flourish::GlobalSignalsRuntime;
+/// use flourish_extra::distinct_window;
+///
+/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+///
+/// let input = Signal::cell(1);
+/// # let sleep = |_| std::future::pending();
+/// let calm = distinct_window::<_, GlobalSignalsRuntime, _>(
+/// 	move || input.get(),
+/// 	Duration::from_millis(100),
+/// 	sleep,
+/// );
+/// assert_eq!(calm.get(), 1);
+/// # }
+/// ```
+pub fn distinct_window<
+	'a,
+	T: 'a + Send + PartialEq,
+	SR: 'static + SignalsRuntimeRef + Default,
+	F: 'static + Send + Future<Output = ()>,
+>(
+	fn_pin: impl 'a + Send + FnMut() -> T,
+	min_interval: Duration,
+	sleeper: impl 'a + Send + FnMut(Duration) -> F,
+) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR> {
+	distinct_window_with_runtime(fn_pin, min_interval, sleeper, SR::default())
+}
+
+/// Like [`Signal::distinct_with_runtime`], but additionally throttles propagation of *different* values to at most
+/// one per `min_interval`.
+///
+/// Each propagated change opens a window of `min_interval`. Changes during that window are held back,
+/// and once it closes, the latest result of `fn_pin` (if different) is emitted and opens the next window.  
+/// The first value always propagates immediately.
+///
+/// `sleeper` is called with `min_interval` whenever a window opens and **should** return a [`Future`]
+/// that completes once that time has passed. It's polled directly from its [`Waker`](`core::task::Waker`),
+/// so no executor is necessary, but it's up to the sleeper to wake it eventually.
+///
+/// Built on [`Signal::reduced_with_runtime`].
+pub fn distinct_window_with_runtime<
+	'a,
+	T: 'a + Send + PartialEq,
+	SR: 'static + SignalsRuntimeRef,
+	F: 'static + Send + Future<Output = ()>,
+>(
+	mut fn_pin: impl 'a + Send + FnMut() -> T,
+	min_interval: Duration,
+	mut sleeper: impl 'a + Send + FnMut(Duration) -> F,
+	runtime: SR,
+) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR> {
+	// Set when a window closes, which re-runs `fn_pin` to emit the latest value.
+	let closed = SignalArc::new(inert_cell((), runtime.clone()));
+	let open = Arc::new(AtomicBool::new(false));
+	let mut window = Window(None);
+	Signal::reduced_with_runtime(
+		{
+			let closed = closed.clone();
+			move || {
+				closed.touch();
+				fn_pin()
+			}
+		},
+		move |value, new_value| {
+			if *value == new_value || open.load(Ordering::Acquire) {
+				return Propagation::Halt;
+			}
+			*value = new_value;
+
+			open.store(true, Ordering::Release);
+			let sleep = closed
+				.clone_runtime_ref()
+				.run_detached(|| sleeper(min_interval));
+			window.set(Timer::start(sleep, {
+				let open = Arc::clone(&open);
+				let closed = closed.downgrade();
+				move || {
+					open.store(false, Ordering::Release);
+					if let Some(closed) = closed.upgrade() {
+						closed.set(());
+					}
+				}
+			}));
+			Propagation::Propagate
+		},
+		runtime,
+	)
+}
+
+/// Cancels the pending sleeper, if any, when the signal is dropped.
+struct Window(Option<Arc<Timer>>);
+
+impl Window {
+	fn set(&mut self, timer: Arc<Timer>) {
+		if let Some(previous) = self.0.replace(timer) {
+			previous.cancel();
+		}
+	}
+}
+
+impl Drop for Window {
+	fn drop(&mut self) {
+		if let Some(timer) = self.0.take() {
+			timer.cancel();
+		}
+	}
+}
@@ -0,0 +1,50 @@
+use flourish::{Effect, Propagation, SignalArcDynCell, SignalsRuntimeRef};
+
+/// Appends each result of `fn_pin` to the [`Vec`] in `buffer` for as long as the returned [`Effect`] is alive.
+///
+/// `fn_pin` runs immediately and then whenever its dependencies change. The accumulator is owned by
+/// `buffer` rather than the [`Effect`], so several producers can share one sink.
+///
+/// Each result is appended through one [`update_dyn`](`flourish::Signal::update_dyn`), so appends from
+/// distinct producers don't interleave below the level of individual values and appear in the order in which
+/// the signals runtime applies those updates.  
+/// Like any [`Effect`], this one refreshes at most once per batch
+/// (see [`SignalsRuntimeRef::hint_batched_updates`]), so it appends only the result for the latest upstream state.
+///
+/// ```
+/// # {
+/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+/// use flourish::GlobalSignalsRuntime;
+/// use flourish_extra::fold_into;
+///
+/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+///
+/// let a = Signal::cell(1);
+/// let b = Signal::cell(10);
+/// let sink = Signal::cell(Vec::new()).into_dyn_cell();
+///
+/// let _a = fold_into(sink.clone(), || a.get());
+/// let _b = fold_into(sink.clone(), || b.get());
+/// assert_eq!(sink.get_clone(), [1, 10]);
+///
+/// a.set(2);
+/// assert_eq!(sink.get_clone(), [1, 10, 2]);
+/// # }
+/// ```
+pub fn fold_into<'a, T: 'static + Send, SR: 'a + SignalsRuntimeRef>(
+	buffer: SignalArcDynCell<'a, Vec<T>, SR>,
+	mut fn_pin: impl 'a + Send + FnMut() -> T,
+) -> Effect<'a, SR> {
+	let runtime = buffer.clone_runtime_ref();
+	Effect::new_with_runtime(
+		move || {
+			let value = fn_pin();
+			buffer.update_dyn(Box::new(move |values| {
+				values.push(value);
+				Propagation::Propagate
+			}));
+		},
+		drop,
+		runtime,
+	)
+}
@@ -0,0 +1,111 @@
+use flourish::{
+	unmanaged::UnmanagedSignal, Propagation, Signal, SignalArc, SignalArcDyn, SignalsRuntimeRef,
+};
+
+/// Exposes the value of whichever of `sources` propagated most recently, as one [`SignalArc`].
+///
+/// Each source is tracked through a change counter that's bumped whenever it propagates,
+/// so sources are told apart by their updates rather than by comparing values.
+/// Changes are only noticed when the combined signal refreshes. Initially, and whenever several sources
+/// changed since then, the one with the **lowest index** in `sources` wins, not the one that changed last.  
+/// While the combined signal is subscribed, that happens only for changes within the same batch.
+/// Otherwise it's refreshed lazily when read, so this applies to all changes since the previous read.
+///
+/// The combined signal keeps `sources` alive.
+///
+/// All `sources` **must** share the default signals runtime, which the combined signal uses.
+/// See [`latest_of_with_runtime`] to choose a different one.
+///
+/// ```
+/// # {
+/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+/// use flourish::GlobalSignalsRuntime;
+/// use flourish_extra::latest_of;
+///
+/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+///
+/// let mouse = Signal::cell("mouse");
+/// let keyboard = Signal::cell("keyboard");
+///
+/// let input = latest_of(vec![
+/// 	mouse.to_owned().into_dyn(),
+/// 	keyboard.to_owned().into_dyn(),
+/// ]);
+/// assert_eq!(input.get(), "mouse");
+///
+/// keyboard.set("enter");
+/// assert_eq!(input.get(), "enter");
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Iff `sources` is empty.
+pub fn latest_of<'a, T: 'a + Send + Sync + Clone, SR: 'a + SignalsRuntimeRef + Default>(
+	sources: Vec<SignalArcDyn<'a, T, SR>>,
+) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR> {
+	latest_of_with_runtime(sources, SR::default())
+}
+
+/// Exposes the value of whichever of `sources` propagated most recently, as one [`SignalArc`].
+///
+/// Each source is tracked through a change counter that's bumped whenever it propagates,
+/// so sources are told apart by their updates rather than by comparing values.
+/// Changes are only noticed when the combined signal refreshes. Initially, and whenever several sources
+/// changed since then, the one with the **lowest index** in `sources` wins, not the one that changed last.  
+/// While the combined signal is subscribed, that happens only for changes within the same batch.
+/// Otherwise it's refreshed lazily when read, so this applies to all changes since the previous read.
+///
+/// The combined signal keeps `sources` alive.
+///
+/// All `sources` **must** share `runtime`, which the combined signal uses.
+///
+/// # Panics
+///
+/// Iff `sources` is empty.
+pub fn latest_of_with_runtime<'a, T: 'a + Send + Sync + Clone, SR: 'a + SignalsRuntimeRef>(
+	sources: Vec<SignalArcDyn<'a, T, SR>>,
+	runtime: SR,
+) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR> {
+	assert!(
+		!sources.is_empty(),
+		"`latest_of` requires at least one source."
+	);
+
+	let counters = sources
+		.iter()
+		.map(|source| {
+			let source = source.clone();
+			Signal::folded_with_runtime(
+				0_u64,
+				move |count| {
+					source.touch();
+					*count += 1;
+					Propagation::Propagate
+				},
+				runtime.clone(),
+			)
+			.into_dyn()
+		})
+		.collect::<Vec<_>>();
+
+	let mut seen = vec![0_u64; counters.len()];
+	let mut latest = 0;
+	Signal::computed_with_runtime(
+		move || {
+			let mut changed = None;
+			for (index, (counter, seen)) in counters.iter().zip(&mut seen).enumerate() {
+				let count = counter.get();
+				if count != *seen {
+					*seen = count;
+					changed.get_or_insert(index);
+				}
+			}
+			if let Some(changed) = changed {
+				latest = changed;
+			}
+			sources[latest].get_clone()
+		},
+		runtime,
+	)
+}
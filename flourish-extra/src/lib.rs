@@ -0,0 +1,42 @@
+#![warn(clippy::pedantic)]
+#![warn(missing_docs)]
+#![warn(unreachable_pub)]
+//! <details><summary>README (click to expand)</summary>
+//!
+#![cfg_attr(feature = "_doc", doc = include_str!("../README.md"))]
+//!
+//! </details>
+//!
+//! Additional signal combinators for [*flourish*](`flourish`).
+
+mod buffer_until;
+pub use buffer_until::{buffer_until, BufferMode};
+
+mod combine_latest;
+
+mod count_subscribers;
+pub use count_subscribers::count_subscribers;
+
+mod distinct_window;
+pub use distinct_window::{distinct_window, distinct_window_with_runtime};
+
+mod fold_into;
+pub use fold_into::fold_into;
+
+mod latest_of;
+pub use latest_of::{latest_of, latest_of_with_runtime};
+
+mod merge;
+pub use merge::{merge, merge_with_runtime};
+
+mod sample;
+pub use sample::sample;
+
+mod start_with;
+pub use start_with::{start_with, start_with_with_runtime};
+
+#[doc(hidden)]
+pub mod __ {
+	pub use super::combine_latest::{assert_same_runtime, InRuntime};
+	pub use flourish::Signal;
+}
@@ -0,0 +1,57 @@
+use flourish::{unmanaged::UnmanagedSignal, Signal, SignalArc, SignalArcDyn, SignalsRuntimeRef};
+
+/// Merges event-like unit signals into one [`SignalArc`] that propagates whenever any of `sources` does.
+///
+/// Each source is recorded as dependency through [`Signal::touch`], so their values aren't read.  
+/// The merged signal keeps `sources` alive. Without any sources, it never propagates.
+///
+/// All `sources` **must** share the default signals runtime, which the merged signal uses.  
+/// See [`merge_with_runtime`] to choose a different one.
+///
+/// ```
+/// # {
+/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+/// use flourish::GlobalSignalsRuntime;
+/// use flourish_extra::merge;
+///
+/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+/// type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+///
+/// let clicked = Signal::cell(());
+/// let submitted = Signal::cell(());
+///
+/// let any = merge(vec![
+/// 	clicked.to_owned().into_dyn(),
+/// 	submitted.to_owned().into_dyn(),
+/// ]);
+/// let _sub = Subscription::computed(move || any.touch());
+///
+/// clicked.set(());
+/// submitted.set(());
+/// # }
+/// ```
+pub fn merge<'a, SR: 'a + SignalsRuntimeRef + Default>(
+	sources: Vec<SignalArcDyn<'a, (), SR>>,
+) -> SignalArc<(), impl 'a + Sized + UnmanagedSignal<(), SR>, SR> {
+	merge_with_runtime(sources, SR::default())
+}
+
+/// Merges event-like unit signals into one [`SignalArc`] that propagates whenever any of `sources` does.
+///
+/// Each source is recorded as dependency through [`Signal::touch`], so their values aren't read.  
+/// The merged signal keeps `sources` alive. Without any sources, it never propagates.
+///
+/// All `sources` **must** share `runtime`, which the merged signal uses.
+pub fn merge_with_runtime<'a, SR: 'a + SignalsRuntimeRef>(
+	sources: Vec<SignalArcDyn<'a, (), SR>>,
+	runtime: SR,
+) -> SignalArc<(), impl 'a + Sized + UnmanagedSignal<(), SR>, SR> {
+	Signal::computed_with_runtime(
+		move || {
+			for source in &sources {
+				source.touch();
+			}
+		},
+		runtime,
+	)
+}
@@ -0,0 +1,44 @@
+use flourish::{unmanaged::UnmanagedSignal, Signal, SignalArc, SignalDyn, SignalsRuntimeRef};
+
+/// Samples `value_fn_pin` into a [`SignalArc`] once initially and then each time `trigger` changes.
+///
+/// Only `trigger` is recorded as dependency. `value_fn_pin` runs detached,
+/// so changes to signals it reads don't refresh the result until `trigger` does.
+///
+/// The returned signal uses the runtime of `trigger`.
+///
+/// ```
+/// # {
+/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+/// use flourish::GlobalSignalsRuntime;
+/// use flourish_extra::sample;
+///
+/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+///
+/// let value = Signal::cell(1);
+/// let tick = Signal::cell(());
+///
+/// let sampled = sample(|| value.get(), tick.as_dyn());
+/// assert_eq!(sampled.get(), 1);
+///
+/// value.set(2);
+/// assert_eq!(sampled.get(), 1);
+///
+/// tick.set(());
+/// assert_eq!(sampled.get(), 2);
+/// # }
+/// ```
+pub fn sample<'a, T: 'a + Send, SR: 'a + SignalsRuntimeRef>(
+	mut value_fn_pin: impl 'a + Send + FnMut() -> T,
+	trigger: &SignalDyn<'a, (), SR>,
+) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR> {
+	let trigger = trigger.to_owned();
+	let runtime = trigger.clone_runtime_ref();
+	Signal::computed_with_runtime(
+		move || {
+			trigger.touch();
+			trigger.clone_runtime_ref().run_detached(&mut value_fn_pin)
+		},
+		runtime,
+	)
+}
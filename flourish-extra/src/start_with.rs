@@ -0,0 +1,49 @@
+use flourish::{unmanaged::UnmanagedSignal, Signal, SignalArc, SignalsRuntimeRef};
+
+/// A signal that holds `initial` at first and then the results of `fn_pin`.
+///
+/// The first evaluation of the returned signal only schedules a refresh instead of calling `fn_pin`,
+/// so subscribed dependents observe `initial` once before the first computed value.  
+/// Reading the signal directly while it's unsubscribed **may** coalesce the two,
+/// since the signals runtime can apply the scheduled refresh before the read returns.
+///
+/// Wraps [`Signal::computed_with_placeholder`]. Uses the default signals runtime; see [`start_with_with_runtime`] to choose one.
+///
+/// ```
+/// # {
+/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+/// use flourish::GlobalSignalsRuntime;
+/// use flourish_extra::start_with;
+///
+/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+/// type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+///
+/// let input = Signal::cell(1);
+/// let label = start_with::<_, GlobalSignalsRuntime>(String::from("Loading…"), move || {
+/// 	input.get().to_string()
+/// });
+/// let _ui = Subscription::computed(move || println!("{}", label.get_clone())); // Prints "Loading…", then "1".
+/// # }
+/// ```
+pub fn start_with<'a, T: 'a + Send, SR: 'a + SignalsRuntimeRef + Default>(
+	initial: T,
+	fn_pin: impl 'a + Send + FnMut() -> T,
+) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR> {
+	start_with_with_runtime(initial, fn_pin, SR::default())
+}
+
+/// A signal that holds `initial` at first and then the results of `fn_pin`.
+///
+/// The first evaluation of the returned signal only schedules a refresh instead of calling `fn_pin`,
+/// so subscribed dependents observe `initial` once before the first computed value.  
+/// Reading the signal directly while it's unsubscribed **may** coalesce the two,
+/// since the signals runtime can apply the scheduled refresh before the read returns.
+///
+/// Wraps [`Signal::computed_with_placeholder_with_runtime`].
+pub fn start_with_with_runtime<'a, T: 'a + Send, SR: 'a + SignalsRuntimeRef>(
+	initial: T,
+	fn_pin: impl 'a + Send + FnMut() -> T,
+	runtime: SR,
+) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR> {
+	Signal::computed_with_placeholder_with_runtime(initial, fn_pin, runtime)
+}
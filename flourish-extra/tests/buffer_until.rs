@@ -0,0 +1,55 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::GlobalSignalsRuntime;
+use flourish_extra::{buffer_until, BufferMode};
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn latest_collapses() {
+	let v = &Validator::new();
+
+	let value = Signal::cell(1);
+	let flush = Signal::cell(());
+
+	let buffered = buffer_until(|| value.get(), flush.as_dyn(), BufferMode::Latest);
+	let sub = Subscription::computed(|| v.push(buffered.get_clone()));
+	v.expect([vec![1]]);
+
+	value.set(2);
+	value.set(3);
+	v.expect([]);
+
+	flush.set(());
+	v.expect([vec![3]]);
+
+	drop(sub);
+}
+
+#[test]
+fn all_lists_changes() {
+	let v = &Validator::new();
+
+	let value = Signal::cell(1);
+	let flush = Signal::cell(());
+
+	let buffered = buffer_until(|| value.get(), flush.as_dyn(), BufferMode::All);
+	let sub = Subscription::computed(|| v.push(buffered.get_clone()));
+	v.expect([vec![1]]);
+
+	value.set(2);
+	value.set(3);
+	v.expect([]);
+
+	flush.set(());
+	v.expect([vec![2, 3]]);
+
+	flush.set(());
+	v.expect([vec![]]);
+
+	drop(sub);
+}
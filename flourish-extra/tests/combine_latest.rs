@@ -0,0 +1,43 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::{GlobalSignalsRuntime, SignalArcDyn, SignalsRuntimeRef};
+use flourish_extra::combine_latest;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn single_computation() {
+	let v = &Validator::new();
+
+	let a = Signal::cell(1);
+	let b = Signal::cell("b");
+	let c = Signal::cell(3u16);
+	let d = Signal::cell('d');
+	let e = Signal::cell(5u8);
+	let f = Signal::cell(());
+	let g = Signal::cell(7i64);
+	let h = Signal::cell("h".to_string());
+
+	let combined = combine_latest!(&a, &b, &c, &d, &e, &f, &g, &h);
+	let sub = Signal::computed(|| v.push(combined.get_clone())).into_subscription();
+	v.expect([(1, "b", 3, 'd', 5, (), 7, "h".to_string())]);
+
+	GlobalSignalsRuntime.hint_batched_updates(|| {
+		a.set(2);
+		h.set("hh".to_string());
+	});
+	v.expect([(2, "b", 3, 'd', 5, (), 7, "hh".to_string())]);
+
+	drop(sub);
+}
+
+#[allow(dead_code)]
+fn generic_runtime<SR: SignalsRuntimeRef>(
+	a: SignalArcDyn<'static, i32, SR>,
+	b: SignalArcDyn<'static, bool, SR>,
+) -> (i32, bool) {
+	combine_latest!(a, b).get()
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::GlobalSignalsRuntime;
+use flourish_extra::count_subscribers;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn toggles_with_subscriptions() {
+	let v = &Validator::new();
+
+	let target = Signal::cell("a");
+	let (tap, count) = count_subscribers(target.as_dyn());
+	let _count_sub = Subscription::computed(|| v.push(count.get()));
+	v.expect([0]);
+
+	let a = Subscription::computed(|| tap.get());
+	v.expect([1]);
+
+	// `GlobalSignalsRuntime` only reports whether there are any subscribers.
+	let b = Subscription::computed(|| tap.get());
+	v.expect([]);
+
+	drop(a);
+	v.expect([]);
+
+	drop(b);
+	v.expect([0]);
+}
+
+#[test]
+fn passes_values_through() {
+	let target = Signal::cell(1);
+	let (tap, count) = count_subscribers(target.as_dyn());
+
+	let sub = Subscription::computed(|| tap.get());
+	assert_eq!(sub.get(), 1);
+
+	target.set(2);
+	assert_eq!(sub.get(), 2);
+	assert_eq!(count.get(), 1);
+}
+
+#[test]
+fn counting_runtime() {
+	use flourish::GlobalCountingSignalsRuntime;
+
+	type Signal<T, S> = flourish::Signal<T, S, GlobalCountingSignalsRuntime>;
+	type Subscription<T, S> = flourish::Subscription<T, S, GlobalCountingSignalsRuntime>;
+
+	let v = &Validator::new();
+
+	let target = Signal::cell("a");
+	let (tap, count) = count_subscribers(target.as_dyn());
+	let _count_sub = Subscription::computed(|| v.push(count.get()));
+	v.expect([0]);
+
+	let a = Subscription::computed(|| tap.get());
+	v.expect([1]);
+
+	let b = Subscription::computed(|| tap.get());
+	v.expect([2]);
+
+	drop(a);
+	v.expect([1]);
+
+	drop(b);
+	v.expect([0]);
+}
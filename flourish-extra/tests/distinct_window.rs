@@ -0,0 +1,121 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use std::{
+	future::Future,
+	pin::Pin,
+	sync::{Arc, Mutex},
+	task::{Context, Poll, Waker},
+	time::Duration,
+};
+
+use flourish::GlobalSignalsRuntime;
+use flourish_extra::distinct_window;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+/// A sleeper [`Future`] that completes once [`Sleep::elapse`] is called on a clone of it.
+#[derive(Clone, Default)]
+struct Sleep(Arc<Mutex<(bool, Option<Waker>)>>);
+
+impl Sleep {
+	fn elapse(&self) {
+		let waker = {
+			let mut state = self.0.lock().unwrap();
+			state.0 = true;
+			state.1.take()
+		};
+		if let Some(waker) = waker {
+			waker.wake();
+		}
+	}
+}
+
+impl Future for Sleep {
+	type Output = ();
+
+	fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+		let mut state = self.0.lock().unwrap();
+		if state.0 {
+			Poll::Ready(())
+		} else {
+			state.1 = Some(cx.waker().clone());
+			Poll::Pending
+		}
+	}
+}
+
+#[test]
+fn throttles_changes() {
+	let v = &Validator::new();
+	let windows = &Mutex::new(Vec::new());
+
+	let input = Signal::cell(1);
+	let calm = distinct_window::<_, GlobalSignalsRuntime, _>(
+		|| input.get(),
+		Duration::from_secs(1),
+		|min_interval| {
+			assert_eq!(min_interval, Duration::from_secs(1));
+			let sleep = Sleep::default();
+			windows.lock().unwrap().push(sleep.clone());
+			sleep
+		},
+	);
+	let sub = Subscription::computed(|| v.push(calm.get()));
+	v.expect([1]);
+
+	// The first change propagates immediately and opens a window.
+	input.set(2);
+	v.expect([2]);
+	assert_eq!(windows.lock().unwrap().len(), 1);
+
+	// Changes during the window are held back.
+	input.set(3);
+	input.set(4);
+	v.expect([]);
+
+	// The latest value is emitted when the window closes, which opens the next one.
+	let first = windows.lock().unwrap().remove(0);
+	first.elapse();
+	v.expect([4]);
+	assert_eq!(windows.lock().unwrap().len(), 1);
+
+	// Equal values never propagate.
+	input.set(5);
+	input.set(4);
+	let second = windows.lock().unwrap().remove(0);
+	second.elapse();
+	v.expect([]);
+	assert!(windows.lock().unwrap().is_empty());
+
+	// With the window closed, the next change propagates immediately again.
+	input.set(6);
+	v.expect([6]);
+
+	// Dropping the signal cancels the pending sleeper.
+	let third = windows.lock().unwrap().remove(0);
+	drop(sub);
+	drop(calm);
+	assert_eq!(Arc::strong_count(&third.0), 1);
+}
+
+#[test]
+fn first_value_is_immediate() {
+	let windows = &Mutex::new(Vec::new());
+
+	let input = Signal::cell("a");
+	let calm = distinct_window::<_, GlobalSignalsRuntime, _>(
+		|| input.get(),
+		Duration::from_millis(10),
+		|_| {
+			let sleep = Sleep::default();
+			windows.lock().unwrap().push(sleep.clone());
+			sleep
+		},
+	);
+	assert_eq!(calm.get(), "a");
+	assert!(windows.lock().unwrap().is_empty());
+}
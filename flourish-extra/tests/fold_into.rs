@@ -0,0 +1,33 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::{GlobalSignalsRuntime, SignalsRuntimeRef};
+use flourish_extra::fold_into;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+
+#[test]
+fn shared_sink() {
+	let a = Signal::cell(1);
+	let b = Signal::cell(10);
+	let sink = Signal::cell(Vec::new()).into_dyn_cell();
+
+	let fold_a = fold_into(sink.clone(), || a.get());
+	let fold_b = fold_into(sink.clone(), || b.get());
+	assert_eq!(sink.get_clone(), [1, 10]);
+
+	b.set(20);
+	a.set(2);
+	assert_eq!(sink.get_clone(), [1, 10, 20, 2]);
+
+	GlobalSignalsRuntime.hint_batched_updates(|| {
+		a.set(3);
+		a.set(4);
+	});
+	assert_eq!(sink.get_clone(), [1, 10, 20, 2, 4]);
+
+	drop(fold_a);
+	a.set(5);
+	assert_eq!(sink.get_clone(), [1, 10, 20, 2, 4]);
+
+	drop(fold_b);
+}
@@ -0,0 +1,101 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::{GlobalSignalsRuntime, SignalsRuntimeRef};
+use flourish_extra::latest_of;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn follows_most_recent_source() {
+	let v = &Validator::new();
+
+	let a = Signal::cell(1);
+	let b = Signal::cell(2);
+	let c = Signal::cell(3);
+
+	let latest = latest_of(vec![
+		a.to_owned().into_dyn(),
+		b.to_owned().into_dyn(),
+		c.to_owned().into_dyn(),
+	]);
+	let _sub = Subscription::computed(|| v.push(latest.get()));
+	v.expect([1]);
+
+	c.set(4);
+	v.expect([4]);
+
+	b.set(5);
+	v.expect([5]);
+
+	// Same value, but still the most recent change.
+	c.set(4);
+	v.expect([4]);
+
+	a.set(6);
+	v.expect([6]);
+}
+
+#[test]
+fn halted_source_doesnt_win() {
+	let a = Signal::cell(1);
+	let b = Signal::cell(2);
+
+	let latest = latest_of(vec![a.to_owned().into_dyn(), b.to_owned().into_dyn()]);
+	let _sub = latest.to_subscription();
+
+	b.set(3);
+	assert_eq!(latest.get(), 3);
+
+	a.set_if_distinct(1);
+	assert_eq!(latest.get(), 3);
+}
+
+#[test]
+fn tie_goes_to_lowest_index() {
+	let a = Signal::cell(1);
+	let b = Signal::cell(2);
+
+	let latest = latest_of(vec![a.to_owned().into_dyn(), b.to_owned().into_dyn()]);
+	let _sub = latest.to_subscription();
+
+	b.set(3);
+	assert_eq!(latest.get(), 3);
+
+	GlobalSignalsRuntime.hint_batched_updates(|| {
+		a.set(4);
+		b.set(5);
+	});
+	assert_eq!(latest.get(), 4);
+}
+
+#[test]
+fn lazy_read_goes_to_lowest_index() {
+	let a = Signal::cell(1);
+	let b = Signal::cell(2);
+
+	let latest = latest_of(vec![a.to_owned().into_dyn(), b.to_owned().into_dyn()]);
+	assert_eq!(latest.get(), 1);
+
+	// Both change before the next read, so the order of the changes is lost.
+	b.set(3);
+	a.set(4);
+	assert_eq!(latest.get(), 4);
+
+	a.set(5);
+	b.set(6);
+	assert_eq!(latest.get(), 5);
+
+	// A single change since the previous read is still picked up.
+	b.set(7);
+	assert_eq!(latest.get(), 7);
+}
+
+#[test]
+#[should_panic = "at least one source"]
+fn empty_panics() {
+	let _ = latest_of::<i32, GlobalSignalsRuntime>(Vec::new());
+}
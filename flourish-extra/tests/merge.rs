@@ -0,0 +1,51 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::GlobalSignalsRuntime;
+use flourish_extra::merge;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn propagates_for_each_source() {
+	let v = &Validator::new();
+
+	let a = Signal::cell(());
+	let b = Signal::cell(());
+
+	let merged = merge(vec![a.to_owned().into_dyn(), b.to_owned().into_dyn()]);
+	let sub = Subscription::computed(|| {
+		merged.touch();
+		v.push(());
+	});
+	v.expect([()]);
+
+	a.set(());
+	v.expect([()]);
+
+	b.set(());
+	v.expect([()]);
+
+	drop(sub);
+}
+
+#[test]
+fn empty_never_propagates() {
+	let v = &Validator::new();
+
+	let merged = merge::<GlobalSignalsRuntime>(Vec::new());
+	let sub = Subscription::computed(|| {
+		merged.touch();
+		v.push(());
+	});
+	v.expect([()]);
+
+	let unrelated = Signal::cell(());
+	unrelated.set(());
+	v.expect([]);
+
+	drop(sub);
+}
@@ -0,0 +1,31 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::GlobalSignalsRuntime;
+use flourish_extra::sample;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn samples_on_trigger_only() {
+	let v = &Validator::new();
+
+	let value = Signal::cell(1);
+	let trigger = Signal::cell(());
+
+	let sampled = sample(|| value.get(), trigger.as_dyn());
+	let sub = Subscription::computed(|| v.push(sampled.get()));
+	v.expect([1]);
+
+	value.set(2);
+	value.set(3);
+	v.expect([]);
+
+	trigger.set(());
+	v.expect([3]);
+
+	drop(sub);
+}
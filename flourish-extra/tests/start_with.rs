@@ -0,0 +1,44 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::GlobalSignalsRuntime;
+use flourish_extra::start_with;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn subscribed_observes_initial_first() {
+	let v = &Validator::new();
+
+	let input = Signal::cell(1);
+	let signal = start_with::<_, GlobalSignalsRuntime>(0, {
+		let input = input.to_owned();
+		move || input.get()
+	});
+	let sub = Subscription::computed(|| v.push(signal.get()));
+	v.expect([0, 1]);
+
+	input.set(2);
+	v.expect([2]);
+
+	drop(sub);
+}
+
+#[test]
+fn unsubscribed_follows_input() {
+	let input = Signal::cell(1);
+	let signal = start_with::<_, GlobalSignalsRuntime>(0, {
+		let input = input.to_owned();
+		move || input.get()
+	});
+
+	// The initial value may be coalesced away here.
+	let _ = signal.get();
+	assert_eq!(signal.get(), 1);
+
+	input.set(2);
+	assert_eq!(signal.get(), 2);
+}
@@ -0,0 +1,79 @@
+use alloc::sync::Arc;
+use core::{
+	future::Future,
+	pin::Pin,
+	sync::atomic::{AtomicBool, Ordering},
+	task::{ready, Context, Poll, Waker},
+};
+
+use pin_project::pin_project;
+
+use crate::sync::Mutex;
+
+/// Aborts the associated [`Abortable`] when dropped.
+pub(crate) struct AbortHandle(Arc<Shared>);
+
+struct Shared {
+	aborted: AtomicBool,
+	waker: Mutex<Option<Waker>>,
+}
+
+/// Completes with [`None`] (and drops the inner [`Future`]) as soon as it's polled after its [`AbortHandle`] was dropped.
+#[pin_project]
+pub(crate) struct Abortable<F> {
+	#[pin]
+	future: Option<F>,
+	shared: Arc<Shared>,
+}
+
+pub(crate) fn abortable<F: Future>(future: F) -> (Abortable<F>, AbortHandle) {
+	let shared = Arc::new(Shared {
+		aborted: AtomicBool::new(false),
+		waker: Mutex::new(None),
+	});
+	(
+		Abortable {
+			future: Some(future),
+			shared: Arc::clone(&shared),
+		},
+		AbortHandle(shared),
+	)
+}
+
+impl Drop for AbortHandle {
+	fn drop(&mut self) {
+		self.0.aborted.store(true, Ordering::Release);
+		let waker = self.0.waker.lock().unwrap().take();
+		if let Some(waker) = waker {
+			waker.wake();
+		}
+	}
+}
+
+impl<F: Future> Future for Abortable<F> {
+	type Output = Option<F::Output>;
+
+	fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		let mut this = self.project();
+		{
+			// Registered first, so that an abort can't be missed.
+			let mut waker = this.shared.waker.lock().unwrap();
+			match &mut *waker {
+				Some(waker) => waker.clone_from(cx.waker()),
+				None => *waker = Some(cx.waker().clone()),
+			}
+		}
+
+		if this.shared.aborted.load(Ordering::Acquire) {
+			this.future.set(None);
+			return Poll::Ready(None);
+		}
+
+		let Some(future) = this.future.as_mut().as_pin_mut() else {
+			return Poll::Ready(None);
+		};
+		let output = ready!(future.poll(cx));
+		this.future.set(None);
+		Poll::Ready(Some(output))
+	}
+}
@@ -0,0 +1,80 @@
+use alloc::sync::Arc;
+use core::{
+	pin::Pin,
+	task::{Context, Poll, Waker},
+};
+
+use futures_core::Stream;
+use isoprenoid::runtime::SignalsRuntimeRef;
+
+use crate::{effect_slot::EffectSlot, sync::Mutex};
+
+/// Acknowledges one value yielded by [`Subscription::with_ack`](`crate::Subscription::with_ack`) when dropped.
+///
+/// Until then, the stream doesn't yield further values.
+///
+/// Since 0.2.1.
+#[derive(Debug)]
+pub struct Ack(Arc<Mutex<Gate>>);
+
+#[derive(Debug)]
+struct Gate {
+	outstanding: bool,
+	waker: Option<Waker>,
+}
+
+impl Ack {
+	/// Acknowledges the value. This is the same as dropping `self`.
+	///
+	/// Since 0.2.1.
+	pub fn ack(self) {}
+}
+
+impl Drop for Ack {
+	fn drop(&mut self) {
+		let mut gate = self.0.lock().unwrap();
+		gate.outstanding = false;
+		let waker = gate.waker.take();
+		drop(gate);
+		if let Some(waker) = waker {
+			waker.wake();
+		}
+	}
+}
+
+/// The [`Stream`] returned by [`Subscription::with_ack`](`crate::Subscription::with_ack`).
+///
+/// Values coalesce while paused.
+pub(crate) struct Acked<'a, T, SR: 'a + SignalsRuntimeRef> {
+	latest: EffectSlot<'a, T, SR>,
+	gate: Arc<Mutex<Gate>>,
+}
+
+impl<'a, T: 'a + Send, SR: 'a + SignalsRuntimeRef> Acked<'a, T, SR> {
+	pub(crate) fn new(mut get_fn_pin: impl 'a + Send + FnMut() -> T, runtime: SR) -> Self {
+		Self {
+			latest: EffectSlot::new(move || Some(get_fn_pin()), runtime),
+			gate: Arc::new(Mutex::new(Gate {
+				outstanding: false,
+				waker: None,
+			})),
+		}
+	}
+}
+
+impl<'a, T, SR: 'a + SignalsRuntimeRef> Stream for Acked<'a, T, SR> {
+	type Item = (T, Ack);
+
+	fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		// `gate` stays locked here, so that a concurrent acknowledgement can't miss the waker.
+		let mut gate = self.gate.lock().unwrap();
+		if gate.outstanding {
+			drop(gate.waker.replace(cx.waker().clone()));
+			return Poll::Pending;
+		}
+		self.latest.poll_take(cx).map(|value| {
+			gate.outstanding = true;
+			Some((value, Ack(Arc::clone(&self.gate))))
+		})
+	}
+}
@@ -0,0 +1,20 @@
+use isoprenoid::runtime::SignalsRuntimeRef;
+
+use crate::effect_slot::EffectSlot;
+
+/// The [`Iterator`] returned by [`Signal::iter_changes`](`crate::Signal::iter_changes`).
+pub(crate) struct IterChanges<'a, T, SR: 'a + SignalsRuntimeRef>(EffectSlot<'a, T, SR>);
+
+impl<'a, T: 'a + Send, SR: 'a + SignalsRuntimeRef> IterChanges<'a, T, SR> {
+	pub(crate) fn new(mut get_fn_pin: impl 'a + Send + FnMut() -> T, runtime: SR) -> Self {
+		Self(EffectSlot::new(move || Some(get_fn_pin()), runtime))
+	}
+}
+
+impl<'a, T, SR: 'a + SignalsRuntimeRef> Iterator for IterChanges<'a, T, SR> {
+	type Item = T;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		Some(self.0.take_blocking())
+	}
+}
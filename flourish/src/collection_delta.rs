@@ -0,0 +1,199 @@
+use alloc::vec::Vec;
+use core::{
+	fmt::{self, Debug, Formatter},
+	pin::Pin,
+	task::{ready, Context, Poll},
+};
+#[cfg(feature = "std")]
+use std::{
+	collections::HashMap,
+	hash::{BuildHasher, Hash},
+};
+
+use futures_core::Stream;
+use isoprenoid::runtime::SignalsRuntimeRef;
+
+use crate::effect_slot::EffectSlot;
+
+/// The changes between two snapshots of a [`DiffCollection`], as yielded by [`Signal::diff_collection`](`crate::Signal::diff_collection`).
+///
+/// The order of entries within each list is unspecified for unordered collections.
+///
+/// Since 0.2.1.
+pub struct CollectionDelta<T: DiffCollection> {
+	/// Entries present only in the current snapshot.
+	pub added: Vec<(T::Key, T::Value)>,
+	/// Entries present only in the previous snapshot.
+	pub removed: Vec<(T::Key, T::Value)>,
+	/// Entries present in both snapshots with unequal values, as `(key, previous, current)`.
+	pub changed: Vec<(T::Key, T::Value, T::Value)>,
+}
+
+impl<T: DiffCollection> CollectionDelta<T> {
+	/// Whether this delta contains no changes at all.
+	///
+	/// Since 0.2.1.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+	}
+}
+
+impl<T: DiffCollection> Default for CollectionDelta<T> {
+	fn default() -> Self {
+		Self {
+			added: Vec::new(),
+			removed: Vec::new(),
+			changed: Vec::new(),
+		}
+	}
+}
+
+impl<T: DiffCollection> Clone for CollectionDelta<T>
+where
+	T::Key: Clone,
+	T::Value: Clone,
+{
+	fn clone(&self) -> Self {
+		Self {
+			added: self.added.clone(),
+			removed: self.removed.clone(),
+			changed: self.changed.clone(),
+		}
+	}
+}
+
+impl<T: DiffCollection> Debug for CollectionDelta<T>
+where
+	T::Key: Debug,
+	T::Value: Debug,
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.debug_struct("CollectionDelta")
+			.field("added", &self.added)
+			.field("removed", &self.removed)
+			.field("changed", &self.changed)
+			.finish()
+	}
+}
+
+impl<T: DiffCollection> PartialEq for CollectionDelta<T>
+where
+	T::Key: PartialEq,
+	T::Value: PartialEq,
+{
+	fn eq(&self, other: &Self) -> bool {
+		self.added == other.added && self.removed == other.removed && self.changed == other.changed
+	}
+}
+
+impl<T: DiffCollection> Eq for CollectionDelta<T>
+where
+	T::Key: Eq,
+	T::Value: Eq,
+{
+}
+
+/// Collections that can be compared snapshot to snapshot by value, for [`Signal::diff_collection`](`crate::Signal::diff_collection`).
+///
+/// Since 0.2.1.
+pub trait DiffCollection: Default {
+	/// Identifies an entry across snapshots.
+	type Key;
+	/// The value of an entry.
+	type Value;
+
+	/// Computes the [`CollectionDelta`] that turns `previous` into `current`.
+	fn diff(previous: &Self, current: &Self) -> CollectionDelta<Self>;
+}
+
+/// Compares by position: Common indices with unequal elements are `changed`,
+/// and the tail of the longer [`Vec`] is `added` or `removed` (in index order).
+impl<T: Clone + PartialEq> DiffCollection for Vec<T> {
+	type Key = usize;
+	type Value = T;
+
+	fn diff(previous: &Self, current: &Self) -> CollectionDelta<Self> {
+		let common = previous.len().min(current.len());
+		CollectionDelta {
+			added: current.iter().cloned().enumerate().skip(common).collect(),
+			removed: previous.iter().cloned().enumerate().skip(common).collect(),
+			changed: previous
+				.iter()
+				.zip(current)
+				.enumerate()
+				.filter(|(_, (previous, current))| previous != current)
+				.map(|(index, (previous, current))| (index, previous.clone(), current.clone()))
+				.collect(),
+		}
+	}
+}
+
+/// Compares by key: Entries with equal keys and unequal values are `changed`.
+#[cfg(feature = "std")]
+impl<K: Clone + Eq + Hash, V: Clone + PartialEq, S: BuildHasher + Default> DiffCollection
+	for HashMap<K, V, S>
+{
+	type Key = K;
+	type Value = V;
+
+	fn diff(previous: &Self, current: &Self) -> CollectionDelta<Self> {
+		let mut delta = CollectionDelta::default();
+		for (key, value) in current {
+			match previous.get(key) {
+				None => delta.added.push((key.clone(), value.clone())),
+				Some(previous) if previous != value => {
+					delta
+						.changed
+						.push((key.clone(), previous.clone(), value.clone()));
+				}
+				Some(_) => (),
+			}
+		}
+		delta.removed = previous
+			.iter()
+			.filter(|(key, _)| !current.contains_key(*key))
+			.map(|(key, value)| (key.clone(), value.clone()))
+			.collect();
+		delta
+	}
+}
+
+/// The [`Stream`] returned by [`Signal::diff_collection`](`crate::Signal::diff_collection`).
+///
+/// Unconsumed snapshots coalesce into the latest one.
+pub(crate) struct CollectionDiffs<'a, T, SR: 'a + SignalsRuntimeRef> {
+	latest: EffectSlot<'a, T, SR>,
+	/// The snapshot the next delta is computed against, [`None`] before the first one.
+	emitted: Option<T>,
+}
+
+impl<'a, T: 'a + Send, SR: 'a + SignalsRuntimeRef> CollectionDiffs<'a, T, SR> {
+	pub(crate) fn new(mut get_fn_pin: impl 'a + Send + FnMut() -> T, runtime: SR) -> Self {
+		Self {
+			latest: EffectSlot::new(move || Some(get_fn_pin()), runtime),
+			emitted: None,
+		}
+	}
+}
+
+// Never structurally pinned.
+impl<'a, T, SR: 'a + SignalsRuntimeRef> Unpin for CollectionDiffs<'a, T, SR> {}
+
+impl<'a, T: DiffCollection, SR: 'a + SignalsRuntimeRef> Stream for CollectionDiffs<'a, T, SR> {
+	type Item = CollectionDelta<T>;
+
+	fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		let this = self.get_mut();
+		loop {
+			let latest = ready!(this.latest.poll_take(cx));
+			let previous = this.emitted.take();
+			let first = previous.is_none();
+			let delta = T::diff(&previous.unwrap_or_default(), this.emitted.insert(latest));
+			if first || !delta.is_empty() {
+				return Poll::Ready(Some(delta));
+			}
+			// The collection changed back in the meantime.
+		}
+	}
+}
@@ -0,0 +1,41 @@
+use isoprenoid::runtime::SignalsRuntimeRef;
+
+use crate::{traits::UnmanagedSignal, Signal};
+
+/// Explicit dependency control for the closure of [`Signal::computed_with_context`].
+///
+/// Reads through [`Signal`] accessors are still recorded as usual.  
+/// Dependencies can't be removed once recorded during a run,
+/// so use [`ignore`](`Context::ignore`) to avoid recording them in the first place.
+#[derive(Debug, Clone)]
+pub struct Context<SR: SignalsRuntimeRef> {
+	runtime: SR,
+}
+
+impl<SR: SignalsRuntimeRef> Context<SR> {
+	pub(crate) fn new(runtime: SR) -> Self {
+		Self { runtime }
+	}
+
+	/// Records `signal` as dependency without accessing its value.
+	///
+	/// Equivalent to [`signal.touch()`](`Signal::touch`).
+	pub fn depend<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>>(
+		&self,
+		signal: &Signal<T, S, SR>,
+	) {
+		signal.touch();
+	}
+
+	/// Runs `f` without recording any dependencies, including through nested reads.
+	///
+	/// Wraps [`SignalsRuntimeRef::run_detached`].
+	pub fn ignore<T>(&self, f: impl FnOnce() -> T) -> T {
+		self.runtime.run_detached(f)
+	}
+
+	/// The signals runtime this [`Context`] belongs to.
+	pub fn runtime(&self) -> &SR {
+		&self.runtime
+	}
+}
@@ -114,7 +114,8 @@
 
 #![allow(unused_imports)] // Used by documentation.
 
-use std::{borrow::Borrow, ops::Deref};
+use alloc::borrow::ToOwned;
+use core::{borrow::Borrow, ops::Deref};
 
 use isoprenoid::runtime::SignalsRuntimeRef;
 
@@ -0,0 +1,56 @@
+use core::{borrow::Borrow, marker::PhantomData, ops::Deref};
+
+use crate::traits::Guard;
+
+/// A read-guard that can also be converted into an owned value, returned by [`Signal::read_cow`](`crate::Signal::read_cow`).
+///
+/// The dependency is recorded when the guard is created, so [`CowGuard::into_owned`] doesn't record it again.
+///
+/// Since 0.2.1.
+#[must_use = "Guards hold a lock on the signal's value while they exist."]
+pub struct CowGuard<T: ?Sized, G: Guard<T>> {
+	guard: G,
+	_phantom: PhantomData<fn(&T)>,
+}
+
+impl<T: ?Sized, G: Guard<T>> CowGuard<T, G> {
+	pub(crate) fn new(guard: G) -> Self {
+		Self {
+			guard,
+			_phantom: PhantomData,
+		}
+	}
+
+	/// Clones the borrowed value and releases the guard.
+	///
+	/// This is equivalent to [`Signal::get_clone`](`crate::Signal::get_clone`), but without recording the dependency a second time.
+	#[must_use]
+	pub fn into_owned(self) -> T
+	where
+		T: Clone,
+	{
+		(*self.guard).clone()
+	}
+}
+
+impl<T: ?Sized, G: Guard<T>> Guard<T> for CowGuard<T, G> {}
+
+impl<T: ?Sized, G: Guard<T>> Deref for CowGuard<T, G> {
+	type Target = T;
+
+	fn deref(&self) -> &Self::Target {
+		self.guard.deref()
+	}
+}
+
+impl<T: ?Sized, G: Guard<T>> Borrow<T> for CowGuard<T, G> {
+	fn borrow(&self) -> &T {
+		self.guard.borrow()
+	}
+}
+
+impl<T: ?Sized, G: Guard<T>> AsRef<T> for CowGuard<T, G> {
+	fn as_ref(&self) -> &T {
+		self
+	}
+}
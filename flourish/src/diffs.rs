@@ -0,0 +1,37 @@
+use core::{
+	pin::Pin,
+	task::{Context, Poll},
+};
+
+use futures_core::Stream;
+use isoprenoid::runtime::SignalsRuntimeRef;
+
+use crate::effect_slot::EffectSlot;
+
+/// The [`Stream`] returned by [`Signal::diffs`](`crate::Signal::diffs`).
+///
+/// Unconsumed pairs coalesce into the latest one.
+pub(crate) struct Diffs<'a, T, SR: 'a + SignalsRuntimeRef>(EffectSlot<'a, (T, T), SR>);
+
+impl<'a, T: 'a + Send + Clone, SR: 'a + SignalsRuntimeRef> Diffs<'a, T, SR> {
+	pub(crate) fn new(mut get_fn_pin: impl 'a + Send + FnMut() -> T, runtime: SR) -> Self {
+		let mut previous = None;
+		Self(EffectSlot::new(
+			move || {
+				let current = get_fn_pin();
+				previous
+					.replace(current.clone())
+					.map(|previous| (previous, current))
+			},
+			runtime,
+		))
+	}
+}
+
+impl<'a, T, SR: 'a + SignalsRuntimeRef> Stream for Diffs<'a, T, SR> {
+	type Item = (T, T);
+
+	fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		self.0.poll_take(cx).map(Some)
+	}
+}
@@ -0,0 +1,35 @@
+use core::fmt::{self, Debug, Display, Formatter};
+
+use isoprenoid::runtime::SignalsRuntimeRef;
+
+use crate::{traits::UnmanagedSignal, Signal};
+
+/// Formats a [`Signal`]'s value with [`Display`], as returned by [`Signal::display`].
+pub(crate) struct DisplayValue<'r, T: ?Sized + Send, S: ?Sized + Send + Sync, SR: SignalsRuntimeRef>(
+	pub(crate) &'r Signal<T, S, SR>,
+);
+
+/// Formats a [`Signal`]'s value with [`Debug`], as returned by [`Signal::debug_value`].
+pub(crate) struct DebugValue<'r, T: ?Sized + Send, S: ?Sized + Send + Sync, SR: SignalsRuntimeRef>(
+	pub(crate) &'r Signal<T, S, SR>,
+);
+
+impl<T: ?Sized + Send + Display, S: ?Sized + UnmanagedSignal<T, SR>, SR: SignalsRuntimeRef> Display
+	for DisplayValue<'_, T, S, SR>
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		self.0
+			.clone_runtime_ref()
+			.run_detached(|| Display::fmt(&**self.0.read_exclusive_dyn(), f))
+	}
+}
+
+impl<T: ?Sized + Send + Debug, S: ?Sized + UnmanagedSignal<T, SR>, SR: SignalsRuntimeRef> Debug
+	for DebugValue<'_, T, S, SR>
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		self.0
+			.clone_runtime_ref()
+			.run_detached(|| Debug::fmt(&**self.0.read_exclusive_dyn(), f))
+	}
+}
@@ -1,8 +1,20 @@
-use std::{marker::PhantomData, pin::Pin};
+#[cfg(any(feature = "global_signals_runtime", feature = "critical_section"))]
+use alloc::vec::Vec;
+use alloc::{boxed::Box, sync::Arc};
+use core::{
+	marker::PhantomData,
+	pin::Pin,
+	sync::atomic::{AtomicBool, Ordering},
+};
 
 use isoprenoid::runtime::SignalsRuntimeRef;
+#[cfg(any(feature = "global_signals_runtime", feature = "critical_section"))]
+use isoprenoid::runtime::{GSRSymbol, GlobalSignalsRuntime};
 
-use crate::unmanaged::new_raw_unsubscribed_effect;
+use crate::{
+	unmanaged::{inert_cell, new_raw_unsubscribed_effect},
+	Signal,
+};
 
 /// An [`Effect`] subscribes to signal sources just like a [`Subscription`](`crate::Subscription`) does,
 /// but instead of exposing the value, its main use is to execute side-effects with cleanup.
@@ -14,6 +26,9 @@ use crate::unmanaged::new_raw_unsubscribed_effect;
 #[must_use = "Effects are cancelled when dropped."]
 pub struct Effect<'a, SR: 'a + ?Sized + SignalsRuntimeRef> {
 	_raw_effect: Pin<Box<dyn 'a + DropHandle>>,
+	/// Only needed for [`Effect::dependencies`].
+	#[cfg(any(feature = "global_signals_runtime", feature = "critical_section"))]
+	id: SR::Symbol,
 	_phantom: PhantomData<SR>,
 }
 
@@ -45,8 +60,245 @@ impl<'a, SR: SignalsRuntimeRef> Effect<'a, SR> {
 		let box_ = Box::pin(new_raw_unsubscribed_effect(fn_pin, drop_fn_pin, runtime));
 		box_.as_ref().pull();
 		Self {
+			#[cfg(any(feature = "global_signals_runtime", feature = "critical_section"))]
+			id: box_.id(),
 			_raw_effect: box_,
 			_phantom: PhantomData,
 		}
 	}
+
+	/// An effect that records dependencies on setup, but runs its side-effect only on refreshes.
+	///
+	/// `select_fn_pin` runs immediately and on each refresh, and is the dependency detection scope.
+	/// `effect_fn_pin` consumes each selected value *except the first* and is **not** part of
+	/// the dependency detection scope.
+	///
+	/// Since 0.2.1.
+	pub fn new_skip_initial<T: 'a + Send>(
+		select_fn_pin: impl 'a + Send + FnMut() -> T,
+		effect_fn_pin: impl 'a + Send + FnMut(T),
+	) -> Self
+	where
+		SR: Default,
+	{
+		Self::new_skip_initial_with_runtime(select_fn_pin, effect_fn_pin, SR::default())
+	}
+
+	/// An effect that records dependencies on setup, but runs its side-effect only on refreshes.
+	///
+	/// `select_fn_pin` runs immediately and on each refresh, and is the dependency detection scope.
+	/// `effect_fn_pin` consumes each selected value *except the first* and is **not** part of
+	/// the dependency detection scope.
+	///
+	/// Since 0.2.1.
+	pub fn new_skip_initial_with_runtime<T: 'a + Send>(
+		mut select_fn_pin: impl 'a + Send + FnMut() -> T,
+		mut effect_fn_pin: impl 'a + Send + FnMut(T),
+		runtime: SR,
+	) -> Self {
+		let mut primed = false;
+		Self::new_with_runtime(
+			{
+				let runtime = runtime.clone();
+				move || {
+					let value = select_fn_pin();
+					if primed {
+						runtime.run_detached(|| effect_fn_pin(value));
+					} else {
+						primed = true;
+					}
+				}
+			},
+			drop,
+			runtime,
+		)
+	}
+
+	/// An effect whose side-effects are posted to `spawner`, for example to run them on a UI thread.
+	///
+	/// `body_fn_pin` runs inline immediately and on each refresh, and is the dependency detection scope.
+	/// The side-effect closure it returns is boxed and passed to `spawner` each time.
+	///
+	/// The side-effect closure runs detached, so it's **not** part of the dependency detection scope
+	/// even if `spawner` calls it right away.
+	///
+	/// Since 0.2.1.
+	pub fn new_on<F: 'a + Send + FnOnce()>(
+		spawner: impl 'a + Send + FnMut(Box<dyn 'a + Send + FnOnce()>),
+		body_fn_pin: impl 'a + Send + FnMut() -> F,
+	) -> Self
+	where
+		SR: Default,
+	{
+		Self::new_on_with_runtime(spawner, body_fn_pin, SR::default())
+	}
+
+	/// An effect whose side-effects are posted to `spawner`, for example to run them on a UI thread.
+	///
+	/// `body_fn_pin` runs inline immediately and on each refresh, and is the dependency detection scope.
+	/// The side-effect closure it returns is boxed and passed to `spawner` each time.
+	///
+	/// The side-effect closure runs detached, so it's **not** part of the dependency detection scope
+	/// even if `spawner` calls it right away.
+	///
+	/// Since 0.2.1.
+	pub fn new_on_with_runtime<F: 'a + Send + FnOnce()>(
+		mut spawner: impl 'a + Send + FnMut(Box<dyn 'a + Send + FnOnce()>),
+		mut body_fn_pin: impl 'a + Send + FnMut() -> F,
+		runtime: SR,
+	) -> Self {
+		Self::new_with_runtime(
+			{
+				let runtime = runtime.clone();
+				move || {
+					let side_effect = body_fn_pin();
+					let runtime = runtime.clone();
+					spawner(Box::new(move || runtime.run_detached(side_effect)));
+				}
+			},
+			drop,
+			runtime,
+		)
+	}
+
+	/// An effect that defers refreshes until the signals runtime is idle, so that
+	/// several invalidations result in one run of `body_fn_pin` that sees the settled state.
+	///
+	/// `body_fn_pin` runs immediately and is the dependency detection scope.
+	/// When any of its dependencies change, the effect schedules a run through
+	/// [`SignalsRuntimeRef::on_idle`] instead of running `body_fn_pin` right away.
+	/// That run happens once all pending updates (including ones submitted by other effects) are processed.
+	///
+	/// While a run is scheduled, the effect depends only on an internal trigger signal.
+	/// The scheduled run records `body_fn_pin`'s dependencies again.
+	///
+	/// Runtimes that call [`SignalsRuntimeRef::on_idle`] callbacks immediately make this behave like [`Effect::new`].
+	///
+	/// Since 0.2.1.
+	pub fn new_coalesced(body_fn_pin: impl 'a + Send + FnMut()) -> Self
+	where
+		SR: 'static + Default,
+	{
+		Self::new_coalesced_with_runtime(body_fn_pin, SR::default())
+	}
+
+	/// An effect that defers refreshes until the signals runtime is idle, so that
+	/// several invalidations result in one run of `body_fn_pin` that sees the settled state.
+	///
+	/// `body_fn_pin` runs immediately and is the dependency detection scope.
+	/// When any of its dependencies change, the effect schedules a run through
+	/// [`SignalsRuntimeRef::on_idle`] instead of running `body_fn_pin` right away.
+	/// That run happens once all pending updates (including ones submitted by other effects) are processed.
+	///
+	/// While a run is scheduled, the effect depends only on an internal trigger signal.
+	/// The scheduled run records `body_fn_pin`'s dependencies again.
+	///
+	/// Runtimes that call [`SignalsRuntimeRef::on_idle`] callbacks immediately make this behave like [`Effect::new_with_runtime`].
+	///
+	/// Since 0.2.1.
+	pub fn new_coalesced_with_runtime(
+		mut body_fn_pin: impl 'a + Send + FnMut(),
+		runtime: SR,
+	) -> Self
+	where
+		SR: 'static,
+	{
+		let trigger = Signal::new(inert_cell((), runtime.clone()));
+		// Set right before `trigger` is, so that the next run is a real one.
+		let due = Arc::new(AtomicBool::new(true));
+		Self::new_with_runtime(
+			{
+				let runtime = runtime.clone();
+				move || {
+					if due.swap(false, Ordering::AcqRel) {
+						body_fn_pin();
+					} else {
+						trigger.touch();
+						let trigger = trigger.downgrade();
+						let due = Arc::clone(&due);
+						runtime.on_idle(move || {
+							if let Some(trigger) = trigger.upgrade() {
+								due.store(true, Ordering::Release);
+								trigger.set(());
+							}
+						});
+					}
+				}
+			},
+			drop,
+			runtime,
+		)
+	}
+
+	/// An effect that runs its side-effect only when a derived key changes.
+	///
+	/// `key_fn_pin` runs immediately and on each refresh, and is the dependency detection scope.
+	/// `body_fn_pin` runs with the first key and then whenever a new key isn't equal to the previous one.
+	/// It's **not** part of the dependency detection scope.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::GlobalSignalsRuntime;
+	/// type Effect<'a> = flourish::Effect<'a, GlobalSignalsRuntime>;
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// let user = Signal::cell(("Alice", 1));
+	/// let _effect = Effect::watch_keyed(|| user.get().1, |id| println!("Logged in as #{id}."));
+	/// user.set(("Alice (away)", 1)); // Doesn't print.
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	pub fn watch_keyed<K: 'a + Send + PartialEq>(
+		key_fn_pin: impl 'a + Send + FnMut() -> K,
+		body_fn_pin: impl 'a + Send + FnMut(&K),
+	) -> Self
+	where
+		SR: Default,
+	{
+		Self::watch_keyed_with_runtime(key_fn_pin, body_fn_pin, SR::default())
+	}
+
+	/// An effect that runs its side-effect only when a derived key changes.
+	///
+	/// `key_fn_pin` runs immediately and on each refresh, and is the dependency detection scope.
+	/// `body_fn_pin` runs with the first key and then whenever a new key isn't equal to the previous one.
+	/// It's **not** part of the dependency detection scope.
+	///
+	/// Since 0.2.1.
+	pub fn watch_keyed_with_runtime<K: 'a + Send + PartialEq>(
+		mut key_fn_pin: impl 'a + Send + FnMut() -> K,
+		mut body_fn_pin: impl 'a + Send + FnMut(&K),
+		runtime: SR,
+	) -> Self {
+		let mut last_key = None;
+		Self::new_with_runtime(
+			{
+				let runtime = runtime.clone();
+				move || {
+					let key = key_fn_pin();
+					if last_key.as_ref() != Some(&key) {
+						runtime.run_detached(|| body_fn_pin(&key));
+						last_key = Some(key);
+					}
+				}
+			},
+			drop,
+			runtime,
+		)
+	}
+}
+
+#[cfg(any(feature = "global_signals_runtime", feature = "critical_section"))]
+impl Effect<'_, GlobalSignalsRuntime> {
+	/// A snapshot of the signals this [`Effect`] currently depends on, in creation order.
+	///
+	/// This is meant for debugging. See [`GlobalSignalsRuntime::dependencies`].
+	///
+	/// Since 0.2.1.
+	#[must_use]
+	pub fn dependencies(&self) -> Vec<GSRSymbol> {
+		GlobalSignalsRuntime.dependencies(self.id)
+	}
 }
@@ -0,0 +1,74 @@
+use alloc::sync::Arc;
+use core::task::{Context, Poll, Waker};
+
+use isoprenoid::runtime::SignalsRuntimeRef;
+
+#[cfg(feature = "std")]
+use crate::park::park_until;
+use crate::{sync::Mutex, Effect};
+
+/// Holds the latest value produced by an [`Effect`] until a consumer takes it.
+///
+/// Values that aren't taken in time are overwritten, so they coalesce.
+/// This backs the change streams and iterators of [`Signal`](`crate::Signal`).
+pub(crate) struct EffectSlot<'a, T, SR: 'a + SignalsRuntimeRef> {
+	shared: Arc<Mutex<Shared<T>>>,
+	_effect: Effect<'a, SR>,
+}
+
+struct Shared<T> {
+	latest: Option<T>,
+	waker: Option<Waker>,
+}
+
+impl<'a, T: 'a + Send, SR: 'a + SignalsRuntimeRef> EffectSlot<'a, T, SR> {
+	/// Runs `fn_pin` as [`Effect`]. Each [`Some`] it returns replaces the held value and wakes the consumer.
+	pub(crate) fn new(mut fn_pin: impl 'a + Send + FnMut() -> Option<T>, runtime: SR) -> Self {
+		let shared = Arc::new(Mutex::new(Shared {
+			latest: None,
+			waker: None,
+		}));
+		let effect = Effect::new_with_runtime(
+			{
+				let shared = Arc::clone(&shared);
+				move || {
+					if let Some(current) = fn_pin() {
+						let mut shared = shared.lock().unwrap();
+						shared.latest = Some(current);
+						let waker = shared.waker.take();
+						drop(shared);
+						// Never waits for the consumer, so propagation isn't blocked.
+						if let Some(waker) = waker {
+							waker.wake();
+						}
+					}
+				}
+			},
+			drop,
+			runtime,
+		);
+		Self {
+			shared,
+			_effect: effect,
+		}
+	}
+}
+
+impl<'a, T, SR: 'a + SignalsRuntimeRef> EffectSlot<'a, T, SR> {
+	/// Takes the held value, or registers `cx`'s waker for the next one.
+	pub(crate) fn poll_take(&self, cx: &mut Context<'_>) -> Poll<T> {
+		let mut shared = self.shared.lock().unwrap();
+		if let Some(latest) = shared.latest.take() {
+			Poll::Ready(latest)
+		} else {
+			drop(shared.waker.replace(cx.waker().clone()));
+			Poll::Pending
+		}
+	}
+
+	/// Takes the held value, parking the current thread until there is one.
+	#[cfg(feature = "std")]
+	pub(crate) fn take_blocking(&self) -> T {
+		park_until(|cx| self.poll_take(cx))
+	}
+}
@@ -0,0 +1,30 @@
+use alloc::sync::Arc;
+
+/// A handle that drops the cached value of the signal returned alongside it by [`Signal::computed_evictable`](`crate::Signal::computed_evictable`).
+///
+/// Register [`evict`](`Evictor::evict`) with a memory-pressure notification of your choice.
+///
+/// This doesn't hold on to that signal. Once it's dropped, [`evict`](`Evictor::evict`) does nothing.
+///
+/// Since 0.2.1.
+#[derive(Clone)]
+pub struct Evictor {
+	evict: Arc<dyn Send + Sync + Fn()>,
+}
+
+impl Evictor {
+	pub(crate) fn new(evict: impl 'static + Send + Sync + Fn()) -> Self {
+		Self {
+			evict: Arc::new(evict),
+		}
+	}
+
+	/// Drops the associated signal's cached value, so that it's recomputed when next read.
+	///
+	/// Iff the signal is subscribed, it recomputes immediately instead, which notifies its dependents.
+	///
+	/// This doesn't record any dependencies and is a no-op if the signal was dropped.
+	pub fn evict(&self) {
+		(self.evict)();
+	}
+}
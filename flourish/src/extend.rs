@@ -0,0 +1,76 @@
+//! In-place appends to signal cells of collections.
+
+use alloc::{boxed::Box, vec::Vec};
+use isoprenoid::runtime::{Propagation, SignalsRuntimeRef};
+
+use crate::{traits::UnmanagedSignalCell, Signal};
+
+/// Extension methods that append to cells of [`Extend`] collections, like [`Vec`] or [`String`],
+/// without cloning or replacing the collection.
+///
+/// Each call submits one [`update`](`Signal::update`) that always signals dependents.  
+/// Inside [`SignalsRuntimeRef::hint_batched_updates`] (or a transaction), runtimes that
+/// batch updates refresh dependents once for many appends.
+///
+/// ```
+/// # {
+/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+/// use flourish::{extend::ExtendSignalExt, GlobalSignalsRuntime, SignalsRuntimeRef};
+///
+/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+///
+/// let log = Signal::cell(Vec::new());
+/// GlobalSignalsRuntime.hint_batched_updates(|| {
+/// 	log.push("a");
+/// 	log.extend(["b", "c"]);
+/// });
+/// assert_eq!(log.get_clone(), ["a", "b", "c"]);
+/// # }
+/// ```
+///
+/// Since 0.2.1.
+pub trait ExtendSignalExt<A: 'static + Send> {
+	/// Appends `item` in place and signals dependents.
+	///
+	/// # Logic
+	///
+	/// This method **must not** block *indefinitely*.  
+	/// This method **may** defer its effect.
+	fn push(&self, item: A);
+
+	/// Appends the items of `iter` in place and signals dependents.
+	///
+	/// `iter` is collected right away, so it doesn't have to be [`Send`] or `'static`.  
+	/// Nothing happens iff `iter` is empty.
+	///
+	/// # Logic
+	///
+	/// This method **must not** block *indefinitely*.  
+	/// This method **may** defer its effect.
+	fn extend(&self, iter: impl IntoIterator<Item = A>);
+}
+
+impl<
+		A: 'static + Send,
+		T: 'static + Send + Extend<A>,
+		S: ?Sized + UnmanagedSignalCell<T, SR>,
+		SR: ?Sized + SignalsRuntimeRef,
+	> ExtendSignalExt<A> for Signal<T, S, SR>
+{
+	fn push(&self, item: A) {
+		self.update_dyn(Box::new(move |value| {
+			value.extend([item]);
+			Propagation::Propagate
+		}));
+	}
+
+	fn extend(&self, iter: impl IntoIterator<Item = A>) {
+		let items: Vec<A> = iter.into_iter().collect();
+		if !items.is_empty() {
+			self.update_dyn(Box::new(move |value| {
+				value.extend(items);
+				Propagation::Propagate
+			}));
+		}
+	}
+}
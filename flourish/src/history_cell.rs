@@ -0,0 +1,214 @@
+use alloc::{boxed::Box, collections::VecDeque, vec::Vec};
+use core::{mem, ops::Deref};
+
+use isoprenoid::runtime::{Propagation, SignalsRuntimeRef};
+
+use crate::{
+	sync::{Mutex, MutexGuard, PoisonError},
+	unmanaged::InertCell,
+	SignalArc, SignalArcDynCell, SignalDyn,
+};
+
+/// A signal cell that remembers its previous values, so that writes can be undone and redone.
+///
+/// Each [`set`](`HistoryCell::set`) or [`update`](`HistoryCell::update`) commits a new value,
+/// pushing the previous one onto the undo stack and clearing the redo stack.
+/// [`undo`](`HistoryCell::undo`) and [`redo`](`HistoryCell::redo`) apply a remembered value as new commit,
+/// which signals dependents as usual.
+///
+/// At most `capacity` previous values are kept. Older ones are discarded first.
+///
+/// Reads go through [`Deref`] to a read-only [`SignalDyn`].
+///
+/// ```
+/// # {
+/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+/// # use flourish::GlobalSignalsRuntime;
+/// type HistoryCell<'a, T> = flourish::HistoryCell<'a, T, GlobalSignalsRuntime>;
+///
+/// let text = HistoryCell::new("a", 100);
+/// text.set("ab");
+/// text.set("abc");
+///
+/// assert!(text.undo());
+/// assert_eq!(text.get(), "ab");
+/// assert!(text.undo());
+/// assert_eq!(text.get(), "a");
+/// assert!(!text.undo());
+///
+/// assert!(text.redo());
+/// assert_eq!(text.get(), "ab");
+/// # }
+/// ```
+///
+/// Since 0.2.1.
+pub struct HistoryCell<'a, T: 'a + Send, SR: 'a + SignalsRuntimeRef> {
+	cell: SignalArcDynCell<'a, T, SR>,
+	history: Mutex<History<T>>,
+}
+
+struct History<T> {
+	undo: VecDeque<T>,
+	redo: Vec<T>,
+	capacity: usize,
+}
+
+impl<T> History<T> {
+	fn push_undo(&mut self, previous: T) {
+		self.undo.push_back(previous);
+		while self.undo.len() > self.capacity {
+			drop(self.undo.pop_front());
+		}
+	}
+}
+
+impl<'a, T: 'a + Send, SR: 'a + SignalsRuntimeRef> HistoryCell<'a, T, SR> {
+	/// Creates a new [`HistoryCell`] with an empty history that keeps at most `capacity` previous values.
+	///
+	/// Since 0.2.1.
+	pub fn new(initial_value: T, capacity: usize) -> Self
+	where
+		SR: Default,
+	{
+		Self::new_with_runtime(initial_value, capacity, SR::default())
+	}
+
+	/// Creates a new [`HistoryCell`] with an empty history that keeps at most `capacity` previous values.
+	///
+	/// Since 0.2.1.
+	pub fn new_with_runtime(initial_value: T, capacity: usize, runtime: SR) -> Self {
+		Self {
+			cell: SignalArc::new(InertCell::with_runtime(initial_value, runtime)).into_dyn_cell(),
+			history: Mutex::new(History {
+				undo: VecDeque::new(),
+				redo: Vec::new(),
+				capacity,
+			}),
+		}
+	}
+
+	/// Commits `new_value` and signals dependents.
+	///
+	/// The previous value is pushed onto the undo stack and the redo stack is cleared.
+	///
+	/// # Logic
+	///
+	/// This method **may** block *indefinitely* iff called in signal callbacks.
+	///
+	/// Since 0.2.1.
+	pub fn set(&self, new_value: T) {
+		self.cell.update_blocking_dyn(Box::new(|value| {
+			let mut history = self.history();
+			history.push_undo(mem::replace(value, new_value));
+			history.redo.clear();
+			Propagation::Propagate
+		}));
+	}
+
+	/// Modifies the current value in place, commits the result and signals dependents.
+	///
+	/// The previous value is pushed onto the undo stack and the redo stack is cleared.
+	///
+	/// # Logic
+	///
+	/// This method **may** block *indefinitely* iff called in signal callbacks.
+	///
+	/// Since 0.2.1.
+	pub fn update(&self, update: impl FnOnce(&mut T))
+	where
+		T: Clone,
+	{
+		self.cell.update_blocking_dyn(Box::new(|value| {
+			let previous = value.clone();
+			update(value);
+			let mut history = self.history();
+			history.push_undo(previous);
+			history.redo.clear();
+			Propagation::Propagate
+		}));
+	}
+
+	/// Re-commits the previous value and signals dependents.
+	///
+	/// The current value is pushed onto the redo stack.
+	///
+	/// # Returns
+	///
+	/// Whether there was a previous value. If not, this is a no-op.
+	///
+	/// # Logic
+	///
+	/// This method **may** block *indefinitely* iff called in signal callbacks.
+	///
+	/// Since 0.2.1.
+	pub fn undo(&self) -> bool {
+		let mut undone = false;
+		self.cell.update_blocking_dyn(Box::new(|value| {
+			let mut history = self.history();
+			let Some(previous) = history.undo.pop_back() else {
+				return Propagation::Halt;
+			};
+			history.redo.push(mem::replace(value, previous));
+			undone = true;
+			Propagation::Propagate
+		}));
+		undone
+	}
+
+	/// Re-commits the most recently undone value and signals dependents.
+	///
+	/// The current value is pushed onto the undo stack.
+	///
+	/// # Returns
+	///
+	/// Whether there was an undone value. If not, this is a no-op.
+	///
+	/// # Logic
+	///
+	/// This method **may** block *indefinitely* iff called in signal callbacks.
+	///
+	/// Since 0.2.1.
+	pub fn redo(&self) -> bool {
+		let mut redone = false;
+		self.cell.update_blocking_dyn(Box::new(|value| {
+			let mut history = self.history();
+			let Some(next) = history.redo.pop() else {
+				return Propagation::Halt;
+			};
+			let previous = mem::replace(value, next);
+			history.push_undo(previous);
+			redone = true;
+			Propagation::Propagate
+		}));
+		redone
+	}
+
+	/// The history is only modified outside of user code, so it stays consistent even if poisoned.
+	fn history(&self) -> MutexGuard<'_, History<T>> {
+		self.history.lock().unwrap_or_else(PoisonError::into_inner)
+	}
+
+	/// Whether [`undo`](`HistoryCell::undo`) currently has a value to apply.
+	///
+	/// Since 0.2.1.
+	#[must_use]
+	pub fn can_undo(&self) -> bool {
+		!self.history().undo.is_empty()
+	}
+
+	/// Whether [`redo`](`HistoryCell::redo`) currently has a value to apply.
+	///
+	/// Since 0.2.1.
+	#[must_use]
+	pub fn can_redo(&self) -> bool {
+		!self.history().redo.is_empty()
+	}
+}
+
+impl<'a, T: 'a + Send, SR: 'a + SignalsRuntimeRef> Deref for HistoryCell<'a, T, SR> {
+	type Target = SignalDyn<'a, T, SR>;
+
+	fn deref(&self) -> &Self::Target {
+		self.cell.as_read_only()
+	}
+}
@@ -0,0 +1,48 @@
+use alloc::{sync::Arc, task::Wake};
+use core::task::Waker;
+
+use isoprenoid::runtime::SignalsRuntimeRef;
+
+use crate::SignalWeakDynCell;
+
+/// A handle that marks the signal returned alongside it by [`Signal::computed_manually`](`crate::Signal::computed_manually`) stale.
+///
+/// This doesn't hold on to that signal. Once it's dropped, [`invalidate`](`Invalidator::invalidate`) does nothing.
+#[derive(Clone)]
+pub struct Invalidator<SR: 'static + SignalsRuntimeRef> {
+	trigger: SignalWeakDynCell<'static, (), SR>,
+}
+
+impl<SR: 'static + SignalsRuntimeRef> Invalidator<SR> {
+	pub(crate) fn new(trigger: SignalWeakDynCell<'static, (), SR>) -> Self {
+		Self { trigger }
+	}
+
+	/// Marks the associated signal stale, so that it recomputes when next read or, if subscribed, soon.
+	///
+	/// This doesn't record any dependencies and is a no-op if the signal was dropped.
+	pub fn invalidate(&self) {
+		if let Some(trigger) = self.trigger.upgrade() {
+			trigger.set(());
+		}
+	}
+}
+
+/// Invalidates through the wrapped [`Invalidator`] when woken.
+pub(crate) struct InvalidatingWaker<SR: 'static + SignalsRuntimeRef>(Invalidator<SR>);
+
+impl<SR: 'static + SignalsRuntimeRef> InvalidatingWaker<SR> {
+	pub(crate) fn waker(invalidator: Invalidator<SR>) -> Waker {
+		Waker::from(Arc::new(Self(invalidator)))
+	}
+}
+
+impl<SR: 'static + SignalsRuntimeRef> Wake for InvalidatingWaker<SR> {
+	fn wake(self: Arc<Self>) {
+		self.0.invalidate();
+	}
+
+	fn wake_by_ref(self: &Arc<Self>) {
+		self.0.invalidate();
+	}
+}
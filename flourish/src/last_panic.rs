@@ -0,0 +1,54 @@
+use alloc::boxed::Box;
+use core::any::Any;
+
+use crate::sync::{Mutex, PoisonError};
+
+type Payload = Box<dyn Any + Send>;
+
+/// The panic state of a signal created by [`Signal::computed_resilient`](`crate::Signal::computed_resilient`).
+///
+/// Owned by the signal and borrowed through [`Signal::last_error`](`crate::Signal::last_error`).
+///
+/// # Features
+///
+/// **The feature `"std"` is required to enable this type.**
+pub struct LastPanic {
+	payload: Mutex<Option<Payload>>,
+}
+
+impl LastPanic {
+	pub(crate) fn new() -> Self {
+		Self {
+			payload: Mutex::new(None),
+		}
+	}
+
+	pub(crate) fn record(&self, payload: Option<Payload>) {
+		*self.payload.lock().unwrap_or_else(PoisonError::into_inner) = payload;
+	}
+
+	/// Whether the signal's most recent computation panicked,
+	/// meaning it currently holds the last good value (if any).
+	///
+	/// Since 0.2.1.
+	#[must_use]
+	pub fn is_errored(&self) -> bool {
+		self.payload
+			.lock()
+			.unwrap_or_else(PoisonError::into_inner)
+			.is_some()
+	}
+
+	/// Takes the panic payload of the most recent computation, iff that panicked.
+	///
+	/// Afterwards, [`is_errored`](`LastPanic::is_errored`) returns `false` until the next panic.
+	///
+	/// Since 0.2.1.
+	#[must_use]
+	pub fn take(&self) -> Option<Box<dyn Any + Send>> {
+		self.payload
+			.lock()
+			.unwrap_or_else(PoisonError::into_inner)
+			.take()
+	}
+}
@@ -2,6 +2,7 @@
 #![warn(missing_docs)]
 #![warn(unreachable_pub)]
 // #![warn(clippy::single_call_fn)]
+#![cfg_attr(not(feature = "std"), no_std)]
 //! <details><summary>README / Quick Start (click to expand)</summary>
 //!
 #![cfg_attr(feature = "_doc", doc = include_str!("../README.md"))]
@@ -15,15 +16,31 @@
 //! # Safety Notes
 //!
 //! [`impl FnMut`](`FnMut`) closures that appear in parameters with "`fn_pin`" in their name are guaranteed to be [pinned](`core::pin`) when called.
+//!
+//! # `no_std`
+//!
+//! Disable the default `std` feature and enable `spin` to use this crate with only `alloc`.  
+//! Enable `critical_section` for a usable [`GlobalSignalsRuntime`] there.
+//!
+//! Threaded helpers, like blocking waits, timers and panic handling, aren't available then.
+
+extern crate alloc;
+
+#[cfg(not(any(feature = "std", feature = "spin")))]
+compile_error!("Without the `std` feature, please enable the `spin` feature to provide locking.");
 
 pub mod conversions;
 mod opaque;
 
 mod signal;
-pub use signal::{Signal, SignalDyn, SignalDynCell};
+pub use signal::{Signal, SignalBuilder, SignalDyn, SignalDynCell};
 
 pub mod unmanaged;
 
+pub mod result;
+
+pub mod extend;
+
 //TODO: Inter-runtime signals (i.e. takes two signals runtimes as parameters, acts as source for one and dynamic subscriber for the other).
 
 mod signal_arc;
@@ -32,15 +49,263 @@ pub use signal_arc::{
 };
 
 mod subscription;
-pub use subscription::{Subscription, SubscriptionDyn, SubscriptionDynCell};
+pub use subscription::{Scope, Subscription, SubscriptionDyn, SubscriptionDynCell};
+
+mod stored_subscription;
+pub use stored_subscription::StoredSubscription;
 
 mod effect;
 pub use effect::Effect;
 
+mod context;
+pub use context::Context;
+
+mod evictor;
+pub use evictor::Evictor;
+
+mod invalidator;
+pub use invalidator::Invalidator;
+
+#[cfg(feature = "std")]
+mod last_panic;
+#[cfg(feature = "std")]
+pub use last_panic::LastPanic;
+
+mod reentrant;
+pub use reentrant::Reentrant;
+
+#[cfg(feature = "std")]
+mod registry;
+#[cfg(feature = "std")]
+pub use registry::SignalRegistry;
+
+mod validated;
+pub use validated::Validated;
+
+mod history_cell;
+pub use history_cell::HistoryCell;
+
+mod optimistic_cell;
+pub use optimistic_cell::OptimisticCell;
+
+mod set_all;
+pub use set_all::set_all;
+
+mod map_like;
+pub use map_like::MapLike;
+
+mod observers;
+pub use observers::{ObserverHandle, Observers};
+
+mod cow_guard;
+pub use cow_guard::CowGuard;
+
+mod ttl;
+pub use ttl::Timer;
+
+#[cfg(feature = "arc-swap")]
+mod synced_arc_swap;
+#[cfg(feature = "arc-swap")]
+pub use synced_arc_swap::SyncedArcSwap;
+
+#[cfg(feature = "futures")]
+mod ack;
+#[cfg(feature = "futures")]
+pub use ack::Ack;
+
+#[cfg(feature = "futures")]
+mod collection_delta;
+#[cfg(feature = "futures")]
+pub use collection_delta::{CollectionDelta, DiffCollection};
+
 mod traits;
 pub use traits::Guard;
 
-pub use isoprenoid::runtime::{GlobalSignalsRuntime, Propagation, SignalsRuntimeRef};
+mod abort;
+#[cfg(feature = "std")]
+mod changes;
+#[cfg(feature = "futures")]
+mod diffs;
+mod display;
+#[cfg(any(feature = "std", feature = "futures"))]
+mod effect_slot;
+#[cfg(feature = "std")]
+mod park;
+mod sync;
+mod value;
+
+pub use isoprenoid::runtime::{
+	CallbackTableTypes, GCSRSymbol, GSRSymbol, GlobalCountingSignalsRuntime, GlobalSignalsRuntime,
+	Propagation, SignalsRuntimeRef, SubscriberCount,
+};
+
+/// The thread-local variant of this crate, [*flourish-unsend*](`flourish_unsend`).
+///
+/// Its [`LocalSignalsRuntime`](`flourish_unsend::LocalSignalsRuntime`) doesn't lock a mutex,
+/// and its signals accept values that aren't [`Send`] or [`Sync`], but they aren't [`Send`] or [`Sync`] either.  
+/// These are distinct types from the ones in this crate and don't interoperate with them.
+///
+/// ```
+/// # {
+/// # #![cfg(feature = "local_signals_runtime")] // flourish feature
+/// use std::rc::Rc;
+/// use flourish::local::LocalSignalsRuntime;
+///
+/// type Signal<T, S> = flourish::local::Signal<T, S, LocalSignalsRuntime>;
+///
+/// let a = Signal::cell(Rc::new(1));
+/// let b = Signal::computed(move || *a.get_clone() + 1);
+/// assert_eq!(b.get(), 2);
+/// # }
+/// ```
+///
+/// Since 0.2.1.
+#[cfg(feature = "local_signals_runtime")]
+pub use flourish_unsend as local;
+
+/// A [`local::Signal`] on the [`LocalSignalsRuntime`](`local::LocalSignalsRuntime`).
+///
+/// Neither its closures nor its value have to be [`Send`], so this is the shortest way to
+/// build single-threaded graphs. As a distinct type, it coexists with [`Signal`] without conflicts.
+///
+/// ```
+/// # {
+/// # #![cfg(feature = "local_signals_runtime")] // flourish feature
+/// use std::rc::Rc;
+/// use flourish::LocalSignal;
+///
+/// let a = LocalSignal::cell(Rc::new(1));
+/// let b = LocalSignal::computed(move || Rc::new(*a.get_clone() + 1));
+/// assert_eq!(*b.get_clone(), 2);
+/// # }
+/// ```
+///
+/// **The feature `"local_signals_runtime"` is required to enable this type alias.**
+///
+/// Since 0.2.1.
+#[cfg(feature = "local_signals_runtime")]
+pub type LocalSignal<T, S> = local::Signal<T, S, local::LocalSignalsRuntime>;
+
+/// A [`local::Subscription`] on the [`LocalSignalsRuntime`](`local::LocalSignalsRuntime`).
+///
+/// See [`LocalSignal`].
+///
+/// **The feature `"local_signals_runtime"` is required to enable this type alias.**
+///
+/// Since 0.2.1.
+#[cfg(feature = "local_signals_runtime")]
+pub type LocalSubscription<T, S> = local::Subscription<T, S, local::LocalSignalsRuntime>;
+
+/// Runs `f` on the [`GlobalSignalsRuntime`] without recording dependencies,
+/// so that signals read inside of it aren't subscribed to by the surrounding computation.
+///
+/// Calls nest, and reads after `f` returns are recorded as usual again.
+/// See [`untracked_with_runtime`] for other runtimes.
+///
+/// ```
+/// # {
+/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+/// use flourish::{untracked, GlobalSignalsRuntime};
+///
+/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+///
+/// let config = Signal::cell(10);
+/// let input = Signal::cell(1);
+/// let scaled = Signal::computed(|| input.get() * untracked(|| config.get()));
+/// assert_eq!(scaled.get(), 10);
+///
+/// config.set(20);
+/// assert_eq!(scaled.get(), 10);
+///
+/// input.set(2);
+/// assert_eq!(scaled.get(), 40);
+/// # }
+/// ```
+///
+/// **The feature `"global_signals_runtime"` or `"critical_section"` is required to enable this function.**
+///
+/// Since 0.2.1.
+#[cfg(any(feature = "global_signals_runtime", feature = "critical_section"))]
+pub fn untracked<T>(f: impl FnOnce() -> T) -> T {
+	untracked_with_runtime(f, &GlobalSignalsRuntime)
+}
+
+/// Runs `f` on `runtime` without recording dependencies,
+/// so that signals read inside of it aren't subscribed to by the surrounding computation.
+///
+/// Calls nest, and reads after `f` returns are recorded as usual again.
+///
+/// Since 0.2.1.
+pub fn untracked_with_runtime<T, SR: SignalsRuntimeRef>(f: impl FnOnce() -> T, runtime: &SR) -> T {
+	runtime.run_detached(f)
+}
+
+/// The outcome of a conditional write like [`Signal::set_if_distinct_reporting`].
+///
+/// Since 0.2.1.
+#[must_use = "The rejected value is handed back in `MaybeSet::Unchanged`."]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MaybeSet<T> {
+	/// The value was overwritten and dependents were signalled.
+	Set,
+	/// The new value was rejected, for example because it equalled the current one,
+	/// which was left untouched.
+	///
+	/// Contains the rejected value.
+	Unchanged(T),
+}
+
+impl<T> From<Result<(), T>> for MaybeSet<T> {
+	fn from(result: Result<(), T>) -> Self {
+		match result {
+			Ok(()) => Self::Set,
+			Err(value) => Self::Unchanged(value),
+		}
+	}
+}
+
+/// The outcome of a conditional replacement like [`Signal::swap_if_distinct_blocking`].
+///
+/// Since 0.2.1.
+#[must_use = "Either the previous or the rejected value is handed back."]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MaybeReplaced<T> {
+	/// The value was replaced and dependents were signalled.
+	///
+	/// Contains the previous value.
+	Replaced(T),
+	/// The new value equalled the current one, which was left untouched.
+	///
+	/// Contains the rejected value.
+	Unchanged(T),
+}
+
+impl<T> From<Result<T, T>> for MaybeReplaced<T> {
+	fn from(result: Result<T, T>) -> Self {
+		match result {
+			Ok(previous) => Self::Replaced(previous),
+			Err(rejected) => Self::Unchanged(rejected),
+		}
+	}
+}
+
+/// Whether reading a stale unsubscribed signal refreshes it first,
+/// as set with [`Signal::computed_with_stale_read`].
+///
+/// Subscribed signals are refreshed by the signals runtime regardless.
+///
+/// Since 0.2.1.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum StaleRead {
+	/// Refreshes the signal before reading it. This is how [`Signal::computed`] behaves.
+	#[default]
+	Refresh,
+	/// Returns the last computed value, which **may** be stale.
+	///
+	/// The signal still computes its value when read for the first time
+	/// and refreshes whenever it's subscribed.
+	ReturnStale,
+}
 
 pub mod prelude {
 	//! Unmanaged signal accessors and [`SignalsRuntimeRef`].  
@@ -60,6 +325,40 @@ pub mod __ {
 			new_raw_unsubscribed_subscription, pin_into_pin_impl_source, pull_new_subscription,
 		},
 	};
+	pub use super::value::{ValueProbe, ValueViaClone, ValueViaCopy};
+}
+
+/// Records a signal as dependency and retrieves its value,
+/// through [`Signal::get`] iff the value type is known to be [`Copy`] and [`Signal::get_clone`] otherwise.
+///
+/// The argument can be anything that dereferences to a [`Signal`], like a [`SignalArc`] or [`Subscription`].
+///
+/// In generic code where `T` is only known to be [`Clone`], this always clones.  
+/// (This is a macro since a method can't make that choice without specialisation.)
+///
+/// ```
+/// # {
+/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+/// use flourish::{signal_value, GlobalSignalsRuntime};
+///
+/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+///
+/// let copied = Signal::cell(1);
+/// let cloned = Signal::cell("a".to_string());
+///
+/// assert_eq!(signal_value!(copied), 1);
+/// assert_eq!(signal_value!(cloned), "a");
+/// # }
+/// ```
+///
+/// Since 0.2.1.
+#[macro_export]
+macro_rules! signal_value {
+	($signal:expr$(,)?) => {{
+		#[allow(unused_imports)]
+		use $crate::__::{ValueViaClone as _, ValueViaCopy as _};
+		(&&$crate::__::ValueProbe(&$signal)).__value()
+	}};
 }
 
 /// Shadows each identifier in place with its [`Clone::clone`].
@@ -87,10 +386,10 @@ pub mod __ {
 macro_rules! shadow_clone {
 	($ident:ident$(,)?) => {
 		// This would warn because of extra parentheses… and it's fewer tokens.
-		let $ident = ::std::clone::Clone::clone(&$ident);
+		let $ident = ::core::clone::Clone::clone(&$ident);
 	};
     ($($ident:ident),*$(,)?) => {
-		let ($($ident),*) = ($(::std::clone::Clone::clone(&$ident)),*);
+		let ($($ident),*) = ($(::core::clone::Clone::clone(&$ident)),*);
 	};
 }
 
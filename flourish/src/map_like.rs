@@ -0,0 +1,28 @@
+use alloc::collections::BTreeMap;
+#[cfg(feature = "std")]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// Map-like collections whose entries [`Signal::with_entry`](`crate::Signal::with_entry`) can access in place.
+///
+/// Since 0.2.1.
+pub trait MapLike<K, V> {
+	/// Returns the value for `key`, first inserting [`V::default()`](`Default::default`) iff it's missing.
+	///
+	/// Since 0.2.1.
+	fn entry_or_default(&mut self, key: K) -> &mut V;
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, V: Default, S: BuildHasher> MapLike<K, V> for HashMap<K, V, S> {
+	fn entry_or_default(&mut self, key: K) -> &mut V {
+		self.entry(key).or_default()
+	}
+}
+
+impl<K: Ord, V: Default> MapLike<K, V> for BTreeMap<K, V> {
+	fn entry_or_default(&mut self, key: K) -> &mut V {
+		self.entry(key).or_default()
+	}
+}
@@ -0,0 +1,170 @@
+use alloc::{
+	boxed::Box,
+	sync::{Arc, Weak},
+	vec::Vec,
+};
+use core::{mem, pin::Pin};
+
+use crate::sync::{Mutex, PoisonError};
+
+type Callback<T> = Box<dyn Send + FnMut(&T)>;
+type Start<T> = Box<dyn Send + Sync + Fn(Weak<Shared<T>>) -> Pin<Box<dyn Send + Sync>>>;
+
+/// A set of observers of one signal, all notified by a single shared effect.
+///
+/// Create one with [`Subscription::observers`](`crate::Subscription::observers`) and add observers
+/// with [`on_change`](`Observers::on_change`). The effect runs only while there is at least one observer.
+///
+/// Dropping this removes all of its observers.
+///
+/// Since 0.2.1.
+#[must_use = "The observers are removed when this is dropped."]
+pub struct Observers<T: ?Sized> {
+	shared: Arc<Shared<T>>,
+	/// Creates the notifying effect.
+	start: Start<T>,
+}
+
+/// The callbacks along with the single effect that notifies all of them.
+pub(crate) struct Shared<T: ?Sized> {
+	/// Present iff there's at least one observer. Always locked before `state`.
+	effect: Mutex<Option<Pin<Box<dyn Send + Sync>>>>,
+	state: Mutex<State<T>>,
+}
+
+struct State<T: ?Sized> {
+	next_id: u64,
+	callbacks: Vec<(u64, Callback<T>)>,
+	/// Whether `callbacks` are currently taken out to be called.
+	notifying: bool,
+	/// Observers that were removed while `notifying`.
+	removed: Vec<u64>,
+}
+
+impl<T: ?Sized + 'static> Observers<T> {
+	pub(crate) fn new(
+		start: impl 'static + Send + Sync + Fn(Weak<Shared<T>>) -> Pin<Box<dyn Send + Sync>>,
+	) -> Self {
+		Self {
+			shared: Arc::new(Shared {
+				effect: Mutex::new(None),
+				state: Mutex::new(State {
+					next_id: 0,
+					callbacks: Vec::new(),
+					notifying: false,
+					removed: Vec::new(),
+				}),
+			}),
+			start: Box::new(start),
+		}
+	}
+
+	/// Adds an observer that's called with the new value after each change,
+	/// for as long as the returned [`ObserverHandle`] and this [`Observers`] are alive.
+	///
+	/// Observers don't record dependencies and may add or remove observers themselves.
+	///
+	/// Since 0.2.1.
+	pub fn on_change(&self, f: impl 'static + Send + FnMut(&T)) -> ObserverHandle {
+		let shared = &self.shared;
+		let mut effect = shared.effect.lock().unwrap_or_else(PoisonError::into_inner);
+		let id = {
+			let mut state = shared.state.lock().unwrap_or_else(PoisonError::into_inner);
+			let id = state.next_id;
+			state.next_id += 1;
+			state.callbacks.push((id, Box::new(f)));
+			id
+		};
+		if effect.is_none() {
+			*effect = Some((self.start)(Arc::downgrade(shared)));
+		}
+		ObserverHandle {
+			observers: Arc::downgrade(shared) as Weak<dyn Remove>,
+			id,
+		}
+	}
+}
+
+impl<T: ?Sized> Shared<T> {
+	/// Calls each callback with `value`.
+	///
+	/// The callbacks are called without holding a lock, so they can add or remove observers.
+	pub(crate) fn notify(&self, value: &T) {
+		/// Puts the callbacks back when dropped, including while unwinding from one of them.
+		struct Restore<'a, T: ?Sized> {
+			shared: &'a Shared<T>,
+			callbacks: Vec<(u64, Callback<T>)>,
+		}
+
+		impl<T: ?Sized> Drop for Restore<'_, T> {
+			fn drop(&mut self) {
+				let mut callbacks = mem::take(&mut self.callbacks);
+				let mut state = self
+					.shared
+					.state
+					.lock()
+					.unwrap_or_else(PoisonError::into_inner);
+				state.notifying = false;
+				let removed = mem::take(&mut state.removed);
+				callbacks.retain(|(id, _)| !removed.contains(id));
+				callbacks.append(&mut state.callbacks);
+				state.callbacks = callbacks;
+			}
+		}
+
+		let mut restore = Restore {
+			shared: self,
+			callbacks: {
+				let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+				state.notifying = true;
+				mem::take(&mut state.callbacks)
+			},
+		};
+		for (_, callback) in &mut restore.callbacks {
+			callback(value);
+		}
+	}
+}
+
+trait Remove: Send + Sync {
+	fn remove(&self, id: u64);
+}
+
+impl<T: ?Sized> Remove for Shared<T> {
+	fn remove(&self, id: u64) {
+		let idle = {
+			let mut effect = self.effect.lock().unwrap_or_else(PoisonError::into_inner);
+			let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+			if let Some(index) = state.callbacks.iter().position(|(i, _)| *i == id) {
+				drop(state.callbacks.remove(index));
+			} else if state.notifying {
+				state.removed.push(id);
+			}
+			// The effect can't be dropped from within its own run, so it stays active until the next removal outside of it.
+			if state.callbacks.is_empty() && !state.notifying {
+				effect.take()
+			} else {
+				None
+			}
+		};
+		// Dropped outside the locks, since this purges the effect from the signals runtime.
+		drop(idle);
+	}
+}
+
+/// Keeps an observer added with [`Observers::on_change`] registered.
+///
+/// Dropping this removes just that observer. The [`Observers`] stay active.
+#[must_use = "The observer is removed when this handle is dropped."]
+pub struct ObserverHandle {
+	observers: Weak<dyn Remove>,
+	id: u64,
+}
+
+impl Drop for ObserverHandle {
+	fn drop(&mut self) {
+		if let Some(observers) = self.observers.upgrade() {
+			observers.remove(self.id);
+		}
+	}
+}
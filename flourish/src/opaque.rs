@@ -1,4 +1,5 @@
-use std::{
+use alloc::boxed::Box;
+use core::{
 	borrow::Borrow,
 	future::Future,
 	marker::{PhantomData, PhantomPinned},
@@ -46,6 +47,14 @@ impl<T: ?Sized + Send, SR: ?Sized + SignalsRuntimeRef> UnmanagedSignal<T, SR> fo
 		Self: 'r + Sized,
 		T: 'r + Sync;
 
+	fn try_read<'r>(self: Pin<&'r Self>) -> Option<OpaqueGuard<T>>
+	where
+		Self: Sized,
+		T: 'r + Sync,
+	{
+		match *self {}
+	}
+
 	fn read_exclusive<'r>(self: Pin<&'r Self>) -> OpaqueGuard<T>
 	where
 		Self: Sized,
@@ -326,3 +335,9 @@ impl<T: ?Sized> Borrow<T> for OpaqueGuard<T> {
 		match self._vacant {}
 	}
 }
+
+impl<T: ?Sized> AsRef<T> for OpaqueGuard<T> {
+	fn as_ref(&self) -> &T {
+		self
+	}
+}
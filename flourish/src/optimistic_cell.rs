@@ -0,0 +1,197 @@
+use alloc::boxed::Box;
+use core::ops::Deref;
+
+use isoprenoid::runtime::{Propagation, SignalsRuntimeRef};
+
+use crate::{
+	unmanaged::{computed, InertCell},
+	SignalArc, SignalArcDyn, SignalArcDynCell, SignalDyn,
+};
+
+/// A signal cell for optimistic updates, with a separate confirmed value to fall back to.
+///
+/// [`set`](`OptimisticCell::set`) applies a value optimistically, that is: immediately and tentatively.
+/// [`confirm`](`OptimisticCell::confirm`) then accepts it as confirmed value,
+/// while [`rollback`](`OptimisticCell::rollback`) discards it and reverts to the last confirmed value.
+/// The confirmed value can also be replaced directly via [`set_confirmed`](`OptimisticCell::set_confirmed`),
+/// for example when the authoritative state changes elsewhere.
+///
+/// Reads go through [`Deref`] to a read-only [`SignalDyn`] of the optimistic value,
+/// which is the pending value if there is one and the confirmed value otherwise.
+/// The confirmed value alone is available through [`confirmed`](`OptimisticCell::confirmed`).
+///
+/// ```
+/// # {
+/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+/// # use flourish::GlobalSignalsRuntime;
+/// type OptimisticCell<'a, T> = flourish::OptimisticCell<'a, T, GlobalSignalsRuntime>;
+///
+/// let likes = OptimisticCell::new(10);
+///
+/// likes.set(11);
+/// assert_eq!(likes.get(), 11);
+/// assert_eq!(likes.confirmed().get(), 10);
+///
+/// // The request failed.
+/// assert!(likes.rollback());
+/// assert_eq!(likes.get(), 10);
+///
+/// likes.set(11);
+/// // The request succeeded.
+/// assert!(likes.confirm());
+/// assert_eq!(likes.confirmed().get(), 11);
+/// # }
+/// ```
+///
+/// Since 0.2.1.
+pub struct OptimisticCell<'a, T: 'a + Send, SR: 'a + SignalsRuntimeRef> {
+	confirmed: SignalArcDynCell<'a, T, SR>,
+	pending: SignalArcDynCell<'a, Option<T>, SR>,
+	optimistic: SignalArcDyn<'a, T, SR>,
+}
+
+impl<'a, T: 'a + Send + Sync + Clone, SR: 'a + SignalsRuntimeRef> OptimisticCell<'a, T, SR> {
+	/// Creates a new [`OptimisticCell`] with `confirmed_value` and no pending value.
+	///
+	/// Since 0.2.1.
+	pub fn new(confirmed_value: T) -> Self
+	where
+		SR: Default,
+	{
+		Self::new_with_runtime(confirmed_value, SR::default())
+	}
+
+	/// Creates a new [`OptimisticCell`] with `confirmed_value` and no pending value.
+	///
+	/// Since 0.2.1.
+	pub fn new_with_runtime(confirmed_value: T, runtime: SR) -> Self {
+		let confirmed = SignalArc::new(InertCell::with_runtime(confirmed_value, runtime.clone()))
+			.into_dyn_cell();
+		let pending =
+			SignalArc::new(InertCell::with_runtime(None::<T>, runtime.clone())).into_dyn_cell();
+		let optimistic = SignalArc::new(computed(
+			{
+				let confirmed = confirmed.clone();
+				let pending = pending.clone();
+				move || match &**pending.read_dyn() {
+					Some(value) => value.clone(),
+					None => confirmed.get_clone(),
+				}
+			},
+			runtime,
+		))
+		.into_dyn();
+		Self {
+			confirmed,
+			pending,
+			optimistic,
+		}
+	}
+
+	/// Optimistically replaces the current value with `new_value` and signals dependents.
+	///
+	/// This replaces any earlier pending value. The confirmed value is unchanged.
+	///
+	/// # Logic
+	///
+	/// This method **may** block *indefinitely* iff called in signal callbacks.
+	///
+	/// Since 0.2.1.
+	pub fn set(&self, new_value: T) {
+		self.pending.set_blocking(Some(new_value));
+	}
+
+	/// Accepts the pending value as new confirmed value.
+	///
+	/// Both cells are updated within one [`hint_batched_updates`](`SignalsRuntimeRef::hint_batched_updates`),
+	/// so dependents don't observe the intermediate state.
+	///
+	/// # Returns
+	///
+	/// Whether there was a pending value. If not, this is a no-op.
+	///
+	/// # Logic
+	///
+	/// This method **may** block *indefinitely* iff called in signal callbacks.
+	///
+	/// Since 0.2.1.
+	pub fn confirm(&self) -> bool {
+		self.confirmed.clone_runtime_ref().hint_batched_updates(|| {
+			let Some(value) = self.take_pending() else {
+				return false;
+			};
+			self.confirmed.set_blocking(value);
+			true
+		})
+	}
+
+	/// Discards the pending value, reverting to the last confirmed value, and signals dependents.
+	///
+	/// # Returns
+	///
+	/// Whether there was a pending value. If not, this is a no-op.
+	///
+	/// # Logic
+	///
+	/// This method **may** block *indefinitely* iff called in signal callbacks.
+	///
+	/// Since 0.2.1.
+	pub fn rollback(&self) -> bool {
+		self.take_pending().is_some()
+	}
+
+	/// Replaces the confirmed value with `new_value` and signals its dependents.
+	///
+	/// The pending value (if any) is unaffected and continues to shadow it.
+	///
+	/// # Logic
+	///
+	/// This method **may** block *indefinitely* iff called in signal callbacks.
+	///
+	/// Since 0.2.1.
+	pub fn set_confirmed(&self, new_value: T) {
+		self.confirmed.set_blocking(new_value);
+	}
+
+	/// Whether there currently is a pending (optimistic but unconfirmed) value.
+	///
+	/// This isn't recorded as dependency.
+	///
+	/// Since 0.2.1.
+	#[must_use]
+	pub fn is_pending(&self) -> bool {
+		self.confirmed
+			.clone_runtime_ref()
+			.run_detached(|| self.pending.read_dyn().is_some())
+	}
+
+	/// The confirmed value, as read-only [`SignalDyn`].
+	///
+	/// Since 0.2.1.
+	#[must_use]
+	pub fn confirmed(&self) -> &SignalDyn<'a, T, SR> {
+		self.confirmed.as_read_only()
+	}
+
+	/// Removes the pending value, signalling dependents only if there was one.
+	fn take_pending(&self) -> Option<T> {
+		let mut taken = None;
+		self.pending.update_blocking_dyn(Box::new(|pending| {
+			taken = pending.take();
+			if taken.is_some() {
+				Propagation::Propagate
+			} else {
+				Propagation::Halt
+			}
+		}));
+		taken
+	}
+}
+
+impl<'a, T: 'a + Send, SR: 'a + SignalsRuntimeRef> Deref for OptimisticCell<'a, T, SR> {
+	type Target = SignalDyn<'a, T, SR>;
+
+	fn deref(&self) -> &Self::Target {
+		&self.optimistic
+	}
+}
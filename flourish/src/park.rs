@@ -0,0 +1,30 @@
+use alloc::{sync::Arc, task::Wake};
+use core::task::{Context, Poll, Waker};
+use std::thread::{self, Thread};
+
+use futures_channel::mpsc::{SendError, Sender};
+
+struct Unparker(Thread);
+
+impl Wake for Unparker {
+	fn wake(self: Arc<Self>) {
+		self.0.unpark();
+	}
+}
+
+/// Polls `poll` with a [`Waker`] that unparks the current thread, parking it in between, until it's ready.
+pub(crate) fn park_until<T>(mut poll: impl FnMut(&mut Context<'_>) -> Poll<T>) -> T {
+	let waker = Waker::from(Arc::new(Unparker(thread::current())));
+	let mut cx = Context::from_waker(&waker);
+	loop {
+		match poll(&mut cx) {
+			Poll::Ready(t) => return t,
+			Poll::Pending => thread::park(),
+		}
+	}
+}
+
+/// Parks the current thread until `sender` has capacity or is disconnected.
+pub(crate) fn park_until_ready<T>(sender: &mut Sender<T>) -> Result<(), SendError> {
+	park_until(|cx| sender.poll_ready(cx))
+}
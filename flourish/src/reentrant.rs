@@ -0,0 +1,45 @@
+use alloc::collections::BTreeMap;
+
+/// Memoized recursion within one run of a signal created by [`Signal::computed_reentrant`](`crate::Signal::computed_reentrant`).
+///
+/// Each key is computed at most once per run. Reading a key again while it's still being
+/// computed is a cycle, which panics instead of recursing indefinitely.
+///
+/// Since 0.2.1.
+pub struct Reentrant<'r, K, T> {
+	fn_pin: &'r dyn Fn(&K, &mut Reentrant<'r, K, T>) -> T,
+	/// [`None`] while the key is being computed.
+	memo: BTreeMap<K, Option<T>>,
+}
+
+impl<'r, K: Ord + Clone, T: Clone> Reentrant<'r, K, T> {
+	pub(crate) fn run(fn_pin: &'r dyn Fn(&K, &mut Reentrant<'r, K, T>) -> T, key: K) -> T {
+		Self {
+			fn_pin,
+			memo: BTreeMap::new(),
+		}
+		.get(key)
+	}
+
+	/// Computes the value for `key`, or clones it iff it was already computed during this run.
+	///
+	/// Dependencies are recorded for the signal as a whole.
+	///
+	/// # Panics
+	///
+	/// Iff `key` is currently being computed, as that would recurse indefinitely.
+	pub fn get(&mut self, key: K) -> T {
+		match self.memo.get(&key) {
+			Some(Some(value)) => return value.clone(),
+			Some(None) => panic!(
+				"Cyclic read in `Signal::computed_reentrant`: A key was read while it was being computed."
+			),
+			None => (),
+		}
+		self.memo.insert(key.clone(), None);
+		let fn_pin = self.fn_pin;
+		let value = fn_pin(&key, self);
+		self.memo.insert(key, Some(value.clone()));
+		value
+	}
+}
@@ -0,0 +1,121 @@
+use core::{
+	borrow::Borrow,
+	fmt::{self, Debug, Formatter},
+	hash::Hash,
+};
+use std::{collections::HashMap, vec::Vec};
+
+use isoprenoid::runtime::SignalsRuntimeRef;
+
+use crate::{
+	sync::{Mutex, PoisonError},
+	SignalArcDyn, SignalWeak, SignalWeakDyn,
+};
+
+/// A map from keys to weakly-held signals, for deduplicating derived signals (for example per entity).
+///
+/// Entries whose signal was dropped are pruned on each access, without upgrading them.
+///
+/// ```
+/// # {
+/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+/// # use flourish::GlobalSignalsRuntime;
+/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+/// type SignalRegistry<K, T> = flourish::SignalRegistry<K, T, GlobalSignalsRuntime>;
+///
+/// let registry = SignalRegistry::<u32, u32>::new();
+///
+/// let a = registry.get_or_create(1, || Signal::computed(|| 2).into_dyn_static());
+/// let b = registry.get_or_create(1, || unreachable!());
+/// assert!(std::ptr::addr_eq(&*a, &*b));
+///
+/// drop((a, b));
+/// assert!(registry.get(&1).is_none());
+/// # }
+/// ```
+///
+/// # Features
+///
+/// **The feature `"std"` is required to enable this type.**
+///
+/// Since 0.2.1.
+pub struct SignalRegistry<K, T: ?Sized + Send, SR: SignalsRuntimeRef> {
+	entries: Mutex<HashMap<K, SignalWeakDyn<'static, T, SR>>>,
+}
+
+impl<K, T: ?Sized + Send, SR: SignalsRuntimeRef> Default for SignalRegistry<K, T, SR> {
+	fn default() -> Self {
+		Self {
+			entries: Mutex::new(HashMap::new()),
+		}
+	}
+}
+
+impl<K: Debug, T: ?Sized + Send, SR: SignalsRuntimeRef> Debug for SignalRegistry<K, T, SR> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		let entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+		f.debug_set().entries(entries.keys()).finish()
+	}
+}
+
+impl<K: Eq + Hash, T: ?Sized + Send, SR: SignalsRuntimeRef> SignalRegistry<K, T, SR> {
+	/// Creates a new empty [`SignalRegistry`].
+	///
+	/// Since 0.2.1.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Retrieves the live signal registered for `key`, if any.
+	///
+	/// Since 0.2.1.
+	pub fn get<Q: ?Sized + Eq + Hash>(&self, key: &Q) -> Option<SignalArcDyn<'static, T, SR>>
+	where
+		K: Borrow<Q>,
+	{
+		let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+		let pruned = Self::prune(&mut entries);
+		let signal = entries.get(key).and_then(SignalWeak::upgrade);
+		drop(entries);
+		drop(pruned);
+		signal
+	}
+
+	/// Retrieves the live signal registered for `key`, or registers the one returned by `create`.
+	///
+	/// The registry stays locked while `create` runs, so concurrent calls with the same `key`
+	/// return the same signal.  
+	/// **Don't** access this [`SignalRegistry`] from `create`, as that would deadlock.
+	///
+	/// Since 0.2.1.
+	pub fn get_or_create(
+		&self,
+		key: K,
+		create: impl FnOnce() -> SignalArcDyn<'static, T, SR>,
+	) -> SignalArcDyn<'static, T, SR> {
+		let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+		let pruned = Self::prune(&mut entries);
+		let (signal, replaced) = match entries.get(&key).and_then(SignalWeak::upgrade) {
+			Some(signal) => (signal, None),
+			None => {
+				// The entry may have been dropped concurrently since pruning.
+				let signal = create();
+				let replaced = entries.insert(key, signal.downgrade());
+				(signal, replaced)
+			}
+		};
+		drop(entries);
+		drop((pruned, replaced));
+		signal
+	}
+
+	/// Removes entries whose signal was dropped.
+	///
+	/// They are returned so that they can be dropped once the registry is unlocked.
+	fn prune(
+		entries: &mut HashMap<K, SignalWeakDyn<'static, T, SR>>,
+	) -> Vec<(K, SignalWeakDyn<'static, T, SR>)> {
+		entries.extract_if(|_, weak| !weak.weak.is_live()).collect()
+	}
+}
@@ -0,0 +1,97 @@
+//! Combinators for signals of [`Result`]s.
+
+use isoprenoid::runtime::SignalsRuntimeRef;
+
+use crate::{traits::UnmanagedSignal, Signal, SignalArc};
+
+/// Extension methods for [`SignalArc`]s of [`Result`]s.
+///
+/// Each combinator creates a new cached computation that depends on and keeps alive `self`.
+///
+/// ```
+/// # {
+/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+/// use flourish::{result::ResultSignalExt, GlobalSignalsRuntime};
+///
+/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+///
+/// let input = Signal::cell("1");
+/// let parsed = Signal::computed(move || input.get().parse::<u32>())
+/// 	.map_ok(|value| value + 1)
+/// 	.map_err(|error| error.to_string());
+/// assert_eq!(parsed.get_clone(), Ok(2));
+/// # }
+/// ```
+///
+/// Since 0.2.1.
+pub trait ResultSignalExt<T: Send, E: Send, SR: SignalsRuntimeRef>: Sized {
+	/// Maps [`Ok`] values with `map_fn_pin`, which doesn't run for [`Err`]s.
+	///
+	/// [`Err`]s are passed through by [`Clone`].
+	fn map_ok<'a, U: 'a + Send>(
+		self,
+		map_fn_pin: impl 'a + Send + FnMut(&T) -> U,
+	) -> SignalArc<Result<U, E>, impl 'a + Sized + UnmanagedSignal<Result<U, E>, SR>, SR>
+	where
+		Self: 'a,
+		T: 'a,
+		E: 'a + Clone,
+		SR: 'a;
+
+	/// Maps [`Err`] values with `map_fn_pin`, which doesn't run for [`Ok`]s.
+	///
+	/// [`Ok`]s are passed through by [`Clone`].
+	fn map_err<'a, F: 'a + Send>(
+		self,
+		map_fn_pin: impl 'a + Send + FnMut(&E) -> F,
+	) -> SignalArc<Result<T, F>, impl 'a + Sized + UnmanagedSignal<Result<T, F>, SR>, SR>
+	where
+		Self: 'a,
+		T: 'a + Clone,
+		E: 'a,
+		SR: 'a;
+}
+
+impl<T: Send, E: Send, S: ?Sized + UnmanagedSignal<Result<T, E>, SR>, SR: SignalsRuntimeRef>
+	ResultSignalExt<T, E, SR> for SignalArc<Result<T, E>, S, SR>
+{
+	fn map_ok<'a, U: 'a + Send>(
+		self,
+		mut map_fn_pin: impl 'a + Send + FnMut(&T) -> U,
+	) -> SignalArc<Result<U, E>, impl 'a + Sized + UnmanagedSignal<Result<U, E>, SR>, SR>
+	where
+		Self: 'a,
+		T: 'a,
+		E: 'a + Clone,
+		SR: 'a,
+	{
+		let runtime = self.clone_runtime_ref();
+		Signal::computed_with_runtime(
+			move || match &**self.read_exclusive_dyn() {
+				Ok(value) => Ok(map_fn_pin(value)),
+				Err(error) => Err(error.clone()),
+			},
+			runtime,
+		)
+	}
+
+	fn map_err<'a, F: 'a + Send>(
+		self,
+		mut map_fn_pin: impl 'a + Send + FnMut(&E) -> F,
+	) -> SignalArc<Result<T, F>, impl 'a + Sized + UnmanagedSignal<Result<T, F>, SR>, SR>
+	where
+		Self: 'a,
+		T: 'a + Clone,
+		E: 'a,
+		SR: 'a,
+	{
+		let runtime = self.clone_runtime_ref();
+		Signal::computed_with_runtime(
+			move || match &**self.read_exclusive_dyn() {
+				Ok(value) => Ok(value.clone()),
+				Err(error) => Err(map_fn_pin(error)),
+			},
+			runtime,
+		)
+	}
+}
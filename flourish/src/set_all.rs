@@ -0,0 +1,43 @@
+use isoprenoid::runtime::SignalsRuntimeRef;
+
+use crate::SignalDynCell;
+
+/// Sets each cell in `pairs` to its paired value, all within one [`SignalsRuntimeRef::hint_batched_updates`],
+/// so that dependents refresh only once for the whole batch.
+///
+/// Each value is applied via [`set`](`crate::Signal::set`), in iteration order.
+/// If a cell appears more than once, the last value wins.  
+/// (Blocking setters can't be used here, as they would have to wait for the batch to propagate.)
+///
+/// The batch is hinted to the runtime of the first cell. All cells are expected to share it.
+///
+/// ```
+/// # {
+/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+/// use flourish::{set_all, GlobalSignalsRuntime};
+///
+/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+///
+/// let a = Signal::cell(1);
+/// let b = Signal::cell(2);
+///
+/// set_all([(a.as_dyn_cell(), 10), (b.as_dyn_cell(), 20)]);
+/// assert_eq!((a.get(), b.get()), (10, 20));
+/// # }
+/// ```
+///
+/// Since 0.2.1.
+pub fn set_all<'r, 'a: 'r, T: 'static + Send, SR: 'a + SignalsRuntimeRef>(
+	pairs: impl IntoIterator<Item = (&'r SignalDynCell<'a, T, SR>, T)>,
+) {
+	let mut pairs = pairs.into_iter();
+	let Some((first_cell, first_value)) = pairs.next() else {
+		return;
+	};
+	first_cell.clone_runtime_ref().hint_batched_updates(|| {
+		first_cell.set(first_value);
+		for (cell, value) in pairs {
+			cell.set(value);
+		}
+	});
+}
@@ -1,4 +1,5 @@
-use std::{
+use alloc::{borrow::ToOwned, boxed::Box, sync::Arc, vec::Vec};
+use core::{
 	borrow::Borrow,
 	cell::UnsafeCell,
 	fmt::{self, Debug, Formatter},
@@ -7,24 +8,63 @@ use std::{
 	mem::{self, ManuallyDrop, MaybeUninit},
 	ops::Deref,
 	pin::Pin,
-	process::abort,
 	sync::atomic::{AtomicUsize, Ordering},
+	task::{self, Poll},
+	time::Duration,
 	usize,
 };
+#[cfg(feature = "std")]
+use std::{
+	hash::{DefaultHasher, Hash, Hasher},
+	panic::{self, AssertUnwindSafe},
+	process::abort,
+	sync::mpsc,
+	thread,
+};
+
+#[cfg(feature = "timing")]
+use core::sync::atomic::AtomicU64;
+#[cfg(feature = "timing")]
+use std::time::Instant;
 
+#[cfg(feature = "std")]
+use futures_channel::mpsc::Sender;
+use futures_channel::oneshot;
 use futures_lite::FutureExt as _;
-use isoprenoid::runtime::{CallbackTableTypes, Propagation, SignalsRuntimeRef};
+use isoprenoid::runtime::{CallbackTableTypes, Propagation, SignalsRuntimeRef, SubscriberCount};
 use tap::Conv;
 
+#[cfg(feature = "arc-swap")]
+use crate::SyncedArcSwap;
 use crate::{
+	abort::{abortable, AbortHandle},
+	context::Context,
+	display::{DebugValue, DisplayValue},
+	invalidator::{InvalidatingWaker, Invalidator},
+	map_like::MapLike,
 	opaque::Opaque,
 	signal_arc::SignalWeakDynCell,
-	traits::{UnmanagedSignal, UnmanagedSignalCell},
+	signals_helper,
+	sync::{Mutex, OnceLock},
+	traits::{emplace_guard, UnmanagedSignal, UnmanagedSignalCell},
+	ttl::Timer,
 	unmanaged::{
-		computed, computed_uncached, computed_uncached_mut, distinct, folded, reduced, InertCell,
-		ReactiveCell, ReactiveCellMut, Shared,
+		computed, computed_uncached, computed_uncached_mut, distinct, folded, reduced, Computed,
+		ComputedEvictable, ComputedSlice, Folded, InertCell, Lens, Reactive, ReactiveCell,
+		ReactiveCellMut, Shared, WhileSubscribed,
 	},
-	Guard, SignalArc, SignalArcDyn, SignalArcDynCell, SignalWeak, Subscription,
+	CowGuard, Evictor, Guard, MaybeReplaced, MaybeSet, Reentrant, Scope, SignalArc, SignalArcDyn,
+	SignalArcDynCell, SignalWeak, StaleRead, Subscription, Validated,
+};
+#[cfg(feature = "std")]
+use crate::{
+	changes::IterChanges, last_panic::LastPanic, park::park_until_ready,
+	traits::UnmanagedSignalResilient, unmanaged::ComputedResilient, Effect,
+};
+#[cfg(feature = "futures")]
+use crate::{
+	collection_delta::{CollectionDelta, CollectionDiffs, DiffCollection},
+	diffs::Diffs,
 };
 
 /// A reference-counted signal.
@@ -123,9 +163,14 @@ impl<T: ?Sized + Send, SR: ?Sized + SignalsRuntimeRef> Signal<T, Opaque, SR> {
 		SignalArc::new(computed(fn_pin, runtime))
 	}
 
-	/// A simple cached computation.
+	/// A cached computation over a family of keyed values that may read each other recursively,
+	/// like memoized recursion over a finite DAG.
 	///
-	/// Doesn't update its cache or propagate iff the new result is equal.
+	/// `fn_pin` computes the value for one key and reads others through [`Reentrant::get`].
+	/// The signal holds the value for `key`. Each key is computed at most once per run,
+	/// and reading a key while it's being computed panics instead of recursing indefinitely.
+	///
+	/// The family is one signal, so a change of any dependency recomputes all keys reached from `key`.
 	///
 	/// ```
 	/// # {
@@ -133,103 +178,172 @@ impl<T: ?Sized + Send, SR: ?Sized + SignalsRuntimeRef> Signal<T, Opaque, SR> {
 	/// # use flourish::GlobalSignalsRuntime;
 	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
 	///
-	/// # let input = Signal::cell(1);
-	/// Signal::distinct(|| input.get() + 1);
+	/// let n = 50u64;
+	/// let fibonacci = Signal::computed_reentrant(n, |&n, family| match n {
+	/// 	0 | 1 => n,
+	/// 	n => family.get(n - 1) + family.get(n - 2),
+	/// });
+	/// assert_eq!(fibonacci.get(), 12586269025);
 	/// # }
 	/// ```
 	///
-	/// Note that iff there is no subscriber,
-	/// this signal and its dependents will still become stale unconditionally.
-	///
-	/// Wraps [`distinct`](`distinct()`).
-	pub fn distinct<'a>(
-		fn_pin: impl 'a + Send + FnMut() -> T,
+	/// Since 0.2.1.
+	pub fn computed_reentrant<'a, K: 'a + Send + Ord + Clone>(
+		key: K,
+		fn_pin: impl 'a + Send + Fn(&K, &mut Reentrant<'_, K, T>) -> T,
 	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
 	where
-		T: 'a + Sized + PartialEq,
+		T: 'a + Sized + Clone,
 		SR: 'a + Default,
 	{
-		Self::distinct_with_runtime(fn_pin, SR::default())
+		Self::computed_reentrant_with_runtime(key, fn_pin, SR::default())
 	}
 
-	/// A simple cached computation.
+	/// A cached computation over a family of keyed values that may read each other recursively,
+	/// like memoized recursion over a finite DAG.
 	///
-	/// Doesn't update its cache or propagate iff the new result is equal.
+	/// `fn_pin` computes the value for one key and reads others through [`Reentrant::get`].
+	/// The signal holds the value for `key`. Each key is computed at most once per run,
+	/// and reading a key while it's being computed panics instead of recursing indefinitely.
+	///
+	/// The family is one signal, so a change of any dependency recomputes all keys reached from `key`.
+	///
+	/// Since 0.2.1.
+	pub fn computed_reentrant_with_runtime<'a, K: 'a + Send + Ord + Clone>(
+		key: K,
+		fn_pin: impl 'a + Send + Fn(&K, &mut Reentrant<'_, K, T>) -> T,
+		runtime: SR,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
+	where
+		T: 'a + Sized + Clone,
+		SR: 'a,
+	{
+		Self::computed_with_runtime(move || Reentrant::run(&fn_pin, key.clone()), runtime)
+	}
+
+	/// A simple cached computation with an explicit [`StaleRead`] policy.
+	///
+	/// With [`StaleRead::Refresh`], this is the same as [`computed`](`Signal::computed`).  
+	/// With [`StaleRead::ReturnStale`], reading the signal while it's unsubscribed returns
+	/// the last computed value instead of refreshing it.
 	///
 	/// ```
 	/// # {
 	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
-	/// # use flourish::{GlobalSignalsRuntime, Signal};
-	/// # let input = Signal::cell_with_runtime(1, GlobalSignalsRuntime);
-	/// Signal::distinct_with_runtime(|| input.get() + 1, input.clone_runtime_ref());
+	/// # use flourish::GlobalSignalsRuntime;
+	/// use flourish::StaleRead;
+	///
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// let input = Signal::cell(1);
+	/// let lazy = Signal::computed_with_stale_read(|| input.get(), StaleRead::ReturnStale);
+	/// assert_eq!(lazy.get(), 1);
+	///
+	/// input.set(2);
+	/// assert_eq!(lazy.get(), 1);
 	/// # }
 	/// ```
 	///
-	/// Note that iff there is no subscriber,
-	/// this signal and its dependents will still become stale unconditionally.
+	/// Since 0.2.1.
+	pub fn computed_with_stale_read<'a>(
+		fn_pin: impl 'a + Send + FnMut() -> T,
+		stale_read: StaleRead,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
+	where
+		T: 'a + Sized,
+		SR: 'a + Default,
+	{
+		Self::computed_with_stale_read_with_runtime(fn_pin, stale_read, SR::default())
+	}
+
+	/// A simple cached computation with an explicit [`StaleRead`] policy.
 	///
-	/// Wraps [`distinct`](`distinct()`).
-	pub fn distinct_with_runtime<'a>(
+	/// With [`StaleRead::Refresh`], this is the same as [`computed_with_runtime`](`Signal::computed_with_runtime`).  
+	/// With [`StaleRead::ReturnStale`], reading the signal while it's unsubscribed returns
+	/// the last computed value instead of refreshing it.
+	///
+	/// Since 0.2.1.
+	pub fn computed_with_stale_read_with_runtime<'a>(
 		fn_pin: impl 'a + Send + FnMut() -> T,
+		stale_read: StaleRead,
 		runtime: SR,
 	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
 	where
-		T: 'a + Sized + PartialEq,
+		T: 'a + Sized,
 		SR: 'a,
 	{
-		SignalArc::new(distinct(fn_pin, runtime))
+		SignalArc::new(Computed::with_stale_read(fn_pin, stale_read, runtime))
 	}
 
-	/// A simple **uncached** computation.
+	/// A simple cached computation that only recomputes while subscribed.
+	///
+	/// When its last subscriber is removed, the signal drops its dependencies and holds its
+	/// last value. It then doesn't go stale and isn't refreshed when read, unlike [`computed`](`Signal::computed`).  
+	/// When it's subscribed again, it recomputes once immediately to catch up.
 	///
 	/// ```
 	/// # {
 	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
 	/// # use flourish::GlobalSignalsRuntime;
 	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	/// type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
 	///
-	/// # let input = Signal::cell(1);
-	/// Signal::computed_uncached(|| input.get() + 1);
+	/// let input = Signal::cell(1);
+	/// let gated = Signal::computed_while_subscribed(|| input.get());
+	///
+	/// let sub = Subscription::computed(|| gated.get());
+	/// input.set(2);
+	/// assert_eq!(sub.get(), 2);
+	///
+	/// drop(sub);
+	/// input.set(3);
+	/// assert_eq!(gated.get(), 2);
+	///
+	/// let sub = Subscription::computed(|| gated.get());
+	/// assert_eq!(sub.get(), 3);
 	/// # }
 	/// ```
 	///
-	/// Wraps [`computed_uncached`](`computed_uncached()`).
-	pub fn computed_uncached<'a>(
-		fn_pin: impl 'a + Send + Sync + Fn() -> T,
+	/// Since 0.2.1.
+	pub fn computed_while_subscribed<'a>(
+		fn_pin: impl 'a + Send + FnMut() -> T,
 	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
 	where
 		T: 'a + Sized,
 		SR: 'a + Default,
+		<SR::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus: SubscriberCount,
 	{
-		Self::computed_uncached_with_runtime(fn_pin, SR::default())
+		Self::computed_while_subscribed_with_runtime(fn_pin, SR::default())
 	}
 
-	/// A simple **uncached** computation.
+	/// A simple cached computation that only recomputes while subscribed.
 	///
-	/// ```
-	/// # {
-	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
-	/// # use flourish::{GlobalSignalsRuntime, Signal};
-	/// # let input = Signal::cell_with_runtime(1, GlobalSignalsRuntime);
-	/// Signal::computed_uncached_with_runtime(|| input.get() + 1, input.clone_runtime_ref());
-	/// # }
-	/// ```
+	/// When its last subscriber is removed, the signal drops its dependencies and holds its
+	/// last value. It then doesn't go stale and isn't refreshed when read, unlike [`computed_with_runtime`](`Signal::computed_with_runtime`).  
+	/// When it's subscribed again, it recomputes once immediately to catch up.
 	///
-	/// Wraps [`computed_uncached`](`computed_uncached()`).
-	pub fn computed_uncached_with_runtime<'a>(
-		fn_pin: impl 'a + Send + Sync + Fn() -> T,
+	/// Since 0.2.1.
+	pub fn computed_while_subscribed_with_runtime<'a>(
+		fn_pin: impl 'a + Send + FnMut() -> T,
 		runtime: SR,
 	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
 	where
 		T: 'a + Sized,
 		SR: 'a,
+		<SR::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus: SubscriberCount,
 	{
-		SignalArc::new(computed_uncached(fn_pin, runtime))
+		SignalArc::new(Computed::with_gate(
+			fn_pin,
+			WhileSubscribed::default(),
+			StaleRead::Refresh,
+			runtime,
+		))
 	}
 
-	/// A simple **stateful uncached** computation.
+	/// A simple cached computation that can observe subscription status changes.
 	///
-	/// ⚠️ Care must be taken to avoid unexpected behaviour!
+	/// `on_subscribed_change_fn_pin` is called with the runtime's
+	/// [`SubscribedStatus`](`CallbackTableTypes::SubscribedStatus`) whenever it changes.
 	///
 	/// ```
 	/// # {
@@ -237,726 +351,2760 @@ impl<T: ?Sized + Send, SR: ?Sized + SignalsRuntimeRef> Signal<T, Opaque, SR> {
 	/// # use flourish::GlobalSignalsRuntime;
 	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
 	///
-	/// # let input = Signal::cell(1);
-	/// let mut read_count = 0;
-	/// Signal::computed_uncached_mut(move || {
-	/// 	input.touch();
-	/// 	read_count += 1;
-	/// 	read_count
+	/// let input = Signal::cell(1);
+	/// let observed = Signal::computed_reactive(|| input.get(), |status| {
+	/// 	dbg!(status);
 	/// });
 	/// # }
 	/// ```
 	///
-	/// Wraps [`computed_uncached_mut`](`computed_uncached_mut()`).
-	pub fn computed_uncached_mut<'a>(
+	/// Since 0.2.1.
+	pub fn computed_reactive<'a>(
 		fn_pin: impl 'a + Send + FnMut() -> T,
+		on_subscribed_change_fn_pin: impl 'a
+			+ Send
+			+ FnMut(<SR::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus),
 	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
 	where
 		T: 'a + Sized,
 		SR: 'a + Default,
 	{
-		Self::computed_uncached_mut_with_runtime(fn_pin, SR::default())
+		Self::computed_reactive_with_runtime(fn_pin, on_subscribed_change_fn_pin, SR::default())
 	}
 
-	/// A simple **stateful uncached** computation.
+	/// A simple cached computation that can observe subscription status changes.
 	///
-	/// ⚠️ Care must be taken to avoid unexpected behaviour!
+	/// `on_subscribed_change_fn_pin` is called with the runtime's
+	/// [`SubscribedStatus`](`CallbackTableTypes::SubscribedStatus`) whenever it changes.
+	///
+	/// Since 0.2.1.
+	pub fn computed_reactive_with_runtime<'a>(
+		fn_pin: impl 'a + Send + FnMut() -> T,
+		on_subscribed_change_fn_pin: impl 'a
+			+ Send
+			+ FnMut(<SR::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus),
+		runtime: SR,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
+	where
+		T: 'a + Sized,
+		SR: 'a,
+	{
+		SignalArc::new(Computed::with_gate(
+			fn_pin,
+			Reactive(on_subscribed_change_fn_pin.into()),
+			StaleRead::Refresh,
+			runtime,
+		))
+	}
+
+	/// A simple cached computation that accumulates the time spent in `fn_pin`, for profiling.
+	///
+	/// The returned counter holds the total nanoseconds across all runs so far.
+	/// The whole `fn_pin` call is timed, which includes reading (and possibly refreshing) dependencies
+	/// and waiting for their locks. The runtime's bookkeeping around the call isn't included.
 	///
 	/// ```
 	/// # {
-	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
-	/// # use flourish::{GlobalSignalsRuntime, Signal};
-	/// # let input = &Signal::cell_with_runtime(1, GlobalSignalsRuntime);
-	/// let mut read_count = 0;
-	/// Signal::computed_uncached_mut_with_runtime(move || {
-	/// 	input.touch();
-	/// 	read_count += 1;
-	/// 	read_count
-	/// }, input.clone_runtime_ref());
+	/// # #![cfg(all(feature = "global_signals_runtime", feature = "timing"))] // flourish features
+	/// # use flourish::GlobalSignalsRuntime;
+	/// use std::sync::atomic::Ordering;
+	///
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// let (sum, nanos) = Signal::computed_timed(|| (0..1000u64).sum::<u64>());
+	/// assert_eq!(sum.get(), 499500);
+	/// println!("Spent {}ns.", nanos.load(Ordering::Relaxed));
 	/// # }
 	/// ```
 	///
-	/// Wraps [`computed_uncached_mut`](`computed_uncached_mut()`).
-	pub fn computed_uncached_mut_with_runtime<'a>(
+	/// # Features
+	///
+	/// **The feature `"timing"` is required to enable this constructor.**
+	///
+	/// Since 0.2.1.
+	#[cfg(feature = "timing")]
+	pub fn computed_timed<'a>(
 		fn_pin: impl 'a + Send + FnMut() -> T,
+	) -> (
+		SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>,
+		Arc<AtomicU64>,
+	)
+	where
+		T: 'a + Sized,
+		SR: 'a + Default,
+	{
+		Self::computed_timed_with_runtime(fn_pin, SR::default())
+	}
+
+	/// A simple cached computation that accumulates the time spent in `fn_pin`, for profiling.
+	///
+	/// The returned counter holds the total nanoseconds across all runs so far.
+	/// The whole `fn_pin` call is timed, which includes reading (and possibly refreshing) dependencies
+	/// and waiting for their locks. The runtime's bookkeeping around the call isn't included.
+	///
+	/// # Features
+	///
+	/// **The feature `"timing"` is required to enable this constructor.**
+	///
+	/// Since 0.2.1.
+	#[cfg(feature = "timing")]
+	pub fn computed_timed_with_runtime<'a>(
+		mut fn_pin: impl 'a + Send + FnMut() -> T,
 		runtime: SR,
-	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
+	) -> (
+		SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>,
+		Arc<AtomicU64>,
+	)
 	where
 		T: 'a + Sized,
 		SR: 'a,
 	{
-		SignalArc::new(computed_uncached_mut(fn_pin, runtime))
+		let nanos = Arc::new(AtomicU64::new(0));
+		let signal = Self::computed_with_runtime(
+			{
+				let nanos = Arc::clone(&nanos);
+				move || {
+					let start = Instant::now();
+					let value = fn_pin();
+					let elapsed = u64::try_from(start.elapsed().as_nanos()).unwrap_or(u64::MAX);
+					nanos.fetch_add(elapsed, Ordering::Relaxed);
+					value
+				}
+			},
+			runtime,
+		);
+		(signal, nanos)
 	}
 
-	/// The closure mutates the value and returns a [`Propagation`].
+	/// A simple cached computation that, in debug builds, panics iff one run records more than `limit` dependencies.
+	///
+	/// This catches runaway dependency graphs early. The panic message names the signal's symbol.  
+	/// In release builds (without `debug_assertions`), this is the same as [`computed`](`Signal::computed`).
 	///
 	/// ```
 	/// # {
 	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
-	/// # use flourish::{GlobalSignalsRuntime, Propagation};
+	/// # use flourish::GlobalSignalsRuntime;
 	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
 	///
-	/// # #[derive(Default, Clone)] struct Container;
-	/// # impl Container { fn sort(&mut self) {} }
-	/// # let input = Signal::cell(Container);
-	/// Signal::folded(Container::default(), move |value| {
-	/// 	value.clone_from(&input.read());
-	/// 	value.sort();
-	/// 	Propagation::Propagate
-	/// });
+	/// # let inputs = [Signal::cell(1), Signal::cell(2)];
+	/// let sum = Signal::computed_with_dependency_limit(
+	/// 	|| inputs.iter().map(|input| input.get()).sum::<i32>(),
+	/// 	2,
+	/// );
+	/// # assert_eq!(sum.get(), 3);
 	/// # }
 	/// ```
 	///
-	/// Wraps [`folded`](`folded()`).
-	pub fn folded<'a>(
-		init: T,
-		fold_fn_pin: impl 'a + Send + FnMut(&mut T) -> Propagation,
+	/// Wraps [`SignalsRuntimeRef::set_dependency_limit`].
+	///
+	/// Since 0.2.1.
+	pub fn computed_with_dependency_limit<'a>(
+		fn_pin: impl 'a + Send + FnMut() -> T,
+		limit: usize,
 	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
 	where
 		T: 'a + Sized,
 		SR: 'a + Default,
 	{
-		Self::folded_with_runtime(init, fold_fn_pin, SR::default())
+		Self::computed_with_dependency_limit_with_runtime(fn_pin, limit, SR::default())
 	}
 
-	/// The closure mutates the value and returns a [`Propagation`].
+	/// A simple cached computation that, in debug builds, panics iff one run records more than `limit` dependencies.
+	///
+	/// This catches runaway dependency graphs early. The panic message names the signal's symbol.  
+	/// In release builds (without `debug_assertions`), this is the same as [`computed_with_runtime`](`Signal::computed_with_runtime`).
 	///
 	/// ```
 	/// # {
 	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
-	/// # use flourish::{GlobalSignalsRuntime, Propagation, Signal};
-	/// # #[derive(Default, Clone)] struct Container;
-	/// # impl Container { fn sort(&mut self) {} }
-	/// # let input = Signal::cell_with_runtime(Container, GlobalSignalsRuntime);
-	/// Signal::folded_with_runtime(Container::default(), |value| {
-	/// 	value.clone_from(&input.read());
-	/// 	value.sort();
-	/// 	Propagation::Propagate
-	/// }, input.clone_runtime_ref());
+	/// # use flourish::{GlobalSignalsRuntime, Signal};
+	/// # let inputs = [1, 2].map(|value| Signal::cell_with_runtime(value, GlobalSignalsRuntime));
+	/// let sum = Signal::computed_with_dependency_limit_with_runtime(
+	/// 	|| inputs.iter().map(|input| input.get()).sum::<i32>(),
+	/// 	2,
+	/// 	GlobalSignalsRuntime,
+	/// );
 	/// # }
 	/// ```
 	///
-	/// Wraps [`folded`](`folded()`).
-	pub fn folded_with_runtime<'a>(
-		init: T,
-		fold_fn_pin: impl 'a + Send + FnMut(&mut T) -> Propagation,
+	/// Wraps [`SignalsRuntimeRef::set_dependency_limit`].
+	///
+	/// Since 0.2.1.
+	pub fn computed_with_dependency_limit_with_runtime<'a>(
+		fn_pin: impl 'a + Send + FnMut() -> T,
+		limit: usize,
 		runtime: SR,
 	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
 	where
 		T: 'a + Sized,
 		SR: 'a,
 	{
-		SignalArc::new(folded(init, fold_fn_pin, runtime))
+		let computed = Computed::new(fn_pin, runtime);
+		#[cfg(debug_assertions)]
+		computed.set_dependency_limit(limit);
+		#[cfg(not(debug_assertions))]
+		let _ = limit;
+		SignalArc::new(computed)
 	}
 
-	/// `select_fn_pin` computes each value.
-	/// `reduce_fn_pin` updates the current value with the next and returns a [`Propagation`].
-	/// Dependencies are detected across both closures.
+	/// A simple cached computation that, when it changes, marks only those dependents stale
+	/// for whose [`Symbol`](`SignalsRuntimeRef::Symbol`) `should_notify` returns `true`.
+	///
+	/// Dependents that aren't notified keep their current value until they're marked stale otherwise.  
+	/// `should_notify` should be cheap. Reads inside it don't record dependencies.
+	///
+	/// Signals without a filter notify all of their dependents, and only this opt-in path pays for the check.
 	///
 	/// ```
 	/// # {
 	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
-	/// # use flourish::{GlobalSignalsRuntime, Propagation};
+	/// # use std::{collections::BTreeSet, sync::{Arc, Mutex}};
+	/// # use flourish::{GlobalSignalsRuntime, GSRSymbol};
 	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
 	///
 	/// # let input = Signal::cell(1);
-	/// let highest_settled = Signal::reduced(
-	/// 	|| input.get(),
-	/// 	|value, next| if next > *value {
-	/// 		*value = next;
-	/// 		Propagation::Propagate
-	/// 	} else {
-	/// 		Propagation::Halt
-	/// 	},
-	/// );
+	/// let muted = Arc::new(Mutex::new(BTreeSet::<GSRSymbol>::new()));
+	/// let doubled = Signal::computed_selective(|| input.get() * 2, {
+	/// 	let muted = Arc::clone(&muted);
+	/// 	move |dependent| !muted.lock().unwrap().contains(&dependent)
+	/// });
+	/// # assert_eq!(doubled.get(), 2);
 	/// # }
 	/// ```
 	///
-	/// Wraps [`reduced`](`reduced()`).
-	pub fn reduced<'a>(
-		select_fn_pin: impl 'a + Send + FnMut() -> T,
-		reduce_fn_pin: impl 'a + Send + FnMut(&mut T, T) -> Propagation,
+	/// Wraps [`SignalsRuntimeRef::set_notification_filter`].
+	///
+	/// Since 0.2.1.
+	pub fn computed_selective<'a>(
+		fn_pin: impl 'a + Send + FnMut() -> T,
+		should_notify: impl 'static + Send + Sync + Fn(SR::Symbol) -> bool,
 	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
 	where
 		T: 'a + Sized,
 		SR: 'a + Default,
 	{
-		Self::reduced_with_runtime(select_fn_pin, reduce_fn_pin, SR::default())
+		Self::computed_selective_with_runtime(fn_pin, should_notify, SR::default())
 	}
 
-	/// `select_fn_pin` computes each value.
-	/// `reduce_fn_pin` updates the current value with the next and returns a [`Propagation`].
-	/// Dependencies are detected across both closures.
+	/// A simple cached computation that, when it changes, marks only those dependents stale
+	/// for whose [`Symbol`](`SignalsRuntimeRef::Symbol`) `should_notify` returns `true`.
+	///
+	/// Dependents that aren't notified keep their current value until they're marked stale otherwise.  
+	/// `should_notify` should be cheap. Reads inside it don't record dependencies.
+	///
+	/// Signals without a filter notify all of their dependents, and only this opt-in path pays for the check.
 	///
 	/// ```
 	/// # {
 	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
-	/// # use flourish::{GlobalSignalsRuntime, Propagation, Signal};
+	/// # use flourish::{GlobalSignalsRuntime, Signal};
 	/// # let input = Signal::cell_with_runtime(1, GlobalSignalsRuntime);
-	/// let highest_settled = Signal::reduced_with_runtime(
-	/// 	|| input.get(),
-	/// 	|value, next| if next > *value {
-	/// 		*value = next;
-	/// 		Propagation::Propagate
-	/// 	} else {
-	/// 		Propagation::Halt
-	/// 	},
+	/// // Changes are only seen by direct reads.
+	/// let doubled = Signal::computed_selective_with_runtime(
+	/// 	|| input.get() * 2,
+	/// 	|_| false,
 	/// 	GlobalSignalsRuntime,
 	/// );
+	/// # assert_eq!(doubled.get(), 2);
 	/// # }
 	/// ```
 	///
-	/// Wraps [`reduced`](`reduced()`).
-	pub fn reduced_with_runtime<'a>(
-		select_fn_pin: impl 'a + Send + FnMut() -> T,
-		reduce_fn_pin: impl 'a + Send + FnMut(&mut T, T) -> Propagation,
+	/// Wraps [`SignalsRuntimeRef::set_notification_filter`].
+	///
+	/// Since 0.2.1.
+	pub fn computed_selective_with_runtime<'a>(
+		fn_pin: impl 'a + Send + FnMut() -> T,
+		should_notify: impl 'static + Send + Sync + Fn(SR::Symbol) -> bool,
 		runtime: SR,
 	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
 	where
 		T: 'a + Sized,
 		SR: 'a,
 	{
-		SignalArc::new(reduced(select_fn_pin, reduce_fn_pin, runtime))
+		let computed = Computed::new(fn_pin, runtime);
+		computed.set_notification_filter(Box::new(should_notify));
+		SignalArc::new(computed)
 	}
 
-	/// A lightweight thread-safe value that's signal-compatible.
+	/// A simple cached computation with explicit dependency control.
 	///
-	/// It doesn't have a signal-identity and isn't recorded as dependency.
+	/// `fn_pin` receives a [`Context`] that can record dependencies explicitly
+	/// ([`depend`](`Context::depend`)) or exempt nested reads from recording
+	/// ([`ignore`](`Context::ignore`)).
 	///
 	/// ```
 	/// # {
 	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
-	/// # use flourish::{GlobalSignalsRuntime, Propagation};
+	/// # use flourish::GlobalSignalsRuntime;
 	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
-	/// type SignalDyn<'a, T> = flourish::SignalDyn<'a, T, GlobalSignalsRuntime>;
-	///
-	/// # #[derive(Default, Clone)] struct Container;
-	/// # impl Container { fn sort(&mut self) {} }
-	/// # let input = Signal::cell(Container);
-	/// let shared = Signal::shared(0);
 	///
-	/// fn accepts_signal<T: Send>(signal: &SignalDyn<'_, T>) {}
-	/// accepts_signal(&*shared);
+	/// # let input = Signal::cell(1);
+	/// # let trigger = Signal::cell(());
+	/// Signal::computed_with_context(move |cx| {
+	/// 	cx.depend(&trigger);
+	/// 	cx.ignore(|| input.get())
+	/// });
 	/// # }
 	/// ```
 	///
-	/// Since 0.1.2.
-	pub fn shared<'a>(value: T) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
+	/// Since 0.2.1.
+	pub fn computed_with_context<'a>(
+		fn_pin: impl 'a + Send + FnMut(&Context<SR>) -> T,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
 	where
-		T: 'a + Sized + Sync,
-		SR: 'a + Default,
+		T: 'a + Sized,
+		SR: 'a + Sized + Default,
 	{
-		Self::shared_with_runtime(value, SR::default())
+		Self::computed_with_context_with_runtime(fn_pin, SR::default())
 	}
 
-	/// A lightweight thread-safe value that's signal-compatible.
+	/// A simple cached computation with explicit dependency control.
 	///
-	/// It doesn't have a signal-identity and isn't recorded as dependency.
+	/// `fn_pin` receives a [`Context`] that can record dependencies explicitly
+	/// ([`depend`](`Context::depend`)) or exempt nested reads from recording
+	/// ([`ignore`](`Context::ignore`)).
 	///
 	/// ```
 	/// # {
 	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
-	/// # use flourish::{GlobalSignalsRuntime, Propagation, Signal};
-	/// let shared = Signal::shared_with_runtime(0, GlobalSignalsRuntime);
-	///
-	/// fn accepts_signal<T: Send, SR: flourish::SignalsRuntimeRef>(
-	///   signal: &flourish::SignalDyn<'_, T, SR>,
-	/// ) {}
-	/// accepts_signal(&*shared);
+	/// # use flourish::{GlobalSignalsRuntime, Signal};
+	/// # let input = Signal::cell_with_runtime(1, GlobalSignalsRuntime);
+	/// # let trigger = Signal::cell_with_runtime((), GlobalSignalsRuntime);
+	/// Signal::computed_with_context_with_runtime(move |cx| {
+	/// 	cx.depend(&trigger);
+	/// 	cx.ignore(|| input.get())
+	/// }, GlobalSignalsRuntime);
 	/// # }
 	/// ```
 	///
-	/// Since 0.1.2.
-	pub fn shared_with_runtime<'a>(
-		value: T,
+	/// Since 0.2.1.
+	pub fn computed_with_context_with_runtime<'a>(
+		mut fn_pin: impl 'a + Send + FnMut(&Context<SR>) -> T,
 		runtime: SR,
 	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
 	where
-		T: 'a + Sized + Sync,
-		SR: 'a + Default,
+		T: 'a + Sized,
+		SR: 'a + Sized,
 	{
-		SignalArc {
-			strong: Strong::pin(Shared::with_runtime(value, runtime)),
-		}
+		let context = Context::new(runtime.clone());
+		Self::computed_with_runtime(move || fn_pin(&context), runtime)
 	}
-}
 
-/// Cell constructors.
-impl<T: Send, SR: SignalsRuntimeRef> Signal<T, Opaque, SR> {
-	/// A thread-safe value cell that's mutable through shared references.
+	/// A simple cached computation.
 	///
-	/// Modification of the value can cause dependent signals to update.
+	/// Doesn't update its cache or propagate iff the new result is equal.
 	///
 	/// ```
 	/// # {
 	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
-	/// # use flourish::{GlobalSignalsRuntime, Propagation};
+	/// # use flourish::GlobalSignalsRuntime;
 	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
 	///
-	/// # #[derive(Default, Clone)] struct Container;
-	/// # impl Container { fn sort(&mut self) {} }
-	/// let cell = Signal::cell(0);
-	///
-	/// cell.set_if_distinct(1);
-	/// cell.set(2);
-	/// cell.update(|value| {
-	/// 	*value += 1;
-	/// 	Propagation::Propagate
-	/// });
+	/// # let input = Signal::cell(1);
+	/// Signal::distinct(|| input.get() + 1);
 	/// # }
 	/// ```
-	pub fn cell<'a>(
-		initial_value: T,
-	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignalCell<T, SR>, SR>
+	///
+	/// Note that iff there is no subscriber,
+	/// this signal and its dependents will still become stale unconditionally.
+	///
+	/// Wraps [`distinct`](`distinct()`).
+	pub fn distinct<'a>(
+		fn_pin: impl 'a + Send + FnMut() -> T,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
 	where
-		T: 'a,
+		T: 'a + Sized + PartialEq,
 		SR: 'a + Default,
 	{
-		Self::cell_with_runtime(initial_value, SR::default())
+		Self::distinct_with_runtime(fn_pin, SR::default())
 	}
 
-	/// A thread-safe value cell that's mutable through shared references.
+	/// A simple cached computation.
 	///
-	/// Modification of the value can cause dependent signals to update.
+	/// Doesn't update its cache or propagate iff the new result is equal.
 	///
 	/// ```
 	/// # {
 	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
-	/// # use flourish::{GlobalSignalsRuntime, Propagation, Signal};
-	/// let cell = Signal::cell_with_runtime(0, GlobalSignalsRuntime);
-	///
-	/// cell.set_if_distinct(1);
-	/// cell.set(2);
-	/// cell.update(|value| {
-	/// 	*value += 1;
-	/// 	Propagation::Propagate
-	/// });
+	/// # use flourish::{GlobalSignalsRuntime, Signal};
+	/// # let input = Signal::cell_with_runtime(1, GlobalSignalsRuntime);
+	/// Signal::distinct_with_runtime(|| input.get() + 1, input.clone_runtime_ref());
 	/// # }
 	/// ```
-	pub fn cell_with_runtime<'a>(
-		initial_value: T,
+	///
+	/// Note that iff there is no subscriber,
+	/// this signal and its dependents will still become stale unconditionally.
+	///
+	/// Wraps [`distinct`](`distinct()`).
+	pub fn distinct_with_runtime<'a>(
+		fn_pin: impl 'a + Send + FnMut() -> T,
 		runtime: SR,
-	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignalCell<T, SR>, SR>
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
 	where
-		T: 'a,
-		SR: 'a + Default,
+		T: 'a + Sized + PartialEq,
+		SR: 'a,
 	{
-		SignalArc {
-			strong: Strong::pin(InertCell::with_runtime(initial_value, runtime)),
-		}
+		SignalArc::new(distinct(fn_pin, runtime))
 	}
 
-	/// A thread-safe value cell that may reference itself.
+	/// A cached computation that always stores its new result,
+	/// but propagates only iff `eq` considers it different from the previous one.
 	///
-	/// Modification of the value can cause dependent signals to update.
+	/// Unlike with [`distinct`](`Signal::distinct`), reading this signal directly always returns the newest result.
+	/// Dependents that weren't refreshed keep what they derived from an `eq`ual previous one.
 	///
 	/// ```
 	/// # {
 	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
-	/// # use flourish::{GlobalSignalsRuntime, Propagation, SignalsRuntimeRef, SignalWeakDynCell};
+	/// # use flourish::GlobalSignalsRuntime;
 	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
 	///
-	/// # struct Resource {}
-	/// # fn get_from_cache(name: &str) -> Option<Resource> { None }
-	/// # fn start_loading<SR: SignalsRuntimeRef>(name: &str, target: SignalWeakDynCell<'_, Option<Resource>, SR>) {}
-	/// fn load_into<SR: SignalsRuntimeRef>(
-	/// 	target: &SignalWeakDynCell<'_, Option<Resource>, SR>,
-	/// 	name: &str,
-	/// ) -> Option<Resource> {
-	/// 	if let Some(resource) = get_from_cache(name) {
-	/// 		Some(resource)
-	/// 	} else {
-	/// 		start_loading(name, target.clone());
-	/// 		None
-	/// 	}
-	/// }
-	///
-	/// let cell = Signal::cell_cyclic(|weak| load_into(weak, "resource"));
+	/// # let input = Signal::cell(1.0_f64);
+	/// Signal::computed_store_distinct(
+	/// 	|| input.get() * 2.0,
+	/// 	|previous, next| (previous - next).abs() < 0.5,
+	/// );
 	/// # }
 	/// ```
-	pub fn cell_cyclic<'a>(
-		make_initial_value: impl 'a + FnOnce(&SignalWeakDynCell<'a, T, SR>) -> T,
-	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignalCell<T, SR>, SR>
+	///
+	/// Note that iff there is no subscriber,
+	/// this signal and its dependents will still become stale unconditionally.
+	///
+	/// Wraps [`reduced`](`reduced()`).
+	///
+	/// Since 0.2.1.
+	pub fn computed_store_distinct<'a>(
+		fn_pin: impl 'a + Send + FnMut() -> T,
+		eq: impl 'a + Send + FnMut(&T, &T) -> bool,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
 	where
-		T: 'a,
+		T: 'a + Sized,
 		SR: 'a + Default,
 	{
-		Self::cell_cyclic_with_runtime(make_initial_value, SR::default())
+		Self::computed_store_distinct_with_runtime(fn_pin, eq, SR::default())
 	}
 
-	/// A thread-safe value cell that may reference itself.
+	/// A cached computation that always stores its new result,
+	/// but propagates only iff `eq` considers it different from the previous one.
 	///
-	/// Modification of the value can cause dependent signals to update.
+	/// Unlike with [`distinct_with_runtime`](`Signal::distinct_with_runtime`), reading this signal directly always returns the newest result.
+	/// Dependents that weren't refreshed keep what they derived from an `eq`ual previous one.
 	///
 	/// ```
 	/// # {
 	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
-	/// # use flourish::{GlobalSignalsRuntime, Propagation, Signal, SignalsRuntimeRef, SignalWeakDynCell};
-	/// # struct Resource {}
-	/// # fn get_from_cache(name: &str) -> Option<Resource> { None }
-	/// # fn start_loading<SR: SignalsRuntimeRef>(name: &str, target: SignalWeakDynCell<'_, Option<Resource>, SR>) {}
-	/// fn load_into<SR: SignalsRuntimeRef>(
-	/// 	target: &SignalWeakDynCell<'_, Option<Resource>, SR>,
-	/// 	name: &str,
-	/// ) -> Option<Resource> {
-	/// 	if let Some(resource) = get_from_cache(name) {
-	/// 		Some(resource)
-	/// 	} else {
-	/// 		start_loading(name, target.clone());
-	/// 		None
-	/// 	}
-	/// }
-	///
-	/// let cell = Signal::cell_cyclic_with_runtime(
-	/// 	|weak| load_into(weak, "resource"),
-	/// 	GlobalSignalsRuntime,
+	/// # use flourish::{GlobalSignalsRuntime, Signal};
+	/// # let input = Signal::cell_with_runtime(1.0_f64, GlobalSignalsRuntime);
+	/// Signal::computed_store_distinct_with_runtime(
+	/// 	|| input.get() * 2.0,
+	/// 	|previous, next| (previous - next).abs() < 0.5,
+	/// 	input.clone_runtime_ref(),
 	/// );
 	/// # }
 	/// ```
-	pub fn cell_cyclic_with_runtime<'a>(
-		make_initial_value: impl 'a + FnOnce(&SignalWeakDynCell<'a, T, SR>) -> T,
+	///
+	/// Note that iff there is no subscriber,
+	/// this signal and its dependents will still become stale unconditionally.
+	///
+	/// Wraps [`reduced`](`reduced()`).
+	///
+	/// Since 0.2.1.
+	pub fn computed_store_distinct_with_runtime<'a>(
+		fn_pin: impl 'a + Send + FnMut() -> T,
+		mut eq: impl 'a + Send + FnMut(&T, &T) -> bool,
 		runtime: SR,
-	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignalCell<T, SR>, SR>
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
 	where
-		T: 'a,
-		SR: 'a + Default,
+		T: 'a + Sized,
+		SR: 'a,
 	{
-		SignalArc {
-			strong: Strong::pin_cyclic(|weak: &Weak<T, InertCell<T, SR>, SR>| {
-				InertCell::with_runtime(
-					make_initial_value(&*ManuallyDrop::new(SignalWeakDynCell {
-						weak: Weak { weak: weak.weak },
-					})),
-					runtime,
-				)
-			}),
-		}
+		Self::reduced_with_runtime(
+			fn_pin,
+			move |value, next| {
+				let propagation = if eq(value, &next) {
+					Propagation::Halt
+				} else {
+					Propagation::Propagate
+				};
+				*value = next;
+				propagation
+			},
+			runtime,
+		)
 	}
 
-	/// A thread-safe value cell that can observe subscription status changes.
+	/// A simple cached computation, like [`distinct`](`Signal::distinct`),
+	/// but for values that are expensive to compare.
 	///
-	/// Modification of the value can cause dependent signals to update.
+	/// The hash of the previous result is stored alongside it, so differing results are usually told apart by
+	/// their hashes alone. Only iff the hashes are equal, the results are compared in full (to rule out collisions).
+	/// Doesn't update its cache or propagate iff the new result is equal.
+	///
+	/// Hashing happens once per result, with [`DefaultHasher`].
 	///
 	/// ```
 	/// # {
 	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
-	/// # use flourish::{GlobalSignalsRuntime, Propagation};
+	/// # use flourish::GlobalSignalsRuntime;
 	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
 	///
-	/// let cell = Signal::cell_reactive(0, |value, status| {
-	/// 		dbg!(status);
-	/// 		Propagation::Halt
-	/// 	});
+	/// # let input = Signal::cell(1);
+	/// Signal::distinct_hashed(move || vec![input.get(); 1000]);
 	/// # }
 	/// ```
-	pub fn cell_reactive<'a>(
-		initial_value: T,
-		on_subscribed_change_fn_pin: impl 'a
-			+ Send
-			+ FnMut(
-				&T,
-				<SR::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus,
-			) -> Propagation,
-	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignalCell<T, SR>, SR>
+	///
+	/// Note that iff there is no subscriber,
+	/// this signal and its dependents will still become stale unconditionally.
+	///
+	/// Wraps [`reduced`](`reduced()`).
+	///
+	/// # Features
+	///
+	/// **The feature `"std"` is required to enable this constructor.**
+	///
+	/// Since 0.2.1.
+	#[cfg(feature = "std")]
+	pub fn distinct_hashed<'a>(
+		fn_pin: impl 'a + Send + FnMut() -> T,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
 	where
-		T: 'a,
+		T: 'a + Sized + Hash + PartialEq,
 		SR: 'a + Default,
 	{
-		Self::cell_reactive_with_runtime(initial_value, on_subscribed_change_fn_pin, SR::default())
+		Self::distinct_hashed_with_runtime(fn_pin, SR::default())
 	}
 
-	/// A thread-safe value cell that can observe subscription status changes.
+	/// A simple cached computation, like [`distinct_with_runtime`](`Signal::distinct_with_runtime`),
+	/// but for values that are expensive to compare.
 	///
-	/// Modification of the value can cause dependent signals to update.
+	/// The hash of the previous result is stored alongside it, so differing results are usually told apart by
+	/// their hashes alone. Only iff the hashes are equal, the results are compared in full (to rule out collisions).
+	/// Doesn't update its cache or propagate iff the new result is equal.
 	///
-	/// ```
-	/// # {
-	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
-	/// # use flourish::{GlobalSignalsRuntime, Propagation, Signal};
-	/// let cell = Signal::cell_reactive_with_runtime(0, |value, status| {
-	/// 		dbg!(status);
-	/// 		Propagation::Halt
-	/// 	}, GlobalSignalsRuntime);
-	/// # }
-	/// ```
-	pub fn cell_reactive_with_runtime<'a>(
-		initial_value: T,
-		on_subscribed_change_fn_pin: impl 'a
-			+ Send
-			+ FnMut(
-				&T,
-				<SR::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus,
-			) -> Propagation,
+	/// Hashing happens once per result, with [`DefaultHasher`].
+	///
+	/// Note that iff there is no subscriber,
+	/// this signal and its dependents will still become stale unconditionally.
+	///
+	/// Wraps [`reduced`](`reduced()`).
+	///
+	/// # Features
+	///
+	/// **The feature `"std"` is required to enable this constructor.**
+	///
+	/// Since 0.2.1.
+	#[cfg(feature = "std")]
+	pub fn distinct_hashed_with_runtime<'a>(
+		fn_pin: impl 'a + Send + FnMut() -> T,
 		runtime: SR,
-	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignalCell<T, SR>, SR>
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
 	where
-		T: 'a,
-		SR: 'a + Default,
+		T: 'a + Sized + Hash + PartialEq,
+		SR: 'a,
 	{
-		SignalArc {
-			strong: Strong::pin(ReactiveCell::with_runtime(
-				initial_value,
-				on_subscribed_change_fn_pin,
-				runtime,
-			)),
+		fn hash_of<T: Hash>(value: &T) -> u64 {
+			let mut hasher = DefaultHasher::new();
+			value.hash(&mut hasher);
+			hasher.finish()
 		}
+
+		// The initial result isn't passed through the reducer, so its hash is calculated lazily.
+		let mut previous_hash = None;
+		Self::reduced_with_runtime(
+			fn_pin,
+			move |value, next| {
+				let next_hash = hash_of(&next);
+				let hash = previous_hash
+					.replace(next_hash)
+					.unwrap_or_else(|| hash_of(value));
+				if hash == next_hash && *value == next {
+					Propagation::Halt
+				} else {
+					*value = next;
+					Propagation::Propagate
+				}
+			},
+			runtime,
+		)
 	}
 
-	/// A thread-safe value cell that can observe subscription status changes and may
-	/// reference itself.
-	///
-	/// Modification of the value can cause dependent signals to update.
+	/// A simple **uncached** computation.
 	///
 	/// ```
 	/// # {
 	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
-	/// # use flourish::{shadow_ref_to_owned, GlobalSignalsRuntime, Propagation};
+	/// # use flourish::GlobalSignalsRuntime;
 	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
 	///
-	/// let cell = Signal::cell_cyclic_reactive(|weak| (0, {
-	/// 		shadow_ref_to_owned!(weak);
-	/// 		move |value, status| {
-	/// 			assert_eq!(weak.upgrade().unwrap().get(), *value);
-	/// 			dbg!(status);
-	/// 			Propagation::Halt
-	/// 	}}));
+	/// # let input = Signal::cell(1);
+	/// Signal::computed_uncached(|| input.get() + 1);
 	/// # }
 	/// ```
-	pub fn cell_cyclic_reactive<
-		'a,
-		HandlerFnPin: 'a
-			+ Send
-			+ FnMut(
-				&T,
-				<SR::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus,
-			) -> Propagation,
-	>(
-		make_initial_value_and_on_subscribed_change_fn_pin: impl FnOnce(
-			&SignalWeakDynCell<'a, T, SR>,
-		) -> (T, HandlerFnPin),
-	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignalCell<T, SR>, SR>
+	///
+	/// Wraps [`computed_uncached`](`computed_uncached()`).
+	pub fn computed_uncached<'a>(
+		fn_pin: impl 'a + Send + Sync + Fn() -> T,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
 	where
-		T: 'a,
+		T: 'a + Sized,
 		SR: 'a + Default,
 	{
-		Self::cell_cyclic_reactive_with_runtime(
-			make_initial_value_and_on_subscribed_change_fn_pin,
-			SR::default(),
-		)
+		Self::computed_uncached_with_runtime(fn_pin, SR::default())
 	}
 
-	/// A thread-safe value cell that can observe subscription status changes and may
-	/// reference itself.
-	///
-	/// Modification of the value can cause dependent signals to update.
+	/// A simple **uncached** computation.
 	///
 	/// ```
 	/// # {
 	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
-	/// # use flourish::{shadow_ref_to_owned, GlobalSignalsRuntime, Propagation, Signal};
-	/// let cell = Signal::cell_cyclic_reactive_with_runtime(|weak| (0, {
-	/// 		shadow_ref_to_owned!(weak);
-	/// 		move |value, status| {
-	/// 			assert_eq!(weak.upgrade().unwrap().get(), *value);
-	/// 			dbg!(status);
-	/// 			Propagation::Halt
-	/// 	}}), GlobalSignalsRuntime);
+	/// # use flourish::{GlobalSignalsRuntime, Signal};
+	/// # let input = Signal::cell_with_runtime(1, GlobalSignalsRuntime);
+	/// Signal::computed_uncached_with_runtime(|| input.get() + 1, input.clone_runtime_ref());
 	/// # }
 	/// ```
-	pub fn cell_cyclic_reactive_with_runtime<
-		'a,
-		HandlerFnPin: 'a
-			+ Send
-			+ FnMut(
-				&T,
-				<SR::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus,
-			) -> Propagation,
-	>(
-		make_initial_value_and_on_subscribed_change_fn_pin: impl FnOnce(
-			&SignalWeakDynCell<'a, T, SR>,
-		) -> (T, HandlerFnPin),
+	///
+	/// Wraps [`computed_uncached`](`computed_uncached()`).
+	pub fn computed_uncached_with_runtime<'a>(
+		fn_pin: impl 'a + Send + Sync + Fn() -> T,
 		runtime: SR,
-	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignalCell<T, SR>, SR>
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
 	where
-		T: 'a,
-		SR: 'a + Default,
+		T: 'a + Sized,
+		SR: 'a,
 	{
-		SignalArc {
-			strong: Strong::pin_cyclic(|weak: &Weak<T, ReactiveCell<T, HandlerFnPin, SR>, SR>| {
-				let (initial_value, on_subscribed_change_fn_pin) =
-					make_initial_value_and_on_subscribed_change_fn_pin(&*ManuallyDrop::new(
-						SignalWeakDynCell {
-							weak: Weak { weak: weak.weak },
-						},
-					));
-				ReactiveCell::with_runtime(initial_value, on_subscribed_change_fn_pin, runtime)
-			}),
-		}
+		SignalArc::new(computed_uncached(fn_pin, runtime))
 	}
 
-	/// A thread-safe value cell that can observe subscription status changes.
+	/// A simple **stateful uncached** computation.
 	///
-	/// Modification of the value can cause dependent signals to update.
+	/// ⚠️ Care must be taken to avoid unexpected behaviour!
 	///
 	/// ```
 	/// # {
 	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
-	/// # use flourish::{GlobalSignalsRuntime, Propagation};
-	/// # fn create_heavy_resource_arc() {}
+	/// # use flourish::GlobalSignalsRuntime;
 	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
 	///
-	/// let cell = Signal::cell_reactive_mut(None, |value, status| {
-	/// 		if status {
-	/// 			value.get_or_insert_with(create_heavy_resource_arc);
-	/// 			Propagation::Propagate
-	/// 		} else {
-	/// 			*value = None;
-	/// 			Propagation::FlushOut
-	/// 		}
-	/// 	});
+	/// # let input = Signal::cell(1);
+	/// let mut read_count = 0;
+	/// Signal::computed_uncached_mut(move || {
+	/// 	input.touch();
+	/// 	read_count += 1;
+	/// 	read_count
+	/// });
 	/// # }
 	/// ```
-	pub fn cell_reactive_mut<'a>(
-		initial_value: T,
-		on_subscribed_change_fn_pin: impl 'a
-			+ Send
-			+ FnMut(
-				&mut T,
-				<SR::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus,
-			) -> Propagation,
-	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignalCell<T, SR>, SR>
+	///
+	/// Wraps [`computed_uncached_mut`](`computed_uncached_mut()`).
+	pub fn computed_uncached_mut<'a>(
+		fn_pin: impl 'a + Send + FnMut() -> T,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
 	where
-		T: 'a,
+		T: 'a + Sized,
 		SR: 'a + Default,
 	{
-		Self::cell_reactive_mut_with_runtime(
-			initial_value,
-			on_subscribed_change_fn_pin,
-			SR::default(),
-		)
+		Self::computed_uncached_mut_with_runtime(fn_pin, SR::default())
 	}
 
-	/// A thread-safe value cell that can observe subscription status changes.
+	/// A simple **stateful uncached** computation.
 	///
-	/// Modification of the value can cause dependent signals to update.
+	/// ⚠️ Care must be taken to avoid unexpected behaviour!
 	///
 	/// ```
 	/// # {
 	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
-	/// # use flourish::{GlobalSignalsRuntime, Propagation, Signal};
-	/// # fn create_heavy_resource_arc() {}
-	/// let cell = Signal::cell_reactive_mut_with_runtime(None, |value, status| {
-	/// 		if status {
-	/// 			value.get_or_insert_with(create_heavy_resource_arc);
-	/// 			Propagation::Propagate
-	/// 		} else {
-	/// 			*value = None;
-	/// 			Propagation::FlushOut
-	/// 		}
-	/// 	}, GlobalSignalsRuntime);
+	/// # use flourish::{GlobalSignalsRuntime, Signal};
+	/// # let input = &Signal::cell_with_runtime(1, GlobalSignalsRuntime);
+	/// let mut read_count = 0;
+	/// Signal::computed_uncached_mut_with_runtime(move || {
+	/// 	input.touch();
+	/// 	read_count += 1;
+	/// 	read_count
+	/// }, input.clone_runtime_ref());
 	/// # }
 	/// ```
-	pub fn cell_reactive_mut_with_runtime<'a>(
-		initial_value: T,
-		on_subscribed_change_fn_pin: impl 'a
-			+ Send
-			+ FnMut(
-				&mut T,
-				<SR::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus,
-			) -> Propagation,
+	///
+	/// Wraps [`computed_uncached_mut`](`computed_uncached_mut()`).
+	pub fn computed_uncached_mut_with_runtime<'a>(
+		fn_pin: impl 'a + Send + FnMut() -> T,
 		runtime: SR,
-	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignalCell<T, SR>, SR>
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
 	where
-		T: 'a,
-		SR: 'a + Default,
+		T: 'a + Sized,
+		SR: 'a,
 	{
-		SignalArc {
-			strong: Strong::pin(ReactiveCellMut::with_runtime(
-				initial_value,
-				on_subscribed_change_fn_pin,
-				runtime,
-			)),
-		}
+		SignalArc::new(computed_uncached_mut(fn_pin, runtime))
 	}
 
-	/// A thread-safe value cell that can observe subscription status changes and may
-	/// reference itself.
-	///
-	/// Modification of the value can cause dependent signals to update.
+	/// The closure mutates the value and returns a [`Propagation`].
 	///
 	/// ```
 	/// # {
 	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
-	/// # use flourish::{shadow_ref_to_owned, GlobalSignalsRuntime, Propagation, SignalsRuntimeRef, SignalArcDynCell};
+	/// # use flourish::{GlobalSignalsRuntime, Propagation};
 	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
 	///
-	/// # fn start_loading<SR: SignalsRuntimeRef>(name: &str, generation: usize, target: SignalArcDynCell<'_, (usize, Resource<()>), SR>) {}
-	/// enum Resource<T> {
-	/// 	Offline,
-	/// 	Pending,
-	/// 	Ready(T),
-	/// }
-	///
-	/// impl<T> Resource<T> {
-	/// 	fn is_offline(&self) -> bool {
-	/// 		matches!(self, Self::Offline)
-	/// 	}
-	/// }
-	///
-	/// let cell = Signal::cell_cyclic_reactive_mut(|weak| ((0, Resource::Offline), {
-	/// 		shadow_ref_to_owned!(weak);
-	/// 		move |(generation, value), status| {
-	/// 			// The order of operations doesn't matter here, as the signal value is locked exclusively here.
-	/// 			if status && value.is_offline() {
-	/// 				*value = Resource::Pending;
-	/// 				start_loading("resource", *generation, weak.upgrade().unwrap_or_else(|| unreachable!()));
-	/// 				Propagation::Propagate
-	/// 			} else if !status {
-	/// 				*value = Resource::Offline;
-	/// 				*generation += 1;
-	/// 				Propagation::FlushOut
-	/// 			} else {
-	/// 				Propagation::Halt
-	/// 			}
-	/// 		}
-	/// 	}));
+	/// # #[derive(Default, Clone)] struct Container;
+	/// # impl Container { fn sort(&mut self) {} }
+	/// # let input = Signal::cell(Container);
+	/// Signal::folded(Container::default(), move |value| {
+	/// 	value.clone_from(&input.read());
+	/// 	value.sort();
+	/// 	Propagation::Propagate
+	/// });
 	/// # }
 	/// ```
-	pub fn cell_cyclic_reactive_mut<
-		'a,
-		HandlerFnPin: 'a
-			+ Send
-			+ FnMut(
-				&mut T,
-				<SR::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus,
-			) -> Propagation,
-	>(
-		make_initial_value_and_on_subscribed_change_fn_pin: impl FnOnce(
-			&SignalWeakDynCell<'a, T, SR>,
-		) -> (T, HandlerFnPin),
-	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignalCell<T, SR>, SR>
+	///
+	/// Wraps [`folded`](`folded()`).
+	pub fn folded<'a>(
+		init: T,
+		fold_fn_pin: impl 'a + Send + FnMut(&mut T) -> Propagation,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
 	where
-		T: 'a,
+		T: 'a + Sized,
 		SR: 'a + Default,
 	{
-		Self::cell_cyclic_reactive_mut_with_runtime(
-			make_initial_value_and_on_subscribed_change_fn_pin,
-			SR::default(),
-		)
+		Self::folded_with_runtime(init, fold_fn_pin, SR::default())
 	}
 
-	/// A thread-safe value cell that can observe subscription status changes and may
-	/// reference itself.
-	///
-	/// Modification of the value can cause dependent signals to update.
+	/// The closure mutates the value and returns a [`Propagation`].
 	///
 	/// ```
 	/// # {
 	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
-	/// # use flourish::{shadow_ref_to_owned, GlobalSignalsRuntime, Propagation, Signal, SignalsRuntimeRef, SignalArcDynCell};
-	/// # fn start_loading<SR: SignalsRuntimeRef>(name: &str, generation: usize, target: SignalArcDynCell<'_, (usize, Resource<()>), SR>) {}
-	/// enum Resource<T> {
-	/// 	Offline,
+	/// # use flourish::{GlobalSignalsRuntime, Propagation, Signal};
+	/// # #[derive(Default, Clone)] struct Container;
+	/// # impl Container { fn sort(&mut self) {} }
+	/// # let input = Signal::cell_with_runtime(Container, GlobalSignalsRuntime);
+	/// Signal::folded_with_runtime(Container::default(), |value| {
+	/// 	value.clone_from(&input.read());
+	/// 	value.sort();
+	/// 	Propagation::Propagate
+	/// }, input.clone_runtime_ref());
+	/// # }
+	/// ```
+	///
+	/// Wraps [`folded`](`folded()`).
+	pub fn folded_with_runtime<'a>(
+		init: T,
+		fold_fn_pin: impl 'a + Send + FnMut(&mut T) -> Propagation,
+		runtime: SR,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
+	where
+		T: 'a + Sized,
+		SR: 'a,
+	{
+		SignalArc::new(folded(init, fold_fn_pin, runtime))
+	}
+
+	/// The closure mutates the value synchronously and returns a [`Future`] that's spawned via `spawn`.
+	/// Once that resolves, dependents are notified according to its [`Propagation`].
+	///
+	/// `&mut T` is only borrowed for the synchronous part of `fold_fn_pin`, which is also the
+	/// dependency detection scope. Its mutation is visible to readers right away, but is only
+	/// *committed* (propagated) when the returned [`Future`] resolves.
+	///
+	/// When the fold is re-triggered while a previous [`Future`] is still in flight, that one is
+	/// cancelled: it's dropped on its next poll and its [`Propagation`] is discarded.
+	/// The same happens when the signal is dropped.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::{GlobalSignalsRuntime, Propagation};
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// # let input = Signal::cell(1);
+	/// # let spawn = drop;
+	/// Signal::folded_async(
+	/// 	0,
+	/// 	move |value| {
+	/// 		*value += input.get();
+	/// 		async { Propagation::Propagate }
+	/// 	},
+	/// 	spawn,
+	/// );
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	pub fn folded_async<'a, F: 'a + Send + Future<Output = Propagation>>(
+		init: T,
+		fold_fn_pin: impl 'a + Send + FnMut(&mut T) -> F,
+		spawn: impl 'a + Send + FnMut(Pin<Box<dyn 'a + Send + Future<Output = ()>>>),
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
+	where
+		T: 'a + Sized,
+		SR: 'a + Sized + Default,
+	{
+		Self::folded_async_with_runtime(init, fold_fn_pin, spawn, SR::default())
+	}
+
+	/// The closure mutates the value synchronously and returns a [`Future`] that's spawned via `spawn`.
+	/// Once that resolves, dependents are notified according to its [`Propagation`].
+	///
+	/// `&mut T` is only borrowed for the synchronous part of `fold_fn_pin`, which is also the
+	/// dependency detection scope. Its mutation is visible to readers right away, but is only
+	/// *committed* (propagated) when the returned [`Future`] resolves.
+	///
+	/// When the fold is re-triggered while a previous [`Future`] is still in flight, that one is
+	/// cancelled: it's dropped on its next poll and its [`Propagation`] is discarded.
+	/// The same happens when the signal is dropped.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::{GlobalSignalsRuntime, Propagation, Signal};
+	/// # let input = Signal::cell_with_runtime(1, GlobalSignalsRuntime);
+	/// # let spawn = drop;
+	/// Signal::folded_async_with_runtime(
+	/// 	0,
+	/// 	|value| {
+	/// 		*value += input.get();
+	/// 		async { Propagation::Propagate }
+	/// 	},
+	/// 	spawn,
+	/// 	input.clone_runtime_ref(),
+	/// );
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	pub fn folded_async_with_runtime<'a, F: 'a + Send + Future<Output = Propagation>>(
+		init: T,
+		mut fold_fn_pin: impl 'a + Send + FnMut(&mut T) -> F,
+		mut spawn: impl 'a + Send + FnMut(Pin<Box<dyn 'a + Send + Future<Output = ()>>>),
+		runtime: SR,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
+	where
+		T: 'a + Sized,
+		SR: 'a + Sized,
+	{
+		let commit = Arc::new(OnceLock::<Box<dyn 'a + Send + Sync + Fn(Propagation)>>::new());
+		let mut in_flight = None::<AbortHandle>;
+		let signal = SignalArc::new(Folded::new(
+			init,
+			{
+				let commit = Arc::clone(&commit);
+				let runtime = runtime.clone();
+				move |value| {
+					// Cancels the previous fold, if any.
+					drop(in_flight.take());
+					let (future, abort_handle) = abortable(fold_fn_pin(value));
+					in_flight = Some(abort_handle);
+					let commit = Arc::clone(&commit);
+					runtime.run_detached(|| {
+						spawn(Box::pin(async move {
+							if let Some(propagation) = future.await {
+								if let Some(commit) = commit.get() {
+									commit(propagation);
+								}
+							}
+						}));
+					});
+					Propagation::Halt
+				}
+			},
+			runtime,
+		));
+		let weak = signal.downgrade();
+		let _ = commit.set(Box::new(move |propagation| {
+			if let Some(signal) = weak.upgrade() {
+				signal._managed().propagate(propagation);
+			}
+		}));
+		signal
+	}
+
+	/// `select_fn_pin` computes each value.
+	/// `reduce_fn_pin` updates the current value with the next and returns a [`Propagation`].
+	/// Dependencies are detected across both closures.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::{GlobalSignalsRuntime, Propagation};
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// # let input = Signal::cell(1);
+	/// let highest_settled = Signal::reduced(
+	/// 	|| input.get(),
+	/// 	|value, next| if next > *value {
+	/// 		*value = next;
+	/// 		Propagation::Propagate
+	/// 	} else {
+	/// 		Propagation::Halt
+	/// 	},
+	/// );
+	/// # }
+	/// ```
+	///
+	/// Wraps [`reduced`](`reduced()`).
+	pub fn reduced<'a>(
+		select_fn_pin: impl 'a + Send + FnMut() -> T,
+		reduce_fn_pin: impl 'a + Send + FnMut(&mut T, T) -> Propagation,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
+	where
+		T: 'a + Sized,
+		SR: 'a + Default,
+	{
+		Self::reduced_with_runtime(select_fn_pin, reduce_fn_pin, SR::default())
+	}
+
+	/// `select_fn_pin` computes each value.
+	/// `reduce_fn_pin` updates the current value with the next and returns a [`Propagation`].
+	/// Dependencies are detected across both closures.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::{GlobalSignalsRuntime, Propagation, Signal};
+	/// # let input = Signal::cell_with_runtime(1, GlobalSignalsRuntime);
+	/// let highest_settled = Signal::reduced_with_runtime(
+	/// 	|| input.get(),
+	/// 	|value, next| if next > *value {
+	/// 		*value = next;
+	/// 		Propagation::Propagate
+	/// 	} else {
+	/// 		Propagation::Halt
+	/// 	},
+	/// 	GlobalSignalsRuntime,
+	/// );
+	/// # }
+	/// ```
+	///
+	/// Wraps [`reduced`](`reduced()`).
+	pub fn reduced_with_runtime<'a>(
+		select_fn_pin: impl 'a + Send + FnMut() -> T,
+		reduce_fn_pin: impl 'a + Send + FnMut(&mut T, T) -> Propagation,
+		runtime: SR,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
+	where
+		T: 'a + Sized,
+		SR: 'a,
+	{
+		SignalArc::new(reduced(select_fn_pin, reduce_fn_pin, runtime))
+	}
+
+	/// A cached computation that keeps its previous value whenever the closure returns [`None`].
+	///
+	/// [`Some`] replaces the value and propagates, while [`None`] halts propagation.  
+	/// Unlike [`Subscription::filter_mapped`], this is synchronous and there's always a value:
+	/// Until the closure first returns [`Some`], it's `T::default()`.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::GlobalSignalsRuntime;
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// let input = Signal::cell("12");
+	/// let last_valid = Signal::computed_keep(|| input.get().parse::<u32>().ok());
+	/// assert_eq!(last_valid.get(), 12);
+	///
+	/// input.set("twelve");
+	/// assert_eq!(last_valid.get(), 12);
+	/// # }
+	/// ```
+	///
+	/// Wraps [`folded`](`folded()`).
+	///
+	/// Since 0.2.1.
+	pub fn computed_keep<'a>(
+		fn_pin: impl 'a + Send + FnMut() -> Option<T>,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
+	where
+		T: 'a + Sized + Default,
+		SR: 'a + Default,
+	{
+		Self::computed_keep_with_runtime(fn_pin, SR::default())
+	}
+
+	/// A cached computation that keeps its previous value whenever the closure returns [`None`].
+	///
+	/// [`Some`] replaces the value and propagates, while [`None`] halts propagation.  
+	/// Unlike [`Subscription::filter_mapped`], this is synchronous and there's always a value:
+	/// Until the closure first returns [`Some`], it's `T::default()`.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::{GlobalSignalsRuntime, Signal};
+	/// # let input = Signal::cell_with_runtime("12", GlobalSignalsRuntime);
+	/// let last_valid = Signal::computed_keep_with_runtime(
+	/// 	|| input.get().parse::<u32>().ok(),
+	/// 	GlobalSignalsRuntime,
+	/// );
+	/// # }
+	/// ```
+	///
+	/// Wraps [`folded`](`folded()`).
+	///
+	/// Since 0.2.1.
+	pub fn computed_keep_with_runtime<'a>(
+		mut fn_pin: impl 'a + Send + FnMut() -> Option<T>,
+		runtime: SR,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
+	where
+		T: 'a + Sized + Default,
+		SR: 'a,
+	{
+		Self::folded_with_runtime(
+			T::default(),
+			move |value| {
+				if let Some(next) = fn_pin() {
+					*value = next;
+					Propagation::Propagate
+				} else {
+					Propagation::Halt
+				}
+			},
+			runtime,
+		)
+	}
+
+	/// A cached computation that serves `placeholder` until `fn_pin` first ran.
+	///
+	/// The first evaluation doesn't call `fn_pin` but only schedules the real computation, which then replaces
+	/// `placeholder` and propagates. This way, the value is available immediately even if `fn_pin` is expensive.
+	///
+	/// # Ordering
+	///
+	/// The first evaluation marks the signal stale again right away, so the real computation is its next refresh.  
+	/// With [`GlobalSignalsRuntime`](`crate::GlobalSignalsRuntime`), that refresh happens before the
+	/// read or subscription that caused the first evaluation returns:
+	///
+	/// - A dependent that's subscribed (e.g. immediately) observes `placeholder` once and then the real value.
+	/// - A plain read returns the real value, as the signal is already stale again when it's read.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::GlobalSignalsRuntime;
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	/// type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+	///
+	/// let input = Signal::cell(2_u32);
+	/// let expensive = Signal::computed_with_placeholder(0, || input.get().pow(10));
+	///
+	/// let seen = std::sync::Mutex::new(Vec::new());
+	/// let _sub = Subscription::computed(|| seen.lock().unwrap().push(expensive.get()));
+	/// assert_eq!(*seen.lock().unwrap(), [0, 1024]);
+	/// # }
+	/// ```
+	///
+	/// Wraps [`folded`](`folded()`).
+	///
+	/// Since 0.2.1.
+	pub fn computed_with_placeholder<'a>(
+		placeholder: T,
+		fn_pin: impl 'a + Send + FnMut() -> T,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
+	where
+		T: 'a + Sized,
+		SR: 'a + Default,
+	{
+		Self::computed_with_placeholder_with_runtime(placeholder, fn_pin, SR::default())
+	}
+
+	/// A cached computation that serves `placeholder` until `fn_pin` first ran.
+	///
+	/// The first evaluation doesn't call `fn_pin` but only schedules the real computation, which then replaces
+	/// `placeholder` and propagates. This way, the value is available immediately even if `fn_pin` is expensive.
+	///
+	/// # Ordering
+	///
+	/// The first evaluation marks the signal stale again right away, so the real computation is its next refresh.  
+	/// With [`GlobalSignalsRuntime`](`crate::GlobalSignalsRuntime`), that refresh happens before the
+	/// read or subscription that caused the first evaluation returns:
+	///
+	/// - A dependent that's subscribed (e.g. immediately) observes `placeholder` once and then the real value.
+	/// - A plain read returns the real value, as the signal is already stale again when it's read.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::{GlobalSignalsRuntime, Signal};
+	/// # let input = Signal::cell_with_runtime(2_u32, GlobalSignalsRuntime);
+	/// let expensive = Signal::computed_with_placeholder_with_runtime(
+	/// 	0,
+	/// 	|| input.get().pow(10),
+	/// 	GlobalSignalsRuntime,
+	/// );
+	/// # }
+	/// ```
+	///
+	/// Wraps [`folded`](`folded()`).
+	///
+	/// Since 0.2.1.
+	pub fn computed_with_placeholder_with_runtime<'a>(
+		placeholder: T,
+		mut fn_pin: impl 'a + Send + FnMut() -> T,
+		runtime: SR,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
+	where
+		T: 'a + Sized,
+		SR: 'a,
+	{
+		let trigger = SignalArc::new(InertCell::with_runtime((), runtime.clone()));
+		let mut pending = true;
+		Self::folded_with_runtime(
+			placeholder,
+			move |value| {
+				if mem::take(&mut pending) {
+					// Records `trigger` as dependency and then marks `self` stale through it.
+					trigger.touch();
+					trigger.set(());
+					Propagation::Halt
+				} else {
+					*value = fn_pin();
+					Propagation::Propagate
+				}
+			},
+			runtime,
+		)
+	}
+
+	/// A cached computation that additionally becomes stale once `ttl` has elapsed since it last ran.
+	///
+	/// `sleeper` is called with `ttl` after each computation and **should** return a [`Future`]
+	/// that completes once that time has passed. It's polled directly from its [`Waker`](`core::task::Waker`),
+	/// so no executor is necessary, but it's up to the sleeper to wake it eventually.
+	///
+	/// Any recomputation, including one caused by a dependency, cancels the pending sleeper
+	/// and so restarts the timer.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::GlobalSignalsRuntime;
+	/// # use std::time::Duration;
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// # let input = Signal::cell(1);
+	/// # let sleep = |_| std::future::pending();
+	/// Signal::computed_ttl(|| input.get() + 1, Duration::from_secs(60), sleep);
+	/// # }
+	/// ```
+	///
+	/// Note that iff the sleeper completes synchronously (for example for a zero `ttl`),
+	/// a subscribed instance of this signal will refresh continuously.
+	///
+	/// Since 0.2.1.
+	pub fn computed_ttl<'a, F: 'static + Send + Future<Output = ()>>(
+		fn_pin: impl 'a + Send + FnMut() -> T,
+		ttl: Duration,
+		sleeper: impl 'a + Send + FnMut(Duration) -> F,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
+	where
+		T: 'a + Sized,
+		SR: 'static + Sized + Default,
+	{
+		Self::computed_ttl_with_runtime(fn_pin, ttl, sleeper, SR::default())
+	}
+
+	/// A cached computation that additionally becomes stale once `ttl` has elapsed since it last ran.
+	///
+	/// `sleeper` is called with `ttl` after each computation and **should** return a [`Future`]
+	/// that completes once that time has passed. It's polled directly from its [`Waker`](`core::task::Waker`),
+	/// so no executor is necessary, but it's up to the sleeper to wake it eventually.
+	///
+	/// Any recomputation, including one caused by a dependency, cancels the pending sleeper
+	/// and so restarts the timer.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::{GlobalSignalsRuntime, Signal};
+	/// # use std::time::Duration;
+	/// # let input = Signal::cell_with_runtime(1, GlobalSignalsRuntime);
+	/// # let sleep = |_| std::future::pending();
+	/// Signal::computed_ttl_with_runtime(
+	/// 	|| input.get() + 1,
+	/// 	Duration::from_secs(60),
+	/// 	sleep,
+	/// 	input.clone_runtime_ref(),
+	/// );
+	/// # }
+	/// ```
+	///
+	/// Note that iff the sleeper completes synchronously (for example for a zero `ttl`),
+	/// a subscribed instance of this signal will refresh continuously.
+	///
+	/// Since 0.2.1.
+	pub fn computed_ttl_with_runtime<'a, F: 'static + Send + Future<Output = ()>>(
+		mut fn_pin: impl 'a + Send + FnMut() -> T,
+		ttl: Duration,
+		mut sleeper: impl 'a + Send + FnMut(Duration) -> F,
+		runtime: SR,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
+	where
+		T: 'a + Sized,
+		SR: 'static + Sized,
+	{
+		let expired = SignalArc::new(InertCell::with_runtime((), runtime.clone()));
+		let mut timer = None::<Arc<Timer>>;
+		Self::computed_with_runtime(
+			move || {
+				expired.touch();
+				let value = fn_pin();
+				if let Some(timer) = timer.take() {
+					timer.cancel();
+				}
+				let sleep = expired.clone_runtime_ref().run_detached(|| sleeper(ttl));
+				timer = Some(Timer::start(sleep, {
+					let expired = expired.downgrade();
+					move || {
+						if let Some(expired) = expired.upgrade() {
+							expired.set(());
+						}
+					}
+				}));
+				value
+			},
+			runtime,
+		)
+	}
+
+	/// A cached computation that can additionally be marked stale from outside any signal context.
+	///
+	/// Wraps [`computed`](`Signal::computed`).  
+	/// The returned [`Invalidator`] doesn't keep the signal alive.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::GlobalSignalsRuntime;
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// # let read_external = || 0;
+	/// let (signal, invalidator) = Signal::computed_manually(read_external);
+	/// # assert_eq!(signal.get(), 0);
+	/// invalidator.invalidate();
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	pub fn computed_manually<'a>(
+		fn_pin: impl 'a + Send + FnMut() -> T,
+	) -> (
+		SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>,
+		Invalidator<SR>,
+	)
+	where
+		T: 'a + Sized,
+		SR: 'static + Sized + Default,
+	{
+		Self::computed_manually_with_runtime(fn_pin, SR::default())
+	}
+
+	/// A cached computation that can additionally be marked stale from outside any signal context.
+	///
+	/// Wraps [`computed_with_runtime`](`Signal::computed_with_runtime`).  
+	/// The returned [`Invalidator`] doesn't keep the signal alive.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::{GlobalSignalsRuntime, Signal};
+	/// # let read_external = || 0;
+	/// let (signal, invalidator) = Signal::computed_manually_with_runtime(
+	/// 	read_external,
+	/// 	GlobalSignalsRuntime,
+	/// );
+	/// # assert_eq!(signal.get(), 0);
+	/// invalidator.invalidate();
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	pub fn computed_manually_with_runtime<'a>(
+		mut fn_pin: impl 'a + Send + FnMut() -> T,
+		runtime: SR,
+	) -> (
+		SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>,
+		Invalidator<SR>,
+	)
+	where
+		T: 'a + Sized,
+		SR: 'static + Sized,
+	{
+		let trigger = SignalArc::new(InertCell::with_runtime((), runtime.clone()));
+		let invalidator = Invalidator::new(trigger.downgrade().into_dyn_cell());
+		let signal = Self::computed_with_runtime(
+			move || {
+				trigger.touch();
+				fn_pin()
+			},
+			runtime,
+		);
+		(signal, invalidator)
+	}
+
+	/// A cached computation whose cached value can be dropped from outside any signal context,
+	/// for example under memory pressure.
+	///
+	/// After eviction, the value is recomputed when next read.  
+	/// Iff the signal is subscribed, it's recomputed immediately instead, which notifies its dependents.  
+	/// The returned [`Evictor`] doesn't keep the signal alive.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::GlobalSignalsRuntime;
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// # let on_memory_pressure = |_: Box<dyn Fn()>| ();
+	/// let (table, evictor) = Signal::computed_evictable(|| vec![0u8; 1024]);
+	/// # assert_eq!(table.read().len(), 1024);
+	/// on_memory_pressure(Box::new(move || evictor.evict()));
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	pub fn computed_evictable(
+		fn_pin: impl 'static + Send + FnMut() -> T,
+	) -> (
+		SignalArc<T, impl 'static + Sized + UnmanagedSignal<T, SR>, SR>,
+		Evictor,
+	)
+	where
+		T: 'static + Sized,
+		SR: 'static + Sized + Default,
+	{
+		Self::computed_evictable_with_runtime(fn_pin, SR::default())
+	}
+
+	/// A cached computation whose cached value can be dropped from outside any signal context,
+	/// for example under memory pressure.
+	///
+	/// After eviction, the value is recomputed when next read.  
+	/// Iff the signal is subscribed, it's recomputed immediately instead, which notifies its dependents.  
+	/// The returned [`Evictor`] doesn't keep the signal alive.
+	///
+	/// Since 0.2.1.
+	pub fn computed_evictable_with_runtime(
+		mut fn_pin: impl 'static + Send + FnMut() -> T,
+		runtime: SR,
+	) -> (
+		SignalArc<T, impl 'static + Sized + UnmanagedSignal<T, SR>, SR>,
+		Evictor,
+	)
+	where
+		T: 'static + Sized,
+		SR: 'static + Sized,
+	{
+		let trigger = SignalArc::new(InertCell::with_runtime((), runtime.clone()));
+		let stale = Invalidator::new(trigger.downgrade().into_dyn_cell());
+		let signal = SignalArc::new(ComputedEvictable::new(
+			move || {
+				trigger.touch();
+				Some(fn_pin())
+			},
+			runtime,
+		));
+		let evictor = Evictor::new({
+			let signal = signal.downgrade();
+			move || {
+				if let Some(signal) = signal.upgrade() {
+					signal._managed().evict();
+					// Refreshes the signal right away iff it's subscribed.
+					stale.invalidate();
+				}
+			}
+		});
+		(signal, evictor)
+	}
+
+	/// A cached computation that catches panics of `fn_pin` instead of letting them unwind through the signals runtime.
+	///
+	/// When `fn_pin` panics, the signal keeps the last good value and doesn't propagate,
+	/// and the payload is available through [`last_error`](`Signal::last_error`) until the next successful run.  
+	/// The signal also keeps the dependencies of its previous run, so it recovers when any of them changes.
+	///
+	/// If `fn_pin` hasn't succeeded yet, reading the value panics at the read site.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::GlobalSignalsRuntime;
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// let divisor = Signal::cell(2);
+	/// let quotient = Signal::computed_resilient(|| 10 / divisor.get());
+	/// assert_eq!(quotient.get(), 5);
+	///
+	/// divisor.set(0);
+	/// assert_eq!(quotient.get(), 5);
+	/// assert!(quotient.last_error().is_errored());
+	/// # }
+	/// ```
+	///
+	/// # Features
+	///
+	/// **The feature `"std"` is required to enable this constructor.**
+	///
+	/// Since 0.2.1.
+	#[cfg(feature = "std")]
+	pub fn computed_resilient<'a>(
+		fn_pin: impl 'a + Send + FnMut() -> T,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignalResilient<T, SR>, SR>
+	where
+		T: 'a + Sized,
+		SR: 'a + Default,
+	{
+		Self::computed_resilient_with_runtime(fn_pin, SR::default())
+	}
+
+	/// A cached computation that catches panics of `fn_pin` instead of letting them unwind through the signals runtime.
+	///
+	/// When `fn_pin` panics, the signal keeps the last good value and doesn't propagate,
+	/// and the payload is available through [`last_error`](`Signal::last_error`) until the next successful run.  
+	/// The signal also keeps the dependencies of its previous run, so it recovers when any of them changes.
+	///
+	/// If `fn_pin` hasn't succeeded yet, reading the value panics at the read site.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::{GlobalSignalsRuntime, Signal};
+	/// # let divisor = Signal::cell_with_runtime(2, GlobalSignalsRuntime);
+	/// let quotient = Signal::computed_resilient_with_runtime(
+	/// 	|| 10 / divisor.get(),
+	/// 	GlobalSignalsRuntime,
+	/// );
+	/// # }
+	/// ```
+	///
+	/// # Features
+	///
+	/// **The feature `"std"` is required to enable this constructor.**
+	///
+	/// Since 0.2.1.
+	#[cfg(feature = "std")]
+	pub fn computed_resilient_with_runtime<'a>(
+		fn_pin: impl 'a + Send + FnMut() -> T,
+		runtime: SR,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignalResilient<T, SR>, SR>
+	where
+		T: 'a + Sized,
+		SR: 'a + Sized,
+	{
+		SignalArc::new(ComputedResilient::new(fn_pin, runtime))
+	}
+
+	/// A cached fallible computation, with a companion signal of its last [`Ok`] value.
+	///
+	/// The first returned signal holds each result of `fn_pin` as-is, including [`Err`]s.  
+	/// The second returned signal holds a clone of the most recent [`Ok`] value,
+	/// which stays available (and doesn't propagate) while `fn_pin` errors.
+	/// It's [`None`] only iff there was no [`Ok`] result yet.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::GlobalSignalsRuntime;
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// let input = Signal::cell("x");
+	/// let (parsed, last_ok) = Signal::computed_try(|| input.get().parse::<i32>());
+	/// assert!(parsed.get_clone().is_err());
+	/// assert_eq!(last_ok.get(), None);
+	///
+	/// input.set("1");
+	/// assert_eq!(parsed.get_clone(), Ok(1));
+	/// assert_eq!(last_ok.get(), Some(1));
+	///
+	/// input.set("y");
+	/// assert!(parsed.get_clone().is_err());
+	/// assert_eq!(last_ok.get(), Some(1));
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	pub fn computed_try<'a, E: 'a + Send>(
+		fn_pin: impl 'a + Send + FnMut() -> Result<T, E>,
+	) -> (
+		SignalArc<Result<T, E>, impl 'a + Sized + UnmanagedSignal<Result<T, E>, SR>, SR>,
+		SignalArc<Option<T>, impl 'a + Sized + UnmanagedSignal<Option<T>, SR>, SR>,
+	)
+	where
+		T: 'a + Sized + Clone,
+		SR: 'a + Default,
+	{
+		Self::computed_try_with_runtime(fn_pin, SR::default())
+	}
+
+	/// A cached fallible computation, with a companion signal of its last [`Ok`] value.
+	///
+	/// The first returned signal holds each result of `fn_pin` as-is, including [`Err`]s.  
+	/// The second returned signal holds a clone of the most recent [`Ok`] value,
+	/// which stays available (and doesn't propagate) while `fn_pin` errors.
+	/// It's [`None`] only iff there was no [`Ok`] result yet.
+	///
+	/// The companion signal is [`folded`](`Signal::folded_with_runtime`) over the first one and keeps it alive.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::{GlobalSignalsRuntime, Signal};
+	/// # let input = Signal::cell_with_runtime("1", GlobalSignalsRuntime);
+	/// let (parsed, last_ok) = Signal::computed_try_with_runtime(
+	/// 	|| input.get().parse::<i32>(),
+	/// 	GlobalSignalsRuntime,
+	/// );
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	pub fn computed_try_with_runtime<'a, E: 'a + Send>(
+		fn_pin: impl 'a + Send + FnMut() -> Result<T, E>,
+		runtime: SR,
+	) -> (
+		SignalArc<Result<T, E>, impl 'a + Sized + UnmanagedSignal<Result<T, E>, SR>, SR>,
+		SignalArc<Option<T>, impl 'a + Sized + UnmanagedSignal<Option<T>, SR>, SR>,
+	)
+	where
+		T: 'a + Sized + Clone,
+		SR: 'a + Sized,
+	{
+		let result = Signal::computed_with_runtime(fn_pin, runtime.clone());
+		let last_ok = Signal::folded_with_runtime(
+			None,
+			{
+				let result = result.clone();
+				move |last_ok| match &*result.read_exclusive() {
+					Ok(value) => {
+						*last_ok = Some(value.clone());
+						Propagation::Propagate
+					}
+					Err(_) => Propagation::Halt,
+				}
+			},
+			runtime,
+		);
+		(result, last_ok)
+	}
+
+	/// A cached computation that re-runs only when its *group key* changes.
+	///
+	/// `group_key` reads any number of member signals and maps them to a logical group key.
+	/// It's cached as [`distinct`](`Signal::distinct`) signal, so changes of members that don't
+	/// change the key are absorbed there, and several members changing at once are coalesced
+	/// into at most one re-run of `fn_pin`.
+	///
+	/// `fn_pin` receives the current key and runs *untracked*:
+	/// Signals it reads aren't recorded as dependencies, so the key is its only trigger.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::GlobalSignalsRuntime;
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// let width = Signal::cell(1200);
+	/// let height = Signal::cell(800);
+	///
+	/// let layout = Signal::computed_grouped(
+	/// 	|| width.get() >= 1000 && height.get() >= 600,
+	/// 	|&large| if large { "wide" } else { "compact" },
+	/// );
+	/// assert_eq!(layout.get(), "wide");
+	///
+	/// // Same group, so `layout` isn't recomputed.
+	/// width.set(1100);
+	/// assert_eq!(layout.get(), "wide");
+	///
+	/// height.set(500);
+	/// assert_eq!(layout.get(), "compact");
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	pub fn computed_grouped<'a, K: 'a + Send + Sync + PartialEq>(
+		group_key: impl 'a + Send + FnMut() -> K,
+		fn_pin: impl 'a + Send + FnMut(&K) -> T,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
+	where
+		T: 'a + Sized,
+		SR: 'a + Default,
+	{
+		Self::computed_grouped_with_runtime(group_key, fn_pin, SR::default())
+	}
+
+	/// A cached computation that re-runs only when its *group key* changes.
+	///
+	/// `group_key` reads any number of member signals and maps them to a logical group key.
+	/// It's cached as [`distinct`](`Signal::distinct_with_runtime`) signal, so changes of members that don't
+	/// change the key are absorbed there, and several members changing at once are coalesced
+	/// into at most one re-run of `fn_pin`.
+	///
+	/// `fn_pin` receives the current key and runs *untracked*:
+	/// Signals it reads aren't recorded as dependencies, so the key is its only trigger.
+	///
+	/// The key signal is kept alive by the returned one.
+	///
+	/// Since 0.2.1.
+	pub fn computed_grouped_with_runtime<'a, K: 'a + Send + Sync + PartialEq>(
+		group_key: impl 'a + Send + FnMut() -> K,
+		mut fn_pin: impl 'a + Send + FnMut(&K) -> T,
+		runtime: SR,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
+	where
+		T: 'a + Sized,
+		SR: 'a + Sized,
+	{
+		let key = Signal::distinct_with_runtime(group_key, runtime.clone());
+		Signal::computed_with_runtime(
+			{
+				let runtime = runtime.clone();
+				move || {
+					let key = key.read();
+					runtime.run_detached(|| fn_pin(&key))
+				}
+			},
+			runtime,
+		)
+	}
+
+	/// A lightweight thread-safe value that's signal-compatible.
+	///
+	/// It doesn't have a signal-identity and isn't recorded as dependency.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::{GlobalSignalsRuntime, Propagation};
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	/// type SignalDyn<'a, T> = flourish::SignalDyn<'a, T, GlobalSignalsRuntime>;
+	///
+	/// # #[derive(Default, Clone)] struct Container;
+	/// # impl Container { fn sort(&mut self) {} }
+	/// # let input = Signal::cell(Container);
+	/// let shared = Signal::shared(0);
+	///
+	/// fn accepts_signal<T: Send>(signal: &SignalDyn<'_, T>) {}
+	/// accepts_signal(&*shared);
+	/// # }
+	/// ```
+	///
+	/// Since 0.1.2.
+	pub fn shared<'a>(value: T) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
+	where
+		T: 'a + Sized + Sync,
+		SR: 'a + Default,
+	{
+		Self::shared_with_runtime(value, SR::default())
+	}
+
+	/// A lightweight thread-safe value that's signal-compatible.
+	///
+	/// It doesn't have a signal-identity and isn't recorded as dependency.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::{GlobalSignalsRuntime, Propagation, Signal};
+	/// let shared = Signal::shared_with_runtime(0, GlobalSignalsRuntime);
+	///
+	/// fn accepts_signal<T: Send, SR: flourish::SignalsRuntimeRef>(
+	///   signal: &flourish::SignalDyn<'_, T, SR>,
+	/// ) {}
+	/// accepts_signal(&*shared);
+	/// # }
+	/// ```
+	///
+	/// Since 0.1.2.
+	pub fn shared_with_runtime<'a>(
+		value: T,
+		runtime: SR,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
+	where
+		T: 'a + Sized + Sync,
+		SR: 'a + Default,
+	{
+		SignalArc {
+			strong: Strong::pin(Shared::with_runtime(value, runtime)),
+		}
+	}
+}
+
+/// Polling constructors.
+impl<T: Send, SR: SignalsRuntimeRef> Signal<Option<T>, Opaque, SR> {
+	/// A signal that bridges a [`Poll`]-based source, holding the latest [`Ready`](`Poll::Ready`) value.
+	///
+	/// `poll_fn_pin` runs when the signal is first read or subscribed and again whenever
+	/// the [`Waker`](`task::Waker`) in its [`task::Context`] is woken. The value is [`None`] until the first
+	/// [`Ready`](`Poll::Ready`), and only [`Ready`](`Poll::Ready`) results notify dependents.
+	///
+	/// `poll_fn_pin` isn't called again until woken, so it **should** register the [`Waker`](`task::Waker`)
+	/// whenever it expects another value, including when it returns [`Ready`](`Poll::Ready`).
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::GlobalSignalsRuntime;
+	/// use std::{sync::mpsc, task::Poll};
+	///
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// let (sender, receiver) = mpsc::channel();
+	/// let latest = Signal::from_poll_fn(move |_cx| match receiver.try_recv() {
+	/// 	Ok(value) => Poll::Ready(value),
+	/// 	// A real source would store `_cx.waker()` to wake the signal later.
+	/// 	Err(_) => Poll::Pending,
+	/// });
+	/// assert_eq!(latest.get(), None);
+	///
+	/// sender.send(1).unwrap();
+	/// # }
+	/// ```
+	///
+	/// Note that iff `poll_fn_pin` wakes its [`Waker`](`task::Waker`) synchronously on each call,
+	/// a subscribed instance of this signal will refresh continuously.
+	///
+	/// Since 0.2.1.
+	pub fn from_poll_fn<'a>(
+		poll_fn_pin: impl 'a + Send + FnMut(&mut task::Context<'_>) -> Poll<T>,
+	) -> SignalArc<Option<T>, impl 'a + Sized + UnmanagedSignal<Option<T>, SR>, SR>
+	where
+		T: 'a,
+		SR: 'static + Default,
+	{
+		Self::from_poll_fn_with_runtime(poll_fn_pin, SR::default())
+	}
+
+	/// A signal that bridges a [`Poll`]-based source, holding the latest [`Ready`](`Poll::Ready`) value.
+	///
+	/// `poll_fn_pin` runs when the signal is first read or subscribed and again whenever
+	/// the [`Waker`](`task::Waker`) in its [`task::Context`] is woken. The value is [`None`] until the first
+	/// [`Ready`](`Poll::Ready`), and only [`Ready`](`Poll::Ready`) results notify dependents.
+	///
+	/// `poll_fn_pin` isn't called again until woken, so it **should** register the [`Waker`](`task::Waker`)
+	/// whenever it expects another value, including when it returns [`Ready`](`Poll::Ready`).
+	///
+	/// Note that iff `poll_fn_pin` wakes its [`Waker`](`task::Waker`) synchronously on each call,
+	/// a subscribed instance of this signal will refresh continuously.
+	///
+	/// Since 0.2.1.
+	pub fn from_poll_fn_with_runtime<'a>(
+		mut poll_fn_pin: impl 'a + Send + FnMut(&mut task::Context<'_>) -> Poll<T>,
+		runtime: SR,
+	) -> SignalArc<Option<T>, impl 'a + Sized + UnmanagedSignal<Option<T>, SR>, SR>
+	where
+		T: 'a,
+		SR: 'static,
+	{
+		let trigger = SignalArc::new(InertCell::with_runtime((), runtime.clone()));
+		let waker = InvalidatingWaker::waker(Invalidator::new(trigger.downgrade().into_dyn_cell()));
+		Signal::folded_with_runtime(
+			None,
+			move |value| {
+				trigger.touch();
+				match poll_fn_pin(&mut task::Context::from_waker(&waker)) {
+					Poll::Ready(next) => {
+						*value = Some(next);
+						Propagation::Propagate
+					}
+					Poll::Pending => Propagation::Halt,
+				}
+			},
+			runtime,
+		)
+	}
+}
+
+/// Tokio interop constructors.
+#[cfg(feature = "tokio")]
+impl<T: 'static + Send + Sync + Clone, SR: 'static + SignalsRuntimeRef> Signal<T, Opaque, SR> {
+	/// A signal that mirrors the value of a [`tokio::sync::watch`] channel.
+	///
+	/// A spawned task copies each new value into the signal until either the sender
+	/// or the signal is dropped. The signal doesn't keep that task or `receiver` alive.
+	///
+	/// **The feature `"tokio"` is required to enable this constructor.**
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(all(feature = "global_signals_runtime", feature = "tokio"))] // flourish features
+	/// # use flourish::GlobalSignalsRuntime;
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+	/// let _guard = runtime.enter();
+	///
+	/// let (tx, rx) = tokio::sync::watch::channel(1);
+	/// let mirror = Signal::from_watch(rx);
+	/// assert_eq!(mirror.get(), 1);
+	///
+	/// tx.send_replace(2);
+	/// runtime.block_on(tokio::task::yield_now());
+	/// assert_eq!(mirror.get(), 2);
+	/// # }
+	/// ```
+	///
+	/// # Panics
+	///
+	/// Iff called outside of a Tokio runtime context, like [`tokio::spawn`].
+	///
+	/// Since 0.2.1.
+	pub fn from_watch(
+		receiver: tokio::sync::watch::Receiver<T>,
+	) -> SignalArc<T, impl 'static + Sized + UnmanagedSignal<T, SR>, SR>
+	where
+		SR: Default,
+	{
+		Self::from_watch_with_runtime(receiver, SR::default())
+	}
+
+	/// A signal that mirrors the value of a [`tokio::sync::watch`] channel.
+	///
+	/// A spawned task copies each new value into the signal until either the sender
+	/// or the signal is dropped. The signal doesn't keep that task or `receiver` alive.
+	///
+	/// **The feature `"tokio"` is required to enable this constructor.**
+	///
+	/// # Panics
+	///
+	/// Iff called outside of a Tokio runtime context, like [`tokio::spawn`].
+	///
+	/// Since 0.2.1.
+	pub fn from_watch_with_runtime(
+		mut receiver: tokio::sync::watch::Receiver<T>,
+		runtime: SR,
+	) -> SignalArc<T, impl 'static + Sized + UnmanagedSignal<T, SR>, SR> {
+		let cell = SignalArc::new(InertCell::with_runtime(
+			receiver.borrow_and_update().clone(),
+			runtime,
+		));
+		let weak = cell.downgrade();
+		tokio::spawn(async move {
+			while receiver.changed().await.is_ok() {
+				let Some(cell) = weak.upgrade() else {
+					break;
+				};
+				let value = receiver.borrow_and_update().clone();
+				cell.set(value);
+			}
+		});
+		cell.into_read_only()
+	}
+}
+
+/// `arc-swap` interop constructors.
+#[cfg(feature = "arc-swap")]
+impl<U: Send + Sync, SR: SignalsRuntimeRef> Signal<Arc<U>, Opaque, SR> {
+	/// A signal that loads the current [`Arc`] from `swap`, refreshed whenever `trigger` changes.
+	///
+	/// **Stores into an [`ArcSwap`](`arc_swap::ArcSwap`) aren't observable**, so this signal
+	/// can't notice them by itself. Set `trigger` after storing into `swap` to propagate the change.  
+	/// Only `trigger` is recorded as dependency. Reads clone the [`Arc`], not the value.
+	///
+	/// The returned signal uses the runtime of `trigger`.
+	///
+	/// **The feature `"arc-swap"` is required to enable this constructor.**
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(all(feature = "global_signals_runtime", feature = "arc-swap"))] // flourish features
+	/// # use flourish::GlobalSignalsRuntime;
+	/// use std::sync::Arc;
+	/// use arc_swap::ArcSwap;
+	///
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// let swap = Arc::new(ArcSwap::from_pointee(1));
+	/// let trigger = Signal::cell(());
+	/// let signal = Signal::from_arc_swap(Arc::clone(&swap), trigger.as_dyn());
+	/// assert_eq!(*signal.get_clone(), 1);
+	///
+	/// swap.store(Arc::new(2));
+	/// assert_eq!(*signal.get_clone(), 1);
+	///
+	/// trigger.set(());
+	/// assert_eq!(*signal.get_clone(), 2);
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	pub fn from_arc_swap<'a>(
+		swap: Arc<arc_swap::ArcSwap<U>>,
+		trigger: &SignalDyn<'a, (), SR>,
+	) -> SignalArc<Arc<U>, impl 'a + Sized + UnmanagedSignal<Arc<U>, SR>, SR>
+	where
+		U: 'a,
+		SR: 'a,
+	{
+		let trigger = trigger.to_owned();
+		let runtime = trigger.clone_runtime_ref();
+		Signal::computed_with_runtime(
+			move || {
+				trigger.touch();
+				swap.load_full()
+			},
+			runtime,
+		)
+	}
+}
+
+/// `arc-swap` interop.
+#[cfg(feature = "arc-swap")]
+impl<U: Send + Sync, S: ?Sized + UnmanagedSignal<Arc<U>, SR>, SR: ?Sized + SignalsRuntimeRef>
+	Signal<Arc<U>, S, SR>
+{
+	/// An [`ArcSwap`](`arc_swap::ArcSwap`) that is kept in sync with this signal by a forwarding effect.
+	///
+	/// Each change stores a clone of the current [`Arc`], so the value itself isn't cloned.
+	///
+	/// The returned [`SyncedArcSwap`] owns the forwarding effect, which stops when the handle is dropped.
+	/// The effect doesn't keep this signal alive and also stops once it's dropped.
+	///
+	/// **The feature `"arc-swap"` is required to enable this method.**
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(all(feature = "global_signals_runtime", feature = "arc-swap"))] // flourish features
+	/// # use flourish::GlobalSignalsRuntime;
+	/// use std::sync::Arc;
+	///
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// let cell = Signal::cell(Arc::new(1));
+	/// let swap = cell.to_arc_swap();
+	/// assert_eq!(**swap.load(), 1);
+	///
+	/// cell.set(Arc::new(2));
+	/// assert_eq!(**swap.load(), 2);
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	pub fn to_arc_swap(&self) -> SyncedArcSwap<U>
+	where
+		U: 'static,
+		S: 'static,
+		SR: 'static + Sized,
+	{
+		let swap = Arc::new(arc_swap::ArcSwap::new(
+			self.clone_runtime_ref().run_detached(|| self.get_clone()),
+		));
+		let subscription = Subscription::computed_with_runtime(
+			{
+				let swap = Arc::clone(&swap);
+				let source = self.downgrade();
+				move || {
+					// Not reading `source` anymore makes this dormant.
+					if let Some(source) = source.upgrade() {
+						swap.store(source.get_clone());
+					}
+				}
+			},
+			self.clone_runtime_ref(),
+		);
+		SyncedArcSwap::new(swap, subscription.into_stored())
+	}
+}
+
+/// Map cell methods.
+impl<T: Send, S: ?Sized + UnmanagedSignalCell<T, SR>, SR: ?Sized + SignalsRuntimeRef>
+	Signal<T, S, SR>
+{
+	/// Modifies the value for `key` in place using the given closure, without cloning the map.
+	///
+	/// Iff `key` is missing, [`V::default()`](`Default::default`) is inserted first.
+	///
+	/// The closure decides whether to signal dependents. Note that an insertion is kept even if it returns
+	/// [`Propagation::Halt`]. Dependents then aren't notified of the new entry either, so they may keep results
+	/// derived from the map without it until the next propagating update.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::{GlobalSignalsRuntime, Propagation};
+	/// use std::collections::HashMap;
+	///
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// let counts = Signal::cell(HashMap::<&str, u32>::new());
+	/// counts.with_entry("a", |count| {
+	/// 	*count += 1;
+	/// 	Propagation::Propagate
+	/// });
+	/// assert_eq!(counts.read()["a"], 1);
+	/// # }
+	/// ```
+	///
+	/// # Logic
+	///
+	/// This method **must not** block *indefinitely*.  
+	/// This method **may** defer its effect.
+	///
+	/// Since 0.2.1.
+	pub fn with_entry<K: 'static + Send, V>(
+		&self,
+		key: K,
+		f: impl 'static + Send + FnOnce(&mut V) -> Propagation,
+	) where
+		T: 'static + MapLike<K, V>,
+	{
+		self.update_dyn(Box::new(move |map| f(map.entry_or_default(key))));
+	}
+}
+
+/// Slice constructors.
+impl<U: Send, SR: SignalsRuntimeRef> Signal<[U], Opaque, SR> {
+	/// A cached computation of a [`Vec`] that's read as slice.
+	///
+	/// The [`Vec`] is kept alive inside the signal, so [`read`](`Signal::read`) borrows
+	/// the slice directly instead of cloning or boxing it.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::GlobalSignalsRuntime;
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// let count = Signal::cell(3);
+	/// let numbers = Signal::computed_slice(move || (0..count.get()).collect());
+	/// let slice: &[i32] = &numbers.read();
+	/// assert_eq!(slice, [0, 1, 2]);
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	pub fn computed_slice<'a>(
+		fn_pin: impl 'a + Send + FnMut() -> Vec<U>,
+	) -> SignalArc<[U], impl 'a + Sized + UnmanagedSignal<[U], SR>, SR>
+	where
+		U: 'a,
+		SR: 'a + Default,
+	{
+		Self::computed_slice_with_runtime(fn_pin, SR::default())
+	}
+
+	/// A cached computation of a [`Vec`] that's read as slice.
+	///
+	/// The [`Vec`] is kept alive inside the signal, so [`read`](`Signal::read`) borrows
+	/// the slice directly instead of cloning or boxing it.
+	///
+	/// Since 0.2.1.
+	pub fn computed_slice_with_runtime<'a>(
+		fn_pin: impl 'a + Send + FnMut() -> Vec<U>,
+		runtime: SR,
+	) -> SignalArc<[U], impl 'a + Sized + UnmanagedSignal<[U], SR>, SR>
+	where
+		U: 'a,
+		SR: 'a,
+	{
+		SignalArc::new(ComputedSlice::new(fn_pin, runtime))
+	}
+}
+
+/// Cell constructors.
+impl<T: Send, SR: SignalsRuntimeRef> Signal<T, Opaque, SR> {
+	/// A thread-safe value cell that's mutable through shared references.
+	///
+	/// Modification of the value can cause dependent signals to update.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::{GlobalSignalsRuntime, Propagation};
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// # #[derive(Default, Clone)] struct Container;
+	/// # impl Container { fn sort(&mut self) {} }
+	/// let cell = Signal::cell(0);
+	///
+	/// cell.set_if_distinct(1);
+	/// cell.set(2);
+	/// cell.update(|value| {
+	/// 	*value += 1;
+	/// 	Propagation::Propagate
+	/// });
+	/// # }
+	/// ```
+	pub fn cell<'a>(
+		initial_value: T,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignalCell<T, SR>, SR>
+	where
+		T: 'a,
+		SR: 'a + Default,
+	{
+		Self::cell_with_runtime(initial_value, SR::default())
+	}
+
+	/// A thread-safe value cell that's mutable through shared references.
+	///
+	/// Modification of the value can cause dependent signals to update.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::{GlobalSignalsRuntime, Propagation, Signal};
+	/// let cell = Signal::cell_with_runtime(0, GlobalSignalsRuntime);
+	///
+	/// cell.set_if_distinct(1);
+	/// cell.set(2);
+	/// cell.update(|value| {
+	/// 	*value += 1;
+	/// 	Propagation::Propagate
+	/// });
+	/// # }
+	/// ```
+	pub fn cell_with_runtime<'a>(
+		initial_value: T,
+		runtime: SR,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignalCell<T, SR>, SR>
+	where
+		T: 'a,
+		SR: 'a + Default,
+	{
+		SignalArc {
+			strong: Strong::pin(InertCell::with_runtime(initial_value, runtime)),
+		}
+	}
+
+	/// A thread-safe value cell that calls `observer` synchronously after each change.
+	///
+	/// `observer` sees the committed value and runs once per update that wasn't
+	/// [`Halt`](`Propagation::Halt`)ed, e.g. not for a [`set_if_distinct`](`Signal::set_if_distinct`)
+	/// with an equal value. It doesn't record dependencies.
+	///
+	/// `observer` runs as part of the update, while the value is locked for reading.
+	/// It **may** write to the same cell through queued methods like [`set`](`Signal::set`) and [`update`](`Signal::update`),
+	/// which apply after it returns and then notify it again. Blocking writes to the same cell (like [`set_blocking`](`Signal::set_blocking`))
+	/// can't be applied there. [`GlobalSignalsRuntime`](`crate::GlobalSignalsRuntime`) panics on them instead of deadlocking.
+	///
+	/// Modification of the value can cause dependent signals to update.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::GlobalSignalsRuntime;
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// let cell = Signal::cell_with_observer(0, |value| println!("{value}"));
+	///
+	/// cell.set(1); // Prints "1".
+	/// cell.set_if_distinct(1); // Prints nothing.
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	pub fn cell_with_observer<'a>(
+		initial_value: T,
+		observer: impl 'static + Send + FnMut(&T),
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignalCell<T, SR>, SR>
+	where
+		T: 'a,
+		SR: 'a + Default,
+	{
+		Self::cell_with_observer_with_runtime(initial_value, observer, SR::default())
+	}
+
+	/// A thread-safe value cell that calls `observer` synchronously after each change.
+	///
+	/// `observer` sees the committed value and runs once per update that wasn't
+	/// [`Halt`](`Propagation::Halt`)ed, e.g. not for a [`set_if_distinct`](`Signal::set_if_distinct`)
+	/// with an equal value. It doesn't record dependencies.
+	///
+	/// `observer` runs as part of the update, while the value is locked for reading.
+	/// It **may** write to the same cell through queued methods like [`set`](`Signal::set`) and [`update`](`Signal::update`),
+	/// which apply after it returns and then notify it again. Blocking writes to the same cell (like [`set_blocking`](`Signal::set_blocking`))
+	/// can't be applied there. [`GlobalSignalsRuntime`](`crate::GlobalSignalsRuntime`) panics on them instead of deadlocking.
+	///
+	/// Modification of the value can cause dependent signals to update.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::{GlobalSignalsRuntime, Signal};
+	/// let cell = Signal::cell_with_observer_with_runtime(
+	/// 	0,
+	/// 	|value| println!("{value}"),
+	/// 	GlobalSignalsRuntime,
+	/// );
+	///
+	/// cell.set(1); // Prints "1".
+	/// cell.set_if_distinct(1); // Prints nothing.
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	pub fn cell_with_observer_with_runtime<'a>(
+		initial_value: T,
+		observer: impl 'static + Send + FnMut(&T),
+		runtime: SR,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignalCell<T, SR>, SR>
+	where
+		T: 'a,
+		SR: 'a,
+	{
+		SignalArc {
+			strong: Strong::pin(InertCell::with_observer_and_runtime(
+				initial_value,
+				Mutex::new(observer),
+				runtime,
+			)),
+		}
+	}
+
+	/// A cell that only accepts values for which `validator` returns [`Ok`].
+	///
+	/// Writes go through [`Validated::try_set`], which hands back rejected values along with
+	/// the validator's error. Invalid values are never written, so they never propagate.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::GlobalSignalsRuntime;
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// let percentage = Signal::cell_validated(50, |value: &u8| {
+	/// 	if *value <= 100 { Ok(()) } else { Err("too large") }
+	/// })
+	/// .unwrap();
+	///
+	/// assert_eq!(percentage.try_set(101), Err((101, "too large")));
+	/// assert_eq!(percentage.try_set(100), Ok(()));
+	/// assert_eq!(percentage.get(), 100);
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns `initial_value` together with the validator's error iff it's rejected.
+	///
+	/// Since 0.2.1.
+	pub fn cell_validated<'a, V: Fn(&T) -> Result<(), E>, E>(
+		initial_value: T,
+		validator: V,
+	) -> Result<Validated<'a, T, V, SR>, (T, E)>
+	where
+		T: 'a,
+		SR: 'a + Default,
+	{
+		Self::cell_validated_with_runtime(initial_value, validator, SR::default())
+	}
+
+	/// A cell that only accepts values for which `validator` returns [`Ok`].
+	///
+	/// Writes go through [`Validated::try_set`], which hands back rejected values along with
+	/// the validator's error. Invalid values are never written, so they never propagate.
+	///
+	/// # Errors
+	///
+	/// Returns `initial_value` together with the validator's error iff it's rejected.
+	///
+	/// Since 0.2.1.
+	pub fn cell_validated_with_runtime<'a, V: Fn(&T) -> Result<(), E>, E>(
+		initial_value: T,
+		validator: V,
+		runtime: SR,
+	) -> Result<Validated<'a, T, V, SR>, (T, E)>
+	where
+		T: 'a,
+		SR: 'a,
+	{
+		Validated::try_new_with_runtime(initial_value, validator, runtime)
+	}
+
+	/// A thread-safe value cell that may reference itself.
+	///
+	/// Modification of the value can cause dependent signals to update.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::{GlobalSignalsRuntime, Propagation, SignalsRuntimeRef, SignalWeakDynCell};
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// # struct Resource {}
+	/// # fn get_from_cache(name: &str) -> Option<Resource> { None }
+	/// # fn start_loading<SR: SignalsRuntimeRef>(name: &str, target: SignalWeakDynCell<'_, Option<Resource>, SR>) {}
+	/// fn load_into<SR: SignalsRuntimeRef>(
+	/// 	target: &SignalWeakDynCell<'_, Option<Resource>, SR>,
+	/// 	name: &str,
+	/// ) -> Option<Resource> {
+	/// 	if let Some(resource) = get_from_cache(name) {
+	/// 		Some(resource)
+	/// 	} else {
+	/// 		start_loading(name, target.clone());
+	/// 		None
+	/// 	}
+	/// }
+	///
+	/// let cell = Signal::cell_cyclic(|weak| load_into(weak, "resource"));
+	/// # }
+	/// ```
+	pub fn cell_cyclic<'a>(
+		make_initial_value: impl 'a + FnOnce(&SignalWeakDynCell<'a, T, SR>) -> T,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignalCell<T, SR>, SR>
+	where
+		T: 'a,
+		SR: 'a + Default,
+	{
+		Self::cell_cyclic_with_runtime(make_initial_value, SR::default())
+	}
+
+	/// A thread-safe value cell that may reference itself.
+	///
+	/// Modification of the value can cause dependent signals to update.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::{GlobalSignalsRuntime, Propagation, Signal, SignalsRuntimeRef, SignalWeakDynCell};
+	/// # struct Resource {}
+	/// # fn get_from_cache(name: &str) -> Option<Resource> { None }
+	/// # fn start_loading<SR: SignalsRuntimeRef>(name: &str, target: SignalWeakDynCell<'_, Option<Resource>, SR>) {}
+	/// fn load_into<SR: SignalsRuntimeRef>(
+	/// 	target: &SignalWeakDynCell<'_, Option<Resource>, SR>,
+	/// 	name: &str,
+	/// ) -> Option<Resource> {
+	/// 	if let Some(resource) = get_from_cache(name) {
+	/// 		Some(resource)
+	/// 	} else {
+	/// 		start_loading(name, target.clone());
+	/// 		None
+	/// 	}
+	/// }
+	///
+	/// let cell = Signal::cell_cyclic_with_runtime(
+	/// 	|weak| load_into(weak, "resource"),
+	/// 	GlobalSignalsRuntime,
+	/// );
+	/// # }
+	/// ```
+	pub fn cell_cyclic_with_runtime<'a>(
+		make_initial_value: impl 'a + FnOnce(&SignalWeakDynCell<'a, T, SR>) -> T,
+		runtime: SR,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignalCell<T, SR>, SR>
+	where
+		T: 'a,
+		SR: 'a + Default,
+	{
+		SignalArc {
+			strong: Strong::pin_cyclic(|weak: &Weak<T, InertCell<T, SR>, SR>| {
+				InertCell::with_runtime(
+					make_initial_value(&*ManuallyDrop::new(SignalWeakDynCell {
+						weak: Weak { weak: weak.weak },
+					})),
+					runtime,
+				)
+			}),
+		}
+	}
+
+	/// A thread-safe value cell that can observe subscription status changes.
+	///
+	/// Modification of the value can cause dependent signals to update.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::{GlobalSignalsRuntime, Propagation};
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// let cell = Signal::cell_reactive(0, |value, status| {
+	/// 		dbg!(status);
+	/// 		Propagation::Halt
+	/// 	});
+	/// # }
+	/// ```
+	pub fn cell_reactive<'a>(
+		initial_value: T,
+		on_subscribed_change_fn_pin: impl 'a
+			+ Send
+			+ FnMut(
+				&T,
+				<SR::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus,
+			) -> Propagation,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignalCell<T, SR>, SR>
+	where
+		T: 'a,
+		SR: 'a + Default,
+	{
+		Self::cell_reactive_with_runtime(initial_value, on_subscribed_change_fn_pin, SR::default())
+	}
+
+	/// A thread-safe value cell that can observe subscription status changes.
+	///
+	/// Modification of the value can cause dependent signals to update.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::{GlobalSignalsRuntime, Propagation, Signal};
+	/// let cell = Signal::cell_reactive_with_runtime(0, |value, status| {
+	/// 		dbg!(status);
+	/// 		Propagation::Halt
+	/// 	}, GlobalSignalsRuntime);
+	/// # }
+	/// ```
+	pub fn cell_reactive_with_runtime<'a>(
+		initial_value: T,
+		on_subscribed_change_fn_pin: impl 'a
+			+ Send
+			+ FnMut(
+				&T,
+				<SR::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus,
+			) -> Propagation,
+		runtime: SR,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignalCell<T, SR>, SR>
+	where
+		T: 'a,
+		SR: 'a + Default,
+	{
+		SignalArc {
+			strong: Strong::pin(ReactiveCell::with_runtime(
+				initial_value,
+				on_subscribed_change_fn_pin,
+				runtime,
+			)),
+		}
+	}
+
+	/// A thread-safe value cell that can observe subscription status changes,
+	/// which starts out holding `placeholder` but reports itself as uninitialised.
+	///
+	/// [`get_initialized`](`Signal::get_initialized`) returns [`None`] until the value is first updated,
+	/// for example once it was loaded after the first subscription.  
+	/// All other accessors see `placeholder` until then.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::{GlobalSignalsRuntime, Propagation};
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// let cell = Signal::cell_reactive_uninitialized(0, |value, status| {
+	/// 		dbg!(status);
+	/// 		Propagation::Halt
+	/// 	});
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	pub fn cell_reactive_uninitialized<'a>(
+		placeholder: T,
+		on_subscribed_change_fn_pin: impl 'a
+			+ Send
+			+ FnMut(
+				&T,
+				<SR::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus,
+			) -> Propagation,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignalCell<T, SR>, SR>
+	where
+		T: 'a,
+		SR: 'a + Default,
+	{
+		Self::cell_reactive_uninitialized_with_runtime(
+			placeholder,
+			on_subscribed_change_fn_pin,
+			SR::default(),
+		)
+	}
+
+	/// A thread-safe value cell that can observe subscription status changes,
+	/// which starts out holding `placeholder` but reports itself as uninitialised.
+	///
+	/// [`get_initialized`](`Signal::get_initialized`) returns [`None`] until the value is first updated,
+	/// for example once it was loaded after the first subscription.  
+	/// All other accessors see `placeholder` until then.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::{GlobalSignalsRuntime, Propagation, Signal};
+	/// let cell = Signal::cell_reactive_uninitialized_with_runtime(0, |value, status| {
+	/// 		dbg!(status);
+	/// 		Propagation::Halt
+	/// 	}, GlobalSignalsRuntime);
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	pub fn cell_reactive_uninitialized_with_runtime<'a>(
+		placeholder: T,
+		on_subscribed_change_fn_pin: impl 'a
+			+ Send
+			+ FnMut(
+				&T,
+				<SR::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus,
+			) -> Propagation,
+		runtime: SR,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignalCell<T, SR>, SR>
+	where
+		T: 'a,
+		SR: 'a,
+	{
+		SignalArc {
+			strong: Strong::pin(ReactiveCell::uninitialized_with_runtime(
+				placeholder,
+				on_subscribed_change_fn_pin,
+				runtime,
+			)),
+		}
+	}
+
+	/// A thread-safe value cell that can observe subscription status changes and may
+	/// reference itself.
+	///
+	/// Modification of the value can cause dependent signals to update.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::{shadow_ref_to_owned, GlobalSignalsRuntime, Propagation};
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// let cell = Signal::cell_cyclic_reactive(|weak| (0, {
+	/// 		shadow_ref_to_owned!(weak);
+	/// 		move |value, status| {
+	/// 			assert_eq!(weak.upgrade().unwrap().get(), *value);
+	/// 			dbg!(status);
+	/// 			Propagation::Halt
+	/// 	}}));
+	/// # }
+	/// ```
+	pub fn cell_cyclic_reactive<
+		'a,
+		HandlerFnPin: 'a
+			+ Send
+			+ FnMut(
+				&T,
+				<SR::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus,
+			) -> Propagation,
+	>(
+		make_initial_value_and_on_subscribed_change_fn_pin: impl FnOnce(
+			&SignalWeakDynCell<'a, T, SR>,
+		) -> (T, HandlerFnPin),
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignalCell<T, SR>, SR>
+	where
+		T: 'a,
+		SR: 'a + Default,
+	{
+		Self::cell_cyclic_reactive_with_runtime(
+			make_initial_value_and_on_subscribed_change_fn_pin,
+			SR::default(),
+		)
+	}
+
+	/// A thread-safe value cell that can observe subscription status changes and may
+	/// reference itself.
+	///
+	/// Modification of the value can cause dependent signals to update.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::{shadow_ref_to_owned, GlobalSignalsRuntime, Propagation, Signal};
+	/// let cell = Signal::cell_cyclic_reactive_with_runtime(|weak| (0, {
+	/// 		shadow_ref_to_owned!(weak);
+	/// 		move |value, status| {
+	/// 			assert_eq!(weak.upgrade().unwrap().get(), *value);
+	/// 			dbg!(status);
+	/// 			Propagation::Halt
+	/// 	}}), GlobalSignalsRuntime);
+	/// # }
+	/// ```
+	pub fn cell_cyclic_reactive_with_runtime<
+		'a,
+		HandlerFnPin: 'a
+			+ Send
+			+ FnMut(
+				&T,
+				<SR::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus,
+			) -> Propagation,
+	>(
+		make_initial_value_and_on_subscribed_change_fn_pin: impl FnOnce(
+			&SignalWeakDynCell<'a, T, SR>,
+		) -> (T, HandlerFnPin),
+		runtime: SR,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignalCell<T, SR>, SR>
+	where
+		T: 'a,
+		SR: 'a + Default,
+	{
+		SignalArc {
+			strong: Strong::pin_cyclic(|weak: &Weak<T, ReactiveCell<T, HandlerFnPin, SR>, SR>| {
+				let (initial_value, on_subscribed_change_fn_pin) =
+					make_initial_value_and_on_subscribed_change_fn_pin(&*ManuallyDrop::new(
+						SignalWeakDynCell {
+							weak: Weak { weak: weak.weak },
+						},
+					));
+				ReactiveCell::with_runtime(initial_value, on_subscribed_change_fn_pin, runtime)
+			}),
+		}
+	}
+
+	/// A thread-safe value cell that can observe subscription status changes.
+	///
+	/// Modification of the value can cause dependent signals to update.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::{GlobalSignalsRuntime, Propagation};
+	/// # fn create_heavy_resource_arc() {}
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// let cell = Signal::cell_reactive_mut(None, |value, status| {
+	/// 		if status {
+	/// 			value.get_or_insert_with(create_heavy_resource_arc);
+	/// 			Propagation::Propagate
+	/// 		} else {
+	/// 			*value = None;
+	/// 			Propagation::FlushOut
+	/// 		}
+	/// 	});
+	/// # }
+	/// ```
+	pub fn cell_reactive_mut<'a>(
+		initial_value: T,
+		on_subscribed_change_fn_pin: impl 'a
+			+ Send
+			+ FnMut(
+				&mut T,
+				<SR::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus,
+			) -> Propagation,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignalCell<T, SR>, SR>
+	where
+		T: 'a,
+		SR: 'a + Default,
+	{
+		Self::cell_reactive_mut_with_runtime(
+			initial_value,
+			on_subscribed_change_fn_pin,
+			SR::default(),
+		)
+	}
+
+	/// A thread-safe value cell that can observe subscription status changes.
+	///
+	/// Modification of the value can cause dependent signals to update.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::{GlobalSignalsRuntime, Propagation, Signal};
+	/// # fn create_heavy_resource_arc() {}
+	/// let cell = Signal::cell_reactive_mut_with_runtime(None, |value, status| {
+	/// 		if status {
+	/// 			value.get_or_insert_with(create_heavy_resource_arc);
+	/// 			Propagation::Propagate
+	/// 		} else {
+	/// 			*value = None;
+	/// 			Propagation::FlushOut
+	/// 		}
+	/// 	}, GlobalSignalsRuntime);
+	/// # }
+	/// ```
+	pub fn cell_reactive_mut_with_runtime<'a>(
+		initial_value: T,
+		on_subscribed_change_fn_pin: impl 'a
+			+ Send
+			+ FnMut(
+				&mut T,
+				<SR::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus,
+			) -> Propagation,
+		runtime: SR,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignalCell<T, SR>, SR>
+	where
+		T: 'a,
+		SR: 'a + Default,
+	{
+		SignalArc {
+			strong: Strong::pin(ReactiveCellMut::with_runtime(
+				initial_value,
+				on_subscribed_change_fn_pin,
+				runtime,
+			)),
+		}
+	}
+
+	/// A thread-safe value cell that can observe subscription status changes and may
+	/// reference itself.
+	///
+	/// Modification of the value can cause dependent signals to update.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::{shadow_ref_to_owned, GlobalSignalsRuntime, Propagation, SignalsRuntimeRef, SignalArcDynCell};
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// # fn start_loading<SR: SignalsRuntimeRef>(name: &str, generation: usize, target: SignalArcDynCell<'_, (usize, Resource<()>), SR>) {}
+	/// enum Resource<T> {
+	/// 	Offline,
+	/// 	Pending,
+	/// 	Ready(T),
+	/// }
+	///
+	/// impl<T> Resource<T> {
+	/// 	fn is_offline(&self) -> bool {
+	/// 		matches!(self, Self::Offline)
+	/// 	}
+	/// }
+	///
+	/// let cell = Signal::cell_cyclic_reactive_mut(|weak| ((0, Resource::Offline), {
+	/// 		shadow_ref_to_owned!(weak);
+	/// 		move |(generation, value), status| {
+	/// 			// The order of operations doesn't matter here, as the signal value is locked exclusively here.
+	/// 			if status && value.is_offline() {
+	/// 				*value = Resource::Pending;
+	/// 				start_loading("resource", *generation, weak.upgrade().unwrap_or_else(|| unreachable!()));
+	/// 				Propagation::Propagate
+	/// 			} else if !status {
+	/// 				*value = Resource::Offline;
+	/// 				*generation += 1;
+	/// 				Propagation::FlushOut
+	/// 			} else {
+	/// 				Propagation::Halt
+	/// 			}
+	/// 		}
+	/// 	}));
+	/// # }
+	/// ```
+	pub fn cell_cyclic_reactive_mut<
+		'a,
+		HandlerFnPin: 'a
+			+ Send
+			+ FnMut(
+				&mut T,
+				<SR::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus,
+			) -> Propagation,
+	>(
+		make_initial_value_and_on_subscribed_change_fn_pin: impl FnOnce(
+			&SignalWeakDynCell<'a, T, SR>,
+		) -> (T, HandlerFnPin),
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignalCell<T, SR>, SR>
+	where
+		T: 'a,
+		SR: 'a + Default,
+	{
+		Self::cell_cyclic_reactive_mut_with_runtime(
+			make_initial_value_and_on_subscribed_change_fn_pin,
+			SR::default(),
+		)
+	}
+
+	/// A thread-safe value cell that can observe subscription status changes and may
+	/// reference itself.
+	///
+	/// Modification of the value can cause dependent signals to update.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::{shadow_ref_to_owned, GlobalSignalsRuntime, Propagation, Signal, SignalsRuntimeRef, SignalArcDynCell};
+	/// # fn start_loading<SR: SignalsRuntimeRef>(name: &str, generation: usize, target: SignalArcDynCell<'_, (usize, Resource<()>), SR>) {}
+	/// enum Resource<T> {
+	/// 	Offline,
 	/// 	Pending,
 	/// 	Ready(T),
 	/// }
@@ -1033,484 +3181,1384 @@ pub(crate) struct Signal_<T: ?Sized + Send, S: ?Sized + Send + Sync, SR: ?Sized
 	managed: UnsafeCell<ManuallyDrop<S>>,
 }
 
-pub(crate) struct Strong<
-	T: ?Sized + Send,
-	S: ?Sized + UnmanagedSignal<T, SR>,
-	SR: ?Sized + SignalsRuntimeRef,
-> {
-	strong: *const Signal<T, S, SR>,
-}
+pub(crate) struct Strong<
+	T: ?Sized + Send,
+	S: ?Sized + UnmanagedSignal<T, SR>,
+	SR: ?Sized + SignalsRuntimeRef,
+> {
+	strong: *const Signal<T, S, SR>,
+}
+
+pub(crate) struct Weak<
+	T: ?Sized + Send,
+	S: ?Sized + UnmanagedSignal<T, SR>,
+	SR: ?Sized + SignalsRuntimeRef,
+> {
+	weak: *const Signal<T, S, SR>,
+}
+
+/// # Safety
+///
+/// [`Send`] and [`Sync`] bound on `S` are implied by [`UnmanagedSignal`].
+unsafe impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef>
+	Send for Signal<T, S, SR>
+{
+}
+
+/// # Safety
+///
+/// [`Send`] and [`Sync`] bound on `S` are implied by [`UnmanagedSignal`].
+unsafe impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef>
+	Send for Signal_<T, S, SR>
+{
+}
+
+/// # Safety
+///
+/// [`Send`] and [`Sync`] bound on `S` are implied by [`UnmanagedSignal`].
+unsafe impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef>
+	Send for Strong<T, S, SR>
+{
+}
+
+/// # Safety
+///
+/// [`Send`] and [`Sync`] bound on `S` are implied by [`UnmanagedSignal`].
+unsafe impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef>
+	Send for Weak<T, S, SR>
+{
+}
+
+/// # Safety
+///
+/// [`Send`] and [`Sync`] bound on `S` are implied by [`UnmanagedSignal`].
+unsafe impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef>
+	Sync for Signal<T, S, SR>
+{
+}
+
+/// # Safety
+///
+/// [`Send`] and [`Sync`] bound on `S` are implied by [`UnmanagedSignal`].
+unsafe impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef>
+	Sync for Signal_<T, S, SR>
+{
+}
+
+/// # Safety
+///
+/// [`Send`] and [`Sync`] bound on `S` are implied by [`UnmanagedSignal`].
+unsafe impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef>
+	Sync for Strong<T, S, SR>
+{
+}
+
+/// # Safety
+///
+/// [`Send`] and [`Sync`] bound on `S` are implied by [`UnmanagedSignal`].
+unsafe impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef>
+	Sync for Weak<T, S, SR>
+{
+}
+
+impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef>
+	Strong<T, S, SR>
+{
+	pub(crate) fn pin(managed: S) -> Self
+	where
+		S: Sized,
+	{
+		Self {
+			strong: Box::into_raw(Box::new(Signal {
+				inner: Signal_ {
+					_phantom: PhantomData,
+					strong: 1.into(),
+					weak: 1.into(),
+					managed: UnsafeCell::new(ManuallyDrop::new(managed)),
+				}
+				.into(),
+			})),
+		}
+	}
+	pub(crate) fn pin_cyclic(constructor: impl FnOnce(&Weak<T, S, SR>) -> S) -> Self
+	where
+		S: Sized,
+	{
+		let weak: *const Signal<T, MaybeUninit<S>, SR> = Box::into_raw(Box::new(Signal {
+			inner: Signal_ {
+				_phantom: PhantomData,
+				strong: 0.into(),
+				weak: 1.into(),
+				managed: UnsafeCell::new(ManuallyDrop::new(MaybeUninit::<S>::uninit())),
+			}
+			.into(),
+		}))
+		.cast_const();
+
+		let weak = unsafe {
+			(&mut *(*weak).inner().managed.get())
+				.write(constructor(&*ManuallyDrop::new(Weak { weak: weak.cast() })));
+			weak.cast::<Signal<T, S, SR>>()
+		};
+
+		(*ManuallyDrop::new(Self { strong: weak })).clone()
+	}
+
+	pub(crate) fn _get(&self) -> &Signal<T, S, SR> {
+		unsafe { &*self.strong }
+	}
+
+	/// Moves out the managed `S` iff `self` is the only [`Strong`] and there are no [`Weak`]s.
+	pub(crate) fn try_unwrap(self) -> Result<S, Self>
+	where
+		S: Sized + Unpin,
+	{
+		let inner = self._get().inner();
+		// Checked first: Without other `Weak`s or `Strong`s, no new handle can be created concurrently.
+		if inner.weak.load(Ordering::Acquire) != 1
+			|| inner
+				.strong
+				.compare_exchange(1, 0, Ordering::Acquire, Ordering::Relaxed)
+				.is_err()
+		{
+			return Err(self);
+		}
+
+		let this = ManuallyDrop::new(self);
+		unsafe {
+			//SAFETY: The strong count is now zero, so `managed` is never dropped in place.
+			//        `S: Unpin`, so moving it out is fine.
+			let managed = ManuallyDrop::take(&mut *this._get().inner().managed.get());
+			drop(Weak { weak: this.strong });
+			Ok(managed)
+		}
+	}
+
+	pub(crate) unsafe fn unsafe_copy(&self) -> Self {
+		Self {
+			strong: self.strong,
+		}
+	}
+
+	pub(crate) fn into_dyn<'a>(self) -> Strong<T, dyn 'a + UnmanagedSignal<T, SR>, SR>
+	where
+		S: 'a + Sized,
+	{
+		let this = ManuallyDrop::new(self);
+		Strong {
+			strong: this.strong,
+		}
+	}
+
+	pub(crate) fn into_dyn_cell<'a>(self) -> Strong<T, dyn 'a + UnmanagedSignalCell<T, SR>, SR>
+	where
+		S: 'a + Sized + UnmanagedSignalCell<T, SR>,
+	{
+		let this = ManuallyDrop::new(self);
+		Strong {
+			strong: this.strong,
+		}
+	}
+}
+
+impl<'a, T: 'a + ?Sized + Send, SR: 'a + ?Sized + SignalsRuntimeRef>
+	Strong<T, dyn 'a + UnmanagedSignalCell<T, SR>, SR>
+{
+	pub(crate) fn into_read_only(self) -> Strong<T, dyn 'a + UnmanagedSignal<T, SR>, SR> {
+		let this = ManuallyDrop::new(self);
+		Strong {
+			strong: this.strong,
+		}
+	}
+}
+
+impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef> Deref
+	for Strong<T, S, SR>
+{
+	type Target = Signal<T, S, SR>;
+
+	fn deref(&self) -> &Self::Target {
+		self._get()
+	}
+}
+
+impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef>
+	Borrow<Signal<T, S, SR>> for Strong<T, S, SR>
+{
+	fn borrow(&self) -> &Signal<T, S, SR> {
+		self._get()
+	}
+}
+
+impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef>
+	Weak<T, S, SR>
+{
+	fn _inner(&self) -> &Signal_<T, S, SR> {
+		unsafe { &*(*self.weak).inner.get().cast_const() }
+	}
+
+	pub(crate) fn upgrade(&self) -> Option<Strong<T, S, SR>> {
+		let mut strong = self._inner().strong.load(Ordering::Relaxed);
+		while strong > 0 {
+			match self._inner().strong.compare_exchange(
+				strong,
+				strong + 1,
+				Ordering::Acquire,
+				Ordering::Relaxed,
+			) {
+				Ok(_) => return Some(Strong { strong: self.weak }),
+				Err(actual) => strong = actual,
+			}
+		}
+		None
+	}
+
+	/// Whether the managed [`Signal`] may still be upgraded to, without upgrading.
+	#[cfg(feature = "std")]
+	pub(crate) fn is_live(&self) -> bool {
+		self._inner().strong.load(Ordering::Relaxed) > 0
+	}
+
+	pub(crate) unsafe fn unsafe_copy(&self) -> Self {
+		Self { weak: self.weak }
+	}
+
+	pub(crate) fn into_dyn<'a>(self) -> Weak<T, dyn 'a + UnmanagedSignal<T, SR>, SR>
+	where
+		S: 'a + Sized,
+	{
+		let this = ManuallyDrop::new(self);
+		Weak { weak: this.weak }
+	}
 
-pub(crate) struct Weak<
-	T: ?Sized + Send,
-	S: ?Sized + UnmanagedSignal<T, SR>,
-	SR: ?Sized + SignalsRuntimeRef,
-> {
-	weak: *const Signal<T, S, SR>,
+	pub(crate) fn into_dyn_cell<'a>(self) -> Weak<T, dyn 'a + UnmanagedSignalCell<T, SR>, SR>
+	where
+		S: 'a + Sized + UnmanagedSignalCell<T, SR>,
+	{
+		let this = ManuallyDrop::new(self);
+		Weak { weak: this.weak }
+	}
 }
 
-/// # Safety
-///
-/// [`Send`] and [`Sync`] bound on `S` are implied by [`UnmanagedSignal`].
-unsafe impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef>
-	Send for Signal<T, S, SR>
+impl<'a, T: ?Sized + Send, SR: ?Sized + SignalsRuntimeRef>
+	Weak<T, dyn 'a + UnmanagedSignalCell<T, SR>, SR>
 {
+	pub(crate) fn into_read_only(self) -> Weak<T, dyn 'a + UnmanagedSignal<T, SR>, SR> {
+		let this = ManuallyDrop::new(self);
+		Weak { weak: this.weak }
+	}
 }
 
-/// # Safety
-///
-/// [`Send`] and [`Sync`] bound on `S` are implied by [`UnmanagedSignal`].
-unsafe impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef>
-	Send for Signal_<T, S, SR>
+impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef> Drop
+	for Strong<T, S, SR>
 {
+	fn drop(&mut self) {
+		if self._get().inner().strong.fetch_sub(1, Ordering::Release) == 1 {
+			unsafe { ManuallyDrop::drop(&mut *self._get().inner().managed.get()) }
+			drop(Weak { weak: self.strong })
+		}
+	}
 }
 
-/// # Safety
-///
-/// [`Send`] and [`Sync`] bound on `S` are implied by [`UnmanagedSignal`].
-unsafe impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef>
-	Send for Strong<T, S, SR>
+impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef> Drop
+	for Weak<T, S, SR>
 {
+	fn drop(&mut self) {
+		if self._inner().weak.fetch_sub(1, Ordering::Release) == 1 {
+			unsafe {
+				drop(Box::from_raw(self.weak.cast_mut()));
+			}
+		}
+	}
 }
 
-/// # Safety
-///
-/// [`Send`] and [`Sync`] bound on `S` are implied by [`UnmanagedSignal`].
-unsafe impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef>
-	Send for Weak<T, S, SR>
+impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef> ToOwned
+	for Signal<T, S, SR>
 {
+	type Owned = SignalArc<T, S, SR>;
+
+	fn to_owned(&self) -> Self::Owned {
+		(*ManuallyDrop::new(SignalArc {
+			strong: Strong { strong: self },
+		}))
+		.clone()
+	}
 }
 
-/// # Safety
-///
-/// [`Send`] and [`Sync`] bound on `S` are implied by [`UnmanagedSignal`].
-unsafe impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef>
-	Sync for Signal<T, S, SR>
-{
+/// Like [`Arc`]'s reference count overflow handling.
+#[cold]
+fn overflow(message: &str) -> ! {
+	#[cfg(feature = "std")]
+	{
+		eprintln!("{message}");
+		abort()
+	}
+	// There's no stable way to abort with only `core`.
+	#[cfg(not(feature = "std"))]
+	panic!("{message}")
 }
 
-/// # Safety
-///
-/// [`Send`] and [`Sync`] bound on `S` are implied by [`UnmanagedSignal`].
-unsafe impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef>
-	Sync for Signal_<T, S, SR>
+impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef> Clone
+	for Strong<T, S, SR>
 {
+	fn clone(&self) -> Self {
+		if self._get().inner().strong.fetch_add(1, Ordering::Relaxed) > usize::MAX / 2 {
+			overflow("SignalArc overflow.")
+		}
+		Self {
+			strong: self.strong,
+		}
+	}
 }
 
-/// # Safety
-///
-/// [`Send`] and [`Sync`] bound on `S` are implied by [`UnmanagedSignal`].
-unsafe impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef>
-	Sync for Strong<T, S, SR>
+impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef> Clone
+	for Weak<T, S, SR>
 {
+	fn clone(&self) -> Self {
+		if self._inner().weak.fetch_add(1, Ordering::Relaxed) > usize::MAX / 2 {
+			overflow("SignalWeak overflow.")
+		}
+		Self { weak: self.weak }
+	}
 }
 
-/// # Safety
-///
-/// [`Send`] and [`Sync`] bound on `S` are implied by [`UnmanagedSignal`].
-unsafe impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef>
-	Sync for Weak<T, S, SR>
-{
+/// **Most application code should consume this.** Interface for movable signal handles that have an accessible value.
+impl<T: ?Sized + Send, S: ?Sized + Send + Sync, SR: ?Sized + SignalsRuntimeRef> Signal<T, S, SR> {
+	pub(crate) fn _managed(&self) -> Pin<&S> {
+		unsafe { Pin::new_unchecked(&*self.inner().managed.get()) }
+	}
 }
 
+/// Adapters.
 impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef>
-	Strong<T, S, SR>
+	Signal<T, S, SR>
 {
-	pub(crate) fn pin(managed: S) -> Self
+	/// Creates a new [`Subscription`] for this [`Signal`].
+	///
+	/// Where you consume an owned [`SignalArc`], prefer [`SignalArc::into_subscription`] to avoid some memory barriers.
+	pub fn to_subscription(&self) -> Subscription<T, S, SR> {
+		self.to_owned().into_subscription()
+	}
+
+	/// Creates a new [`Subscription`] for this [`Signal`] that's owned by `scope`,
+	/// so that it's dropped along with all others there.
+	///
+	/// See [`Scope`] for more information.
+	///
+	/// Since 0.2.1.
+	pub fn subscribe_scoped<'s, 'a>(&self, scope: &'s Scope<'a, SR>) -> &'s Subscription<T, S, SR>
 	where
-		S: Sized,
+		T: 'a,
+		S: 'a + Sized,
+		SR: Sized,
 	{
-		Self {
-			strong: Box::into_raw(Box::new(Signal {
-				inner: Signal_ {
-					_phantom: PhantomData,
-					strong: 1.into(),
-					weak: 1.into(),
-					managed: UnsafeCell::new(ManuallyDrop::new(managed)),
+		scope.push(self.to_subscription())
+	}
+
+	/// Creates a new [`Subscription`] for this [`Signal`] right away, then,
+	/// when awaited, resolves to it together with its first observed value.
+	///
+	/// As the subscribed signal always has a current value, the [`Future`] is ready immediately.  
+	/// See [`subscribe_first_ready`](`Signal::subscribe_first_ready`) to wait for a value that's ready.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::GlobalSignalsRuntime;
+	/// use std::{future::Future, pin::pin, task::{Context, Poll, Waker}};
+	///
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// let cell = Signal::cell(1);
+	/// let mut first = pin!(cell.subscribe_first());
+	/// let mut cx = Context::from_waker(Waker::noop());
+	///
+	/// let Poll::Ready((sub, 1)) = first.as_mut().poll(&mut cx) else { panic!() };
+	/// assert_eq!(sub.get(), 1);
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	pub fn subscribe_first<'f>(
+		&self,
+	) -> impl 'f + Send + Future<Output = (Subscription<T, S, SR>, T)>
+	where
+		T: 'f + Sized + Clone,
+		S: 'f,
+		SR: 'f,
+	{
+		self.subscribe_first_ready(|_| true)
+	}
+
+	/// Creates a new [`Subscription`] for this [`Signal`] right away, then,
+	/// when awaited, resolves to it together with its first value for which `is_ready` returns `true`.
+	///
+	/// Values are observed through an effect that's active until the [`Future`] resolves or is dropped,
+	/// so values that loaders set later (for example from another thread or task) are picked up.  
+	/// Iff the current value is ready already, the [`Future`] is ready immediately.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::GlobalSignalsRuntime;
+	/// use std::{future::Future, pin::pin, task::{Context, Poll, Waker}};
+	///
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// let loaded = Signal::cell(None);
+	/// let mut first = pin!(loaded.subscribe_first_ready(Option::is_some));
+	/// let mut cx = Context::from_waker(Waker::noop());
+	/// assert!(first.as_mut().poll(&mut cx).is_pending());
+	///
+	/// // Elsewhere, once loading completes:
+	/// loaded.set(Some("loaded"));
+	///
+	/// let Poll::Ready((_sub, first)) = first.as_mut().poll(&mut cx) else { panic!() };
+	/// assert_eq!(first, Some("loaded"));
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	pub fn subscribe_first_ready<'f>(
+		&self,
+		mut is_ready: impl 'f + Send + FnMut(&T) -> bool,
+	) -> impl 'f + Send + Future<Output = (Subscription<T, S, SR>, T)>
+	where
+		T: 'f + Sized + Clone,
+		S: 'f,
+		SR: 'f,
+	{
+		let sub = self.to_subscription();
+		async move {
+			let (notify_ready, ready) = oneshot::channel();
+			let mut notify_ready = Some(notify_ready);
+			let first = {
+				signals_helper! {
+					let effect = effect_with_runtime!({
+						let sub = &sub;
+						move || {
+							let value = sub.read_exclusive_dyn();
+							if notify_ready.is_some() && is_ready(&value) {
+								let notify_ready = notify_ready.take().expect("unreachable");
+								// Fails only if the `Future` was dropped, in which case this effect is too.
+								notify_ready.send(T::clone(&value)).ok();
+							}
+						}
+					}, drop, sub.clone_runtime_ref());
 				}
-				.into(),
-			})),
+				ready
+					.await
+					.expect("The effect is dropped only after this resolves.")
+			};
+			(sub, first)
 		}
 	}
-	pub(crate) fn pin_cyclic(constructor: impl FnOnce(&Weak<T, S, SR>) -> S) -> Self
+
+	/// Creates a new [`SignalWeak`] for this [`Signal`].
+	pub fn downgrade(&self) -> SignalWeak<T, S, SR> {
+		(*ManuallyDrop::new(SignalWeak {
+			weak: Weak { weak: self },
+		}))
+		.clone()
+	}
+
+	/// Reborrows without the [`UnmanagedSignal`] `S` in the type signature.
+	pub fn as_dyn<'a>(&self) -> &SignalDyn<'a, T, SR>
 	where
-		S: Sized,
+		S: 'a + Sized,
 	{
-		let weak: *const Signal<T, MaybeUninit<S>, SR> = Box::into_raw(Box::new(Signal {
-			inner: Signal_ {
-				_phantom: PhantomData,
-				strong: 0.into(),
-				weak: 1.into(),
-				managed: UnsafeCell::new(ManuallyDrop::new(MaybeUninit::<S>::uninit())),
-			}
-			.into(),
-		}))
-		.cast_const();
+		self
+	}
 
-		let weak = unsafe {
-			(&mut *(*weak).inner().managed.get())
-				.write(constructor(&*ManuallyDrop::new(Weak { weak: weak.cast() })));
-			weak.cast::<Signal<T, S, SR>>()
-		};
+	/// Reborrows without the [`UnmanagedSignalCell`] `S` in the type signature.
+	pub fn as_dyn_cell<'a>(&self) -> &SignalDynCell<'a, T, SR>
+	where
+		S: 'a + Sized + UnmanagedSignalCell<T, SR>,
+	{
+		self
+	}
 
-		(*ManuallyDrop::new(Self { strong: weak })).clone()
+	/// Creates a new [`SignalArcDyn`] for this [`Signal`], without the [`UnmanagedSignal`] `S` in the type signature.
+	pub fn to_dyn<'a>(&self) -> SignalArcDyn<'a, T, SR>
+	where
+		S: 'a + Sized,
+	{
+		self.to_owned().into_dyn()
+	}
+
+	/// Creates a new [`SignalArcDynCell`] for this [`Signal`], without the [`UnmanagedSignalCell`] `S` in the type signature.
+	pub fn to_dyn_cell<'a>(&self) -> SignalArcDynCell<'a, T, SR>
+	where
+		S: 'a + Sized + UnmanagedSignalCell<T, SR>,
+	{
+		self.to_owned().into_dyn_cell()
+	}
+}
+
+/// Comparisons.
+impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef>
+	Signal<T, S, SR>
+{
+	/// A cached boolean signal that's `true` iff `self`'s value is equal to `other`'s.
+	///
+	/// Both signals are kept alive and tracked as dependencies.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::GlobalSignalsRuntime;
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// let a = Signal::cell(1);
+	/// let b = Signal::cell(2);
+	/// let equal = a.eq_signal(&b);
+	/// assert!(!equal.get());
+	///
+	/// b.set(1);
+	/// assert!(equal.get());
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	pub fn eq_signal<'a, S2: 'a + ?Sized + UnmanagedSignal<T, SR>>(
+		&self,
+		other: &Signal<T, S2, SR>,
+	) -> SignalArc<bool, impl 'a + Sized + UnmanagedSignal<bool, SR>, SR>
+	where
+		T: 'a + Sync + PartialEq,
+		S: 'a,
+		SR: 'a + Sized,
+	{
+		compare_signals(self, other, T::eq)
 	}
 
-	pub(crate) fn _get(&self) -> &Signal<T, S, SR> {
-		unsafe { &*self.strong }
+	/// A cached boolean signal that's `true` iff `self`'s value is not equal to `other`'s.
+	///
+	/// Both signals are kept alive and tracked as dependencies.
+	///
+	/// Since 0.2.1.
+	pub fn ne_signal<'a, S2: 'a + ?Sized + UnmanagedSignal<T, SR>>(
+		&self,
+		other: &Signal<T, S2, SR>,
+	) -> SignalArc<bool, impl 'a + Sized + UnmanagedSignal<bool, SR>, SR>
+	where
+		T: 'a + Sync + PartialEq,
+		S: 'a,
+		SR: 'a + Sized,
+	{
+		compare_signals(self, other, T::ne)
 	}
 
-	pub(crate) unsafe fn unsafe_copy(&self) -> Self {
-		Self {
-			strong: self.strong,
-		}
+	/// A cached boolean signal that's `true` iff `self`'s value is less than `other`'s.
+	///
+	/// Both signals are kept alive and tracked as dependencies.
+	///
+	/// Since 0.2.1.
+	pub fn lt_signal<'a, S2: 'a + ?Sized + UnmanagedSignal<T, SR>>(
+		&self,
+		other: &Signal<T, S2, SR>,
+	) -> SignalArc<bool, impl 'a + Sized + UnmanagedSignal<bool, SR>, SR>
+	where
+		T: 'a + Sync + PartialOrd,
+		S: 'a,
+		SR: 'a + Sized,
+	{
+		compare_signals(self, other, T::lt)
 	}
 
-	pub(crate) fn into_dyn<'a>(self) -> Strong<T, dyn 'a + UnmanagedSignal<T, SR>, SR>
+	/// A cached boolean signal that's `true` iff `self`'s value is less than or equal to `other`'s.
+	///
+	/// Both signals are kept alive and tracked as dependencies.
+	///
+	/// Since 0.2.1.
+	pub fn le_signal<'a, S2: 'a + ?Sized + UnmanagedSignal<T, SR>>(
+		&self,
+		other: &Signal<T, S2, SR>,
+	) -> SignalArc<bool, impl 'a + Sized + UnmanagedSignal<bool, SR>, SR>
 	where
-		S: 'a + Sized,
+		T: 'a + Sync + PartialOrd,
+		S: 'a,
+		SR: 'a + Sized,
 	{
-		let this = ManuallyDrop::new(self);
-		Strong {
-			strong: this.strong,
-		}
+		compare_signals(self, other, T::le)
 	}
 
-	pub(crate) fn into_dyn_cell<'a>(self) -> Strong<T, dyn 'a + UnmanagedSignalCell<T, SR>, SR>
+	/// A cached boolean signal that's `true` iff `self`'s value is greater than `other`'s.
+	///
+	/// Both signals are kept alive and tracked as dependencies.
+	///
+	/// Since 0.2.1.
+	pub fn gt_signal<'a, S2: 'a + ?Sized + UnmanagedSignal<T, SR>>(
+		&self,
+		other: &Signal<T, S2, SR>,
+	) -> SignalArc<bool, impl 'a + Sized + UnmanagedSignal<bool, SR>, SR>
 	where
-		S: 'a + Sized + UnmanagedSignalCell<T, SR>,
+		T: 'a + Sync + PartialOrd,
+		S: 'a,
+		SR: 'a + Sized,
 	{
-		let this = ManuallyDrop::new(self);
-		Strong {
-			strong: this.strong,
-		}
+		compare_signals(self, other, T::gt)
 	}
-}
 
-impl<'a, T: 'a + ?Sized + Send, SR: 'a + ?Sized + SignalsRuntimeRef>
-	Strong<T, dyn 'a + UnmanagedSignalCell<T, SR>, SR>
-{
-	pub(crate) fn into_read_only(self) -> Strong<T, dyn 'a + UnmanagedSignal<T, SR>, SR> {
-		let this = ManuallyDrop::new(self);
-		Strong {
-			strong: this.strong,
-		}
+	/// A cached boolean signal that's `true` iff `self`'s value is greater than or equal to `other`'s.
+	///
+	/// Both signals are kept alive and tracked as dependencies.
+	///
+	/// Since 0.2.1.
+	pub fn ge_signal<'a, S2: 'a + ?Sized + UnmanagedSignal<T, SR>>(
+		&self,
+		other: &Signal<T, S2, SR>,
+	) -> SignalArc<bool, impl 'a + Sized + UnmanagedSignal<bool, SR>, SR>
+	where
+		T: 'a + Sync + PartialOrd,
+		S: 'a,
+		SR: 'a + Sized,
+	{
+		compare_signals(self, other, T::ge)
 	}
 }
 
-impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef> Deref
-	for Strong<T, S, SR>
-{
-	type Target = Signal<T, S, SR>;
-
-	fn deref(&self) -> &Self::Target {
-		self._get()
-	}
+fn compare_signals<
+	'a,
+	T: 'a + ?Sized + Send + Sync,
+	S1: 'a + ?Sized + UnmanagedSignal<T, SR>,
+	S2: 'a + ?Sized + UnmanagedSignal<T, SR>,
+	SR: 'a + SignalsRuntimeRef,
+>(
+	a: &Signal<T, S1, SR>,
+	b: &Signal<T, S2, SR>,
+	compare: fn(&T, &T) -> bool,
+) -> SignalArc<bool, impl 'a + Sized + UnmanagedSignal<bool, SR>, SR> {
+	let (a, b) = (a.to_owned(), b.to_owned());
+	let runtime = a.clone_runtime_ref();
+	Signal::computed_with_runtime(move || compare(&a.read_dyn(), &b.read_dyn()), runtime)
 }
 
-impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef>
-	Borrow<Signal<T, S, SR>> for Strong<T, S, SR>
+#[cfg(feature = "std")]
+impl<
+		T: ?Sized + Send,
+		S: ?Sized + UnmanagedSignalResilient<T, SR>,
+		SR: ?Sized + SignalsRuntimeRef,
+	> Signal<T, S, SR>
 {
-	fn borrow(&self) -> &Signal<T, S, SR> {
-		self._get()
+	/// The panic state of this signal's most recent computation.
+	///
+	/// This doesn't record a dependency or refresh the signal.
+	///
+	/// # Features
+	///
+	/// **The feature `"std"` is required to enable this method.**
+	///
+	/// Since 0.2.1.
+	#[must_use]
+	pub fn last_error(&self) -> &LastPanic {
+		self._managed().last_error()
 	}
 }
 
-impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef>
-	Weak<T, S, SR>
-{
-	fn _inner(&self) -> &Signal_<T, S, SR> {
-		unsafe { &*(*self.weak).inner.get().cast_const() }
-	}
-
-	pub(crate) fn upgrade(&self) -> Option<Strong<T, S, SR>> {
-		let mut strong = self._inner().strong.load(Ordering::Relaxed);
-		while strong > 0 {
-			match self._inner().strong.compare_exchange(
-				strong,
-				strong + 1,
-				Ordering::Acquire,
-				Ordering::Relaxed,
-			) {
-				Ok(_) => return Some(Strong { strong: self.weak }),
-				Err(actual) => strong = actual,
-			}
-		}
-		None
-	}
-
-	pub(crate) unsafe fn unsafe_copy(&self) -> Self {
-		Self { weak: self.weak }
-	}
-
-	pub(crate) fn into_dyn<'a>(self) -> Weak<T, dyn 'a + UnmanagedSignal<T, SR>, SR>
+impl<T: ?Sized + Send, S: UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef> Signal<T, S, SR> {
+	/// Reborrows with the [`UnmanagedSignalCell`] `S` replaced by an opaque [`UnmanagedSignal`] in the type signature.
+	pub fn as_read_only<'a>(&self) -> &Signal<T, impl 'a + UnmanagedSignal<T, SR>, SR>
 	where
-		S: 'a + Sized,
+		S: 'a + UnmanagedSignalCell<T, SR>,
 	{
-		let this = ManuallyDrop::new(self);
-		Weak { weak: this.weak }
+		self
 	}
 
-	pub(crate) fn into_dyn_cell<'a>(self) -> Weak<T, dyn 'a + UnmanagedSignalCell<T, SR>, SR>
+	/// Creates a new [`SignalArc`] for this [`Signal`], with the [`UnmanagedSignalCell`] `S` replaced by an opaque [`UnmanagedSignal`] in the type signature.
+	pub fn to_read_only<'a>(&self) -> SignalArc<T, impl 'a + UnmanagedSignal<T, SR>, SR>
 	where
 		S: 'a + Sized + UnmanagedSignalCell<T, SR>,
 	{
-		let this = ManuallyDrop::new(self);
-		Weak { weak: this.weak }
+		self.to_owned()
 	}
 }
 
-impl<'a, T: ?Sized + Send, SR: ?Sized + SignalsRuntimeRef>
-	Weak<T, dyn 'a + UnmanagedSignalCell<T, SR>, SR>
-{
-	pub(crate) fn into_read_only(self) -> Weak<T, dyn 'a + UnmanagedSignal<T, SR>, SR> {
-		let this = ManuallyDrop::new(self);
-		Weak { weak: this.weak }
+impl<'a, T: 'a + ?Sized + Send, SR: 'a + ?Sized + SignalsRuntimeRef> SignalDynCell<'a, T, SR> {
+	/// Reborrows while upcasting the reference, discarding mutation access.
+	///
+	/// Since 0.1.2.
+	pub fn as_read_only(&self) -> &SignalDyn<'a, T, SR> {
+		self
 	}
-}
 
-impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef> Drop
-	for Strong<T, S, SR>
-{
-	fn drop(&mut self) {
-		if self._get().inner().strong.fetch_sub(1, Ordering::Release) == 1 {
-			unsafe { ManuallyDrop::drop(&mut *self._get().inner().managed.get()) }
-			drop(Weak { weak: self.strong })
-		}
+	/// Creates a new [`SignalArcDyn`] for this [`SignalDynCell`], discarding mutation access.
+	///
+	/// Since 0.1.2.
+	pub fn to_read_only(&self) -> SignalArcDyn<'a, T, SR> {
+		self.as_read_only().to_owned()
 	}
 }
 
-impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef> Drop
-	for Weak<T, S, SR>
+/// Value accessors.
+impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef>
+	Signal<T, S, SR>
 {
-	fn drop(&mut self) {
-		if self._inner().weak.fetch_sub(1, Ordering::Release) == 1 {
-			unsafe {
-				drop(Box::from_raw(self.weak.cast_mut()));
-			}
-		}
+	/// Records `self` as dependency without accessing the value.
+	pub fn touch(&self) {
+		self._managed().touch()
 	}
-}
 
-impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef> ToOwned
-	for Signal<T, S, SR>
-{
-	type Owned = SignalArc<T, S, SR>;
+	/// Records `self` as dependency and retrieves a copy of the value.
+	///
+	/// Prefer [`Signal::touch`] where possible.
+	pub fn get(&self) -> T
+	where
+		T: Sync + Copy,
+	{
+		self._managed().get()
+	}
 
-	fn to_owned(&self) -> Self::Owned {
-		(*ManuallyDrop::new(SignalArc {
-			strong: Strong { strong: self },
-		}))
-		.clone()
+	/// Records `self` as dependency and retrieves a copy of the value, iff it's initialised.
+	///
+	/// Only [uninitialised reactive cells](`Signal::cell_reactive_uninitialized`) return [`None`],
+	/// until their value is first updated. Other signals, including all computed ones, always return [`Some`].
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::{GlobalSignalsRuntime, Propagation};
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// let cell = Signal::cell_reactive_uninitialized(0, |_, _| Propagation::Halt);
+	/// assert_eq!(cell.get_initialized(), None);
+	/// assert_eq!(cell.get(), 0);
+	///
+	/// cell.set(1);
+	/// assert_eq!(cell.get_initialized(), Some(1));
+	///
+	/// let computed = Signal::computed(|| cell.get() + 1);
+	/// assert_eq!(computed.get_initialized(), Some(2));
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	pub fn get_initialized(&self) -> Option<T>
+	where
+		T: Sync + Copy,
+	{
+		self._managed().get_initialized()
 	}
-}
 
-impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef> Clone
-	for Strong<T, S, SR>
-{
-	fn clone(&self) -> Self {
-		if self._get().inner().strong.fetch_add(1, Ordering::Relaxed) > usize::MAX / 2 {
-			eprintln!("SignalArc overflow.");
-			abort()
-		}
-		Self {
-			strong: self.strong,
+	/// Like [`get`](`Signal::get`), but in debug builds, first asserts that `self` isn't stale.
+	///
+	/// This helps catch reads that happen before a pending refresh in tests.  
+	/// In release builds (without `debug_assertions`), this is the same as [`get`](`Signal::get`).
+	///
+	/// Only signals with dependencies can be stale.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::GlobalSignalsRuntime;
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// let cell = Signal::cell(1);
+	/// let doubled = Signal::computed(|| cell.get() * 2);
+	///
+	/// assert_eq!(doubled.get_assert_fresh(), 2);
+	/// # }
+	/// ```
+	///
+	/// # Panics
+	///
+	/// In debug builds, iff the signals runtime currently considers `self` stale.  
+	/// The panic message includes its [`Symbol`](`SignalsRuntimeRef::Symbol`).
+	///
+	/// Wraps [`SignalsRuntimeRef::is_stale`].
+	///
+	/// Since 0.2.1.
+	pub fn get_assert_fresh(&self) -> T
+	where
+		T: Sync + Copy,
+		SR::Symbol: Debug,
+	{
+		#[cfg(debug_assertions)]
+		if let Some(symbol) = self._managed().stale_symbol() {
+			panic!("Read signal {symbol:?} while it was stale.");
 		}
+		self.get()
 	}
-}
 
-impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef> Clone
-	for Weak<T, S, SR>
-{
-	fn clone(&self) -> Self {
-		if self._inner().weak.fetch_add(1, Ordering::Relaxed) > usize::MAX / 2 {
-			eprintln!("SignalWeak overflow.");
-			abort()
-		}
-		Self { weak: self.weak }
+	/// Records `self` as dependency and retrieves a clone of the value.
+	///
+	/// Prefer [`Signal::get`] where available.
+	pub fn get_clone(&self) -> T
+	where
+		T: Sync + Clone,
+	{
+		self._managed().get_clone()
+	}
+
+	/// Records `self` as dependency and retrieves a copy of the value.
+	///
+	/// Prefer [`Signal::get`] where available.
+	pub fn get_exclusive(&self) -> T
+	where
+		T: Copy,
+	{
+		self._managed().get_clone_exclusive()
+	}
+
+	/// Records `self` as dependency and retrieves a clone of the value.
+	///
+	/// Prefer [`Signal::get_clone`] where available.
+	pub fn get_clone_exclusive(&self) -> T
+	where
+		T: Clone,
+	{
+		self._managed().get_clone_exclusive()
 	}
-}
 
-/// **Most application code should consume this.** Interface for movable signal handles that have an accessible value.
-impl<T: ?Sized + Send, S: ?Sized + Send + Sync, SR: ?Sized + SignalsRuntimeRef> Signal<T, S, SR> {
-	pub(crate) fn _managed(&self) -> Pin<&S> {
-		unsafe { Pin::new_unchecked(&*self.inner().managed.get()) }
+	/// Records `self` as dependency and allows borrowing the value.
+	pub fn read<'r>(&'r self) -> S::Read<'r>
+	where
+		S: Sized,
+		T: 'r + Sync,
+	{
+		self._managed().read()
 	}
-}
 
-/// Adapters.
-impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef>
-	Signal<T, S, SR>
-{
-	/// Creates a new [`Subscription`] for this [`Signal`].
+	/// Records `self` as dependency and allows borrowing the value.
 	///
-	/// Where you consume an owned [`SignalArc`], prefer [`SignalArc::into_subscription`] to avoid some memory barriers.
-	pub fn to_subscription(&self) -> Subscription<T, S, SR> {
-		self.to_owned().into_subscription()
-	}
-
-	/// Creates a new [`SignalWeak`] for this [`Signal`].
-	pub fn downgrade(&self) -> SignalWeak<T, S, SR> {
-		(*ManuallyDrop::new(SignalWeak {
-			weak: Weak { weak: self },
-		}))
-		.clone()
+	/// Additionally returns whether this read (re)computed the value instead of serving it from the cache,
+	/// which is useful for profiling cache hit rates. For uncached signals, this is always `true`.  
+	/// The flag is advisory under concurrent access, as another thread may refresh `self` in between.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::GlobalSignalsRuntime;
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// let cell = Signal::cell(1);
+	/// let doubled = Signal::computed(|| cell.get() * 2);
+	///
+	/// let (value, fresh) = doubled.read_with_freshness();
+	/// assert_eq!((*value, fresh), (2, true));
+	/// drop(value);
+	///
+	/// let (value, fresh) = doubled.read_with_freshness();
+	/// assert_eq!((*value, fresh), (2, false));
+	/// # }
+	/// ```
+	///
+	/// Wraps [`UnmanagedSignal::read_with_freshness`].
+	///
+	/// Since 0.2.1.
+	pub fn read_with_freshness<'r>(&'r self) -> (S::Read<'r>, bool)
+	where
+		S: Sized,
+		T: 'r + Sync,
+	{
+		self._managed().read_with_freshness()
 	}
 
-	/// Reborrows without the [`UnmanagedSignal`] `S` in the type signature.
-	pub fn as_dyn<'a>(&self) -> &SignalDyn<'a, T, SR>
+	/// Records `self` as dependency and allows borrowing the value,
+	/// with the option to convert the guard [`into_owned`](`CowGuard::into_owned`) later.
+	///
+	/// This lets a call site defer the choice between [`Signal::read`] and [`Signal::get_clone`].
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::GlobalSignalsRuntime;
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// let cell = Signal::cell(String::from("text"));
+	///
+	/// let guard = cell.read_cow();
+	/// let owned: String = if guard.is_empty() { String::new() } else { guard.into_owned() };
+	/// assert_eq!(owned, "text");
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	pub fn read_cow<'r>(&'r self) -> CowGuard<T, S::Read<'r>>
 	where
-		S: 'a + Sized,
+		S: Sized,
+		T: 'r + Sync,
 	{
-		self
+		CowGuard::new(self.read())
 	}
 
-	/// Reborrows without the [`UnmanagedSignalCell`] `S` in the type signature.
-	pub fn as_dyn_cell<'a>(&self) -> &SignalDynCell<'a, T, SR>
+	/// Records `self` as dependency and allows borrowing the value, iff that's possible without blocking.
+	///
+	/// Returns [`None`] iff the value isn't available as-is, that is: if it wasn't computed yet, is stale,
+	/// is currently being updated or is locked, for example by an exclusive guard.  
+	/// In that case, nothing is (re)computed and `self` isn't recorded as dependency.
+	/// Uncached signals always return [`None`], as reading them means evaluating them.
+	///
+	/// Since 0.2.1.
+	pub fn try_read<'r>(&'r self) -> Option<S::Read<'r>>
 	where
-		S: 'a + Sized + UnmanagedSignalCell<T, SR>,
+		S: Sized,
+		T: 'r + Sync,
 	{
-		self
+		self._managed().try_read()
 	}
 
-	/// Creates a new [`SignalArcDyn`] for this [`Signal`], without the [`UnmanagedSignal`] `S` in the type signature.
-	pub fn to_dyn<'a>(&self) -> SignalArcDyn<'a, T, SR>
+	/// Records `self` as dependency and allows borrowing the value.
+	///
+	/// Prefer [`Signal::read`] where available.
+	pub fn read_exclusive<'r>(&'r self) -> S::ReadExclusive<'r>
 	where
-		S: 'a + Sized,
+		S: Sized,
+		T: 'r,
 	{
-		self.to_owned().into_dyn()
+		self._managed().read_exclusive()
 	}
 
-	/// Creates a new [`SignalArcDynCell`] for this [`Signal`], without the [`UnmanagedSignalCell`] `S` in the type signature.
-	pub fn to_dyn_cell<'a>(&self) -> SignalArcDynCell<'a, T, SR>
+	/// The same as [`Signal::read`], but dyn-compatible.
+	///
+	/// Prefer [`Signal::read`] where available.
+	pub fn read_dyn<'r>(&'r self) -> Box<dyn 'r + Guard<T>>
 	where
-		S: 'a + Sized + UnmanagedSignalCell<T, SR>,
+		T: 'r + Sync,
 	{
-		self.to_owned().into_dyn_cell()
+		self._managed().read_dyn()
 	}
-}
 
-impl<T: ?Sized + Send, S: UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef> Signal<T, S, SR> {
-	/// Reborrows with the [`UnmanagedSignalCell`] `S` replaced by an opaque [`UnmanagedSignal`] in the type signature.
-	pub fn as_read_only<'a>(&self) -> &Signal<T, impl 'a + UnmanagedSignal<T, SR>, SR>
+	/// The same as [`Signal::read_exclusive`], but dyn-compatible.
+	///
+	/// Prefer [`Signal::read_dyn`] where available.
+	pub fn read_exclusive_dyn<'r>(&'r self) -> Box<dyn 'r + Guard<T>>
 	where
-		S: 'a + UnmanagedSignalCell<T, SR>,
+		T: 'r,
 	{
-		self
+		self._managed().read_exclusive_dyn()
 	}
 
-	/// Creates a new [`SignalArc`] for this [`Signal`], with the [`UnmanagedSignalCell`] `S` replaced by an opaque [`UnmanagedSignal`] in the type signature.
-	pub fn to_read_only<'a>(&self) -> SignalArc<T, impl 'a + UnmanagedSignal<T, SR>, SR>
+	/// Records `self` as dependency and calls `f` with a reference to the value, returning its result.
+	///
+	/// The value is borrowed exclusively (via [`Signal::read_exclusive`]) for the duration of the call only,
+	/// so this doesn't allocate.
+	///
+	/// # Panics
+	///
+	/// Panics raised by `f` are resumed *after* the borrow is released,
+	/// so they don't poison the value for later readers.
+	///
+	/// Since 0.2.1.
+	pub fn with_ref<R>(&self, f: impl FnOnce(&T) -> R) -> R
 	where
-		S: 'a + Sized + UnmanagedSignalCell<T, SR>,
+		S: Sized,
 	{
-		self.to_owned()
+		let guard = self.read_exclusive();
+		#[cfg(feature = "std")]
+		{
+			let result = panic::catch_unwind(AssertUnwindSafe(|| f(&guard)));
+			drop(guard);
+			result.unwrap_or_else(|payload| panic::resume_unwind(payload))
+		}
+		// Spinlocks aren't poisoned.
+		#[cfg(not(feature = "std"))]
+		f(&guard)
 	}
-}
 
-impl<'a, T: 'a + ?Sized + Send, SR: 'a + ?Sized + SignalsRuntimeRef> SignalDynCell<'a, T, SR> {
-	/// Reborrows while upcasting the reference, discarding mutation access.
+	/// Formats the current value with [`Display`](`fmt::Display`), so that `{}` works directly in log statements.
 	///
-	/// Since 0.1.2.
-	pub fn as_read_only(&self) -> &SignalDyn<'a, T, SR> {
-		self
+	/// The value is read each time the result is formatted, without recording a dependency.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::GlobalSignalsRuntime;
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// let name = Signal::cell("flourish");
+	/// assert_eq!(format!("Hello, {}!", name.display()), "Hello, flourish!");
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	pub fn display(&self) -> impl '_ + fmt::Display
+	where
+		T: fmt::Display,
+		SR: Sized,
+	{
+		DisplayValue(self)
 	}
 
-	/// Creates a new [`SignalArcDyn`] for this [`SignalDynCell`], discarding mutation access.
+	/// Formats the current value with [`Debug`], unlike [`Signal`]'s own [`Debug`] implementation.
 	///
-	/// Since 0.1.2.
-	pub fn to_read_only(&self) -> SignalArcDyn<'a, T, SR> {
-		self.as_read_only().to_owned()
+	/// The value is read each time the result is formatted, without recording a dependency.
+	///
+	/// Since 0.2.1.
+	pub fn debug_value(&self) -> impl '_ + Debug
+	where
+		T: Debug,
+		SR: Sized,
+	{
+		DebugValue(self)
 	}
-}
 
-/// Value accessors.
-impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef>
-	Signal<T, S, SR>
-{
-	/// Records `self` as dependency without accessing the value.
-	pub fn touch(&self) {
-		self._managed().touch()
+	/// A blocking [`Iterator`] over this signal's values, starting with the current one.
+	///
+	/// Each call to [`next`](`Iterator::next`) blocks until the value has changed since the
+	/// previous item. Changes that happen while no one is waiting replace each other,
+	/// so rapid changes coalesce to the latest value. The iterator never ends on its own.
+	///
+	/// The iterator internally holds an [`Effect`], so dropping it unsubscribes.
+	///
+	/// This is the synchronous counterpart to [`diffs`](`Signal::diffs`), meant for simple scripts.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::GlobalSignalsRuntime;
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// let cell = Signal::cell(1);
+	/// let mut changes = cell.iter_changes();
+	/// assert_eq!(changes.next(), Some(1));
+	///
+	/// cell.set(2);
+	/// assert_eq!(changes.next(), Some(2));
+	/// # }
+	/// ```
+	///
+	/// # Panics
+	///
+	/// Calling [`next`](`Iterator::next`) in signal callbacks **may** panic or deadlock.
+	///
+	/// # Features
+	///
+	/// **The feature `"std"` is required to enable this method.**
+	///
+	/// Since 0.2.1.
+	#[cfg(feature = "std")]
+	pub fn iter_changes<'a>(&self) -> impl 'a + Iterator<Item = T>
+	where
+		T: 'a + Sized + Sync + Clone,
+		S: 'a,
+		SR: 'a + Sized,
+	{
+		let source = self.to_owned();
+		IterChanges::new(move || source.get_clone(), self.clone_runtime_ref())
 	}
 
-	/// Records `self` as dependency and retrieves a copy of the value.
+	/// A [`Stream`](`futures_core::Stream`) of `(previous, current)` value pairs, yielding one per change.
 	///
-	/// Prefer [`Signal::touch`] where possible.
-	pub fn get(&self) -> T
+	/// The initial value is only recorded, so the first pair is yielded after the first change.  
+	/// Changes that happen while a pair is waiting to be polled replace it, so rapid changes coalesce to the latest pair.
+	///
+	/// The stream internally holds an [`Effect`], so dropping it unsubscribes.
+	///
+	/// **The feature `"futures"` is required to enable this method.**
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::GlobalSignalsRuntime;
+	/// use std::{pin::pin, task::{Context, Poll, Waker}};
+	/// use futures_core::Stream;
+	///
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// let cell = Signal::cell(1);
+	/// let mut diffs = pin!(cell.diffs());
+	/// let mut cx = Context::from_waker(Waker::noop());
+	/// assert_eq!(diffs.as_mut().poll_next(&mut cx), Poll::Pending);
+	///
+	/// cell.set(2);
+	/// assert_eq!(diffs.as_mut().poll_next(&mut cx), Poll::Ready(Some((1, 2))));
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	#[cfg(feature = "futures")]
+	pub fn diffs<'a>(&self) -> impl 'a + futures_core::Stream<Item = (T, T)>
 	where
-		T: Sync + Copy,
+		T: 'a + Sized + Sync + Clone,
+		S: 'a,
+		SR: 'a + Sized,
 	{
-		self._managed().get()
+		let source = self.to_owned();
+		Diffs::new(move || source.get_clone(), self.clone_runtime_ref())
 	}
 
-	/// Records `self` as dependency and retrieves a clone of the value.
+	/// A [`Stream`](`futures_core::Stream`) of [`CollectionDelta`]s between successive values of a collection,
+	/// so that consumers can apply changes incrementally instead of processing whole snapshots.
 	///
-	/// Prefer [`Signal::get`] where available.
-	pub fn get_clone(&self) -> T
+	/// The first item lists the initial value as all-[`added`](`CollectionDelta::added`) (even if it's empty).  
+	/// Each later item is the delta from the previously yielded snapshot to the latest one.
+	/// Changes that happen while a delta is waiting to be polled are combined into it,
+	/// and changes that cancel out entirely aren't yielded.
+	///
+	/// How entries are compared depends on the [`DiffCollection`] implementation:
+	/// [`Vec`]s by index and [`HashMap`](`std::collections::HashMap`)s by key (which requires [`Eq`] and [`Hash`]).
+	///
+	/// The stream internally holds an [`Effect`], so dropping it unsubscribes.
+	///
+	/// **The feature `"futures"` is required to enable this method.**
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::GlobalSignalsRuntime;
+	/// use std::{pin::pin, task::{Context, Poll, Waker}};
+	/// use flourish::CollectionDelta;
+	/// use futures_core::Stream;
+	///
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// let cell = Signal::cell(vec!["a"]);
+	/// let mut deltas = pin!(cell.diff_collection());
+	/// let mut cx = Context::from_waker(Waker::noop());
+	/// assert_eq!(
+	/// 	deltas.as_mut().poll_next(&mut cx),
+	/// 	Poll::Ready(Some(CollectionDelta {
+	/// 		added: vec![(0, "a")],
+	/// 		removed: vec![],
+	/// 		changed: vec![],
+	/// 	})),
+	/// );
+	///
+	/// cell.set(vec!["b", "c"]);
+	/// assert_eq!(
+	/// 	deltas.as_mut().poll_next(&mut cx),
+	/// 	Poll::Ready(Some(CollectionDelta {
+	/// 		added: vec![(1, "c")],
+	/// 		removed: vec![],
+	/// 		changed: vec![(0, "a", "b")],
+	/// 	})),
+	/// );
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	#[cfg(feature = "futures")]
+	pub fn diff_collection<'a>(&self) -> impl 'a + futures_core::Stream<Item = CollectionDelta<T>>
 	where
-		T: Sync + Clone,
+		T: 'a + Sized + Sync + Clone + DiffCollection,
+		S: 'a,
+		SR: 'a + Sized,
 	{
-		self._managed().get_clone()
+		let source = self.to_owned();
+		CollectionDiffs::new(move || source.get_clone(), self.clone_runtime_ref())
 	}
 
-	/// Records `self` as dependency and retrieves a copy of the value.
+	/// A [`tokio::sync::watch::Receiver`] that always holds a clone of this signal's current value.
 	///
-	/// Prefer [`Signal::get`] where available.
-	pub fn get_exclusive(&self) -> T
+	/// Values are sent by a forwarding subscription, which keeps this signal alive and subscribed.
+	/// Once all receivers (including clones) are dropped, it stops reading the signal and
+	/// a spawned task drops it.
+	///
+	/// **The feature `"tokio"` is required to enable this method.**
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(all(feature = "global_signals_runtime", feature = "tokio"))] // flourish features
+	/// # use flourish::GlobalSignalsRuntime;
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+	/// let _guard = runtime.enter();
+	///
+	/// let cell = Signal::cell(1);
+	/// let rx = cell.to_watch();
+	/// assert_eq!(*rx.borrow(), 1);
+	///
+	/// cell.set(2);
+	/// assert_eq!(*rx.borrow(), 2);
+	/// # }
+	/// ```
+	///
+	/// # Panics
+	///
+	/// Iff called outside of a Tokio runtime context, like [`tokio::spawn`].
+	///
+	/// Since 0.2.1.
+	#[cfg(feature = "tokio")]
+	pub fn to_watch(&self) -> tokio::sync::watch::Receiver<T>
 	where
-		T: Copy,
+		T: 'static + Sized + Sync + Clone,
+		S: 'static,
+		SR: 'static + Sized,
 	{
-		self._managed().get_clone_exclusive()
+		let source = self.to_owned();
+		let (tx, rx) = tokio::sync::watch::channel(
+			self.clone_runtime_ref().run_detached(|| source.get_clone()),
+		);
+		let tx = Arc::new(tx);
+		let forwarding = Subscription::computed_with_runtime(
+			{
+				let tx = Arc::clone(&tx);
+				let mut first = true;
+				move || {
+					// Not reading `source` anymore makes this dormant until it's dropped.
+					if !tx.is_closed() {
+						let value = source.get_clone();
+						// The channel starts out with the current value, so receivers don't see this as change.
+						let notify = !mem::take(&mut first);
+						tx.send_if_modified(|current| {
+							*current = value;
+							notify
+						});
+					}
+				}
+			},
+			self.clone_runtime_ref(),
+		);
+		tokio::spawn(async move {
+			tx.closed().await;
+			drop(forwarding);
+		});
+		rx
 	}
 
-	/// Records `self` as dependency and retrieves a clone of the value.
+	/// Sends a clone of the current value into `tx` immediately and then on each change,
+	/// for as long as the returned [`Effect`] is alive.
+	///
+	/// Values are dropped while the channel is full.
+	/// Use [`forward_to_sender_blocking`](`Signal::forward_to_sender_blocking`) to wait for capacity instead.
+	///
+	/// Once the receiver is dropped, the [`Effect`] stops recording dependencies and so goes dormant.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::GlobalSignalsRuntime;
+	/// use futures_channel::mpsc;
+	///
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// let cell = Signal::cell(1);
+	/// let (tx, mut rx) = mpsc::channel(8);
+	///
+	/// let forwarding = cell.forward_to_sender(tx);
+	/// cell.set(2);
+	///
+	/// assert_eq!(rx.try_recv().unwrap(), 1);
+	/// assert_eq!(rx.try_recv().unwrap(), 2);
+	/// # drop(forwarding);
+	/// # }
+	/// ```
 	///
-	/// Prefer [`Signal::get_clone`] where available.
-	pub fn get_clone_exclusive(&self) -> T
-	where
-		T: Clone,
-	{
-		self._managed().get_clone_exclusive()
-	}
-
-	/// Records `self` as dependency and allows borrowing the value.
-	pub fn read<'r>(&'r self) -> S::Read<'r>
+	/// # Features
+	///
+	/// **The feature `"std"` is required to enable this method.**
+	///
+	/// Since 0.2.1.
+	#[cfg(feature = "std")]
+	pub fn forward_to_sender<'a>(&self, tx: Sender<T>) -> Effect<'a, SR>
 	where
-		S: Sized,
-		T: 'r + Sync,
+		T: 'a + Sized + Sync + Clone,
+		S: 'a,
+		SR: 'a + Sized,
 	{
-		self._managed().read()
+		let source = self.to_owned();
+		let mut tx = Some(tx);
+		Effect::new_with_runtime(
+			move || {
+				let Some(sender) = tx.as_mut().filter(|sender| !sender.is_closed()) else {
+					tx = None;
+					return;
+				};
+				if let Err(error) = sender.try_send(source.get_clone()) {
+					if error.is_disconnected() {
+						tx = None;
+					}
+				}
+			},
+			drop,
+			self.clone_runtime_ref(),
+		)
 	}
 
-	/// Records `self` as dependency and allows borrowing the value.
+	/// Sends a clone of the current value into `tx` immediately and then on each change,
+	/// for as long as the returned [`Effect`] is alive.
 	///
-	/// Prefer [`Signal::read`] where available.
-	pub fn read_exclusive<'r>(&'r self) -> S::ReadExclusive<'r>
+	/// The [`Effect`] hands each value to a dedicated forwarding thread, which parks until the channel has capacity.
+	/// This applies backpressure to that thread only, so the signals runtime isn't blocked by a full channel.  
+	/// While the channel is full, values queue up (without bound) in front of the forwarding thread.
+	/// They are sent in order and none are dropped.
+	///
+	/// Once the receiver is dropped, the [`Effect`] stops recording dependencies and so goes dormant.
+	/// The forwarding thread exits once the receiver or the [`Effect`] is dropped.
+	///
+	/// # Features
+	///
+	/// **The feature `"std"` is required to enable this method.**
+	///
+	/// # Panics
+	///
+	/// Iff the forwarding thread can't be spawned.
+	///
+	/// Since 0.2.1.
+	#[cfg(feature = "std")]
+	pub fn forward_to_sender_blocking<'a>(&self, tx: Sender<T>) -> Effect<'a, SR>
 	where
-		S: Sized,
-		T: 'r,
+		T: 'static + Sized + Sync + Clone,
+		S: 'a,
+		SR: 'a + Sized,
 	{
-		self._managed().read_exclusive()
+		let source = self.to_owned();
+		let (handoff, queue) = mpsc::channel::<T>();
+		let mut forward = Some((handoff, tx.clone()));
+		thread::Builder::new()
+			.name("flourish forward_to_sender_blocking".to_string())
+			.spawn(move || {
+				let mut tx = tx;
+				// Ends when the `Effect` is dropped.
+				for value in queue {
+					if park_until_ready(&mut tx)
+						.and_then(|()| tx.start_send(value))
+						.is_err()
+					{
+						break;
+					}
+				}
+			})
+			.expect("Failed to spawn the forwarding thread.");
+		Effect::new_with_runtime(
+			move || {
+				let Some((handoff, _)) = forward.as_ref().filter(|(_, tx)| !tx.is_closed()) else {
+					forward = None;
+					return;
+				};
+				if handoff.send(source.get_clone()).is_err() {
+					forward = None;
+				}
+			},
+			drop,
+			self.clone_runtime_ref(),
+		)
 	}
 
-	/// The same as [`Signal::read`], but dyn-compatible.
+	/// The same as [`Signal::read_dyn`], but stores the guard in `buf`,
+	/// reusing its allocation where the previous guard's layout matches.
+	///
+	/// The previous guard in `buf` is dropped *before* the value is borrowed again.
 	///
+	/// This is an opt-in micro-optimisation for hot loops over type-erased guards.
 	/// Prefer [`Signal::read`] where available.
-	pub fn read_dyn<'r>(&'r self) -> Box<dyn 'r + Guard<T>>
+	///
+	/// Since 0.2.1.
+	pub fn read_dyn_into<'r>(&'r self, buf: &mut Option<Box<dyn 'r + Guard<T>>>)
 	where
+		S: Sized,
 		T: 'r + Sync,
 	{
-		self._managed().read_dyn()
+		emplace_guard(buf, || self.read());
 	}
 
-	/// The same as [`Signal::read_exclusive`], but dyn-compatible.
+	/// The same as [`Signal::read_exclusive_dyn`], but stores the guard in `buf`,
+	/// reusing its allocation where the previous guard's layout matches.
 	///
-	/// Prefer [`Signal::read_dyn`] where available.
-	pub fn read_exclusive_dyn<'r>(&'r self) -> Box<dyn 'r + Guard<T>>
+	/// The previous guard in `buf` is dropped *before* the value is borrowed again.
+	///
+	/// This is an opt-in micro-optimisation for hot loops over type-erased guards.
+	/// Prefer [`Signal::read_dyn_into`] where available.
+	///
+	/// Since 0.2.1.
+	pub fn read_exclusive_dyn_into<'r>(&'r self, buf: &mut Option<Box<dyn 'r + Guard<T>>>)
 	where
+		S: Sized,
 		T: 'r,
 	{
-		self._managed().read_exclusive_dyn()
+		emplace_guard(buf, || self.read_exclusive());
 	}
 
 	/// Clones this [`Signal`]'s [`SignalsRuntimeRef`].
@@ -1556,6 +4604,34 @@ impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignalCell<T, SR>, SR: ?Sized + Sign
 		self._managed().set(new_value)
 	}
 
+	/// Unconditionally replaces the current value with `new_value` and signals dependents,
+	/// then defers dropping the previous value via [`SignalsRuntimeRef::defer_drop`].
+	///
+	/// This is an opt-in alternative to [`.set(new_value)`](`Signal::set`) for values with
+	/// expensive [`Drop`] implementations, which otherwise run inside the update.
+	///
+	/// Note that the previous value is then dropped *after* the update completes and **may** be
+	/// dropped on another thread. Values deferred from the same thread are still dropped
+	/// in order (as far as the runtime guarantees this), but not in order with other drops.
+	///
+	/// # Logic
+	///
+	/// This method **must not** block *indefinitely*.  
+	/// This method **may** defer its effect.
+	///
+	/// Since 0.2.1.
+	pub fn set_defer_drop(&self, new_value: T)
+	where
+		T: 'static + Sized,
+		SR: 'static + Sized,
+	{
+		let runtime = self.clone_runtime_ref();
+		self.update_dyn(Box::new(move |value| {
+			runtime.defer_drop(mem::replace(value, new_value));
+			Propagation::Propagate
+		}));
+	}
+
 	/// Modifies the current value using the given closure.
 	///
 	/// The closure decides whether to signal dependents.
@@ -1580,6 +4656,51 @@ impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignalCell<T, SR>, SR: ?Sized + Sign
 		self._managed().update_dyn(update)
 	}
 
+	/// Modifies the current value using the given closure,
+	/// then signals dependents iff the result differs from the previous value.
+	///
+	/// This is useful to change several fields of a value at once, with a single propagation
+	/// and without having to decide on a [`Propagation`] manually.
+	///
+	/// Note that this clones the *whole* value before each update, to compare against it afterwards.
+	/// Prefer [`update`](`Signal::update`) where that is expensive.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # type Signal<T, S> = flourish::Signal<T, S, flourish::GlobalSignalsRuntime>;
+	/// let point = Signal::cell((1, 2));
+	///
+	/// point.update_if_changed(|(x, y)| {
+	/// 	*x = 3;
+	/// 	*y = 4;
+	/// });
+	/// assert_eq!(point.get(), (3, 4));
+	/// # }
+	/// ```
+	///
+	/// # Logic
+	///
+	/// This method **must not** block *indefinitely*.  
+	/// This method **may** defer its effect.
+	///
+	/// Since 0.2.1.
+	pub fn update_if_changed(&self, update: impl 'static + Send + FnOnce(&mut T))
+	where
+		S: Sized,
+		T: 'static + Sized + Clone + PartialEq,
+	{
+		self.update(|value| {
+			let previous = value.clone();
+			update(value);
+			if *value == previous {
+				Propagation::Halt
+			} else {
+				Propagation::Propagate
+			}
+		});
+	}
+
 	/// Cheaply creates a [`Future`] that has the effect of [`set_if_distinct_eager`](`Signal::set_if_distinct_eager`) when polled.
 	/// The [`Future`] *does not* hold a strong reference to the [`Signal`].
 	pub fn set_if_distinct_async<'f>(
@@ -2089,6 +5210,31 @@ impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignalCell<T, SR>, SR: ?Sized + Sign
 		self._managed().set_if_distinct_blocking(new_value)
 	}
 
+	/// Iff `new_value` differs from the current value, overwrites it and signals dependents.
+	///
+	/// Like [`set_if_distinct_blocking`](`Signal::set_if_distinct_blocking`),
+	/// but reports the outcome as [`MaybeSet`].
+	///
+	/// # Returns
+	///
+	/// [`MaybeSet::Set`], or [`MaybeSet::Unchanged(new_value)`](`MaybeSet::Unchanged`) iff not replaced.
+	///
+	/// # Panics
+	///
+	/// This method **may** panic if called in signal callbacks.
+	///
+	/// # Logic
+	///
+	/// This method **may** block *indefinitely* iff called in signal callbacks.
+	///
+	/// Since 0.2.1.
+	pub fn set_if_distinct_reporting(&self, new_value: T) -> MaybeSet<T>
+	where
+		T: Sized + PartialEq,
+	{
+		self.set_if_distinct_blocking(new_value).into()
+	}
+
 	/// Iff `new_value` differs from the current value, replaces it and signals dependents.
 	///
 	/// # Returns
@@ -2109,6 +5255,79 @@ impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignalCell<T, SR>, SR: ?Sized + Sign
 		self._managed().replace_if_distinct_blocking(new_value)
 	}
 
+	/// Iff `new_value` differs from the current value, replaces it and signals dependents.
+	///
+	/// Like [`replace_if_distinct_blocking`](`Signal::replace_if_distinct_blocking`),
+	/// but reports the outcome as [`MaybeReplaced`]. Neither value is cloned.
+	///
+	/// # Returns
+	///
+	/// [`MaybeReplaced::Replaced`] with the previous value,
+	/// or [`MaybeReplaced::Unchanged(new_value)`](`MaybeReplaced::Unchanged`) iff not replaced.
+	///
+	/// # Panics
+	///
+	/// This method **may** panic if called in signal callbacks.
+	///
+	/// # Logic
+	///
+	/// This method **may** block *indefinitely* iff called in signal callbacks.
+	///
+	/// Since 0.2.1.
+	pub fn swap_if_distinct_blocking(&self, new_value: T) -> MaybeReplaced<T>
+	where
+		T: Sized + PartialEq,
+	{
+		self.replace_if_distinct_blocking(new_value).into()
+	}
+
+	/// Iff the current value equals `expected`, overwrites it with `new_value` and signals dependents.
+	///
+	/// The comparison and write happen within one [`update_blocking`](`Signal::update_blocking`),
+	/// so no other update to this cell can interleave. This makes the method suitable for
+	/// optimistic concurrency on a shared cell.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # type Signal<T, S> = flourish::Signal<T, S, flourish::GlobalSignalsRuntime>;
+	/// let cell = Signal::cell(1);
+	///
+	/// assert_eq!(cell.compare_and_set(&1, 2), Ok(()));
+	/// assert_eq!(cell.compare_and_set(&1, 3), Err(3));
+	/// assert_eq!(cell.get(), 2);
+	/// # }
+	/// ```
+	///
+	/// # Returns
+	///
+	/// [`Ok`], or [`Err(new_value)`](`Err`) iff the current value didn't equal `expected`.
+	///
+	/// # Panics
+	///
+	/// This method **may** panic if called in signal callbacks.
+	///
+	/// # Logic
+	///
+	/// This method **may** block *indefinitely* iff called in signal callbacks.
+	///
+	/// Since 0.2.1.
+	pub fn compare_and_set(&self, expected: &T, new_value: T) -> Result<(), T>
+	where
+		T: Sized + PartialEq,
+	{
+		let mut new_value = Some(new_value);
+		self.update_blocking_dyn(Box::new(|value| {
+			if *value == *expected {
+				*value = new_value.take().expect("unreachable");
+				Propagation::Propagate
+			} else {
+				Propagation::Halt
+			}
+		}));
+		new_value.map_or(Ok(()), Err)
+	}
+
 	/// Unconditionally overwrites the current value with `new_value` and signals dependents.
 	///
 	/// # Panics
@@ -2145,6 +5364,42 @@ impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignalCell<T, SR>, SR: ?Sized + Sign
 		self._managed().replace_blocking(new_value)
 	}
 
+	/// Replaces the current value with [`T::default()`](`Default::default`) and signals dependents,
+	/// like [`mem::take`](`core::mem::take`).
+	///
+	/// Dependents are signalled even if the default equals the previous value.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # type Signal<T, S> = flourish::Signal<T, S, flourish::GlobalSignalsRuntime>;
+	/// let queue = Signal::cell(vec![1, 2]);
+	///
+	/// assert_eq!(queue.take(), [1, 2]);
+	/// assert!(queue.get_clone().is_empty());
+	/// # }
+	/// ```
+	///
+	/// # Returns
+	///
+	/// The previous value.
+	///
+	/// # Panics
+	///
+	/// This method **may** panic if called in signal callbacks.
+	///
+	/// # Logic
+	///
+	/// This method **may** block *indefinitely* iff called in signal callbacks.
+	///
+	/// Since 0.2.1.
+	pub fn take(&self) -> T
+	where
+		T: Sized + Default,
+	{
+		self.replace_blocking(T::default())
+	}
+
 	/// Modifies the current value using the given closure.
 	///
 	/// The closure decides whether to signal dependents.
@@ -2171,11 +5426,228 @@ impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignalCell<T, SR>, SR: ?Sized + Sign
 	pub fn update_blocking_dyn(&self, update: Box<dyn '_ + FnOnce(&mut T) -> Propagation>) {
 		self._managed().update_blocking_dyn(update)
 	}
+
+	/// A cell signal for one field of this cell's value, given as pair of accessors.
+	///
+	/// Reading the field signal records only that field as dependency:
+	/// Its dependents are refreshed only if the field's value actually changed.  
+	/// Writes to the field signal are applied to this cell via its update methods,
+	/// which `set` the modified clone of the field back into the value.
+	/// [`set_if_distinct`](`Signal::set_if_distinct`) and similar compare only the field.
+	///
+	/// The field signal keeps this cell alive and uses its runtime.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::GlobalSignalsRuntime;
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// #[derive(Clone)]
+	/// struct Point {
+	/// 	x: i32,
+	/// 	y: i32,
+	/// }
+	///
+	/// let point = Signal::cell(Point { x: 1, y: 2 });
+	/// let x = point.field(|point| &point.x, |point, x| point.x = x);
+	/// let y = point.field(|point| &point.y, |point, y| point.y = y);
+	///
+	/// x.set(3);
+	/// assert_eq!(point.read().x, 3);
+	/// assert_eq!(y.get(), 2);
+	/// # }
+	/// ```
+	///
+	/// For structs with many fields, it's convenient to
+	/// write a constructor that projects each of them once:
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::{GlobalSignalsRuntime, SignalArcDynCell};
+	/// # type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	/// #[derive(Clone)]
+	/// struct Settings {
+	/// 	name: String,
+	/// 	volume: u8,
+	/// }
+	///
+	/// struct SettingsFields {
+	/// 	name: SignalArcDynCell<'static, String, GlobalSignalsRuntime>,
+	/// 	volume: SignalArcDynCell<'static, u8, GlobalSignalsRuntime>,
+	/// }
+	///
+	/// impl SettingsFields {
+	/// 	fn new(settings: &Signal<Settings, impl 'static + flourish::unmanaged::UnmanagedSignalCell<Settings, GlobalSignalsRuntime>>) -> Self {
+	/// 		Self {
+	/// 			name: settings.field(|s| &s.name, |s, name| s.name = name),
+	/// 			volume: settings.field(|s| &s.volume, |s, volume| s.volume = volume),
+	/// 		}
+	/// 	}
+	/// }
+	///
+	/// let settings = Signal::cell(Settings { name: "Default".to_string(), volume: 5 });
+	/// let fields = SettingsFields::new(&settings);
+	/// fields.volume.set(7);
+	/// assert_eq!(settings.read().volume, 7);
+	/// assert_eq!(fields.name.get_clone(), "Default");
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	pub fn field<'a, F: 'static + Send + Sync + Clone + PartialEq>(
+		&self,
+		get: impl 'static + Send + Sync + Fn(&T) -> &F,
+		set: impl 'static + Send + Sync + Fn(&mut T, F),
+	) -> SignalArcDynCell<'a, F, SR>
+	where
+		T: 'static,
+		S: 'a + Sized,
+		SR: 'a + Sized,
+	{
+		let get = Arc::new(get);
+		let projection = distinct(
+			{
+				let source = self.to_owned();
+				let get = Arc::clone(&get);
+				move || get(&source.read_exclusive()).clone()
+			},
+			self.clone_runtime_ref(),
+		);
+		SignalArc::new(Lens::new(
+			projection,
+			self.to_dyn_cell(),
+			get,
+			Arc::new(set),
+		))
+		.into_dyn_cell()
+	}
+}
+
+/// A builder for [`computed`](`Signal::computed`) signals over several explicit inputs,
+/// which checks that all inputs share the builder's runtime.
+///
+/// Inputs are collected as owned [`SignalArc`]s in nested pairs, starting from `()`,
+/// so the closure passed to [`computed`](`SignalBuilder::computed`) receives `&((((), a), b), …)`.  
+/// Only inputs the closure actually reads are recorded as dependencies.
+///
+/// ```
+/// # {
+/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+/// use flourish::GlobalSignalsRuntime;
+///
+/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+/// type SignalBuilder<I> = flourish::SignalBuilder<I, GlobalSignalsRuntime>;
+///
+/// let a = Signal::cell(1);
+/// let b = Signal::cell("two");
+///
+/// let joined = SignalBuilder::new()
+/// 	.input(&a)
+/// 	.input(&b)
+/// 	.computed(|(((), a), b)| format!("{} {}", a.get(), b.get()));
+/// assert_eq!(joined.get_clone(), "1 two");
+/// # }
+/// ```
+///
+/// Since 0.2.1.
+#[must_use = "A `SignalBuilder` does nothing unless `computed` is called."]
+pub struct SignalBuilder<I, SR: SignalsRuntimeRef> {
+	inputs: I,
+	runtime: SR,
+}
+
+impl<SR: SignalsRuntimeRef + Default> Default for SignalBuilder<(), SR> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<SR: SignalsRuntimeRef> SignalBuilder<(), SR> {
+	/// Creates a new [`SignalBuilder`] without inputs, using the default runtime.
+	///
+	/// Since 0.2.1.
+	pub fn new() -> Self
+	where
+		SR: Default,
+	{
+		Self::with_runtime(SR::default())
+	}
+
+	/// Creates a new [`SignalBuilder`] without inputs, using `runtime`.
+	///
+	/// Since 0.2.1.
+	pub fn with_runtime(runtime: SR) -> Self {
+		Self {
+			inputs: (),
+			runtime,
+		}
+	}
+}
+
+impl<I, SR: SignalsRuntimeRef> SignalBuilder<I, SR> {
+	/// Adds `signal` as input.
+	///
+	/// # Panics
+	///
+	/// Iff `signal`'s runtime isn't equal to the builder's.
+	///
+	/// Since 0.2.1.
+	pub fn input<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>>(
+		self,
+		signal: &Signal<T, S, SR>,
+	) -> SignalBuilder<(I, SignalArc<T, S, SR>), SR>
+	where
+		SR: PartialEq,
+	{
+		assert!(
+			signal.clone_runtime_ref() == self.runtime,
+			"`SignalBuilder` input doesn't share the builder's signals runtime."
+		);
+		self.input_unchecked(signal)
+	}
+
+	/// Adds `signal` as input, without checking its runtime.
+	///
+	/// Use this iff `SR` isn't [`PartialEq`].
+	///
+	/// # Logic
+	///
+	/// `signal` **should** use the same runtime as the builder.
+	/// Otherwise, dependency tracking and update propagation **may** silently not work as expected.
+	///
+	/// Since 0.2.1.
+	pub fn input_unchecked<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>>(
+		self,
+		signal: &Signal<T, S, SR>,
+	) -> SignalBuilder<(I, SignalArc<T, S, SR>), SR> {
+		SignalBuilder {
+			inputs: (self.inputs, signal.to_owned()),
+			runtime: self.runtime,
+		}
+	}
+
+	/// Creates a [`computed`](`Signal::computed_with_runtime`) signal that calls `fn_pin` with the inputs.
+	///
+	/// Since 0.2.1.
+	pub fn computed<'a, T: 'a + Send>(
+		self,
+		mut fn_pin: impl 'a + Send + FnMut(&I) -> T,
+	) -> SignalArc<T, impl 'a + Sized + UnmanagedSignal<T, SR>, SR>
+	where
+		I: 'a + Send,
+		SR: 'a,
+	{
+		let Self { inputs, runtime } = self;
+		Signal::computed_with_runtime(move || fn_pin(&inputs), runtime)
+	}
 }
 
 /// Duplicated to avoid identities.
 mod private {
-	use std::{
+	use alloc::boxed::Box;
+	use core::{
 		future::Future,
 		marker::PhantomPinned,
 		pin::Pin,
@@ -1,4 +1,5 @@
-use std::{
+use alloc::{borrow::ToOwned, sync::Arc};
+use core::{
 	borrow::Borrow,
 	fmt::{self, Debug, Formatter},
 	mem::ManuallyDrop,
@@ -10,7 +11,7 @@ use isoprenoid::runtime::SignalsRuntimeRef;
 use crate::{
 	signal::{Signal, Strong, Weak},
 	traits::{UnmanagedSignal, UnmanagedSignalCell},
-	Subscription,
+	Subscription, SubscriptionDyn,
 };
 
 /// [`SignalArc`] after type-erasure.
@@ -47,6 +48,30 @@ impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsR
 		self.weak.upgrade().map(|strong| SignalArc { strong })
 	}
 
+	/// Tries to obtain a [`Subscription`] from this [`SignalWeak`].
+	///
+	/// Equivalent to `.upgrade()?.into_subscription()`, but subscribes in a detached context.
+	///
+	/// Upgrading fails once the managed [`Signal`] has started to be purged,
+	/// so a returned [`Subscription`] always refers to a live signal.
+	///
+	/// Since 0.2.1.
+	#[must_use = "Subscriptions are undone when dropped."]
+	pub fn upgrade_subscription(&self) -> Option<Subscription<T, S, SR>>
+	where
+		SR: Sized,
+	{
+		let strong = self.weak.upgrade()?;
+		strong.clone_runtime_ref().run_detached(|| {
+			strong._managed().subscribe();
+			// Important: Wrap only after subscribing succeeds!
+			//            If there's a panic, we still want to release the `Strong` but without calling `.unsubscribe()`.
+			Some(Subscription {
+				subscribed: ManuallyDrop::new(strong),
+			})
+		})
+	}
+
 	/// Erases the (generally opaque) type parameter `S`, allowing the weak signal handle
 	/// to be stored easily.
 	pub fn into_dyn<'a>(self) -> SignalWeakDyn<'a, T, SR>
@@ -187,6 +212,182 @@ impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsR
 		}
 	}
 
+	/// Erases the (generally opaque) type parameter `S` into a `'static` trait object.
+	///
+	/// This is [`into_dyn::<'static>`](`SignalArc::into_dyn`), but reports an unmet `S: 'static`
+	/// bound at the call site rather than where the result is stored.  
+	/// Like [`into_dyn`](`SignalArc::into_dyn`), it only unsizes the handle.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::GlobalSignalsRuntime;
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	/// type SignalArcDyn<'a, T> = flourish::SignalArcDyn<'a, T, GlobalSignalsRuntime>;
+	///
+	/// struct Model {
+	/// 	count: SignalArcDyn<'static, usize>,
+	/// }
+	///
+	/// let model = Model {
+	/// 	count: Signal::computed(|| 1).into_dyn_static(),
+	/// };
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	pub fn into_dyn_static(self) -> SignalArcDyn<'static, T, SR>
+	where
+		S: 'static + Sized,
+	{
+		self.into_dyn()
+	}
+
+	/// Returns the [`UnmanagedSignal`] iff this is the only handle to the managed [`Signal`].
+	///
+	/// The check is atomic, so a concurrent [`SignalWeak::upgrade`] can't race with it.
+	///
+	/// # Errors
+	///
+	/// Returns `self` unchanged iff any other [`SignalArc`], [`SignalWeak`] or [`Subscription`] exists,
+	/// including [`Subscription`]s that were [`leak`](`Subscription::leak`)ed.
+	///
+	/// Since `S` is generally pinned while managed, it must be [`Unpin`] to be moved out.
+	///
+	/// Mirrors [`Arc::try_unwrap`](`std::sync::Arc::try_unwrap`).
+	///
+	/// Since 0.2.1.
+	pub fn try_unwrap(self) -> Result<S, Self>
+	where
+		S: Sized + Unpin,
+	{
+		let Self { strong } = self;
+		strong.try_unwrap().map_err(|strong| Self { strong })
+	}
+
+	/// Clones this handle together with the signal's runtime reference,
+	/// for passing both to `…_with_runtime` constructors.
+	///
+	/// Equivalent to `(self.clone(), self.clone_runtime_ref())`.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::{GlobalSignalsRuntime, Signal};
+	/// let input = Signal::cell_with_runtime(1, GlobalSignalsRuntime).into_read_only();
+	///
+	/// let (input_, runtime) = input.clone_with_runtime();
+	/// let doubled = Signal::computed_with_runtime(move || input_.get() * 2, runtime);
+	/// assert_eq!(doubled.get(), 2);
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	pub fn clone_with_runtime(&self) -> (Self, SR)
+	where
+		SR: Sized,
+	{
+		(self.clone(), self.clone_runtime_ref())
+	}
+
+	/// Maps this signal's value into an [`Arc`] with `map_fn_pin`, reusing the last [`Arc`]
+	/// as long as the input compares equal to the one it was computed from.
+	///
+	/// The first run always calls `map_fn_pin`. Afterwards, the result is pointer-stable across
+	/// refreshes with equal input, so dependents can short-circuit with [`Arc::ptr_eq`].
+	///
+	/// The new cached computation depends on and keeps alive `self`.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// use std::sync::Arc;
+	///
+	/// use flourish::GlobalSignalsRuntime;
+	///
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// let input = Signal::cell(1);
+	/// let mapped = input.to_owned().map_memo(|value| value.to_string());
+	///
+	/// let first = mapped.get_clone();
+	/// input.set(1);
+	/// assert!(Arc::ptr_eq(&first, &mapped.get_clone()));
+	///
+	/// input.set(2);
+	/// assert_eq!(*mapped.get_clone(), "2");
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	pub fn map_memo<'a, U: 'a + Send + Sync>(
+		self,
+		mut map_fn_pin: impl 'a + Send + FnMut(&T) -> U,
+	) -> SignalArc<Arc<U>, impl 'a + Sized + UnmanagedSignal<Arc<U>, SR>, SR>
+	where
+		T: 'a + Sized + Clone + PartialEq,
+		S: 'a,
+		SR: 'a + Sized,
+	{
+		let runtime = self.clone_runtime_ref();
+		let mut memo: Option<(T, Arc<U>)> = None;
+		Signal::computed_with_runtime(
+			move || {
+				let input = self.read_exclusive_dyn();
+				if let Some((last_input, output)) = &memo {
+					if *last_input == **input {
+						return Arc::clone(output);
+					}
+				}
+				let output = Arc::new(map_fn_pin(&input));
+				memo = Some(((**input).clone(), Arc::clone(&output)));
+				output
+			},
+			runtime,
+		)
+	}
+
+	/// Maps this signal's value with `map_fn_pin` into a new cached computation and subscribes to it.
+	///
+	/// This is the subscribed counterpart to [`map_memo`](`SignalArc::map_memo`):
+	/// The returned [`Subscription`] keeps alive the mapped signal, which keeps alive `self`.  
+	/// Since the subscription propagates through the dependency on `self`, the source is
+	/// *subscribed* (not just referenced), so subscription-gated sources like
+	/// [`Signal::cell_reactive`] start loading right away.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// use flourish::{GlobalSignalsRuntime, Propagation};
+	///
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// let source = Signal::cell_reactive(0, |_, subscribed| {
+	///     println!("Subscribed: {subscribed}");
+	///     Propagation::Halt
+	/// });
+	/// let doubled = source.clone().mapped_subscription(|value| value * 2);
+	///
+	/// source.set(2);
+	/// assert_eq!(doubled.get(), 4);
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	pub fn mapped_subscription<'a, U: 'a + Send>(
+		self,
+		mut map_fn_pin: impl 'a + Send + FnMut(&T) -> U,
+	) -> SubscriptionDyn<'a, U, SR>
+	where
+		T: 'a,
+		S: 'a,
+		SR: 'a + Sized,
+	{
+		let runtime = self.clone_runtime_ref();
+		Subscription::computed_with_runtime(move || map_fn_pin(&self.read_exclusive_dyn()), runtime)
+			.into_dyn()
+	}
+
 	/// Subscribes to the managed [`Signal`], converting this [`SignalArc`] into a [`Subscription`].
 	///
 	/// Compared to [`Signal::to_subscription`], this avoids some memory barriers.
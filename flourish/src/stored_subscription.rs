@@ -0,0 +1,28 @@
+use alloc::boxed::Box;
+use core::fmt::{self, Debug, Formatter};
+
+/// A type-erased [`Subscription`](`crate::Subscription`) that only keeps its signal subscribed.
+///
+/// Create it with [`Subscription::into_stored`](`crate::Subscription::into_stored`) (or [`From`])
+/// to keep subscriptions of different value and signal types in one collection.
+/// Dropping it unsubscribes the same way dropping the original [`Subscription`](`crate::Subscription`) would.
+///
+/// Since 0.2.1.
+#[must_use = "Subscriptions are undone when dropped."]
+pub struct StoredSubscription<'a> {
+	_subscription: Box<dyn 'a + Send + Sync>,
+}
+
+impl<'a> StoredSubscription<'a> {
+	pub(crate) fn new(subscription: impl 'a + Send + Sync) -> Self {
+		Self {
+			_subscription: Box::new(subscription),
+		}
+	}
+}
+
+impl Debug for StoredSubscription<'_> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.debug_struct("StoredSubscription").finish_non_exhaustive()
+	}
+}
@@ -1,23 +1,30 @@
-use std::{
+use alloc::{borrow::ToOwned, boxed::Box, vec::Vec};
+use core::{
 	borrow::Borrow,
 	fmt::{self, Debug, Formatter},
 	future::Future,
-	mem::{ManuallyDrop, MaybeUninit},
+	mem::{self, ManuallyDrop, MaybeUninit},
 	ops::Deref,
 	pin::Pin,
+	ptr::{self, NonNull},
 };
 
 use futures_channel::oneshot;
 use isoprenoid::runtime::{Propagation, SignalsRuntimeRef};
 use pin_project::pin_project;
 
+#[cfg(feature = "futures")]
+use crate::{ack::Acked, Ack};
 use crate::{
+	observers::Observers,
 	opaque::Opaque,
 	signal::Strong,
 	signals_helper,
+	sync::{Mutex, PoisonError},
 	traits::{UnmanagedSignal, UnmanagedSignalCell},
+	unmanaged::new_raw_unsubscribed_effect,
 	unmanaged::{computed, folded, reduced},
-	Guard, Signal, SignalArc,
+	Effect, Guard, Signal, SignalArc, SignalArcDynCell, SignalDynCell, StoredSubscription,
 };
 
 /// [`Subscription`] after type-erasure.
@@ -135,11 +142,240 @@ impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: SignalsRuntimeRef
 	/// The underlying [`Signal`] may remain subscribed-to due to other subscriptions.
 	#[must_use = "Use `drop(self)` instead of converting first. Dropping directly can skip signal refreshes caused by `Propagation::FlushOut`."]
 	pub fn unsubscribe(self) -> SignalArc<T, S, SR> {
-		//FIXME: This could avoid refcounting up and down and at least some of the associated memory barriers.
+		let this = ManuallyDrop::new(self);
+		let strong = unsafe {
+			// SAFETY: `this` is never dropped, so the field is moved out exactly once.
+			ptr::read(ptr::from_ref(&*this.subscribed))
+		};
+		// The `Strong` is still held here, so the managed `Signal` isn't exclusive
+		// and remains registered with the signals runtime.
+		strong._managed().unsubscribe();
+		SignalArc { strong }
+	}
+
+	/// Clones this handle as a non-subscribing [`SignalArc`].
+	///
+	/// Unlike [`Clone::clone`], this doesn't subscribe the [`Signal`] again and does no work
+	/// in the signals runtime, so prefer it wherever another subscription isn't needed.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::GlobalSignalsRuntime;
+	/// type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+	///
+	/// let subscription = Subscription::computed(|| 1);
+	/// let arc = subscription.clone_arc();
+	/// assert_eq!(arc.get(), 1);
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	pub fn clone_arc(&self) -> SignalArc<T, S, SR> {
 		SignalArc {
 			strong: (*self.subscribed).clone(),
 		}
-	} // Implicit drop(self) unsubscribes.
+	}
+
+	/// Clones this [`Subscription`] and additionally returns a non-subscribing [`SignalArc`],
+	/// so that one call gives both a live observer and a storable handle.
+	///
+	/// This subscribes the [`Signal`] exactly once more, for the cloned [`Subscription`].
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::GlobalSignalsRuntime;
+	/// type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+	///
+	/// let subscription = Subscription::computed(|| 1);
+	/// let (observer, handle) = subscription.observed_clone();
+	/// drop(subscription);
+	/// assert_eq!((observer.get(), handle.get()), (1, 1));
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	pub fn observed_clone(&self) -> (Self, SignalArc<T, S, SR>) {
+		(self.clone(), self.clone_arc())
+	}
+
+	/// Intentionally leaks this [`Subscription`], keeping the [`Signal`] subscribed-to
+	/// (and allocated) for the rest of the process lifetime.
+	///
+	/// This is useful for fire-and-forget keepalives that don't need a handle.
+	///
+	/// Each call keeps exactly one reference and one intrinsic subscription alive,
+	/// which is the same as holding on to the [`Subscription`].
+	/// (The reference count aborts the process only after more than `usize::MAX / 2`
+	/// references to the same [`Signal`] exist, so leaking is fine to repeat in long runs
+	/// as long as it's not done once per refresh or similar.)
+	///
+	/// Since 0.2.1.
+	pub fn leak<'l>(self) -> &'l Signal<T, S, SR>
+	where
+		T: 'l,
+		S: 'l,
+		SR: 'l,
+	{
+		let this = ManuallyDrop::new(self);
+		unsafe {
+			//SAFETY: The `Strong` is never dropped, so its allocation is never freed.
+			&*ptr::from_ref::<Signal<T, S, SR>>(&this.subscribed)
+		}
+	}
+
+	/// Mirrors this [`Subscription`]'s value into `cell` for as long as the returned [`Effect`] is alive.
+	///
+	/// `cell` is [`set`](`Signal::set`) to a clone of the current value immediately and then on each change.
+	///
+	/// **Don't** forward into a `cell` that this [`Subscription`] (transitively) depends on!  
+	/// Since [`set`](`Signal::set`) always propagates, that would refresh both indefinitely.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::GlobalSignalsRuntime;
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	/// type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+	///
+	/// let input = Signal::cell(1);
+	/// let doubled = Subscription::computed(|| input.get() * 2);
+	/// let mirror = Signal::cell(0);
+	///
+	/// let forwarding = doubled.forward_to(mirror.as_dyn_cell());
+	/// assert_eq!(mirror.get(), 2);
+	///
+	/// input.set(2);
+	/// assert_eq!(mirror.get(), 4);
+	/// # drop(forwarding);
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	pub fn forward_to<'a>(&self, cell: &SignalDynCell<'a, T, SR>) -> Effect<'a, SR>
+	where
+		T: 'static + Sized + Sync + Clone,
+		S: 'a,
+		SR: 'a,
+	{
+		let source = (**self).to_owned();
+		let cell = cell.to_owned();
+		Effect::new_with_runtime(
+			move || cell.set(source.get_clone()),
+			drop,
+			self.clone_runtime_ref(),
+		)
+	}
+
+	/// Creates an empty set of [`Observers`] of this subscription's signal.
+	///
+	/// All observers added through [`Observers::on_change`] share one effect, which is created for the first
+	/// observer and dropped again once the last [`ObserverHandle`](`crate::ObserverHandle`) is dropped.  
+	/// The [`Observers`] keep their own handle to the signal, so they don't end with this [`Subscription`].
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::GlobalSignalsRuntime;
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	/// type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+	///
+	/// let input = Signal::cell(1);
+	/// let doubled = Subscription::computed({
+	/// 	let input = input.to_owned();
+	/// 	move || input.get() * 2
+	/// });
+	///
+	/// let observers = doubled.observers();
+	/// let logging = observers.on_change(|value| println!("doubled: {value}"));
+	/// let tracing = observers.on_change(|value| println!("traced: {value}"));
+	///
+	/// input.set(2); // Prints both lines.
+	/// drop(logging);
+	/// input.set(3); // Prints only "traced: 6".
+	/// # drop(tracing);
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	pub fn observers(&self) -> Observers<T>
+	where
+		T: 'static + Sync,
+		S: 'static,
+		SR: 'static,
+	{
+		let source = (**self).to_owned();
+		Observers::new(move |observers| {
+			let source = source.clone();
+			let runtime = source.clone_runtime_ref();
+			let effect_runtime = source.clone_runtime_ref();
+			let mut primed = false;
+			let effect = Box::pin(new_raw_unsubscribed_effect(
+				move || {
+					let value = source.read_dyn();
+					if primed {
+						if let Some(observers) = observers.upgrade() {
+							runtime.run_detached(|| observers.notify(&**value));
+						}
+					} else {
+						primed = true;
+					}
+				},
+				drop,
+				effect_runtime,
+			));
+			effect.as_ref().pull();
+			effect
+		})
+	}
+
+	/// A [`Stream`](`futures_core::Stream`) of this subscription's values, each paired with an [`Ack`].
+	///
+	/// The current value is yielded first. After that, the stream yields the next value only
+	/// once the previous [`Ack`] was dropped (or [`ack`](`Ack::ack`)ed), which lets slow consumers exert backpressure.  
+	/// Pausing doesn't block propagation: Changes made meanwhile coalesce to the latest value.
+	///
+	/// The stream internally holds an [`Effect`] that keeps the signal alive, so dropping it unsubscribes that effect.
+	///
+	/// **The feature `"futures"` is required to enable this method.**
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::GlobalSignalsRuntime;
+	/// use std::{pin::pin, task::{Context, Poll, Waker}};
+	/// use futures_core::Stream;
+	///
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	/// type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+	///
+	/// let cell = Signal::cell(1);
+	/// let sub = Subscription::computed(|| cell.get());
+	/// let mut values = pin!(sub.with_ack());
+	/// let mut cx = Context::from_waker(Waker::noop());
+	///
+	/// let Poll::Ready(Some((1, ack))) = values.as_mut().poll_next(&mut cx) else { panic!() };
+	/// cell.set(2);
+	/// cell.set(3);
+	/// assert!(values.as_mut().poll_next(&mut cx).is_pending());
+	///
+	/// ack.ack();
+	/// let Poll::Ready(Some((3, _ack))) = values.as_mut().poll_next(&mut cx) else { panic!() };
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	#[cfg(feature = "futures")]
+	pub fn with_ack<'a>(&self) -> impl 'a + futures_core::Stream<Item = (T, Ack)>
+	where
+		T: 'a + Sized + Sync + Clone,
+		S: 'a,
+		SR: 'a + Sized,
+	{
+		let source = (**self).to_owned();
+		Acked::new(move || source.get_clone(), self.clone_runtime_ref())
+	}
 }
 
 impl<T: ?Sized + Send, S: Sized + UnmanagedSignal<T, SR>, SR: SignalsRuntimeRef>
@@ -178,6 +414,50 @@ impl<T: ?Sized + Send, S: Sized + UnmanagedSignal<T, SR>, SR: SignalsRuntimeRef>
 	}
 }
 
+impl<T: ?Sized + Send, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef>
+	Subscription<T, S, SR>
+{
+	/// Erases all type parameters, keeping only the subscription alive.
+	///
+	/// This allows storing [`Subscription`]s of different value types in one collection.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::GlobalSignalsRuntime;
+	/// use flourish::StoredSubscription;
+	///
+	/// type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+	///
+	/// let subscriptions: Vec<StoredSubscription> = vec![
+	/// 	Subscription::computed(|| 1).into_stored(),
+	/// 	Subscription::computed(|| "a").into_stored(),
+	/// ];
+	/// drop(subscriptions); // Unsubscribes both.
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	pub fn into_stored<'a>(self) -> StoredSubscription<'a>
+	where
+		Self: 'a,
+	{
+		StoredSubscription::new(self)
+	}
+}
+
+impl<
+		'a,
+		T: 'a + ?Sized + Send,
+		S: 'a + ?Sized + UnmanagedSignal<T, SR>,
+		SR: 'a + ?Sized + SignalsRuntimeRef,
+	> From<Subscription<T, S, SR>> for StoredSubscription<'a>
+{
+	fn from(subscription: Subscription<T, S, SR>) -> Self {
+		subscription.into_stored()
+	}
+}
+
 impl<T: ?Sized + Send, S: Sized + UnmanagedSignalCell<T, SR>, SR: ?Sized + SignalsRuntimeRef>
 	Subscription<T, S, SR>
 {
@@ -213,6 +493,146 @@ impl<'a, T: 'a + ?Sized + Send, SR: 'a + ?Sized + SignalsRuntimeRef>
 			}
 		}
 	}
+
+	/// Clones this handle as a writable, non-subscribing [`SignalArcDynCell`] to the same cell.
+	///
+	/// Like [`clone_arc`](`Subscription::clone_arc`), this doesn't change the [`Signal`]'s
+	/// subscriptions and does no work in the signals runtime.
+	///
+	/// ```
+	/// # {
+	/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+	/// # use flourish::GlobalSignalsRuntime;
+	/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+	///
+	/// let subscription = Signal::cell(1).into_subscription().into_dyn_cell();
+	/// let cell = subscription.to_cell();
+	/// drop(subscription);
+	///
+	/// cell.set(2);
+	/// assert_eq!(cell.get(), 2);
+	/// # }
+	/// ```
+	///
+	/// Since 0.2.1.
+	pub fn to_cell(&self) -> SignalArcDynCell<'a, T, SR> {
+		SignalArc {
+			strong: (*self.subscribed).clone(),
+		}
+	}
+}
+
+/// An arena of [`Subscription`]s that are all dropped together, when the [`Scope`] is.
+///
+/// Add subscriptions to it with [`Signal::subscribe_scoped`].  
+/// On drop, all of them are unsubscribed within one [`SignalsRuntimeRef::hint_batched_updates`],
+/// so that dependency changes caused by this are processed as one batch.
+///
+/// ```
+/// # {
+/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+/// # use flourish::GlobalSignalsRuntime;
+/// type Scope<'a> = flourish::Scope<'a, GlobalSignalsRuntime>;
+/// type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+///
+/// let a = Signal::cell(1);
+/// let b = Signal::computed(|| a.get() + 1);
+///
+/// let scope = Scope::new();
+/// let sub_a = a.subscribe_scoped(&scope);
+/// let sub_b = b.subscribe_scoped(&scope);
+/// assert_eq!((sub_a.get(), sub_b.get()), (1, 2));
+///
+/// drop(scope); // Unsubscribes both at once.
+/// # }
+/// ```
+///
+/// Since 0.2.1.
+#[must_use = "Subscriptions in a scope are undone when it's dropped."]
+pub struct Scope<'a, SR: 'a + SignalsRuntimeRef> {
+	/// Each subscription is boxed separately, so references to it remain valid while more are added.
+	///
+	/// These are from [`Box::into_raw`] rather than [`Box`]es, since moving a [`Box`] would invalidate those references.
+	subscriptions: Mutex<Vec<NonNull<dyn 'a + Send + Sync>>>,
+	runtime: SR,
+}
+
+// SAFETY: The pointers are owned like `Box<dyn 'a + Send + Sync>`, which is `Send` and `Sync`.
+unsafe impl<'a, SR: 'a + SignalsRuntimeRef + Send> Send for Scope<'a, SR> {}
+unsafe impl<'a, SR: 'a + SignalsRuntimeRef + Sync> Sync for Scope<'a, SR> {}
+
+impl<'a, SR: 'a + SignalsRuntimeRef> Scope<'a, SR> {
+	/// Creates a new, empty [`Scope`].
+	///
+	/// Since 0.2.1.
+	pub fn new() -> Self
+	where
+		SR: Default,
+	{
+		Self::with_runtime(SR::default())
+	}
+
+	/// Creates a new, empty [`Scope`] that batches unsubscriptions on `runtime`.
+	///
+	/// Since 0.2.1.
+	pub fn with_runtime(runtime: SR) -> Self {
+		Self {
+			subscriptions: Mutex::new(Vec::new()),
+			runtime,
+		}
+	}
+
+	pub(crate) fn push<T: 'a + ?Sized + Send, S: 'a + UnmanagedSignal<T, SR>>(
+		&self,
+		subscription: Subscription<T, S, SR>,
+	) -> &Subscription<T, S, SR> {
+		let pointer = NonNull::from(Box::leak(Box::new(subscription)));
+		self.subscriptions
+			.lock()
+			.unwrap_or_else(PoisonError::into_inner)
+			.push(pointer);
+		unsafe {
+			//SAFETY: The allocation is only freed along with `self`, and only shared references to it exist until then.
+			pointer.as_ref()
+		}
+	}
+}
+
+impl<'a, SR: 'a + SignalsRuntimeRef + Default> Default for Scope<'a, SR> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<'a, SR: 'a + SignalsRuntimeRef> Debug for Scope<'a, SR> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Scope")
+			.field(
+				"subscriptions",
+				&self
+					.subscriptions
+					.lock()
+					.unwrap_or_else(PoisonError::into_inner)
+					.len(),
+			)
+			.finish_non_exhaustive()
+	}
+}
+
+impl<'a, SR: 'a + SignalsRuntimeRef> Drop for Scope<'a, SR> {
+	fn drop(&mut self) {
+		let subscriptions = mem::take(
+			self.subscriptions
+				.get_mut()
+				.unwrap_or_else(PoisonError::into_inner),
+		);
+		self.runtime.hint_batched_updates(|| {
+			for subscription in subscriptions {
+				//SAFETY: Each pointer is from `Box::leak` in `Self::push`, and references to it can't outlive `self`.
+				drop(unsafe { Box::from_raw(subscription.as_ptr()) });
+			}
+		});
+	}
 }
 
 /// Secondary constructors.
@@ -868,6 +1288,12 @@ unsafe fn assume_init_subscription<
 		}
 	}
 
+	impl<T: ?Sized + Send + Copy, G: ?Sized + Deref<Target = MaybeUninit<T>>> AsRef<T> for AbiShim<G> {
+		fn as_ref(&self) -> &T {
+			self
+		}
+	}
+
 	unsafe {
 		//SAFETY: This may reinterpret a fat pointer, which skips over the `AbiShim` methods
 		//        entirely, but that's fine since everything is fully ABI-compatible.
@@ -0,0 +1,182 @@
+//! Locks that are available without `std`.
+
+#[cfg(feature = "std")]
+pub(crate) use std::sync::{
+	Mutex, MutexGuard, OnceLock, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard,
+	TryLockError,
+};
+
+#[cfg(not(feature = "std"))]
+pub(crate) use spin_locks::{
+	Mutex, MutexGuard, OnceLock, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard,
+	TryLockError,
+};
+
+#[cfg(not(feature = "std"))]
+mod spin_locks {
+	use core::{
+		convert::Infallible,
+		fmt::{self, Debug, Display, Formatter},
+		marker::PhantomData,
+	};
+
+	pub(crate) type MutexGuard<'a, T> = spin::MutexGuard<'a, T>;
+	pub(crate) type RwLockReadGuard<'a, T> = spin::RwLockReadGuard<'a, T>;
+	pub(crate) type RwLockWriteGuard<'a, T> = spin::RwLockWriteGuard<'a, T>;
+
+	/// Spinlocks aren't poisoned by panics, so this is never constructed.
+	pub(crate) struct PoisonError<T> {
+		never: Infallible,
+		_phantom: PhantomData<T>,
+	}
+
+	impl<T> PoisonError<T> {
+		pub(crate) fn into_inner(self) -> T {
+			match self.never {}
+		}
+	}
+
+	impl<T> Debug for PoisonError<T> {
+		fn fmt(&self, _: &mut Formatter<'_>) -> fmt::Result {
+			match self.never {}
+		}
+	}
+
+	impl<T> Display for PoisonError<T> {
+		fn fmt(&self, _: &mut Formatter<'_>) -> fmt::Result {
+			match self.never {}
+		}
+	}
+
+	/// Like `std::sync::TryLockError`, but [`TryLockError::Poisoned`] is never constructed.
+	pub(crate) enum TryLockError<T> {
+		#[allow(dead_code)]
+		Poisoned(PoisonError<T>),
+		WouldBlock,
+	}
+
+	impl<T> Debug for TryLockError<T> {
+		fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+			match self {
+				TryLockError::Poisoned(poison_error) => Debug::fmt(poison_error, f),
+				TryLockError::WouldBlock => f.write_str("WouldBlock"),
+			}
+		}
+	}
+
+	/// A [`spin::Mutex`] with the subset of `std::sync::Mutex`'s API that this crate uses.
+	#[derive(Default)]
+	pub(crate) struct Mutex<T: ?Sized>(spin::Mutex<T>);
+
+	impl<T> Mutex<T> {
+		pub(crate) const fn new(value: T) -> Self {
+			Self(spin::Mutex::new(value))
+		}
+
+		pub(crate) fn into_inner(self) -> Result<T, PoisonError<T>> {
+			Ok(self.0.into_inner())
+		}
+	}
+
+	impl<T: ?Sized> Mutex<T> {
+		pub(crate) fn lock(&self) -> Result<MutexGuard<'_, T>, PoisonError<MutexGuard<'_, T>>> {
+			Ok(self.0.lock())
+		}
+
+		pub(crate) fn try_lock(
+			&self,
+		) -> Result<MutexGuard<'_, T>, TryLockError<MutexGuard<'_, T>>> {
+			self.0.try_lock().ok_or(TryLockError::WouldBlock)
+		}
+
+		pub(crate) fn get_mut(&mut self) -> Result<&mut T, PoisonError<&mut T>> {
+			Ok(self.0.get_mut())
+		}
+	}
+
+	impl<T> From<T> for Mutex<T> {
+		fn from(value: T) -> Self {
+			Self::new(value)
+		}
+	}
+
+	impl<T: ?Sized + Debug> Debug for Mutex<T> {
+		fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+			Debug::fmt(&self.0, f)
+		}
+	}
+
+	/// A [`spin::RwLock`] with the subset of `std::sync::RwLock`'s API that this crate uses.
+	#[derive(Default)]
+	pub(crate) struct RwLock<T: ?Sized>(spin::RwLock<T>);
+
+	impl<T> RwLock<T> {
+		pub(crate) const fn new(value: T) -> Self {
+			Self(spin::RwLock::new(value))
+		}
+	}
+
+	impl<T: ?Sized> RwLock<T> {
+		pub(crate) fn read(
+			&self,
+		) -> Result<RwLockReadGuard<'_, T>, PoisonError<RwLockReadGuard<'_, T>>> {
+			Ok(self.0.read())
+		}
+
+		pub(crate) fn write(
+			&self,
+		) -> Result<RwLockWriteGuard<'_, T>, PoisonError<RwLockWriteGuard<'_, T>>> {
+			Ok(self.0.write())
+		}
+
+		pub(crate) fn try_read(
+			&self,
+		) -> Result<RwLockReadGuard<'_, T>, TryLockError<RwLockReadGuard<'_, T>>> {
+			self.0.try_read().ok_or(TryLockError::WouldBlock)
+		}
+
+		pub(crate) fn try_write(
+			&self,
+		) -> Result<RwLockWriteGuard<'_, T>, TryLockError<RwLockWriteGuard<'_, T>>> {
+			self.0.try_write().ok_or(TryLockError::WouldBlock)
+		}
+	}
+
+	impl<T> From<T> for RwLock<T> {
+		fn from(value: T) -> Self {
+			Self::new(value)
+		}
+	}
+
+	impl<T: ?Sized + Debug> Debug for RwLock<T> {
+		fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+			Debug::fmt(&self.0, f)
+		}
+	}
+
+	/// A [`spin::Once`] with the subset of `std::sync::OnceLock`'s API that this crate uses.
+	#[derive(Default)]
+	pub(crate) struct OnceLock<T>(spin::Once<T>);
+
+	impl<T> OnceLock<T> {
+		pub(crate) const fn new() -> Self {
+			Self(spin::Once::new())
+		}
+
+		pub(crate) fn get(&self) -> Option<&T> {
+			self.0.get()
+		}
+
+		pub(crate) fn set(&self, value: T) -> Result<(), T> {
+			let mut value = Some(value);
+			self.0.call_once(|| value.take().expect("unreachable"));
+			value.map_or(Ok(()), Err)
+		}
+	}
+
+	impl<T: Debug> Debug for OnceLock<T> {
+		fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+			Debug::fmt(&self.0, f)
+		}
+	}
+}
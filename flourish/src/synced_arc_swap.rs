@@ -0,0 +1,55 @@
+use alloc::sync::Arc;
+use core::{
+	fmt::{self, Debug, Formatter},
+	ops::Deref,
+};
+
+use arc_swap::ArcSwap;
+
+use crate::StoredSubscription;
+
+/// An [`ArcSwap`] that is kept in sync with a signal for as long as this handle exists.
+///
+/// Create it with [`Signal::to_arc_swap`](`crate::Signal::to_arc_swap`).  
+/// Dropping the handle stops the forwarding effect. Clones of [`SyncedArcSwap::arc_swap`]
+/// then keep the last stored value.
+///
+/// **The feature `"arc-swap"` is required to enable this type.**
+///
+/// Since 0.2.1.
+#[must_use = "The `ArcSwap` stops following the signal when this handle is dropped."]
+pub struct SyncedArcSwap<U> {
+	swap: Arc<ArcSwap<U>>,
+	_subscription: StoredSubscription<'static>,
+}
+
+impl<U> SyncedArcSwap<U> {
+	pub(crate) fn new(swap: Arc<ArcSwap<U>>, subscription: StoredSubscription<'static>) -> Self {
+		Self {
+			swap,
+			_subscription: subscription,
+		}
+	}
+
+	/// The shared [`ArcSwap`], for use apart from this handle.
+	#[must_use]
+	pub fn arc_swap(&self) -> &Arc<ArcSwap<U>> {
+		&self.swap
+	}
+}
+
+impl<U> Deref for SyncedArcSwap<U> {
+	type Target = ArcSwap<U>;
+
+	fn deref(&self) -> &Self::Target {
+		&self.swap
+	}
+}
+
+impl<U: Debug> Debug for SyncedArcSwap<U> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.debug_struct("SyncedArcSwap")
+			.field("swap", &self.swap)
+			.finish_non_exhaustive()
+	}
+}
@@ -1,7 +1,13 @@
-use std::{borrow::Borrow, future::Future, ops::Deref, pin::Pin};
+use alloc::{alloc::dealloc, boxed::Box};
+use core::{
+	alloc::Layout, borrow::Borrow, future::Future, mem::ManuallyDrop, ops::Deref, pin::Pin, ptr,
+};
 
 use isoprenoid::runtime::{Propagation, SignalsRuntimeRef};
 
+#[cfg(feature = "std")]
+use crate::LastPanic;
+
 //TODO: Revise "# Returns" documentation! Some is mismatched.
 
 /// "Unmanaged" (stack-pinnable) signals that have an accessible value.
@@ -27,6 +33,20 @@ pub trait UnmanagedSignal<T: ?Sized + Send, SR: ?Sized + SignalsRuntimeRef>: Sen
 		self.get_clone()
 	}
 
+	/// Records `self` as dependency and retrieves a copy of the value, iff it's initialised.
+	///
+	/// Signals that load their value asynchronously **may** report [`None`] while only holding a placeholder.
+	/// The default implementation always returns [`Some`], which is correct for computed signals.
+	///
+	/// Since 0.2.1.
+	#[must_use]
+	fn get_initialized(self: Pin<&Self>) -> Option<T>
+	where
+		T: Sync + Copy,
+	{
+		Some(self.get())
+	}
+
 	/// Records `self` as dependency and retrieves a clone of the value.
 	///
 	/// Prefer [`get`](`UnmanagedSignal::get`) where available.
@@ -63,6 +83,49 @@ pub trait UnmanagedSignal<T: ?Sized + Send, SR: ?Sized + SignalsRuntimeRef>: Sen
 		Self: 'r + Sized,
 		T: 'r + Sync;
 
+	/// Records `self` as dependency and allows borrowing the value, iff that's possible without blocking.
+	///
+	/// Returns [`None`] iff the value isn't available as-is, that is: if it wasn't computed yet, is stale,
+	/// is currently being updated or is locked, for example by an exclusive guard.
+	/// This makes it usable for best-effort reads in callbacks.
+	///
+	/// # Logic
+	///
+	/// Implementations **must not** (re)compute the value and **should not** block.  
+	/// They **should** record `self` as dependency only when returning [`Some`].
+	///
+	/// The default implementation conservatively always returns [`None`].
+	///
+	/// Since 0.2.1.
+	#[must_use]
+	fn try_read<'r>(self: Pin<&'r Self>) -> Option<Self::Read<'r>>
+	where
+		Self: Sized,
+		T: 'r + Sync,
+	{
+		None
+	}
+
+	/// Like [`read`](`UnmanagedSignal::read`), but also returns whether this read (re)computed the value
+	/// instead of serving it from the cache.
+	///
+	/// The default implementation reports whether `self` was [stale](`UnmanagedSignal::stale_symbol`)
+	/// right before reading. Signals that compute lazily also count their first read,
+	/// and uncached signals always report `true`.
+	///
+	/// Under concurrent access, another thread **may** refresh `self` in between, so the flag is advisory.
+	///
+	/// Since 0.2.1.
+	#[must_use]
+	fn read_with_freshness<'r>(self: Pin<&'r Self>) -> (Self::Read<'r>, bool)
+	where
+		Self: Sized,
+		T: 'r + Sync,
+	{
+		let refreshing = self.stale_symbol().is_some();
+		(self.read(), refreshing)
+	}
+
 	/// Records `self` as dependency and allows borrowing the value.
 	///
 	/// Prefer [`read`](`UnmanagedSignal::read`) where available.
@@ -111,6 +174,34 @@ pub trait UnmanagedSignal<T: ?Sized + Send, SR: ?Sized + SignalsRuntimeRef>: Sen
 	fn clone_runtime_ref(&self) -> SR
 	where
 		SR: Sized;
+
+	/// This signal's [`Symbol`](`SignalsRuntimeRef::Symbol`), iff its signals runtime currently considers it stale.
+	///
+	/// This is meant for debugging. The default implementation returns [`None`].
+	///
+	/// Since 0.2.1.
+	#[must_use]
+	fn stale_symbol(self: Pin<&Self>) -> Option<SR::Symbol> {
+		None
+	}
+}
+
+/// [`UnmanagedSignal`]s that catch panics of their computation instead of unwinding through the signals runtime.
+///
+/// # Features
+///
+/// **The feature `"std"` is required to enable this trait.**
+///
+/// Since 0.2.1.
+#[cfg(feature = "std")]
+pub trait UnmanagedSignalResilient<T: ?Sized + Send, SR: ?Sized + SignalsRuntimeRef>:
+	Send + Sync + UnmanagedSignal<T, SR>
+{
+	/// The panic state of the most recent computation.
+	///
+	/// This doesn't record a dependency or refresh the signal.
+	#[must_use]
+	fn last_error<'r>(self: Pin<&'r Self>) -> &'r LastPanic;
 }
 
 /// [`Cell`](`core::cell::Cell`)-likes that announce changes to their values to a [`SignalsRuntimeRef`].
@@ -469,4 +560,59 @@ pub trait UnmanagedSignalCell<T: ?Sized + Send, SR: ?Sized + SignalsRuntimeRef>:
 /// > ```
 /// >
 /// > See: <https://github.com/rust-lang/rust/issues/65078>
+///
+/// All built-in guards also implement [`AsRef<T>`], as does `dyn Guard<T>`.
 pub trait Guard<T: ?Sized>: Deref<Target = T> + Borrow<T> {}
+
+/// Lets boxed guards (`&*boxed`) interoperate with APIs expecting [`AsRef<T>`].
+impl<T: ?Sized> AsRef<T> for dyn '_ + Guard<T> {
+	fn as_ref(&self) -> &T {
+		self
+	}
+}
+
+/// Replaces the guard in `buf` with the one returned by `read`,
+/// reusing the previous allocation iff its layout matches.
+///
+/// The previous guard is dropped before `read` is called.
+pub(crate) fn emplace_guard<'r, T: ?Sized, G: 'r + Guard<T>>(
+	buf: &mut Option<Box<dyn 'r + Guard<T>>>,
+	read: impl FnOnce() -> G,
+) {
+	/// Deallocates the contained (uninitialised) allocation when dropped.
+	struct Allocation(*mut u8, Layout);
+	impl Drop for Allocation {
+		fn drop(&mut self) {
+			unsafe { dealloc(self.0, self.1) }
+		}
+	}
+
+	let layout = Layout::new::<G>();
+	let allocation = buf.take().and_then(|previous| {
+		if layout.size() != 0 && Layout::for_value(&*previous) == layout {
+			let ptr = Box::into_raw(previous);
+			unsafe {
+				// SAFETY: The allocation is released separately.
+				//         If this panics, the allocation is leaked.
+				ptr::drop_in_place(ptr);
+			}
+			Some(Allocation(ptr.cast::<u8>(), layout))
+		} else {
+			drop(previous);
+			None
+		}
+	});
+
+	let guard = read();
+	*buf = Some(match allocation {
+		Some(allocation) => {
+			let ptr = ManuallyDrop::new(allocation).0.cast::<G>();
+			unsafe {
+				// SAFETY: Allocated by the global allocator with the same layout as `G`.
+				ptr.write(guard);
+				Box::from_raw(ptr)
+			}
+		}
+		None => Box::new(guard),
+	});
+}
@@ -0,0 +1,94 @@
+use alloc::{boxed::Box, sync::Arc, task::Wake};
+use core::{
+	future::Future,
+	pin::Pin,
+	sync::atomic::{AtomicBool, Ordering},
+	task::{Context, Poll, Waker},
+};
+
+use crate::sync::Mutex;
+
+/// Drives an injected sleeper [`Future`] without an executor, by polling it from its own [`Waker`].
+///
+/// `expire` runs (at most) once, when the sleeper completes without being cancelled first.
+///
+/// This is the timer behind [`Signal::computed_ttl`](`crate::Signal::computed_ttl`),
+/// exposed for signals that schedule their own expiry.
+///
+/// Since 0.2.1.
+pub struct Timer {
+	sleep: Mutex<Option<Pin<Box<dyn Send + Future<Output = ()>>>>>,
+	repoll: AtomicBool,
+	expire: Mutex<Option<Box<dyn Send + FnOnce()>>>,
+}
+
+impl Timer {
+	/// Polls `sleep` once right away and then whenever it wakes itself, calling `expire` once it completes.
+	///
+	/// The sleeper keeps the timer alive while it's pending.
+	/// Dropping the returned [`Arc`] doesn't stop it; use [`Timer::cancel`] for that.
+	pub fn start(
+		sleep: impl 'static + Send + Future<Output = ()>,
+		expire: impl 'static + Send + FnOnce(),
+	) -> Arc<Self> {
+		let this = Arc::new(Self {
+			sleep: Mutex::new(Some(Box::pin(sleep))),
+			repoll: AtomicBool::new(false),
+			expire: Mutex::new(Some(Box::new(expire))),
+		});
+		this.wake_by_ref();
+		this
+	}
+
+	/// Drops the sleeper and `expire` callback.
+	///
+	/// If the sleeper is currently being polled, it is dropped by that poller instead.
+	///
+	/// # Panics
+	///
+	/// Iff dropping the `expire` callback panicked before.
+	pub fn cancel(&self) {
+		drop(self.expire.lock().unwrap().take());
+		if let Ok(mut sleep) = self.sleep.try_lock() {
+			drop(sleep.take());
+		}
+	}
+}
+
+impl Wake for Timer {
+	fn wake(self: Arc<Self>) {
+		self.wake_by_ref();
+	}
+
+	fn wake_by_ref(self: &Arc<Self>) {
+		self.repoll.store(true, Ordering::Release);
+		loop {
+			// If this fails, the current poller will see `repoll` after releasing the lock.
+			let Ok(mut sleep) = self.sleep.try_lock() else {
+				return;
+			};
+			self.repoll.store(false, Ordering::Relaxed);
+
+			if self.expire.lock().unwrap().is_none() {
+				// Cancelled.
+				drop(sleep.take());
+			} else if let Some(f) = sleep.as_mut() {
+				let waker = Waker::from(Arc::clone(self));
+				if let Poll::Ready(()) = f.as_mut().poll(&mut Context::from_waker(&waker)) {
+					drop(sleep.take());
+					drop(sleep);
+					let expire = self.expire.lock().unwrap().take();
+					if let Some(expire) = expire {
+						expire();
+					}
+					return;
+				}
+			}
+
+			drop(sleep);
+			if !self.repoll.load(Ordering::Acquire) {
+				return;
+			}
+		}
+	}
+}
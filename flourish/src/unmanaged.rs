@@ -10,13 +10,26 @@
 
 use isoprenoid::runtime::{CallbackTableTypes, Propagation, SignalsRuntimeRef};
 
+#[cfg(feature = "std")]
+pub use crate::traits::UnmanagedSignalResilient;
 pub use crate::traits::{UnmanagedSignal, UnmanagedSignalCell};
 
 mod cached;
 pub(crate) use cached::Cached;
 
 mod computed;
-pub(crate) use computed::Computed;
+pub(crate) use computed::{Computed, Reactive, WhileSubscribed};
+
+mod computed_evictable;
+pub(crate) use computed_evictable::ComputedEvictable;
+
+#[cfg(feature = "std")]
+mod computed_resilient;
+#[cfg(feature = "std")]
+pub(crate) use computed_resilient::ComputedResilient;
+
+mod computed_slice;
+pub(crate) use computed_slice::ComputedSlice;
 
 mod computed_uncached;
 pub(crate) use computed_uncached::ComputedUncached;
@@ -39,6 +52,9 @@ pub(crate) use reactive_cell_mut::ReactiveCellMut;
 mod folded;
 pub(crate) use folded::Folded;
 
+mod lens;
+pub(crate) use lens::Lens;
+
 //TODO?: folded_emplaced
 //TODO?: folded_with
 
@@ -1,9 +1,5 @@
-use std::{
-	borrow::Borrow,
-	ops::Deref,
-	pin::Pin,
-	sync::{RwLock, RwLockReadGuard, RwLockWriteGuard},
-};
+use alloc::boxed::Box;
+use core::{borrow::Borrow, ops::Deref, pin::Pin};
 
 use isoprenoid::{
 	raw::{Callbacks, RawSignal},
@@ -12,7 +8,10 @@ use isoprenoid::{
 };
 use pin_project::pin_project;
 
-use crate::traits::{Guard, UnmanagedSignal};
+use crate::{
+	sync::{RwLock, RwLockReadGuard, RwLockWriteGuard, TryLockError},
+	traits::{Guard, UnmanagedSignal},
+};
 
 #[pin_project]
 #[must_use = "Signals do nothing unless they are polled or subscribed to."]
@@ -52,12 +51,24 @@ impl<'a, T: ?Sized> Borrow<T> for CachedGuard<'a, T> {
 	}
 }
 
+impl<'a, T: ?Sized> AsRef<T> for CachedGuard<'a, T> {
+	fn as_ref(&self) -> &T {
+		self
+	}
+}
+
 impl<'a, T: ?Sized> Borrow<T> for CachedGuardExclusive<'a, T> {
 	fn borrow(&self) -> &T {
 		self.0.borrow()
 	}
 }
 
+impl<'a, T: ?Sized> AsRef<T> for CachedGuardExclusive<'a, T> {
+	fn as_ref(&self) -> &T {
+		self
+	}
+}
+
 // TODO: Safety documentation.
 unsafe impl<T: Send + Clone, S: UnmanagedSignal<T, SR>, SR: SignalsRuntimeRef + Sync> Sync
 	for Cached<T, S, SR>
@@ -83,6 +94,12 @@ impl<T: Send + Clone, S: UnmanagedSignal<T, SR>, SR: SignalsRuntimeRef> Cached<T
 				.0
 		}
 	}
+
+	/// Like [`Self::touch`], but never (re)computes the value, returning [`None`] instead.
+	pub(crate) fn try_touch(self: Pin<&Self>) -> Option<Pin<&RwLock<T>>> {
+		let (_, cache) = self.project_ref().0.project_if_fresh()?;
+		Some(cache.project_ref().0)
+	}
 }
 
 enum E {}
@@ -166,6 +183,19 @@ impl<T: Send + Clone, S: UnmanagedSignal<T, SR>, SR: SignalsRuntimeRef> Unmanage
 		Self: 'r + Sized,
 		T: Sync;
 
+	fn try_read<'r>(self: Pin<&'r Self>) -> Option<CachedGuard<'r, T>>
+	where
+		Self: Sized,
+		T: 'r + Sync,
+	{
+		let touch = unsafe { Pin::into_inner_unchecked(self.try_touch()?) };
+		match touch.try_read() {
+			Ok(guard) => Some(CachedGuard(guard)),
+			Err(TryLockError::WouldBlock) => None,
+			Err(TryLockError::Poisoned(error)) => panic!("{error}"),
+		}
+	}
+
 	fn read_exclusive<'r>(self: Pin<&'r Self>) -> CachedGuardExclusive<'r, T>
 	where
 		Self: Sized,
@@ -202,6 +232,19 @@ impl<T: Send + Clone, S: UnmanagedSignal<T, SR>, SR: SignalsRuntimeRef> Unmanage
 		self.0.clone_runtime_ref()
 	}
 
+	fn stale_symbol(self: Pin<&Self>) -> Option<SR::Symbol> {
+		self.0.is_stale().then(|| self.0.id())
+	}
+
+	fn read_with_freshness<'r>(self: Pin<&'r Self>) -> (Self::Read<'r>, bool)
+	where
+		Self: Sized,
+		T: 'r + Sync,
+	{
+		let refreshing = !self.0.is_initialised() || self.0.is_stale();
+		(self.read(), refreshing)
+	}
+
 	fn subscribe(self: Pin<&Self>) {
 		let signal = self.project_ref().0;
 		signal.subscribe();
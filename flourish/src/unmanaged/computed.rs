@@ -1,31 +1,149 @@
-use std::{
+use alloc::boxed::Box;
+use core::{
 	borrow::Borrow,
 	ops::Deref,
 	pin::Pin,
-	sync::{Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard},
+	sync::atomic::{AtomicBool, Ordering},
 };
 
 use isoprenoid::{
 	raw::{Callbacks, RawSignal},
-	runtime::{CallbackTableTypes, Propagation, SignalsRuntimeRef},
+	runtime::{CallbackTableTypes, Propagation, SignalsRuntimeRef, SubscriberCount},
 	slot::{Slot, Token},
 };
 use pin_project::pin_project;
 
-use crate::traits::{Guard, UnmanagedSignal};
+use crate::{
+	sync::{Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard, TryLockError},
+	traits::{Guard, UnmanagedSignal},
+	StaleRead,
+};
 
+/// With a [`Gate`] `G` other than `()`, the computation can be suspended depending on subscription status.
 #[pin_project]
 #[must_use = "Signals do nothing unless they are polled or subscribed to."]
-pub(crate) struct Computed<T: Send, F: Send + FnMut() -> T, SR: SignalsRuntimeRef>(
-	#[pin] RawSignal<ForceSyncUnpin<Mutex<F>>, ForceSyncUnpin<RwLock<T>>, SR>,
+pub(crate) struct Computed<T: Send, F: Send + FnMut() -> T, SR: SignalsRuntimeRef, G: Gate<SR> = ()>(
+	#[pin] Raw<T, F, SR, G>,
+	StaleRead,
 );
 
+type Raw<T, F, SR, G> = RawSignal<ForceSyncUnpin<Eager<F, G>>, ForceSyncUnpin<RwLock<T>>, SR>;
+
 #[pin_project]
 struct ForceSyncUnpin<T: ?Sized>(#[pin] T);
 unsafe impl<T: ?Sized> Sync for ForceSyncUnpin<T> {}
 
-pub(crate) struct ComputedGuard<'a, T: ?Sized>(RwLockReadGuard<'a, T>);
-pub(crate) struct ComputedGuardExclusive<'a, T: ?Sized>(RwLockWriteGuard<'a, T>);
+struct Eager<F, G> {
+	fn_pin: Mutex<F>,
+	gate: G,
+}
+
+/// Decides whether a [`Computed`] recomputes when refreshed.
+pub(crate) trait Gate<SR: SignalsRuntimeRef>: Send + Sync {
+	/// Whether [`Gate::on_subscribed_change`] is registered with the signals runtime.
+	const GATED: bool;
+
+	/// Whether the value may be recomputed right now.
+	fn is_open(&self) -> bool;
+
+	/// Called instead of recomputing while [closed](`Gate::is_open`).
+	fn skip(&self);
+
+	/// Returns `Some(true)` iff the value should catch up now (recording dependencies again),
+	/// `Some(false)` iff all dependencies should be dropped.
+	fn on_subscribed_change(
+		&self,
+		status: <SR::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus,
+	) -> Option<bool>;
+}
+
+/// Always recomputes.
+impl<SR: SignalsRuntimeRef> Gate<SR> for () {
+	const GATED: bool = false;
+
+	fn is_open(&self) -> bool {
+		true
+	}
+
+	fn skip(&self) {}
+
+	fn on_subscribed_change(
+		&self,
+		_: <SR::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus,
+	) -> Option<bool> {
+		None
+	}
+}
+
+/// Always recomputes, and calls a handler on each subscription status change.
+pub(crate) struct Reactive<H>(pub(crate) Mutex<H>);
+
+impl<
+		SR: SignalsRuntimeRef,
+		H: Send + FnMut(<SR::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus),
+	> Gate<SR> for Reactive<H>
+{
+	const GATED: bool = true;
+
+	fn is_open(&self) -> bool {
+		true
+	}
+
+	fn skip(&self) {}
+
+	fn on_subscribed_change(
+		&self,
+		status: <SR::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus,
+	) -> Option<bool> {
+		self.0.lock().unwrap()(status);
+		None
+	}
+}
+
+/// Recomputes only while subscribed.
+///
+/// When its last subscriber is removed, the signal drops all dependencies and holds its value.
+/// When it becomes subscribed again, it recomputes once immediately to catch up.
+#[derive(Default)]
+pub(crate) struct WhileSubscribed {
+	subscribed: AtomicBool,
+	/// Set whenever an update was skipped (or dependencies were dropped) while unsubscribed.
+	outdated: AtomicBool,
+}
+
+impl<SR: SignalsRuntimeRef> Gate<SR> for WhileSubscribed
+where
+	<SR::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus: SubscriberCount,
+{
+	const GATED: bool = true;
+
+	fn is_open(&self) -> bool {
+		self.subscribed.load(Ordering::Acquire)
+	}
+
+	fn skip(&self) {
+		self.outdated.store(true, Ordering::Release);
+	}
+
+	fn on_subscribed_change(
+		&self,
+		status: <SR::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus,
+	) -> Option<bool> {
+		let subscribed = status.is_subscribed();
+		if self.subscribed.swap(subscribed, Ordering::AcqRel) == subscribed {
+			None
+		} else if subscribed {
+			// Otherwise just initialised or still current.
+			self.outdated.swap(false, Ordering::AcqRel).then_some(true)
+		} else {
+			self.outdated.store(true, Ordering::Release);
+			Some(false)
+		}
+	}
+}
+
+pub(crate) struct ComputedGuard<'a, T: ?Sized>(pub(super) RwLockReadGuard<'a, T>);
+pub(crate) struct ComputedGuardExclusive<'a, T: ?Sized>(pub(super) RwLockWriteGuard<'a, T>);
 
 impl<'a, T: ?Sized> Guard<T> for ComputedGuard<'a, T> {}
 impl<'a, T: ?Sized> Guard<T> for ComputedGuardExclusive<'a, T> {}
@@ -52,88 +170,225 @@ impl<'a, T: ?Sized> Borrow<T> for ComputedGuard<'a, T> {
 	}
 }
 
+impl<'a, T: ?Sized> AsRef<T> for ComputedGuard<'a, T> {
+	fn as_ref(&self) -> &T {
+		self
+	}
+}
+
 impl<'a, T: ?Sized> Borrow<T> for ComputedGuardExclusive<'a, T> {
 	fn borrow(&self) -> &T {
 		self.0.borrow()
 	}
 }
 
+impl<'a, T: ?Sized> AsRef<T> for ComputedGuardExclusive<'a, T> {
+	fn as_ref(&self) -> &T {
+		self
+	}
+}
+
 // TODO: Safety documentation.
-unsafe impl<T: Send, F: Send + FnMut() -> T, SR: SignalsRuntimeRef + Sync> Sync
-	for Computed<T, F, SR>
+unsafe impl<T: Send, F: Send + FnMut() -> T, SR: SignalsRuntimeRef + Sync, G: Gate<SR>> Sync
+	for Computed<T, F, SR, G>
 {
 }
 
 impl<T: Send, F: Send + FnMut() -> T, SR: SignalsRuntimeRef> Computed<T, F, SR> {
 	pub(crate) fn new(fn_pin: F, runtime: SR) -> Self {
-		Self(RawSignal::with_runtime(
-			ForceSyncUnpin(fn_pin.into()),
-			runtime,
-		))
+		Self::with_stale_read(fn_pin, StaleRead::Refresh, runtime)
+	}
+
+	pub(crate) fn with_stale_read(fn_pin: F, stale_read: StaleRead, runtime: SR) -> Self {
+		Self::with_gate(fn_pin, (), stale_read, runtime)
+	}
+}
+
+impl<T: Send, F: Send + FnMut() -> T, SR: SignalsRuntimeRef, G: Gate<SR>> Computed<T, F, SR, G> {
+	pub(crate) fn with_gate(fn_pin: F, gate: G, stale_read: StaleRead, runtime: SR) -> Self {
+		Self(
+			RawSignal::with_runtime(
+				ForceSyncUnpin(Eager {
+					fn_pin: fn_pin.into(),
+					gate,
+				}),
+				runtime,
+			),
+			stale_read,
+		)
+	}
+
+	#[cfg(debug_assertions)]
+	pub(crate) fn set_dependency_limit(&self, limit: usize) {
+		self.0.set_dependency_limit(limit);
+	}
+
+	pub(crate) fn set_notification_filter(
+		&self,
+		should_notify: Box<dyn 'static + Send + Sync + Fn(SR::Symbol) -> bool>,
+	) {
+		self.0.set_notification_filter(should_notify);
 	}
 
 	pub(crate) fn touch(self: Pin<&Self>) -> Pin<&RwLock<T>> {
-		unsafe {
-			self.project_ref()
-				.0
-				.project_or_init::<E>(|fn_pin, cache| Self::init(fn_pin, cache))
-				.1
-				.project_ref()
-				.0
+		self.touch_reporting().0
+	}
+
+	/// Like [`Self::touch`], but also returns whether this call (re)computed the value.
+	///
+	/// A closed [`Gate`] skips the refresh, so the held value isn't recomputed then.
+	pub(crate) fn touch_reporting(self: Pin<&Self>) -> (Pin<&RwLock<T>>, bool) {
+		let this = self.project_ref();
+		match this.1 {
+			StaleRead::Refresh => {
+				// The gate's skip returns `Propagation::Halt`, which isn't reported as recomputation.
+				let (_, cache, recomputed) =
+					this.0
+						.project_or_init_reporting::<E>(|eager, cache| unsafe {
+							Self::init(eager, cache)
+						});
+				(cache.project_ref().0, recomputed)
+			}
+			StaleRead::ReturnStale => {
+				let mut initialised = false;
+				let (_, cache) = this.0.project_or_init_stale::<E>(|eager, cache| {
+					initialised = true;
+					unsafe { Self::init(eager, cache) }
+				});
+				(cache.project_ref().0, initialised)
+			}
 		}
 	}
+
+	/// Modifies the cached value through a (possibly enqueued) update that doesn't propagate.
+	///
+	/// Does nothing iff the value wasn't computed yet.
+	pub(crate) fn update_cache(self: Pin<&Self>, update: impl 'static + Send + FnOnce(&mut T))
+	where
+		T: 'static,
+		F: 'static,
+		SR: 'static,
+		G: 'static,
+	{
+		let signal = self.project_ref().0;
+		if signal.is_initialised() {
+			signal.update(|_, cache| {
+				if let Some(cache) = cache {
+					update(&mut cache.project_ref().0.write().unwrap());
+				}
+				Propagation::Halt
+			});
+		}
+	}
+
+	/// Recomputes the cached value in place, recording dependencies as usual.
+	///
+	/// The value **must** have been computed before.
+	pub(crate) fn recompute(self: Pin<&Self>) {
+		self.project_ref().0.update_dependency_set(|eager, cache| {
+			let new_value = eager.0.fn_pin.lock().unwrap()();
+			*cache.project_ref().0.write().unwrap() = new_value;
+		});
+	}
+
+	/// Like [`Self::touch`], but never (re)computes the value, returning [`None`] instead.
+	pub(crate) fn try_touch(self: Pin<&Self>) -> Option<Pin<&RwLock<T>>> {
+		let (_, cache) = self.project_ref().0.project_if_fresh()?;
+		Some(cache.project_ref().0)
+	}
 }
 
 enum E {}
-impl<T: Send, F: Send + FnMut() -> T, SR: SignalsRuntimeRef>
-	Callbacks<ForceSyncUnpin<Mutex<F>>, ForceSyncUnpin<RwLock<T>>, SR> for E
+impl<T: Send, F: Send + FnMut() -> T, SR: SignalsRuntimeRef, G: Gate<SR>>
+	Callbacks<ForceSyncUnpin<Eager<F, G>>, ForceSyncUnpin<RwLock<T>>, SR> for E
 {
 	const UPDATE: Option<
 		fn(
-			eager: Pin<&ForceSyncUnpin<Mutex<F>>>,
+			eager: Pin<&ForceSyncUnpin<Eager<F, G>>>,
 			lazy: Pin<&ForceSyncUnpin<RwLock<T>>>,
 		) -> Propagation,
 	> = {
-		fn eval<T: Send, F: Send + FnMut() -> T>(
-			fn_pin: Pin<&ForceSyncUnpin<Mutex<F>>>,
+		fn eval<T: Send, F: Send + FnMut() -> T, SR: SignalsRuntimeRef, G: Gate<SR>>(
+			eager: Pin<&ForceSyncUnpin<Eager<F, G>>>,
 			cache: Pin<&ForceSyncUnpin<RwLock<T>>>,
 		) -> Propagation {
+			let eager = &eager.0;
+			if !eager.gate.is_open() {
+				// Not reading anything here also drops all dependencies,
+				// so the signal won't be marked stale again until it's subscribed.
+				eager.gate.skip();
+				return Propagation::Halt;
+			}
 			//FIXME: This is externally synchronised already.
-			let new_value = fn_pin.project_ref().0.try_lock().expect("unreachable")();
+			let new_value = eager.fn_pin.try_lock().expect("unreachable")();
 			*cache.project_ref().0.write().unwrap() = new_value;
 			Propagation::Propagate
 		}
-		Some(eval)
+		Some(eval::<T, F, SR, G>)
 	};
 
 	const ON_SUBSCRIBED_CHANGE: Option<
 		fn(
-			source: Pin<&RawSignal<ForceSyncUnpin<Mutex<F>>, ForceSyncUnpin<RwLock<T>>, SR>>,
-			eager: Pin<&ForceSyncUnpin<Mutex<F>>>,
+			source: Pin<&Raw<T, F, SR, G>>,
+			eager: Pin<&ForceSyncUnpin<Eager<F, G>>>,
 			lazy: Pin<&ForceSyncUnpin<RwLock<T>>>,
 			subscribed: <SR::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus,
 		) -> Propagation,
-	> = None;
+	> = {
+		fn on_subscribed_change<
+			T: Send,
+			F: Send + FnMut() -> T,
+			SR: SignalsRuntimeRef,
+			G: Gate<SR>,
+		>(
+			source: Pin<&Raw<T, F, SR, G>>,
+			eager: Pin<&ForceSyncUnpin<Eager<F, G>>>,
+			_: Pin<&ForceSyncUnpin<RwLock<T>>>,
+			subscribed: <SR::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus,
+		) -> Propagation {
+			match eager.0.gate.on_subscribed_change(subscribed) {
+				None => Propagation::Halt,
+				Some(true) => {
+					// Catch up right away, recording dependencies again.
+					source.update_dependency_set(|eager, cache| {
+						let new_value = eager.0.fn_pin.lock().unwrap()();
+						*cache.project_ref().0.write().unwrap() = new_value;
+					});
+					Propagation::Propagate
+				}
+				Some(false) => {
+					// Drop all dependencies, so that this signal doesn't go stale while unsubscribed.
+					source.update_dependency_set(|_, _| ());
+					Propagation::Halt
+				}
+			}
+		}
+		if G::GATED {
+			Some(on_subscribed_change::<T, F, SR, G>)
+		} else {
+			None
+		}
+	};
 }
 
 /// # Safety
 ///
 /// These are the only functions that access `cache`.
 /// Externally synchronised through guarantees on [`isoprenoid::raw::Callbacks`].
-impl<T: Send, F: Send + FnMut() -> T, SR: SignalsRuntimeRef> Computed<T, F, SR> {
+impl<T: Send, F: Send + FnMut() -> T, SR: SignalsRuntimeRef, G: Gate<SR>> Computed<T, F, SR, G> {
 	unsafe fn init<'a>(
-		fn_pin: Pin<&'a ForceSyncUnpin<Mutex<F>>>,
+		eager: Pin<&'a ForceSyncUnpin<Eager<F, G>>>,
 		cache: Slot<'a, ForceSyncUnpin<RwLock<T>>>,
 	) -> Token<'a> {
 		cache.write(ForceSyncUnpin(
 			//FIXME: This is technically already externally synchronised.
-			fn_pin.project_ref().0.try_lock().expect("unreachable")().into(),
+			eager.0.fn_pin.try_lock().expect("unreachable")().into(),
 		))
 	}
 }
 
-impl<T: Send, F: Send + FnMut() -> T, SR: SignalsRuntimeRef> UnmanagedSignal<T, SR>
-	for Computed<T, F, SR>
+impl<T: Send, F: Send + FnMut() -> T, SR: SignalsRuntimeRef, G: Gate<SR>> UnmanagedSignal<T, SR>
+	for Computed<T, F, SR, G>
 {
 	fn touch(self: Pin<&Self>) {
 		self.touch();
@@ -168,6 +423,19 @@ impl<T: Send, F: Send + FnMut() -> T, SR: SignalsRuntimeRef> UnmanagedSignal<T,
 		Self: 'r + Sized,
 		T: 'r + Sync;
 
+	fn try_read<'r>(self: Pin<&'r Self>) -> Option<ComputedGuard<'r, T>>
+	where
+		Self: Sized,
+		T: 'r + Sync,
+	{
+		let touch = unsafe { Pin::into_inner_unchecked(self.try_touch()?) };
+		match touch.try_read() {
+			Ok(guard) => Some(ComputedGuard(guard)),
+			Err(TryLockError::WouldBlock) => None,
+			Err(TryLockError::Poisoned(error)) => panic!("{error}"),
+		}
+	}
+
 	fn read_exclusive<'r>(self: Pin<&'r Self>) -> ComputedGuardExclusive<'r, T>
 	where
 		Self: Sized,
@@ -204,11 +472,24 @@ impl<T: Send, F: Send + FnMut() -> T, SR: SignalsRuntimeRef> UnmanagedSignal<T,
 		self.0.clone_runtime_ref()
 	}
 
+	fn stale_symbol(self: Pin<&Self>) -> Option<SR::Symbol> {
+		self.0.is_stale().then(|| self.0.id())
+	}
+
+	fn read_with_freshness<'r>(self: Pin<&'r Self>) -> (Self::Read<'r>, bool)
+	where
+		Self: Sized,
+		T: 'r + Sync,
+	{
+		let (_, refreshing) = self.touch_reporting();
+		(self.read(), refreshing)
+	}
+
 	fn subscribe(self: Pin<&Self>) {
 		let signal = self.project_ref().0;
 		signal.subscribe();
 		signal.clone_runtime_ref().run_detached(|| {
-			signal.project_or_init::<E>(|fn_pin, cache| unsafe { Self::init(fn_pin, cache) })
+			signal.project_or_init::<E>(|eager, cache| unsafe { Self::init(eager, cache) })
 		});
 	}
 
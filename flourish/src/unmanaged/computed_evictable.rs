@@ -0,0 +1,230 @@
+use alloc::boxed::Box;
+use core::{borrow::Borrow, ops::Deref, pin::Pin};
+
+use isoprenoid::runtime::SignalsRuntimeRef;
+use pin_project::pin_project;
+
+use crate::{
+	sync::{RwLock, RwLockReadGuard, RwLockWriteGuard, TryLockError},
+	traits::{Guard, UnmanagedSignal},
+};
+
+use super::Computed;
+
+/// A [`Computed`] whose cache can be cleared while it remains valid.
+///
+/// An evicted cache is [`None`] until it's recomputed on the next access.
+#[pin_project]
+#[must_use = "Signals do nothing unless they are polled or subscribed to."]
+pub(crate) struct ComputedEvictable<T: Send, F: Send + FnMut() -> Option<T>, SR: SignalsRuntimeRef>(
+	#[pin] Computed<Option<T>, F, SR>,
+);
+
+/// Only created from a cache that is [`Some`].
+pub(crate) struct ComputedEvictableGuard<'a, T>(RwLockReadGuard<'a, Option<T>>);
+/// Only created from a cache that is [`Some`].
+pub(crate) struct ComputedEvictableGuardExclusive<'a, T>(RwLockWriteGuard<'a, Option<T>>);
+
+impl<'a, T> Guard<T> for ComputedEvictableGuard<'a, T> {}
+impl<'a, T> Guard<T> for ComputedEvictableGuardExclusive<'a, T> {}
+
+impl<'a, T> Deref for ComputedEvictableGuard<'a, T> {
+	type Target = T;
+
+	fn deref(&self) -> &Self::Target {
+		self.0.as_ref().expect("unreachable")
+	}
+}
+
+impl<'a, T> Deref for ComputedEvictableGuardExclusive<'a, T> {
+	type Target = T;
+
+	fn deref(&self) -> &Self::Target {
+		self.0.as_ref().expect("unreachable")
+	}
+}
+
+impl<'a, T> Borrow<T> for ComputedEvictableGuard<'a, T> {
+	fn borrow(&self) -> &T {
+		self
+	}
+}
+
+impl<'a, T> AsRef<T> for ComputedEvictableGuard<'a, T> {
+	fn as_ref(&self) -> &T {
+		self
+	}
+}
+
+impl<'a, T> Borrow<T> for ComputedEvictableGuardExclusive<'a, T> {
+	fn borrow(&self) -> &T {
+		self
+	}
+}
+
+impl<'a, T> AsRef<T> for ComputedEvictableGuardExclusive<'a, T> {
+	fn as_ref(&self) -> &T {
+		self
+	}
+}
+
+impl<T: Send, F: Send + FnMut() -> Option<T>, SR: SignalsRuntimeRef> ComputedEvictable<T, F, SR> {
+	/// `fn_pin` **must** always return [`Some`].
+	pub(crate) fn new(fn_pin: F, runtime: SR) -> Self {
+		Self(Computed::new(fn_pin, runtime))
+	}
+
+	/// Drops the cached value, if any. It's recomputed when next accessed.
+	///
+	/// The cache is cleared through a (possibly enqueued) update that doesn't propagate,
+	/// so the caller should additionally mark `self` stale iff it may be subscribed.
+	pub(crate) fn evict(self: Pin<&Self>)
+	where
+		T: 'static,
+		F: 'static,
+		SR: 'static,
+	{
+		self.project_ref()
+			.0
+			.update_cache(|cache| drop(cache.take()));
+	}
+
+	/// Returns the cache, which is [`Some`] at least while the returned lock is held afterwards,
+	/// and whether the value was (re)computed.
+	fn touch(self: Pin<&Self>) -> (Pin<&RwLock<Option<T>>>, bool) {
+		let computed = self.project_ref().0;
+		let (cache, mut recomputed) = computed.touch_reporting();
+		if cache.read().unwrap().is_none() {
+			// Evicted and not refreshed yet, so recompute here, recording dependencies as usual.
+			recomputed = true;
+			computed.recompute();
+		}
+		(cache, recomputed)
+	}
+
+	/// Loops in the unlikely case that the cache is evicted again between [`Self::touch`] and locking it.
+	fn read_cache<'r, G>(
+		self: Pin<&'r Self>,
+		lock: impl Fn(&'r RwLock<Option<T>>) -> Option<G>,
+	) -> G {
+		loop {
+			let cache = unsafe { Pin::into_inner_unchecked(self.touch().0) };
+			if let Some(guard) = lock(cache) {
+				break guard;
+			}
+		}
+	}
+}
+
+impl<T: Send, F: Send + FnMut() -> Option<T>, SR: SignalsRuntimeRef> UnmanagedSignal<T, SR>
+	for ComputedEvictable<T, F, SR>
+{
+	fn touch(self: Pin<&Self>) {
+		self.touch();
+	}
+
+	fn get_clone(self: Pin<&Self>) -> T
+	where
+		T: Sync + Clone,
+	{
+		self.read().clone()
+	}
+
+	fn get_clone_exclusive(self: Pin<&Self>) -> T
+	where
+		T: Clone,
+	{
+		self.read_exclusive().clone()
+	}
+
+	fn read<'r>(self: Pin<&'r Self>) -> ComputedEvictableGuard<'r, T>
+	where
+		Self: Sized,
+		T: 'r + Sync,
+	{
+		self.read_cache(|cache| {
+			let guard = cache.read().unwrap();
+			guard.is_some().then(|| ComputedEvictableGuard(guard))
+		})
+	}
+
+	type Read<'r>
+		= ComputedEvictableGuard<'r, T>
+	where
+		Self: 'r + Sized,
+		T: 'r + Sync;
+
+	fn try_read<'r>(self: Pin<&'r Self>) -> Option<ComputedEvictableGuard<'r, T>>
+	where
+		Self: Sized,
+		T: 'r + Sync,
+	{
+		let cache = unsafe { Pin::into_inner_unchecked(self.project_ref().0.try_touch()?) };
+		match cache.try_read() {
+			Ok(guard) => guard.is_some().then(|| ComputedEvictableGuard(guard)),
+			Err(TryLockError::WouldBlock) => None,
+			Err(TryLockError::Poisoned(error)) => panic!("{error}"),
+		}
+	}
+
+	fn read_exclusive<'r>(self: Pin<&'r Self>) -> ComputedEvictableGuardExclusive<'r, T>
+	where
+		Self: Sized,
+		T: 'r,
+	{
+		self.read_cache(|cache| {
+			let guard = cache.write().unwrap();
+			guard
+				.is_some()
+				.then(|| ComputedEvictableGuardExclusive(guard))
+		})
+	}
+
+	type ReadExclusive<'r>
+		= ComputedEvictableGuardExclusive<'r, T>
+	where
+		Self: 'r + Sized,
+		T: 'r;
+
+	fn read_dyn<'r>(self: Pin<&'r Self>) -> Box<dyn 'r + Guard<T>>
+	where
+		T: 'r + Sync,
+	{
+		Box::new(self.read())
+	}
+
+	fn read_exclusive_dyn<'r>(self: Pin<&'r Self>) -> Box<dyn 'r + Guard<T>>
+	where
+		T: 'r,
+	{
+		Box::new(self.read_exclusive())
+	}
+
+	fn clone_runtime_ref(&self) -> SR
+	where
+		SR: Sized,
+	{
+		self.0.clone_runtime_ref()
+	}
+
+	fn stale_symbol(self: Pin<&Self>) -> Option<SR::Symbol> {
+		self.project_ref().0.stale_symbol()
+	}
+
+	fn read_with_freshness<'r>(self: Pin<&'r Self>) -> (Self::Read<'r>, bool)
+	where
+		Self: Sized,
+		T: 'r + Sync,
+	{
+		let (_, refreshing) = self.touch();
+		(self.read(), refreshing)
+	}
+
+	fn subscribe(self: Pin<&Self>) {
+		self.project_ref().0.subscribe();
+	}
+
+	fn unsubscribe(self: Pin<&Self>) {
+		self.project_ref().0.unsubscribe();
+	}
+}
@@ -0,0 +1,332 @@
+use alloc::boxed::Box;
+use core::{borrow::Borrow, cell::UnsafeCell, ops::Deref, pin::Pin};
+use std::panic::{self, AssertUnwindSafe};
+
+use isoprenoid::{
+	raw::{Callbacks, RawSignal},
+	runtime::{CallbackTableTypes, Propagation, SignalsRuntimeRef},
+	slot::{Slot, Token},
+};
+use pin_project::pin_project;
+
+use crate::{
+	sync::{RwLock, RwLockReadGuard, RwLockWriteGuard, TryLockError},
+	traits::{Guard, UnmanagedSignal, UnmanagedSignalResilient},
+	LastPanic,
+};
+
+#[pin_project]
+#[must_use = "Signals do nothing unless they are polled or subscribed to."]
+pub(crate) struct ComputedResilient<T: Send, F: Send + FnMut() -> T, SR: SignalsRuntimeRef>(
+	#[pin] RawSignal<ForceSyncUnpin<Eager<T, F, SR>>, (), SR>,
+);
+
+#[pin_project]
+struct ForceSyncUnpin<T: ?Sized>(T);
+unsafe impl<T: ?Sized> Sync for ForceSyncUnpin<T> {}
+
+struct Eager<T, F: ?Sized, SR> {
+	/// [`None`] until the first successful run.
+	value: RwLock<Option<T>>,
+	last_error: LastPanic,
+	/// To keep the previous dependencies when `fn_pin` panics.
+	runtime: SR,
+	fn_pin: UnsafeCell<F>,
+}
+
+const NO_VALUE: &str =
+	"This resilient signal has no value yet, since its computation never succeeded.";
+
+pub(crate) struct ComputedResilientGuard<'a, T>(RwLockReadGuard<'a, Option<T>>);
+pub(crate) struct ComputedResilientGuardExclusive<'a, T>(RwLockWriteGuard<'a, Option<T>>);
+
+impl<'a, T> ComputedResilientGuard<'a, T> {
+	fn new(guard: RwLockReadGuard<'a, Option<T>>) -> Self {
+		if guard.is_none() {
+			// Unlocks first, so that this doesn't poison the lock.
+			drop(guard);
+			panic!("{NO_VALUE}");
+		}
+		Self(guard)
+	}
+}
+
+impl<'a, T> ComputedResilientGuardExclusive<'a, T> {
+	fn new(guard: RwLockWriteGuard<'a, Option<T>>) -> Self {
+		if guard.is_none() {
+			// Unlocks first, so that this doesn't poison the lock.
+			drop(guard);
+			panic!("{NO_VALUE}");
+		}
+		Self(guard)
+	}
+}
+
+impl<'a, T> Guard<T> for ComputedResilientGuard<'a, T> {}
+impl<'a, T> Guard<T> for ComputedResilientGuardExclusive<'a, T> {}
+
+impl<'a, T> Deref for ComputedResilientGuard<'a, T> {
+	type Target = T;
+
+	fn deref(&self) -> &Self::Target {
+		self.0.as_ref().expect("unreachable")
+	}
+}
+
+impl<'a, T> Deref for ComputedResilientGuardExclusive<'a, T> {
+	type Target = T;
+
+	fn deref(&self) -> &Self::Target {
+		self.0.as_ref().expect("unreachable")
+	}
+}
+
+impl<'a, T> Borrow<T> for ComputedResilientGuard<'a, T> {
+	fn borrow(&self) -> &T {
+		self
+	}
+}
+
+impl<'a, T> AsRef<T> for ComputedResilientGuard<'a, T> {
+	fn as_ref(&self) -> &T {
+		self
+	}
+}
+
+impl<'a, T> Borrow<T> for ComputedResilientGuardExclusive<'a, T> {
+	fn borrow(&self) -> &T {
+		self
+	}
+}
+
+impl<'a, T> AsRef<T> for ComputedResilientGuardExclusive<'a, T> {
+	fn as_ref(&self) -> &T {
+		self
+	}
+}
+
+// TODO: Safety documentation.
+unsafe impl<T: Send, F: Send + FnMut() -> T, SR: SignalsRuntimeRef + Sync> Sync
+	for ComputedResilient<T, F, SR>
+{
+}
+
+impl<T: Send, F: Send + FnMut() -> T, SR: SignalsRuntimeRef> ComputedResilient<T, F, SR> {
+	pub(crate) fn new(fn_pin: F, runtime: SR) -> Self {
+		Self(RawSignal::with_runtime(
+			ForceSyncUnpin(Eager {
+				value: RwLock::new(None),
+				last_error: LastPanic::new(),
+				runtime: runtime.clone(),
+				fn_pin: fn_pin.into(),
+			}),
+			runtime,
+		))
+	}
+
+	pub(crate) fn touch(self: Pin<&Self>) -> &RwLock<Option<T>> {
+		unsafe {
+			&Pin::into_inner_unchecked(
+				self.project_ref()
+					.0
+					.project_or_init::<E>(|state, cache| Self::init(state, cache))
+					.0,
+			)
+			.0
+			.value
+		}
+	}
+
+	/// Like [`Self::touch`], but never (re)computes the value, returning [`None`] instead.
+	pub(crate) fn try_touch(self: Pin<&Self>) -> Option<&RwLock<Option<T>>> {
+		let (state, _) = self.project_ref().0.project_if_fresh()?;
+		Some(unsafe { &Pin::into_inner_unchecked(state).0.value })
+	}
+}
+
+impl<T: Send, F: Send + ?Sized + FnMut() -> T, SR: SignalsRuntimeRef> Eager<T, F, SR> {
+	/// Runs `fn_pin` without letting a panic unwind into the signals runtime.
+	///
+	/// # Safety
+	///
+	/// The caller must have exclusive access to `fn_pin`.
+	unsafe fn run(&self) -> Propagation {
+		let fn_pin = &mut *self.fn_pin.get();
+		match panic::catch_unwind(AssertUnwindSafe(fn_pin)) {
+			Ok(next) => {
+				self.last_error.record(None);
+				*self.value.write().unwrap() = Some(next);
+				Propagation::Propagate
+			}
+			Err(payload) => {
+				self.last_error.record(Some(payload));
+				// The run may have ended before reading all of its inputs,
+				// so this keeps it refreshing when any of them changes.
+				self.runtime.retain_dependencies();
+				Propagation::Halt
+			}
+		}
+	}
+}
+
+enum E {}
+impl<T: Send, F: Send + ?Sized + FnMut() -> T, SR: SignalsRuntimeRef>
+	Callbacks<ForceSyncUnpin<Eager<T, F, SR>>, (), SR> for E
+{
+	const UPDATE: Option<
+		fn(eager: Pin<&ForceSyncUnpin<Eager<T, F, SR>>>, lazy: Pin<&()>) -> Propagation,
+	> = {
+		fn eval<T: Send, F: Send + ?Sized + FnMut() -> T, SR: SignalsRuntimeRef>(
+			state: Pin<&ForceSyncUnpin<Eager<T, F, SR>>>,
+			_: Pin<&()>,
+		) -> Propagation {
+			unsafe {
+				//SAFETY: This function has exclusive access to `state`.
+				state.0.run()
+			}
+		}
+		Some(eval)
+	};
+
+	const ON_SUBSCRIBED_CHANGE: Option<
+		fn(
+			source: Pin<&RawSignal<ForceSyncUnpin<Eager<T, F, SR>>, (), SR>>,
+			eager: Pin<&ForceSyncUnpin<Eager<T, F, SR>>>,
+			lazy: Pin<&()>,
+			subscribed: <SR::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus,
+		) -> Propagation,
+	> = None;
+}
+
+/// # Safety
+///
+/// These are the only functions that access `cache`.
+/// Externally synchronised through guarantees on [`isoprenoid::raw::Callbacks`].
+impl<T: Send, F: Send + FnMut() -> T, SR: SignalsRuntimeRef> ComputedResilient<T, F, SR> {
+	unsafe fn init<'a>(
+		state: Pin<&'a ForceSyncUnpin<Eager<T, F, SR>>>,
+		lazy: Slot<'a, ()>,
+	) -> Token<'a> {
+		let _ = state.0.run();
+		lazy.write(())
+	}
+}
+
+impl<T: Send, F: Send + FnMut() -> T, SR: SignalsRuntimeRef> UnmanagedSignal<T, SR>
+	for ComputedResilient<T, F, SR>
+{
+	fn touch(self: Pin<&Self>) {
+		self.touch();
+	}
+
+	fn get_clone(self: Pin<&Self>) -> T
+	where
+		T: Sync + Clone,
+	{
+		self.read().clone()
+	}
+
+	fn get_clone_exclusive(self: Pin<&Self>) -> T
+	where
+		T: Clone,
+	{
+		self.read_exclusive().clone()
+	}
+
+	fn read<'r>(self: Pin<&'r Self>) -> ComputedResilientGuard<'r, T>
+	where
+		Self: Sized,
+		T: 'r + Sync,
+	{
+		let touch = self.touch();
+		ComputedResilientGuard::new(touch.read().unwrap())
+	}
+
+	type Read<'r>
+		= ComputedResilientGuard<'r, T>
+	where
+		Self: 'r + Sized,
+		T: 'r + Sync;
+
+	fn try_read<'r>(self: Pin<&'r Self>) -> Option<ComputedResilientGuard<'r, T>>
+	where
+		Self: Sized,
+		T: 'r + Sync,
+	{
+		let touch = self.try_touch()?;
+		match touch.try_read() {
+			Ok(guard) => guard.is_some().then(|| ComputedResilientGuard(guard)),
+			Err(TryLockError::WouldBlock) => None,
+			Err(TryLockError::Poisoned(error)) => panic!("{error}"),
+		}
+	}
+
+	fn read_exclusive<'r>(self: Pin<&'r Self>) -> ComputedResilientGuardExclusive<'r, T>
+	where
+		Self: Sized,
+		T: 'r,
+	{
+		let touch = self.touch();
+		ComputedResilientGuardExclusive::new(touch.write().unwrap())
+	}
+
+	type ReadExclusive<'r>
+		= ComputedResilientGuardExclusive<'r, T>
+	where
+		Self: 'r + Sized,
+		T: 'r;
+
+	fn read_dyn<'r>(self: Pin<&'r Self>) -> Box<dyn 'r + Guard<T>>
+	where
+		T: 'r + Sync,
+	{
+		Box::new(self.read())
+	}
+
+	fn read_exclusive_dyn<'r>(self: Pin<&'r Self>) -> Box<dyn 'r + Guard<T>>
+	where
+		T: 'r,
+	{
+		Box::new(self.read_exclusive())
+	}
+
+	fn clone_runtime_ref(&self) -> SR
+	where
+		SR: Sized,
+	{
+		self.0.clone_runtime_ref()
+	}
+
+	fn stale_symbol(self: Pin<&Self>) -> Option<SR::Symbol> {
+		self.0.is_stale().then(|| self.0.id())
+	}
+
+	fn read_with_freshness<'r>(self: Pin<&'r Self>) -> (Self::Read<'r>, bool)
+	where
+		Self: Sized,
+		T: 'r + Sync,
+	{
+		let refreshing = !self.0.is_initialised() || self.0.is_stale();
+		(self.read(), refreshing)
+	}
+
+	fn subscribe(self: Pin<&Self>) {
+		let signal = self.project_ref().0;
+		signal.subscribe();
+		signal.clone_runtime_ref().run_detached(|| {
+			signal.project_or_init::<E>(|state, cache| unsafe { Self::init(state, cache) })
+		});
+	}
+
+	fn unsubscribe(self: Pin<&Self>) {
+		self.project_ref().0.unsubscribe()
+	}
+}
+
+impl<T: Send, F: Send + FnMut() -> T, SR: SignalsRuntimeRef> UnmanagedSignalResilient<T, SR>
+	for ComputedResilient<T, F, SR>
+{
+	fn last_error<'r>(self: Pin<&'r Self>) -> &'r LastPanic {
+		&self.project_ref().0.eager().get_ref().0.last_error
+	}
+}
@@ -0,0 +1,178 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::{borrow::Borrow, ops::Deref, pin::Pin};
+
+use isoprenoid::runtime::SignalsRuntimeRef;
+use pin_project::pin_project;
+
+use crate::{
+	sync::{RwLockReadGuard, RwLockWriteGuard, TryLockError},
+	traits::{Guard, UnmanagedSignal},
+};
+
+use super::Computed;
+
+/// A [`Computed`] [`Vec`] that's exposed as slice.
+#[pin_project]
+#[must_use = "Signals do nothing unless they are polled or subscribed to."]
+pub(crate) struct ComputedSlice<U: Send, F: Send + FnMut() -> Vec<U>, SR: SignalsRuntimeRef>(
+	#[pin] Computed<Vec<U>, F, SR>,
+);
+
+pub(crate) struct ComputedSliceGuard<'a, U>(RwLockReadGuard<'a, Vec<U>>);
+pub(crate) struct ComputedSliceGuardExclusive<'a, U>(RwLockWriteGuard<'a, Vec<U>>);
+
+impl<'a, U> Guard<[U]> for ComputedSliceGuard<'a, U> {}
+impl<'a, U> Guard<[U]> for ComputedSliceGuardExclusive<'a, U> {}
+
+impl<'a, U> Deref for ComputedSliceGuard<'a, U> {
+	type Target = [U];
+
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+impl<'a, U> Deref for ComputedSliceGuardExclusive<'a, U> {
+	type Target = [U];
+
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+impl<'a, U> Borrow<[U]> for ComputedSliceGuard<'a, U> {
+	fn borrow(&self) -> &[U] {
+		self
+	}
+}
+
+impl<'a, U> AsRef<[U]> for ComputedSliceGuard<'a, U> {
+	fn as_ref(&self) -> &[U] {
+		self
+	}
+}
+
+impl<'a, U> Borrow<[U]> for ComputedSliceGuardExclusive<'a, U> {
+	fn borrow(&self) -> &[U] {
+		self
+	}
+}
+
+impl<'a, U> AsRef<[U]> for ComputedSliceGuardExclusive<'a, U> {
+	fn as_ref(&self) -> &[U] {
+		self
+	}
+}
+
+impl<U: Send, F: Send + FnMut() -> Vec<U>, SR: SignalsRuntimeRef> ComputedSlice<U, F, SR> {
+	pub(crate) fn new(fn_pin: F, runtime: SR) -> Self {
+		Self(Computed::new(fn_pin, runtime))
+	}
+}
+
+impl<U: Send, F: Send + FnMut() -> Vec<U>, SR: SignalsRuntimeRef> UnmanagedSignal<[U], SR>
+	for ComputedSlice<U, F, SR>
+{
+	fn touch(self: Pin<&Self>) {
+		self.project_ref().0.touch();
+	}
+
+	fn get_clone(self: Pin<&Self>) -> [U]
+	where
+		[U]: Sync + Clone,
+	{
+		self.read().clone()
+	}
+
+	fn get_clone_exclusive(self: Pin<&Self>) -> [U]
+	where
+		[U]: Clone,
+	{
+		self.read_exclusive().clone()
+	}
+
+	fn read<'r>(self: Pin<&'r Self>) -> ComputedSliceGuard<'r, U>
+	where
+		Self: Sized,
+		[U]: 'r + Sync,
+	{
+		let touch = unsafe { Pin::into_inner_unchecked(self.project_ref().0.touch()) };
+		ComputedSliceGuard(touch.read().unwrap())
+	}
+
+	type Read<'r>
+		= ComputedSliceGuard<'r, U>
+	where
+		Self: 'r + Sized,
+		[U]: 'r + Sync;
+
+	fn try_read<'r>(self: Pin<&'r Self>) -> Option<ComputedSliceGuard<'r, U>>
+	where
+		Self: Sized,
+		[U]: 'r + Sync,
+	{
+		let touch = unsafe { Pin::into_inner_unchecked(self.project_ref().0.try_touch()?) };
+		match touch.try_read() {
+			Ok(guard) => Some(ComputedSliceGuard(guard)),
+			Err(TryLockError::WouldBlock) => None,
+			Err(TryLockError::Poisoned(error)) => panic!("{error}"),
+		}
+	}
+
+	fn read_exclusive<'r>(self: Pin<&'r Self>) -> ComputedSliceGuardExclusive<'r, U>
+	where
+		Self: Sized,
+		[U]: 'r,
+	{
+		let touch = unsafe { Pin::into_inner_unchecked(self.project_ref().0.touch()) };
+		ComputedSliceGuardExclusive(touch.write().unwrap())
+	}
+
+	type ReadExclusive<'r>
+		= ComputedSliceGuardExclusive<'r, U>
+	where
+		Self: 'r + Sized,
+		[U]: 'r;
+
+	fn read_dyn<'r>(self: Pin<&'r Self>) -> Box<dyn 'r + Guard<[U]>>
+	where
+		[U]: 'r + Sync,
+	{
+		Box::new(self.read())
+	}
+
+	fn read_exclusive_dyn<'r>(self: Pin<&'r Self>) -> Box<dyn 'r + Guard<[U]>>
+	where
+		[U]: 'r,
+	{
+		Box::new(self.read_exclusive())
+	}
+
+	fn clone_runtime_ref(&self) -> SR
+	where
+		SR: Sized,
+	{
+		self.0.clone_runtime_ref()
+	}
+
+	fn stale_symbol(self: Pin<&Self>) -> Option<SR::Symbol> {
+		self.project_ref().0.stale_symbol()
+	}
+
+	fn read_with_freshness<'r>(self: Pin<&'r Self>) -> (Self::Read<'r>, bool)
+	where
+		Self: Sized,
+		[U]: 'r + Sync,
+	{
+		let (_, refreshing) = self.project_ref().0.touch_reporting();
+		(self.read(), refreshing)
+	}
+
+	fn subscribe(self: Pin<&Self>) {
+		self.project_ref().0.subscribe();
+	}
+
+	fn unsubscribe(self: Pin<&Self>) {
+		self.project_ref().0.unsubscribe();
+	}
+}
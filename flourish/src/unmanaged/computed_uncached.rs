@@ -1,4 +1,5 @@
-use std::{borrow::Borrow, ops::Deref, pin::Pin};
+use alloc::boxed::Box;
+use core::{borrow::Borrow, ops::Deref, pin::Pin};
 
 use isoprenoid::{
 	raw::{NoCallbacks, RawSignal},
@@ -47,12 +48,24 @@ impl<T: ?Sized> Borrow<T> for ComputedUncachedGuard<T> {
 	}
 }
 
+impl<T: ?Sized> AsRef<T> for ComputedUncachedGuard<T> {
+	fn as_ref(&self) -> &T {
+		self
+	}
+}
+
 impl<T: ?Sized> Borrow<T> for ComputedUncachedGuardExclusive<T> {
 	fn borrow(&self) -> &T {
 		self.0.borrow()
 	}
 }
 
+impl<T: ?Sized> AsRef<T> for ComputedUncachedGuardExclusive<T> {
+	fn as_ref(&self) -> &T {
+		self
+	}
+}
+
 // TODO: Safety documentation.
 unsafe impl<T: Send, F: Send + Sync + Fn() -> T, SR: SignalsRuntimeRef + Sync> Sync
 	for ComputedUncached<T, F, SR>
@@ -163,6 +176,19 @@ impl<T: Send, F: Send + Sync + Fn() -> T, SR: SignalsRuntimeRef> UnmanagedSignal
 		self.0.clone_runtime_ref()
 	}
 
+	fn stale_symbol(self: Pin<&Self>) -> Option<SR::Symbol> {
+		self.0.is_stale().then(|| self.0.id())
+	}
+
+	fn read_with_freshness<'r>(self: Pin<&'r Self>) -> (Self::Read<'r>, bool)
+	where
+		Self: Sized,
+		T: 'r + Sync,
+	{
+		// Every read recomputes.
+		(self.read(), true)
+	}
+
 	fn subscribe(self: Pin<&Self>) {
 		let signal = self.project_ref().0;
 		signal.subscribe();
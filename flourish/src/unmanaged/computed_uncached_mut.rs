@@ -1,4 +1,5 @@
-use std::{borrow::Borrow, ops::Deref, pin::Pin, sync::Mutex};
+use alloc::boxed::Box;
+use core::{borrow::Borrow, ops::Deref, pin::Pin};
 
 use isoprenoid::{
 	raw::{NoCallbacks, RawSignal},
@@ -7,7 +8,10 @@ use isoprenoid::{
 };
 use pin_project::pin_project;
 
-use crate::traits::{Guard, UnmanagedSignal};
+use crate::{
+	sync::Mutex,
+	traits::{Guard, UnmanagedSignal},
+};
 
 #[pin_project]
 #[must_use = "Signals do nothing unless they are polled or subscribed to."]
@@ -47,12 +51,24 @@ impl<T: ?Sized> Borrow<T> for ComputedUncachedMutGuard<T> {
 	}
 }
 
+impl<T: ?Sized> AsRef<T> for ComputedUncachedMutGuard<T> {
+	fn as_ref(&self) -> &T {
+		self
+	}
+}
+
 impl<T: ?Sized> Borrow<T> for ComputedUncachedMutGuardExclusive<T> {
 	fn borrow(&self) -> &T {
 		self.0.borrow()
 	}
 }
 
+impl<T: ?Sized> AsRef<T> for ComputedUncachedMutGuardExclusive<T> {
+	fn as_ref(&self) -> &T {
+		self
+	}
+}
+
 // TODO: Safety documentation.
 unsafe impl<T: Send, F: Send + FnMut() -> T, SR: SignalsRuntimeRef + Sync> Sync
 	for ComputedUncachedMut<T, F, SR>
@@ -164,6 +180,19 @@ impl<T: Send, F: Send + FnMut() -> T, SR: SignalsRuntimeRef> UnmanagedSignal<T,
 		self.0.clone_runtime_ref()
 	}
 
+	fn stale_symbol(self: Pin<&Self>) -> Option<SR::Symbol> {
+		self.0.is_stale().then(|| self.0.id())
+	}
+
+	fn read_with_freshness<'r>(self: Pin<&'r Self>) -> (Self::Read<'r>, bool)
+	where
+		Self: Sized,
+		T: 'r + Sync,
+	{
+		// Every read recomputes.
+		(self.read(), true)
+	}
+
 	fn subscribe(self: Pin<&Self>) {
 		let signal = self.project_ref().0;
 		signal.subscribe();
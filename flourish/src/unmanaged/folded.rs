@@ -1,10 +1,5 @@
-use std::{
-	borrow::Borrow,
-	cell::UnsafeCell,
-	ops::Deref,
-	pin::Pin,
-	sync::{RwLock, RwLockReadGuard, RwLockWriteGuard},
-};
+use alloc::boxed::Box;
+use core::{borrow::Borrow, cell::UnsafeCell, ops::Deref, pin::Pin};
 
 use isoprenoid::{
 	raw::{Callbacks, RawSignal},
@@ -13,7 +8,10 @@ use isoprenoid::{
 };
 use pin_project::pin_project;
 
-use crate::traits::{Guard, UnmanagedSignal};
+use crate::{
+	sync::{RwLock, RwLockReadGuard, RwLockWriteGuard, TryLockError},
+	traits::{Guard, UnmanagedSignal},
+};
 
 #[pin_project]
 #[must_use = "Signals do nothing unless they are polled or subscribed to."]
@@ -53,12 +51,24 @@ impl<'a, T: ?Sized> Borrow<T> for FoldedGuard<'a, T> {
 	}
 }
 
+impl<'a, T: ?Sized> AsRef<T> for FoldedGuard<'a, T> {
+	fn as_ref(&self) -> &T {
+		self
+	}
+}
+
 impl<'a, T: ?Sized> Borrow<T> for FoldedGuardExclusive<'a, T> {
 	fn borrow(&self) -> &T {
 		self.0.borrow()
 	}
 }
 
+impl<'a, T: ?Sized> AsRef<T> for FoldedGuardExclusive<'a, T> {
+	fn as_ref(&self) -> &T {
+		self
+	}
+}
+
 // TODO: Safety documentation.
 unsafe impl<T: Send, F: Send + FnMut(&mut T) -> Propagation, SR: SignalsRuntimeRef + Sync> Sync
 	for Folded<T, F, SR>
@@ -73,6 +83,11 @@ impl<T: Send, F: Send + FnMut(&mut T) -> Propagation, SR: SignalsRuntimeRef> Fol
 		))
 	}
 
+	/// Notifies dependents without running `fn_pin`.
+	pub(crate) fn propagate(self: Pin<&Self>, propagation: Propagation) {
+		self.project_ref().0.update(move |_, _| propagation);
+	}
+
 	pub(crate) fn touch(self: Pin<&Self>) -> &RwLock<T> {
 		unsafe {
 			&Pin::into_inner_unchecked(
@@ -85,6 +100,12 @@ impl<T: Send, F: Send + FnMut(&mut T) -> Propagation, SR: SignalsRuntimeRef> Fol
 			 .0
 		}
 	}
+
+	/// Like [`Self::touch`], but never (re)computes the value, returning [`None`] instead.
+	pub(crate) fn try_touch(self: Pin<&Self>) -> Option<&RwLock<T>> {
+		let (state, _) = self.project_ref().0.project_if_fresh()?;
+		Some(unsafe { &Pin::into_inner_unchecked(state).0 .0 })
+	}
 }
 
 enum E {}
@@ -173,6 +194,19 @@ impl<T: Send, F: Send + FnMut(&mut T) -> Propagation, SR: SignalsRuntimeRef> Unm
 		Self: 'r + Sized,
 		T: 'r + Sync;
 
+	fn try_read<'r>(self: Pin<&'r Self>) -> Option<FoldedGuard<'r, T>>
+	where
+		Self: Sized,
+		T: 'r + Sync,
+	{
+		let touch = self.try_touch()?;
+		match touch.try_read() {
+			Ok(guard) => Some(FoldedGuard(guard)),
+			Err(TryLockError::WouldBlock) => None,
+			Err(TryLockError::Poisoned(error)) => panic!("{error}"),
+		}
+	}
+
 	fn read_exclusive<'r>(self: Pin<&'r Self>) -> FoldedGuardExclusive<'r, T>
 	where
 		Self: Sized,
@@ -209,6 +243,19 @@ impl<T: Send, F: Send + FnMut(&mut T) -> Propagation, SR: SignalsRuntimeRef> Unm
 		self.0.clone_runtime_ref()
 	}
 
+	fn stale_symbol(self: Pin<&Self>) -> Option<SR::Symbol> {
+		self.0.is_stale().then(|| self.0.id())
+	}
+
+	fn read_with_freshness<'r>(self: Pin<&'r Self>) -> (Self::Read<'r>, bool)
+	where
+		Self: Sized,
+		T: 'r + Sync,
+	{
+		let refreshing = !self.0.is_initialised() || self.0.is_stale();
+		(self.read(), refreshing)
+	}
+
 	fn subscribe(self: Pin<&Self>) {
 		let signal = self.project_ref().0;
 		signal.subscribe();
@@ -1,11 +1,11 @@
-use std::{
+use alloc::{boxed::Box, sync::Arc};
+use core::{
 	borrow::Borrow,
 	fmt::{self, Debug, Formatter},
 	future::Future,
 	mem,
 	ops::Deref,
 	pin::Pin,
-	sync::{Arc, Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard},
 };
 
 use isoprenoid::{
@@ -14,17 +14,56 @@ use isoprenoid::{
 };
 use pin_project::pin_project;
 
-use crate::{shadow_clone, traits::Guard};
+use crate::{
+	shadow_clone,
+	sync::{Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard, TryLockError},
+	traits::Guard,
+};
 
 use super::{UnmanagedSignal, UnmanagedSignalCell};
 
 #[pin_project]
-pub(crate) struct InertCell<T: ?Sized + Send, SR: SignalsRuntimeRef> {
+pub(crate) struct InertCell<T: ?Sized + Send, SR: SignalsRuntimeRef, O: Observer<T> = ()> {
 	#[pin]
-	signal: RawSignal<AssertSync<RwLock<T>>, (), SR>,
+	signal: RawSignal<AssertSync<Observed<O, RwLock<T>>>, (), SR>,
+}
+
+/// Notified synchronously after each change to an [`InertCell`]'s value that wasn't [`Halt`](`Propagation::Halt`)ed.
+pub(crate) trait Observer<T: ?Sized>: 'static + Send + Sync {
+	fn observe(&self, value: &T);
+}
+
+impl<T: ?Sized> Observer<T> for () {
+	#[inline(always)]
+	fn observe(&self, _: &T) {}
+}
+
+impl<T: ?Sized, F: 'static + Send + FnMut(&T)> Observer<T> for Mutex<F> {
+	fn observe(&self, value: &T) {
+		(self.lock().unwrap())(value);
+	}
+}
+
+struct Observed<O, L: ?Sized> {
+	observer: O,
+	lock: L,
 }
 
-impl<T: ?Sized + Send + Debug, SR: SignalsRuntimeRef + Debug> Debug for InertCell<T, SR>
+impl<O: Observer<T>, T: ?Sized> Observed<O, RwLock<T>> {
+	/// Runs `update` and then, unless it [`Halt`](`Propagation::Halt`)ed, notifies the observer.
+	///
+	/// The write lock is released before that, so the observer sees the committed value.
+	fn update<U>(&self, update: impl FnOnce(&mut T) -> (Propagation, U)) -> (Propagation, U) {
+		let (propagation, u) = update(&mut self.lock.write().unwrap());
+		if propagation != Propagation::Halt {
+			self.observer.observe(&self.lock.read().unwrap());
+		}
+		(propagation, u)
+	}
+}
+
+impl<T: ?Sized + Send + Debug, SR: SignalsRuntimeRef + Debug, O: Observer<T>> Debug
+	for InertCell<T, SR, O>
 where
 	SR::Symbol: Debug,
 {
@@ -36,14 +75,17 @@ where
 }
 
 // TODO: Safety documentation.
-unsafe impl<T: Send + ?Sized, SR: SignalsRuntimeRef + Sync> Sync for InertCell<T, SR> {}
+unsafe impl<T: Send + ?Sized, SR: SignalsRuntimeRef + Sync, O: Observer<T>> Sync
+	for InertCell<T, SR, O>
+{
+}
 
 struct AssertSync<T: ?Sized>(T);
 unsafe impl<T: ?Sized> Sync for AssertSync<T> {}
 
-impl<T: Debug + ?Sized> Debug for AssertSync<RwLock<T>> {
+impl<O, T: Debug + ?Sized> Debug for AssertSync<Observed<O, RwLock<T>>> {
 	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-		let maybe_guard = self.0.try_write();
+		let maybe_guard = self.0.lock.try_write();
 		f.debug_tuple("AssertSync")
 			.field(
 				maybe_guard
@@ -82,19 +124,46 @@ impl<'a, T: ?Sized> Borrow<T> for InertCellGuard<'a, T> {
 	}
 }
 
+impl<'a, T: ?Sized> AsRef<T> for InertCellGuard<'a, T> {
+	fn as_ref(&self) -> &T {
+		self
+	}
+}
+
 impl<'a, T: ?Sized> Borrow<T> for InertCellGuardExclusive<'a, T> {
 	fn borrow(&self) -> &T {
 		self.0.borrow()
 	}
 }
 
+impl<'a, T: ?Sized> AsRef<T> for InertCellGuardExclusive<'a, T> {
+	fn as_ref(&self) -> &T {
+		self
+	}
+}
+
 impl<T: ?Sized + Send, SR: SignalsRuntimeRef> InertCell<T, SR> {
 	pub(crate) fn with_runtime(initial_value: T, runtime: SR) -> Self
+	where
+		T: Sized,
+	{
+		Self::with_observer_and_runtime(initial_value, (), runtime)
+	}
+}
+
+impl<T: ?Sized + Send, SR: SignalsRuntimeRef, O: Observer<T>> InertCell<T, SR, O> {
+	pub(crate) fn with_observer_and_runtime(initial_value: T, observer: O, runtime: SR) -> Self
 	where
 		T: Sized,
 	{
 		Self {
-			signal: RawSignal::with_runtime(AssertSync(RwLock::new(initial_value)), runtime),
+			signal: RawSignal::with_runtime(
+				AssertSync(Observed {
+					observer,
+					lock: RwLock::new(initial_value),
+				}),
+				runtime,
+			),
 		}
 	}
 
@@ -117,12 +186,15 @@ impl<T: ?Sized + Send, SR: SignalsRuntimeRef> InertCell<T, SR> {
 				.signal
 				.project_or_init::<NoCallbacks>(|_, slot| slot.write(()))
 				.0
-				 .0 as *const _)
+				 .0
+				.lock as *const _)
 		}
 	}
 }
 
-impl<T: Send + ?Sized, SR: SignalsRuntimeRef> UnmanagedSignal<T, SR> for InertCell<T, SR> {
+impl<T: Send + ?Sized, SR: SignalsRuntimeRef, O: Observer<T>> UnmanagedSignal<T, SR>
+	for InertCell<T, SR, O>
+{
 	fn touch(self: Pin<&Self>) {
 		self.touch();
 	}
@@ -156,6 +228,19 @@ impl<T: Send + ?Sized, SR: SignalsRuntimeRef> UnmanagedSignal<T, SR> for InertCe
 		Self: 'r + Sized,
 		T: 'r + Sync;
 
+	fn try_read<'r>(self: Pin<&'r Self>) -> Option<InertCellGuard<'r, T>>
+	where
+		Self: Sized,
+		T: 'r + Sync,
+	{
+		let touch = self.touch();
+		match touch.try_read() {
+			Ok(guard) => Some(InertCellGuard(guard)),
+			Err(TryLockError::WouldBlock) => None,
+			Err(TryLockError::Poisoned(error)) => panic!("{error}"),
+		}
+	}
+
 	fn read_exclusive<'r>(self: Pin<&'r Self>) -> InertCellGuardExclusive<'r, T>
 	where
 		Self: Sized,
@@ -205,8 +290,8 @@ impl<T: Send + ?Sized, SR: SignalsRuntimeRef> UnmanagedSignal<T, SR> for InertCe
 	}
 }
 
-impl<T: Send + ?Sized, SR: ?Sized + SignalsRuntimeRef> UnmanagedSignalCell<T, SR>
-	for InertCell<T, SR>
+impl<T: Send + ?Sized, SR: ?Sized + SignalsRuntimeRef, O: Observer<T>> UnmanagedSignalCell<T, SR>
+	for InertCell<T, SR, O>
 {
 	fn set_if_distinct(self: Pin<&Self>, new_value: T)
 	where
@@ -238,7 +323,7 @@ impl<T: Send + ?Sized, SR: ?Sized + SignalsRuntimeRef> UnmanagedSignalCell<T, SR
 			.run_detached(|| self.touch());
 		self.project_ref()
 			.signal
-			.update(|value, _| update(&mut value.0.write().unwrap()))
+			.update(|value, _| value.0.update(|value| (update(value), ())).0)
 	}
 
 	fn update_dyn(self: Pin<&Self>, update: Box<dyn 'static + Send + FnOnce(&mut T) -> Propagation>)
@@ -250,7 +335,7 @@ impl<T: Send + ?Sized, SR: ?Sized + SignalsRuntimeRef> UnmanagedSignalCell<T, SR
 			.run_detached(|| self.touch());
 		self.project_ref()
 			.signal
-			.update(|value, _| update(&mut value.0.write().unwrap()))
+			.update(|value, _| value.0.update(|value| (update(value), ())).0)
 	}
 
 	fn set_if_distinct_eager<'f>(
@@ -440,7 +525,7 @@ impl<T: Send + ?Sized, SR: ?Sized + SignalsRuntimeRef> UnmanagedSignalCell<T, SR
 					.expect("unreachable")
 					.take()
 					.expect("unreachable");
-				update(&mut value.0.write().unwrap())
+				value.0.update(update)
 			}
 		});
 		private::DetachedFuture(Box::pin(async move {
@@ -630,7 +715,7 @@ impl<T: Send + ?Sized, SR: ?Sized + SignalsRuntimeRef> UnmanagedSignalCell<T, SR
 							.expect("unreachable")
 							.take()
 							.expect("unreachable");
-						update(&mut *value.0.write().unwrap())
+						value.0.update(|value| (update(value), ())).0
 					} else {
 						Propagation::Halt
 					},
@@ -691,18 +776,19 @@ impl<T: Send + ?Sized, SR: ?Sized + SignalsRuntimeRef> UnmanagedSignalCell<T, SR
 
 	fn update_blocking<U>(&self, update: impl FnOnce(&mut T) -> (Propagation, U)) -> U {
 		self.signal
-			.update_blocking(|value, _| update(&mut value.0.write().unwrap()))
+			.update_blocking(|value, _| value.0.update(update))
 	}
 
 	fn update_blocking_dyn(&self, update: Box<dyn '_ + FnOnce(&mut T) -> Propagation>) {
 		self.signal
-			.update_blocking(|value, _| (update(&mut value.0.write().unwrap()), ()))
+			.update_blocking(|value, _| value.0.update(|value| (update(value), ())))
 	}
 }
 
 /// Duplicated to avoid identities.
 mod private {
-	use std::{
+	use alloc::boxed::Box;
+	use core::{
 		future::Future,
 		pin::Pin,
 		task::{Context, Poll},
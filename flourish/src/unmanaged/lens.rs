@@ -0,0 +1,515 @@
+use alloc::{boxed::Box, sync::Arc};
+use core::{future::Future, mem, pin::Pin};
+
+use isoprenoid::runtime::{Propagation, SignalsRuntimeRef};
+use pin_project::pin_project;
+
+use crate::{shadow_clone, sync::Mutex, traits::Guard, SignalArcDynCell};
+
+use super::{UnmanagedSignal, UnmanagedSignalCell};
+
+/// A cell view of one field of a source cell.
+///
+/// Reads go through `projection`, which is expected to be a distinct projection of the field.
+/// Writes are forwarded to the source cell, with the field's (in)equality deciding propagation
+/// where the written value is compared at all.
+#[pin_project]
+pub(crate) struct Lens<'a, P: ?Sized + Send, R, G, W, SR: SignalsRuntimeRef> {
+	#[pin]
+	projection: R,
+	source: SignalArcDynCell<'a, P, SR>,
+	get: Arc<G>,
+	set: Arc<W>,
+}
+
+impl<'a, P: ?Sized + Send, R, G, W, SR: SignalsRuntimeRef> Lens<'a, P, R, G, W, SR> {
+	pub(crate) fn new(
+		projection: R,
+		source: SignalArcDynCell<'a, P, SR>,
+		get: Arc<G>,
+		set: Arc<W>,
+	) -> Self {
+		Self {
+			projection,
+			source,
+			get,
+			set,
+		}
+	}
+}
+
+impl<
+		'a,
+		P: 'static + ?Sized + Send,
+		T: 'static + Send + Clone,
+		R: UnmanagedSignal<T, SR>,
+		G: 'static + Send + Sync + Fn(&P) -> &T,
+		W: 'static + Send + Sync + Fn(&mut P, T),
+		SR: SignalsRuntimeRef,
+	> Lens<'a, P, R, G, W, SR>
+{
+	/// Runs `update` on a copy of the field and writes the result back into the source.
+	fn write_back<U>(
+		get: &G,
+		set: &W,
+		source: &mut P,
+		update: impl FnOnce(&mut T) -> (Propagation, U),
+	) -> (Propagation, U) {
+		let mut value = get(source).clone();
+		let (propagation, u) = update(&mut value);
+		set(source, value);
+		(propagation, u)
+	}
+
+	/// Like [`UnmanagedSignalCell::update_eager`], but without requiring `Self: 'f`,
+	/// as the returned [`Future`] doesn't borrow `self`.
+	fn update_eager_detached<
+		'f,
+		U: 'f + Send,
+		F: 'f + Send + FnOnce(&mut T) -> (Propagation, U),
+	>(
+		&self,
+		update: F,
+	) -> private::DetachedFuture<'f, Result<U, F>> {
+		let update = Arc::new(Mutex::new(Some(update)));
+		let result = Arc::new(Mutex::new(None));
+		let f: Pin<Box<_>> = self
+			.source
+			.update_eager_dyn(Box::new({
+				let (get, set) = (Arc::clone(&self.get), Arc::clone(&self.set));
+				shadow_clone!(update, result);
+				move |source: &mut P| {
+					let update = update
+						.try_lock()
+						.expect("unreachable")
+						.take()
+						.expect("unreachable");
+					let (propagation, u) = Self::write_back(&*get, &*set, source, update);
+					*result.try_lock().expect("unreachable") = Some(u);
+					propagation
+				}
+			}))
+			.into();
+		private::DetachedFuture(Box::pin(async move {
+			match f.await {
+				Ok(()) => Ok(result
+					.try_lock()
+					.expect("unreachable")
+					.take()
+					.expect("The update ran.")),
+				Err(_) => Err(update
+					.try_lock()
+					.expect("unreachable")
+					.take()
+					.expect("The update didn't run.")),
+			}
+		}))
+	}
+
+	/// Eagerly applies `write` with `new_value`, returning `new_value` iff the write was cancelled.
+	fn write_eager<'f, O: 'f + Send>(
+		&self,
+		new_value: T,
+		write: impl 'f + Send + FnOnce(&mut T, T) -> (Propagation, O),
+	) -> private::DetachedFuture<'f, Result<O, T>> {
+		let new_value = Arc::new(Mutex::new(Some(new_value)));
+		let f = self.update_eager_detached({
+			shadow_clone!(new_value);
+			move |value: &mut T| {
+				let new_value = new_value
+					.try_lock()
+					.expect("unreachable")
+					.take()
+					.expect("unreachable");
+				write(value, new_value)
+			}
+		});
+		private::DetachedFuture(Box::pin(async move {
+			f.await.map_err(|_| {
+				new_value
+					.try_lock()
+					.expect("unreachable")
+					.take()
+					.expect("The update didn't run.")
+			})
+		}))
+	}
+}
+
+fn set_if_distinct<T: PartialEq>(value: &mut T, new_value: T) -> (Propagation, Result<(), T>) {
+	if *value == new_value {
+		(Propagation::Halt, Err(new_value))
+	} else {
+		*value = new_value;
+		(Propagation::Propagate, Ok(()))
+	}
+}
+
+fn replace_if_distinct<T: PartialEq>(value: &mut T, new_value: T) -> (Propagation, Result<T, T>) {
+	if *value == new_value {
+		(Propagation::Halt, Err(new_value))
+	} else {
+		(Propagation::Propagate, Ok(mem::replace(value, new_value)))
+	}
+}
+
+fn set<T>(value: &mut T, new_value: T) -> (Propagation, ()) {
+	*value = new_value;
+	(Propagation::Propagate, ())
+}
+
+fn replace<T>(value: &mut T, new_value: T) -> (Propagation, T) {
+	(Propagation::Propagate, mem::replace(value, new_value))
+}
+
+impl<
+		'a,
+		P: ?Sized + Send,
+		T: Send,
+		R: UnmanagedSignal<T, SR>,
+		G: Send + Sync,
+		W: Send + Sync,
+		SR: SignalsRuntimeRef,
+	> UnmanagedSignal<T, SR> for Lens<'a, P, R, G, W, SR>
+{
+	fn touch(self: Pin<&Self>) {
+		self.project_ref().projection.touch();
+	}
+
+	fn get_clone(self: Pin<&Self>) -> T
+	where
+		T: Sync + Clone,
+	{
+		self.project_ref().projection.get_clone()
+	}
+
+	fn get_clone_exclusive(self: Pin<&Self>) -> T
+	where
+		T: Clone,
+	{
+		self.project_ref().projection.get_clone_exclusive()
+	}
+
+	fn read<'r>(self: Pin<&'r Self>) -> R::Read<'r>
+	where
+		Self: Sized,
+		T: 'r + Sync,
+	{
+		self.project_ref().projection.read()
+	}
+
+	type Read<'r>
+		= R::Read<'r>
+	where
+		Self: 'r + Sized,
+		T: 'r + Sync;
+
+	fn try_read<'r>(self: Pin<&'r Self>) -> Option<R::Read<'r>>
+	where
+		Self: Sized,
+		T: 'r + Sync,
+	{
+		self.project_ref().projection.try_read()
+	}
+
+	fn read_with_freshness<'r>(self: Pin<&'r Self>) -> (R::Read<'r>, bool)
+	where
+		Self: Sized,
+		T: 'r + Sync,
+	{
+		self.project_ref().projection.read_with_freshness()
+	}
+
+	fn read_exclusive<'r>(self: Pin<&'r Self>) -> R::ReadExclusive<'r>
+	where
+		Self: Sized,
+		T: 'r,
+	{
+		self.project_ref().projection.read_exclusive()
+	}
+
+	type ReadExclusive<'r>
+		= R::ReadExclusive<'r>
+	where
+		Self: 'r + Sized,
+		T: 'r;
+
+	fn read_dyn<'r>(self: Pin<&'r Self>) -> Box<dyn 'r + Guard<T>>
+	where
+		T: 'r + Sync,
+	{
+		self.project_ref().projection.read_dyn()
+	}
+
+	fn read_exclusive_dyn<'r>(self: Pin<&'r Self>) -> Box<dyn 'r + Guard<T>>
+	where
+		T: 'r,
+	{
+		self.project_ref().projection.read_exclusive_dyn()
+	}
+
+	fn subscribe(self: Pin<&Self>) {
+		self.project_ref().projection.subscribe();
+	}
+
+	fn unsubscribe(self: Pin<&Self>) {
+		self.project_ref().projection.unsubscribe();
+	}
+
+	fn clone_runtime_ref(&self) -> SR
+	where
+		SR: Sized,
+	{
+		self.projection.clone_runtime_ref()
+	}
+
+	fn stale_symbol(self: Pin<&Self>) -> Option<SR::Symbol> {
+		self.project_ref().projection.stale_symbol()
+	}
+}
+
+impl<
+		'a,
+		P: 'static + ?Sized + Send,
+		T: 'static + Send + Clone,
+		R: UnmanagedSignal<T, SR>,
+		G: 'static + Send + Sync + Fn(&P) -> &T,
+		W: 'static + Send + Sync + Fn(&mut P, T),
+		SR: SignalsRuntimeRef,
+	> UnmanagedSignalCell<T, SR> for Lens<'a, P, R, G, W, SR>
+{
+	fn set_if_distinct(self: Pin<&Self>, new_value: T)
+	where
+		T: 'static + Sized + PartialEq,
+	{
+		self.update(|value| set_if_distinct(value, new_value).0);
+	}
+
+	fn set(self: Pin<&Self>, new_value: T)
+	where
+		T: 'static + Sized,
+	{
+		self.update(|value| set(value, new_value).0);
+	}
+
+	fn update(self: Pin<&Self>, update: impl 'static + Send + FnOnce(&mut T) -> Propagation) {
+		let (get, set) = (Arc::clone(&self.get), Arc::clone(&self.set));
+		self.source.update_dyn(Box::new(move |source| {
+			Self::write_back(&*get, &*set, source, |value| (update(value), ())).0
+		}));
+	}
+
+	fn update_dyn(self: Pin<&Self>, update: Box<dyn 'static + Send + FnOnce(&mut T) -> Propagation>)
+	where
+		T: 'static,
+	{
+		self.update(update);
+	}
+
+	fn set_if_distinct_eager<'f>(
+		self: Pin<&Self>,
+		new_value: T,
+	) -> private::DetachedFuture<'f, Result<Result<(), T>, T>>
+	where
+		Self: 'f + Sized,
+		T: 'f + Sized + PartialEq,
+	{
+		self.write_eager(new_value, set_if_distinct)
+	}
+
+	type SetIfDistinctEager<'f>
+		= private::DetachedFuture<'f, Result<Result<(), T>, T>>
+	where
+		Self: 'f + Sized,
+		T: 'f + Sized;
+
+	fn replace_if_distinct_eager<'f>(
+		self: Pin<&Self>,
+		new_value: T,
+	) -> private::DetachedFuture<'f, Result<Result<T, T>, T>>
+	where
+		Self: 'f + Sized,
+		T: 'f + Sized + PartialEq,
+	{
+		self.write_eager(new_value, replace_if_distinct)
+	}
+
+	type ReplaceIfDistinctEager<'f>
+		= private::DetachedFuture<'f, Result<Result<T, T>, T>>
+	where
+		Self: 'f + Sized,
+		T: 'f + Sized;
+
+	fn set_eager<'f>(self: Pin<&Self>, new_value: T) -> private::DetachedFuture<'f, Result<(), T>>
+	where
+		Self: 'f + Sized,
+		T: 'f + Sized,
+	{
+		self.write_eager(new_value, set)
+	}
+
+	type SetEager<'f>
+		= private::DetachedFuture<'f, Result<(), T>>
+	where
+		Self: 'f + Sized,
+		T: 'f + Sized;
+
+	fn replace_eager<'f>(
+		self: Pin<&Self>,
+		new_value: T,
+	) -> private::DetachedFuture<'f, Result<T, T>>
+	where
+		Self: 'f + Sized,
+		T: 'f + Sized,
+	{
+		self.write_eager(new_value, replace)
+	}
+
+	type ReplaceEager<'f>
+		= private::DetachedFuture<'f, Result<T, T>>
+	where
+		Self: 'f + Sized,
+		T: 'f + Sized;
+
+	fn update_eager<'f, U: 'f + Send, F: 'f + Send + FnOnce(&mut T) -> (Propagation, U)>(
+		self: Pin<&Self>,
+		update: F,
+	) -> private::DetachedFuture<'f, Result<U, F>>
+	where
+		Self: 'f + Sized,
+	{
+		self.update_eager_detached(update)
+	}
+
+	type UpdateEager<'f, U: 'f, F: 'f>
+		= private::DetachedFuture<'f, Result<U, F>>
+	where
+		Self: 'f + Sized;
+
+	fn set_if_distinct_eager_dyn<'f>(
+		self: Pin<&Self>,
+		new_value: T,
+	) -> Box<dyn 'f + Send + Future<Output = Result<Result<(), T>, T>>>
+	where
+		T: 'f + Sized + PartialEq,
+	{
+		Box::new(self.write_eager(new_value, set_if_distinct))
+	}
+
+	fn replace_if_distinct_eager_dyn<'f>(
+		self: Pin<&Self>,
+		new_value: T,
+	) -> Box<dyn 'f + Send + Future<Output = Result<Result<T, T>, T>>>
+	where
+		T: 'f + Sized + PartialEq,
+	{
+		Box::new(self.write_eager(new_value, replace_if_distinct))
+	}
+
+	fn set_eager_dyn<'f>(
+		self: Pin<&Self>,
+		new_value: T,
+	) -> Box<dyn 'f + Send + Future<Output = Result<(), T>>>
+	where
+		T: 'f + Sized,
+	{
+		Box::new(self.write_eager(new_value, set))
+	}
+
+	fn replace_eager_dyn<'f>(
+		self: Pin<&Self>,
+		new_value: T,
+	) -> Box<dyn 'f + Send + Future<Output = Result<T, T>>>
+	where
+		T: 'f + Sized,
+	{
+		Box::new(self.write_eager(new_value, replace))
+	}
+
+	fn update_eager_dyn<'f>(
+		self: Pin<&Self>,
+		update: Box<dyn 'f + Send + FnOnce(&mut T) -> Propagation>,
+	) -> Box<
+		dyn 'f
+			+ Send
+			+ Future<Output = Result<(), Box<dyn 'f + Send + FnOnce(&mut T) -> Propagation>>>,
+	>
+	where
+		T: 'f,
+	{
+		let f = self.update_eager_detached(move |value: &mut T| (update(value), ()));
+		Box::new(async move {
+			f.await.map_err(|update| {
+				Box::new(move |value: &mut T| update(value).0)
+					as Box<dyn 'f + Send + FnOnce(&mut T) -> Propagation>
+			})
+		})
+	}
+
+	fn set_if_distinct_blocking(&self, new_value: T) -> Result<(), T>
+	where
+		T: Sized + PartialEq,
+	{
+		self.update_blocking(|value| set_if_distinct(value, new_value))
+	}
+
+	fn replace_if_distinct_blocking(&self, new_value: T) -> Result<T, T>
+	where
+		T: Sized + PartialEq,
+	{
+		self.update_blocking(|value| replace_if_distinct(value, new_value))
+	}
+
+	fn set_blocking(&self, new_value: T)
+	where
+		T: Sized,
+	{
+		self.update_blocking(|value| set(value, new_value));
+	}
+
+	fn replace_blocking(&self, new_value: T) -> T
+	where
+		T: Sized,
+	{
+		self.update_blocking(|value| replace(value, new_value))
+	}
+
+	fn update_blocking<U>(&self, update: impl FnOnce(&mut T) -> (Propagation, U)) -> U {
+		let mut result = None;
+		self.source.update_blocking_dyn(Box::new(|source| {
+			let (propagation, u) = Self::write_back(&*self.get, &*self.set, source, update);
+			result = Some(u);
+			propagation
+		}));
+		result.expect("The update ran.")
+	}
+
+	fn update_blocking_dyn(&self, update: Box<dyn '_ + FnOnce(&mut T) -> Propagation>) {
+		self.update_blocking(|value| (update(value), ()));
+	}
+}
+
+/// Duplicated to avoid identities.
+mod private {
+	use alloc::boxed::Box;
+	use core::{
+		future::Future,
+		pin::Pin,
+		task::{Context, Poll},
+	};
+
+	use futures_lite::FutureExt;
+
+	#[must_use = "Eager futures may still cancel their effect iff dropped."]
+	pub(crate) struct DetachedFuture<'f, Output: 'f>(
+		pub(super) Pin<Box<dyn 'f + Send + Future<Output = Output>>>,
+	);
+
+	impl<'f, Output: 'f> Future for DetachedFuture<'f, Output> {
+		type Output = Output;
+
+		fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+			self.0.poll(cx)
+		}
+	}
+}
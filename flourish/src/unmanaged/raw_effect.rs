@@ -1,4 +1,4 @@
-use std::{ops::DerefMut as _, pin::Pin, sync::Mutex};
+use core::{ops::DerefMut as _, pin::Pin};
 
 use isoprenoid::{
 	raw::{Callbacks, RawSignal},
@@ -7,6 +7,8 @@ use isoprenoid::{
 };
 use pin_project::pin_project;
 
+use crate::sync::Mutex;
+
 #[must_use = "Effects are cancelled when dropped."]
 #[repr(transparent)]
 pub struct RawEffect<T: Send, S: Send + FnMut() -> T, D: Send + FnMut(T), SR: SignalsRuntimeRef>(
@@ -105,6 +107,11 @@ impl<T: Send, S: Send + FnMut() -> T, D: Send + FnMut(T), SR: SignalsRuntimeRef>
 		))
 	}
 
+	#[cfg(any(feature = "global_signals_runtime", feature = "critical_section"))]
+	pub(crate) fn id(&self) -> SR::Symbol {
+		self.0.id()
+	}
+
 	pub fn pull(self: Pin<&RawEffect<T, S, D, SR>>) {
 		self.0.clone_runtime_ref().run_detached(|| unsafe {
 			let signal = Pin::new_unchecked(&self.0);
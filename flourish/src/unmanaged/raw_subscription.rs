@@ -1,4 +1,5 @@
-use std::{borrow::Borrow, ops::Deref, pin::Pin};
+use alloc::boxed::Box;
+use core::{borrow::Borrow, ops::Deref, pin::Pin};
 
 use isoprenoid::runtime::SignalsRuntimeRef;
 use pin_project::pin_project;
@@ -51,12 +52,24 @@ impl<'a, T: ?Sized> Borrow<T> for RawSubscriptionGuard<'a, T> {
 	}
 }
 
+impl<'a, T: ?Sized> AsRef<T> for RawSubscriptionGuard<'a, T> {
+	fn as_ref(&self) -> &T {
+		self
+	}
+}
+
 impl<'a, T: ?Sized> Borrow<T> for RawSubscriptionGuardExclusive<'a, T> {
 	fn borrow(&self) -> &T {
 		self.0.borrow()
 	}
 }
 
+impl<'a, T: ?Sized> AsRef<T> for RawSubscriptionGuardExclusive<'a, T> {
+	fn as_ref(&self) -> &T {
+		self
+	}
+}
+
 //TODO: Turn some of these functions into methods.
 
 #[doc(hidden)]
@@ -134,6 +147,23 @@ impl<T: Send + Clone, S: UnmanagedSignal<T, SR>, SR: SignalsRuntimeRef> Unmanage
 		Self: 'r + Sized,
 		T: 'r + Sync;
 
+	fn try_read<'r>(self: Pin<&'r Self>) -> Option<RawSubscriptionGuard<'r, T>>
+	where
+		Self: Sized,
+		T: 'r + Sync,
+	{
+		self.project_ref().0.try_read().map(RawSubscriptionGuard)
+	}
+
+	fn read_with_freshness<'r>(self: Pin<&'r Self>) -> (RawSubscriptionGuard<'r, T>, bool)
+	where
+		Self: Sized,
+		T: 'r + Sync,
+	{
+		let (guard, refreshing) = self.project_ref().0.read_with_freshness();
+		(RawSubscriptionGuard(guard), refreshing)
+	}
+
 	fn read_exclusive<'r>(self: Pin<&'r Self>) -> RawSubscriptionGuardExclusive<'r, T>
 	where
 		Self: Sized,
@@ -1,11 +1,12 @@
-use std::{
+use alloc::{boxed::Box, sync::Arc};
+use core::{
 	borrow::Borrow,
 	fmt::{self, Debug, Formatter},
 	future::Future,
 	mem,
 	ops::Deref,
 	pin::Pin,
-	sync::{Arc, Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard},
+	sync::atomic::{AtomicBool, Ordering},
 };
 
 use isoprenoid::{
@@ -14,7 +15,11 @@ use isoprenoid::{
 };
 use pin_project::pin_project;
 
-use crate::{shadow_clone, traits::Guard};
+use crate::{
+	shadow_clone,
+	sync::{Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard, TryLockError},
+	traits::Guard,
+};
 
 use super::{UnmanagedSignal, UnmanagedSignalCell};
 
@@ -27,7 +32,7 @@ pub(crate) struct ReactiveCell<
 	SR: SignalsRuntimeRef,
 > {
 	#[pin]
-	signal: RawSignal<AssertSync<(Mutex<HandlerFnPin>, RwLock<T>)>, (), SR>,
+	signal: RawSignal<Eager<HandlerFnPin, T>, (), SR>,
 }
 
 impl<
@@ -66,13 +71,23 @@ unsafe impl<
 struct AssertSync<T: ?Sized>(T);
 unsafe impl<T: ?Sized> Sync for AssertSync<T> {}
 
-impl<T: Debug + ?Sized, HandlerFnPin: Debug> Debug
-	for AssertSync<(Mutex<HandlerFnPin>, RwLock<T>)>
-{
+/// The handler, whether the value is initialised, and the value.
+type Eager<HandlerFnPin, T> = AssertSync<(Mutex<HandlerFnPin>, AtomicBool, RwLock<T>)>;
+
+impl<HandlerFnPin, T: ?Sized> Eager<HandlerFnPin, T> {
+	/// Locks the value for an update, which marks it as initialised.
+	fn write(&self) -> RwLockWriteGuard<'_, T> {
+		let guard = self.0 .2.write().unwrap();
+		self.0 .1.store(true, Ordering::Release);
+		guard
+	}
+}
+
+impl<T: Debug + ?Sized, HandlerFnPin: Debug> Debug for Eager<HandlerFnPin, T> {
 	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
 		let debug_tuple = &mut f.debug_tuple("AssertSync");
 		{
-			let maybe_guard = self.0 .1.try_read();
+			let maybe_guard = self.0 .2.try_read();
 			debug_tuple.field(
 				maybe_guard
 					.as_ref()
@@ -119,12 +134,24 @@ impl<'a, T: ?Sized> Borrow<T> for ReactiveCellGuard<'a, T> {
 	}
 }
 
+impl<'a, T: ?Sized> AsRef<T> for ReactiveCellGuard<'a, T> {
+	fn as_ref(&self) -> &T {
+		self
+	}
+}
+
 impl<'a, T: ?Sized> Borrow<T> for ReactiveCellGuardExclusive<'a, T> {
 	fn borrow(&self) -> &T {
 		self.0.borrow()
 	}
 }
 
+impl<'a, T: ?Sized> AsRef<T> for ReactiveCellGuardExclusive<'a, T> {
+	fn as_ref(&self) -> &T {
+		self
+	}
+}
+
 impl<
 		T: ?Sized + Send,
 		HandlerFnPin: Send
@@ -140,6 +167,36 @@ impl<
 		on_subscribed_change_fn_pin: HandlerFnPin,
 		runtime: SR,
 	) -> Self
+	where
+		T: Sized,
+	{
+		Self::with_initialized_and_runtime(
+			initial_value,
+			true,
+			on_subscribed_change_fn_pin,
+			runtime,
+		)
+	}
+
+	/// Like [`with_runtime`](`ReactiveCell::with_runtime`), but reports itself as uninitialised
+	/// (holding `placeholder`) until its first update.
+	pub(crate) fn uninitialized_with_runtime(
+		placeholder: T,
+		on_subscribed_change_fn_pin: HandlerFnPin,
+		runtime: SR,
+	) -> Self
+	where
+		T: Sized,
+	{
+		Self::with_initialized_and_runtime(placeholder, false, on_subscribed_change_fn_pin, runtime)
+	}
+
+	fn with_initialized_and_runtime(
+		initial_value: T,
+		initialized: bool,
+		on_subscribed_change_fn_pin: HandlerFnPin,
+		runtime: SR,
+	) -> Self
 	where
 		T: Sized,
 	{
@@ -147,6 +204,7 @@ impl<
 			signal: RawSignal::with_runtime(
 				AssertSync((
 					Mutex::new(on_subscribed_change_fn_pin),
+					AtomicBool::new(initialized),
 					RwLock::new(initial_value),
 				)),
 				runtime,
@@ -176,7 +234,7 @@ impl<
 				.project_or_init::<E>(|_, slot| slot.write(()))
 				.0
 				 .0
-				 .1 as *const _)
+				 .2 as *const _)
 		}
 	}
 }
@@ -190,19 +248,16 @@ impl<
 				<SR::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus,
 			) -> Propagation,
 		SR: SignalsRuntimeRef,
-	> Callbacks<AssertSync<(Mutex<HandlerFnPin>, RwLock<T>)>, (), SR> for E
+	> Callbacks<Eager<HandlerFnPin, T>, (), SR> for E
 {
 	const UPDATE: Option<
-		fn(
-			eager: Pin<&AssertSync<(Mutex<HandlerFnPin>, RwLock<T>)>>,
-			lazy: Pin<&()>,
-		) -> isoprenoid::runtime::Propagation,
+		fn(eager: Pin<&Eager<HandlerFnPin, T>>, lazy: Pin<&()>) -> isoprenoid::runtime::Propagation,
 	> = None;
 
 	const ON_SUBSCRIBED_CHANGE: Option<
 		fn(
-			signal: Pin<&RawSignal<AssertSync<(Mutex<HandlerFnPin>, RwLock<T>)>, (), SR>>,
-			eager: Pin<&AssertSync<(Mutex<HandlerFnPin>, RwLock<T>)>>,
+			signal: Pin<&RawSignal<Eager<HandlerFnPin, T>, (), SR>>,
+			eager: Pin<&Eager<HandlerFnPin, T>>,
 			lazy: Pin<&()>,
 			subscribed: <<SR as SignalsRuntimeRef>::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus,
 		) -> Propagation,
@@ -211,8 +266,8 @@ impl<
 			T: ?Sized + Send,
 			HandlerFnPin: Send + FnMut(&T, <SR::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus) -> Propagation,
 			SR: SignalsRuntimeRef,
-		>(_: Pin<&RawSignal<AssertSync<(Mutex<HandlerFnPin>, RwLock<T>)>, (), SR>>, eager: Pin<&AssertSync<(Mutex<HandlerFnPin>, RwLock<T>)>>, _ :Pin<&()>, status: <SR::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus) -> Propagation{
-			eager.0.0.lock().unwrap()(&*eager.0.1.read().unwrap(), status)
+		>(_: Pin<&RawSignal<Eager<HandlerFnPin, T>, (), SR>>, eager: Pin<&Eager<HandlerFnPin, T>>, _ :Pin<&()>, status: <SR::CallbackTableTypes as CallbackTableTypes>::SubscribedStatus) -> Propagation{
+			eager.0.0.lock().unwrap()(&*eager.0.2.read().unwrap(), status)
 		}
 
 		Some(on_subscribed_change_fn_pin::<T,HandlerFnPin,SR>)
@@ -262,6 +317,19 @@ impl<
 		Self: 'r + Sized,
 		T: 'r + Sync;
 
+	fn try_read<'r>(self: Pin<&'r Self>) -> Option<ReactiveCellGuard<'r, T>>
+	where
+		Self: Sized,
+		T: 'r + Sync,
+	{
+		let touch = self.touch();
+		match touch.try_read() {
+			Ok(guard) => Some(ReactiveCellGuard(guard)),
+			Err(TryLockError::WouldBlock) => None,
+			Err(TryLockError::Poisoned(error)) => panic!("{error}"),
+		}
+	}
+
 	fn read_exclusive<'r>(self: Pin<&'r Self>) -> ReactiveCellGuardExclusive<'r, T>
 	where
 		Self: Sized,
@@ -291,6 +359,19 @@ impl<
 		Box::new(self.read_exclusive())
 	}
 
+	fn get_initialized(self: Pin<&Self>) -> Option<T>
+	where
+		T: Sync + Copy,
+	{
+		let (eager, _) = self
+			.project_ref()
+			.signal
+			.project_or_init::<E>(|_, slot| slot.write(()));
+		let guard = eager.0 .2.read().unwrap();
+		// Updates set the flag while holding the write lock.
+		eager.0 .1.load(Ordering::Acquire).then(|| *guard)
+	}
+
 	fn clone_runtime_ref(&self) -> SR
 	where
 		SR: Sized,
@@ -351,7 +432,7 @@ impl<
 			.run_detached(|| self.touch());
 		self.project_ref()
 			.signal
-			.update(|value, _| update(&mut value.0 .1.write().unwrap()))
+			.update(|value, _| update(&mut value.write()))
 	}
 
 	fn update_dyn(self: Pin<&Self>, update: Box<dyn 'static + Send + FnOnce(&mut T) -> Propagation>)
@@ -363,7 +444,7 @@ impl<
 			.run_detached(|| self.touch());
 		self.project_ref()
 			.signal
-			.update(|value, _| update(&mut value.0 .1.write().unwrap()))
+			.update(|value, _| update(&mut value.write()))
 	}
 
 	fn set_if_distinct_eager<'f>(
@@ -553,7 +634,7 @@ impl<
 					.expect("unreachable")
 					.take()
 					.expect("unreachable");
-				update(&mut value.0 .1.write().unwrap())
+				update(&mut value.write())
 			}
 		});
 		private::DetachedFuture(Box::pin(async move {
@@ -743,7 +824,7 @@ impl<
 							.expect("unreachable")
 							.take()
 							.expect("unreachable");
-						update(&mut *value.0 .1.write().unwrap())
+						update(&mut value.write())
 					} else {
 						Propagation::Halt
 					},
@@ -827,18 +908,19 @@ impl<
 
 	fn update_blocking<U>(&self, update: impl FnOnce(&mut T) -> (Propagation, U)) -> U {
 		self.signal
-			.update_blocking(|value, _| update(&mut value.0 .1.write().unwrap()))
+			.update_blocking(|value, _| update(&mut value.write()))
 	}
 
 	fn update_blocking_dyn(&self, update: Box<dyn '_ + FnOnce(&mut T) -> Propagation>) {
 		self.signal
-			.update_blocking(|value, _| (update(&mut value.0 .1.write().unwrap()), ()))
+			.update_blocking(|value, _| (update(&mut value.write()), ()))
 	}
 }
 
 /// Duplicated to avoid identities.
 mod private {
-	use std::{
+	use alloc::boxed::Box;
+	use core::{
 		future::Future,
 		pin::Pin,
 		task::{Context, Poll},
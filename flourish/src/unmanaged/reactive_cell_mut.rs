@@ -1,11 +1,11 @@
-use std::{
+use alloc::{boxed::Box, sync::Arc};
+use core::{
 	borrow::{Borrow, BorrowMut},
 	fmt::{self, Debug, Formatter},
 	future::Future,
 	mem,
 	ops::Deref,
 	pin::Pin,
-	sync::{Arc, Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard},
 };
 
 use isoprenoid::{
@@ -14,7 +14,11 @@ use isoprenoid::{
 };
 use pin_project::pin_project;
 
-use crate::{shadow_clone, traits::Guard};
+use crate::{
+	shadow_clone,
+	sync::{Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard, TryLockError},
+	traits::Guard,
+};
 
 use super::{UnmanagedSignal, UnmanagedSignalCell};
 
@@ -122,12 +126,24 @@ impl<'a, T: ?Sized> Borrow<T> for ReactiveCellMutGuard<'a, T> {
 	}
 }
 
+impl<'a, T: ?Sized> AsRef<T> for ReactiveCellMutGuard<'a, T> {
+	fn as_ref(&self) -> &T {
+		self
+	}
+}
+
 impl<'a, T: ?Sized> Borrow<T> for ReactiveCellMutGuardExclusive<'a, T> {
 	fn borrow(&self) -> &T {
 		self.0.borrow()
 	}
 }
 
+impl<'a, T: ?Sized> AsRef<T> for ReactiveCellMutGuardExclusive<'a, T> {
+	fn as_ref(&self) -> &T {
+		self
+	}
+}
+
 impl<
 		T: ?Sized + Send,
 		HandlerFnPin: Send
@@ -265,6 +281,19 @@ impl<
 		Self: 'r + Sized,
 		T: 'r + Sync;
 
+	fn try_read<'r>(self: Pin<&'r Self>) -> Option<ReactiveCellMutGuard<'r, T>>
+	where
+		Self: Sized,
+		T: 'r + Sync,
+	{
+		let touch = self.touch();
+		match touch.try_read() {
+			Ok(guard) => Some(ReactiveCellMutGuard(guard)),
+			Err(TryLockError::WouldBlock) => None,
+			Err(TryLockError::Poisoned(error)) => panic!("{error}"),
+		}
+	}
+
 	fn read_exclusive<'r>(self: Pin<&'r Self>) -> ReactiveCellMutGuardExclusive<'r, T>
 	where
 		Self: Sized,
@@ -841,7 +870,8 @@ impl<
 
 /// Duplicated to avoid identities.
 mod private {
-	use std::{
+	use alloc::boxed::Box;
+	use core::{
 		future::Future,
 		pin::Pin,
 		task::{Context, Poll},
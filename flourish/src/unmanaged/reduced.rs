@@ -1,10 +1,5 @@
-use std::{
-	borrow::Borrow,
-	cell::UnsafeCell,
-	ops::Deref,
-	pin::Pin,
-	sync::{RwLock, RwLockReadGuard, RwLockWriteGuard},
-};
+use alloc::boxed::Box;
+use core::{borrow::Borrow, cell::UnsafeCell, ops::Deref, pin::Pin};
 
 use isoprenoid::{
 	raw::{Callbacks, RawSignal},
@@ -13,7 +8,10 @@ use isoprenoid::{
 };
 use pin_project::pin_project;
 
-use crate::traits::{Guard, UnmanagedSignal};
+use crate::{
+	sync::{RwLock, RwLockReadGuard, RwLockWriteGuard, TryLockError},
+	traits::{Guard, UnmanagedSignal},
+};
 
 #[pin_project]
 #[must_use = "Signals do nothing unless they are polled or subscribed to."]
@@ -56,12 +54,24 @@ impl<'a, T: ?Sized> Borrow<T> for ReducedGuard<'a, T> {
 	}
 }
 
+impl<'a, T: ?Sized> AsRef<T> for ReducedGuard<'a, T> {
+	fn as_ref(&self) -> &T {
+		self
+	}
+}
+
 impl<'a, T: ?Sized> Borrow<T> for ReducedGuardExclusive<'a, T> {
 	fn borrow(&self) -> &T {
 		self.0.borrow()
 	}
 }
 
+impl<'a, T: ?Sized> AsRef<T> for ReducedGuardExclusive<'a, T> {
+	fn as_ref(&self) -> &T {
+		self
+	}
+}
+
 // TODO: Safety documentation.
 unsafe impl<
 		T: Send,
@@ -96,6 +106,12 @@ impl<
 				.0
 		}
 	}
+
+	/// Like [`Self::touch`], but never (re)computes the value, returning [`None`] instead.
+	pub(crate) fn try_touch(self: Pin<&Self>) -> Option<&RwLock<T>> {
+		let (_, cache) = self.project_ref().0.project_if_fresh()?;
+		Some(cache.project_ref().0)
+	}
 }
 
 enum E {}
@@ -202,6 +218,19 @@ impl<
 		Self: 'r + Sized,
 		T: 'r + Sync;
 
+	fn try_read<'r>(self: Pin<&'r Self>) -> Option<ReducedGuard<'r, T>>
+	where
+		Self: Sized,
+		T: 'r + Sync,
+	{
+		let touch = self.try_touch()?;
+		match touch.try_read() {
+			Ok(guard) => Some(ReducedGuard(guard)),
+			Err(TryLockError::WouldBlock) => None,
+			Err(TryLockError::Poisoned(error)) => panic!("{error}"),
+		}
+	}
+
 	fn read_exclusive<'r>(self: Pin<&'r Self>) -> ReducedGuardExclusive<'r, T>
 	where
 		Self: Sized,
@@ -238,6 +267,19 @@ impl<
 		self.0.clone_runtime_ref()
 	}
 
+	fn stale_symbol(self: Pin<&Self>) -> Option<SR::Symbol> {
+		self.0.is_stale().then(|| self.0.id())
+	}
+
+	fn read_with_freshness<'r>(self: Pin<&'r Self>) -> (Self::Read<'r>, bool)
+	where
+		Self: Sized,
+		T: 'r + Sync,
+	{
+		let refreshing = !self.0.is_initialised() || self.0.is_stale();
+		(self.read(), refreshing)
+	}
+
 	fn subscribe(self: Pin<&Self>) {
 		let signal = self.project_ref().0;
 		signal.subscribe();
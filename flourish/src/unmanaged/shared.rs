@@ -1,4 +1,5 @@
-use std::{borrow::Borrow, ops::Deref, pin::Pin};
+use alloc::boxed::Box;
+use core::{borrow::Borrow, ops::Deref, pin::Pin};
 
 use isoprenoid::runtime::SignalsRuntimeRef;
 use pin_project::pin_project;
@@ -43,12 +44,24 @@ impl<T: ?Sized> Borrow<T> for SharedGuard<'_, T> {
 	}
 }
 
+impl<T: ?Sized> AsRef<T> for SharedGuard<'_, T> {
+	fn as_ref(&self) -> &T {
+		self
+	}
+}
+
 impl<T: ?Sized> Borrow<T> for SharedGuardExclusive<'_, T> {
 	fn borrow(&self) -> &T {
 		self.0
 	}
 }
 
+impl<T: ?Sized> AsRef<T> for SharedGuardExclusive<'_, T> {
+	fn as_ref(&self) -> &T {
+		self
+	}
+}
+
 impl<T: Send + Sync + ?Sized, SR: SignalsRuntimeRef> Shared<T, SR> {
 	pub(crate) fn with_runtime(value: T, runtime: SR) -> Self
 	where
@@ -91,6 +104,14 @@ impl<T: Send + Sync + ?Sized, SR: SignalsRuntimeRef> UnmanagedSignal<T, SR> for
 		Self: 'r + Sized,
 		T: 'r + Sync;
 
+	fn try_read<'r>(self: Pin<&'r Self>) -> Option<Self::Read<'r>>
+	where
+		Self: Sized,
+		T: 'r + Sync,
+	{
+		Some(self.read())
+	}
+
 	fn read_exclusive<'r>(self: Pin<&'r Self>) -> Self::ReadExclusive<'r>
 	where
 		Self: Sized,
@@ -0,0 +1,141 @@
+use core::ops::Deref;
+
+use isoprenoid::runtime::SignalsRuntimeRef;
+
+use crate::{unmanaged::InertCell, MaybeSet, SignalArc, SignalArcDynCell, SignalDyn};
+
+/// A signal cell that only accepts values for which `validator` returns `true`.
+///
+/// Reads go through [`Deref`] to a read-only [`SignalDyn`].  
+/// Writes go through [`Validated::set`], which hands back rejected values.
+///
+/// Validators that return a [`Result`] instead are supported through
+/// [`Signal::cell_validated`](`crate::Signal::cell_validated`) and [`Validated::try_set`].
+///
+/// ```
+/// # {
+/// # #![cfg(feature = "global_signals_runtime")] // flourish feature
+/// # use flourish::{GlobalSignalsRuntime, MaybeSet};
+/// type Validated<'a, T, V> = flourish::Validated<'a, T, V, GlobalSignalsRuntime>;
+///
+/// let percentage = Validated::new(50, |value: &u8| *value <= 100).unwrap();
+/// assert_eq!(percentage.get(), 50);
+///
+/// assert_eq!(percentage.set(101), MaybeSet::Unchanged(101));
+/// assert_eq!(percentage.get(), 50);
+///
+/// assert_eq!(percentage.set(100), MaybeSet::Set);
+/// assert_eq!(percentage.get(), 100);
+/// # }
+/// ```
+///
+/// Since 0.2.1.
+pub struct Validated<'a, T: 'a + Send, V, SR: 'a + SignalsRuntimeRef> {
+	cell: SignalArcDynCell<'a, T, SR>,
+	validator: V,
+}
+
+impl<'a, T: 'a + Send, V, SR: 'a + SignalsRuntimeRef> Validated<'a, T, V, SR> {
+	/// Creates a new [`Validated`] cell iff `initial_value` passes `validator`.
+	///
+	/// # Errors
+	///
+	/// Returns `initial_value` iff `validator` rejects it.
+	///
+	/// Since 0.2.1.
+	pub fn new(initial_value: T, validator: V) -> Result<Self, T>
+	where
+		V: Fn(&T) -> bool,
+		SR: Default,
+	{
+		Self::new_with_runtime(initial_value, validator, SR::default())
+	}
+
+	/// Creates a new [`Validated`] cell iff `initial_value` passes `validator`.
+	///
+	/// # Errors
+	///
+	/// Returns `initial_value` iff `validator` rejects it.
+	///
+	/// Since 0.2.1.
+	pub fn new_with_runtime(initial_value: T, validator: V, runtime: SR) -> Result<Self, T>
+	where
+		V: Fn(&T) -> bool,
+	{
+		if !validator(&initial_value) {
+			return Err(initial_value);
+		}
+		Ok(Self {
+			cell: SignalArc::new(InertCell::with_runtime(initial_value, runtime)).into_dyn_cell(),
+			validator,
+		})
+	}
+
+	/// [`set`](`crate::Signal::set`)s `new_value` iff it passes the validator.
+	///
+	/// Otherwise, the current value is left untouched and `new_value` is handed back
+	/// as [`MaybeSet::Unchanged`].
+	///
+	/// Since 0.2.1.
+	pub fn set(&self, new_value: T) -> MaybeSet<T>
+	where
+		T: 'static,
+		V: Fn(&T) -> bool,
+	{
+		if (self.validator)(&new_value) {
+			self.cell.set(new_value);
+			MaybeSet::Set
+		} else {
+			MaybeSet::Unchanged(new_value)
+		}
+	}
+
+	/// Backs [`Signal::cell_validated_with_runtime`](`crate::Signal::cell_validated_with_runtime`).
+	pub(crate) fn try_new_with_runtime<E>(
+		initial_value: T,
+		validator: V,
+		runtime: SR,
+	) -> Result<Self, (T, E)>
+	where
+		V: Fn(&T) -> Result<(), E>,
+	{
+		if let Err(error) = validator(&initial_value) {
+			return Err((initial_value, error));
+		}
+		Ok(Self {
+			cell: SignalArc::new(InertCell::with_runtime(initial_value, runtime)).into_dyn_cell(),
+			validator,
+		})
+	}
+
+	/// [`set`](`crate::Signal::set`)s `new_value` iff the validator returns [`Ok`] for it.
+	///
+	/// Rejected values are never written, so dependents aren't signalled for them.
+	///
+	/// # Errors
+	///
+	/// Returns `new_value` together with the validator's error iff it was rejected.
+	///
+	/// Since 0.2.1.
+	pub fn try_set<E>(&self, new_value: T) -> Result<(), (T, E)>
+	where
+		T: 'static,
+		V: Fn(&T) -> Result<(), E>,
+	{
+		match (self.validator)(&new_value) {
+			Ok(()) => {
+				self.cell.set(new_value);
+				Ok(())
+			}
+			Err(error) => Err((new_value, error)),
+		}
+	}
+}
+
+impl<'a, T: 'a + Send, V, SR: 'a + SignalsRuntimeRef> Deref for Validated<'a, T, V, SR> {
+	type Target = SignalDyn<'a, T, SR>;
+
+	fn deref(&self) -> &Self::Target {
+		self.cell.as_read_only()
+	}
+}
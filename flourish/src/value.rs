@@ -0,0 +1,44 @@
+//! Autoref-based method selection for [`signal_value!`](`crate::signal_value`).
+//!
+//! A `Signal::value` method can't choose between [`Signal::get`] and [`Signal::get_clone`]
+//! without specialisation, but method resolution in a macro expansion can where `T` is known.
+
+use isoprenoid::runtime::SignalsRuntimeRef;
+
+use crate::{traits::UnmanagedSignal, Signal};
+
+/// Wraps the signal so that [`ValueViaCopy`] takes precedence where it applies.
+pub struct ValueProbe<'r, T: ?Sized + Send, S: ?Sized + Send + Sync, SR: ?Sized + SignalsRuntimeRef>(
+	pub &'r Signal<T, S, SR>,
+);
+
+/// Selected first, through the extra reference.
+pub trait ValueViaCopy<T> {
+	/// Calls [`Signal::get`].
+	fn __value(&self) -> T;
+}
+
+/// Fallback after auto-deref.
+pub trait ValueViaClone<T> {
+	/// Calls [`Signal::get_clone`].
+	fn __value(&self) -> T;
+}
+
+impl<T: Send + Sync + Copy, S: ?Sized + UnmanagedSignal<T, SR>, SR: ?Sized + SignalsRuntimeRef>
+	ValueViaCopy<T> for &ValueProbe<'_, T, S, SR>
+{
+	fn __value(&self) -> T {
+		self.0.get()
+	}
+}
+
+impl<
+		T: Send + Sync + Clone,
+		S: ?Sized + UnmanagedSignal<T, SR>,
+		SR: ?Sized + SignalsRuntimeRef,
+	> ValueViaClone<T> for ValueProbe<'_, T, S, SR>
+{
+	fn __value(&self) -> T {
+		self.0.get_clone()
+	}
+}
@@ -0,0 +1,72 @@
+#![cfg(all(feature = "global_signals_runtime", feature = "arc-swap"))]
+
+use std::sync::Arc;
+
+use arc_swap::ArcSwap;
+use flourish::GlobalSignalsRuntime;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn from_arc_swap_needs_trigger() {
+	let v = &Validator::new();
+
+	let swap = Arc::new(ArcSwap::from_pointee(1));
+	let trigger = Signal::cell(());
+	let signal = Signal::from_arc_swap(Arc::clone(&swap), trigger.as_dyn());
+	let sub = Subscription::computed(|| v.push(*signal.get_clone()));
+	v.expect([1]);
+
+	swap.store(Arc::new(2));
+	v.expect([]);
+
+	trigger.set(());
+	v.expect([2]);
+
+	// Reads share the stored `Arc`.
+	assert!(Arc::ptr_eq(&signal.get_clone(), &swap.load_full()));
+
+	drop(sub);
+}
+
+#[test]
+fn to_arc_swap_follows_signal() {
+	let cell = Signal::cell(Arc::new(1));
+	let swap = cell.to_arc_swap();
+	assert_eq!(**swap.load(), 1);
+
+	let two = Arc::new(2);
+	cell.set(Arc::clone(&two));
+	assert!(Arc::ptr_eq(&swap.load_full(), &two));
+
+	// The swap keeps its last value once the signal is gone.
+	let weak = cell.downgrade();
+	drop(cell);
+	assert!(weak.upgrade().is_none());
+	assert_eq!(**swap.load(), 2);
+}
+
+#[test]
+fn to_arc_swap_stops_after_drop() {
+	let cell = Signal::cell(Arc::new(1));
+	let synced = cell.to_arc_swap();
+	let swap = Arc::clone(synced.arc_swap());
+	drop(synced);
+
+	cell.set(Arc::new(2));
+	assert_eq!(*cell.get_clone(), 2);
+	assert_eq!(**swap.load(), 1);
+}
+
+#[test]
+fn to_arc_swap_releases_effect() {
+	let cell = Signal::cell(Arc::new(1));
+	let weak = Arc::downgrade(cell.to_arc_swap().arc_swap());
+
+	// Nothing else keeps the swap (or the forwarding effect that owns it) alive.
+	assert!(weak.upgrade().is_none());
+}
@@ -0,0 +1,85 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use std::{ops::ControlFlow, sync::Mutex};
+
+use flourish::{GSRSymbol, GlobalSignalsRuntime, Propagation, SignalsRuntimeRef};
+use isoprenoid::runtime::UpdateTicket;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn cancel_queued() {
+	static RUNS: Validator<&str> = Validator::new();
+
+	let id = GlobalSignalsRuntime.next_id();
+
+	let (a, b) = GlobalSignalsRuntime.hint_batched_updates(|| {
+		let a = GlobalSignalsRuntime
+			.update_or_enqueue_ticketed(id, || {
+				RUNS.push("a");
+				Propagation::Halt
+			})
+			.unwrap();
+		let b = GlobalSignalsRuntime
+			.update_or_enqueue_ticketed(id, || {
+				RUNS.push("b");
+				Propagation::Halt
+			})
+			.unwrap();
+		assert!(GlobalSignalsRuntime.cancel_update(a));
+		assert!(!GlobalSignalsRuntime.cancel_update(a));
+		RUNS.expect([]);
+		(a, b)
+	});
+	RUNS.expect(["b"]);
+
+	// Completed updates can't be cancelled.
+	assert!(!GlobalSignalsRuntime.cancel_update(a));
+	assert!(!GlobalSignalsRuntime.cancel_update(b));
+}
+
+#[test]
+fn cancel_staged() {
+	static RUNS: Validator<&str> = Validator::new();
+
+	let id = GlobalSignalsRuntime.next_id();
+
+	let flow = GlobalSignalsRuntime.transaction(|| {
+		let a = GlobalSignalsRuntime
+			.update_or_enqueue_ticketed(id, || {
+				RUNS.push("staged a");
+				Propagation::Halt
+			})
+			.unwrap();
+		GlobalSignalsRuntime.update_or_enqueue(id, || {
+			RUNS.push("staged b");
+			Propagation::Halt
+		});
+		assert!(GlobalSignalsRuntime.cancel_update(a));
+		ControlFlow::<(), _>::Continue(())
+	});
+	assert_eq!(flow, ControlFlow::Continue(()));
+	RUNS.expect(["staged b"]);
+}
+
+#[test]
+fn cancel_running_is_noop() {
+	static RUNS: Validator<&str> = Validator::new();
+	static TICKET: Mutex<Option<UpdateTicket<GSRSymbol>>> = Mutex::new(None);
+
+	let id = GlobalSignalsRuntime.next_id();
+
+	GlobalSignalsRuntime.hint_batched_updates(|| {
+		let ticket = GlobalSignalsRuntime
+			.update_or_enqueue_ticketed(id, || {
+				let ticket = TICKET.lock().unwrap().expect("set before running");
+				assert!(!GlobalSignalsRuntime.cancel_update(ticket));
+				RUNS.push("running");
+				Propagation::Halt
+			})
+			.unwrap();
+		*TICKET.lock().unwrap() = Some(ticket);
+	});
+	RUNS.expect(["running"]);
+}
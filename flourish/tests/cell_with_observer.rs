@@ -0,0 +1,138 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use std::sync::{Arc, Mutex, OnceLock};
+
+use flourish::{GlobalSignalsRuntime, Propagation, SignalArcDynCell, SignalWeakDynCell};
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+fn observed() -> (Arc<Mutex<Vec<i32>>>, impl 'static + Send + FnMut(&i32)) {
+	let seen = Arc::new(Mutex::new(Vec::new()));
+	(seen.clone(), move |value: &i32| {
+		seen.lock().unwrap().push(*value)
+	})
+}
+
+#[test]
+fn observes_committed_changes() {
+	let (seen, observer) = observed();
+	let cell = Signal::cell_with_observer(0, observer);
+	assert_eq!(*seen.lock().unwrap(), []);
+
+	cell.set(1);
+	cell.set_blocking(2);
+	cell.update(|value| {
+		*value += 1;
+		Propagation::Propagate
+	});
+	assert_eq!(cell.replace_blocking(4), 3);
+	assert_eq!(*seen.lock().unwrap(), [1, 2, 3, 4]);
+	assert_eq!(cell.get(), 4);
+}
+
+#[test]
+fn skips_halted_updates() {
+	let (seen, observer) = observed();
+	let cell = Signal::cell_with_observer(0, observer);
+
+	cell.set_if_distinct(0);
+	assert_eq!(cell.set_if_distinct_blocking(0), Err(0));
+	cell.update(|_| Propagation::Halt);
+	assert_eq!(*seen.lock().unwrap(), []);
+
+	cell.set_if_distinct(1);
+	assert_eq!(*seen.lock().unwrap(), [1]);
+}
+
+#[test]
+fn observer_sees_value_dependents_see() {
+	let v = &Validator::new();
+	let seen = Arc::new(Mutex::new(Vec::new()));
+
+	let cell = Signal::cell_with_observer(0, {
+		let seen = seen.clone();
+		move |value: &i32| seen.lock().unwrap().push(*value)
+	});
+	let sub = Subscription::computed(|| v.push(cell.get()));
+	v.expect([0]);
+
+	cell.set(5);
+	v.expect([5]);
+	assert_eq!(*seen.lock().unwrap(), [5]);
+
+	drop(sub);
+}
+
+#[test]
+fn observer_does_not_record_dependencies() {
+	let v = &Validator::new();
+
+	let other = Signal::cell(0);
+	let cell = Signal::cell_with_observer(0, {
+		let other = other.to_owned();
+		move |_: &i32| {
+			other.get();
+		}
+	});
+	let trigger = Signal::cell(1);
+	let sub = Subscription::computed(|| {
+		let next = trigger.get();
+		cell.set(next);
+		v.push(next)
+	});
+	v.expect([1]);
+
+	other.set(1);
+	v.expect([]);
+
+	trigger.set(2);
+	v.expect([2]);
+	assert_eq!(cell.get(), 2);
+
+	drop(sub);
+}
+
+fn write_from_observer(
+	write: impl 'static + Send + Fn(&SignalWeakDynCell<'static, i32, GlobalSignalsRuntime>, i32),
+) -> (
+	Arc<Mutex<Vec<i32>>>,
+	SignalArcDynCell<'static, i32, GlobalSignalsRuntime>,
+) {
+	let (seen, mut record) = observed();
+	let this = Arc::new(OnceLock::<
+		SignalWeakDynCell<'static, i32, GlobalSignalsRuntime>,
+	>::new());
+	let cell = Signal::cell_with_observer(0, {
+		let this = Arc::clone(&this);
+		move |value: &i32| {
+			record(value);
+			if *value < 3 {
+				write(this.get().unwrap(), value + 1);
+			}
+		}
+	})
+	.into_dyn_cell();
+	assert!(this.set(cell.downgrade()).is_ok());
+	(seen, cell)
+}
+
+#[test]
+fn reentrant_write_is_queued() {
+	let (seen, cell) = write_from_observer(|cell, value| cell.upgrade().unwrap().set(value));
+
+	cell.set(1);
+	assert_eq!(*seen.lock().unwrap(), [1, 2, 3]);
+	assert_eq!(cell.get(), 3);
+}
+
+#[test]
+#[should_panic = "while propagating another update"]
+fn reentrant_blocking_write_panics() {
+	let (_, cell) = write_from_observer(|cell, value| cell.upgrade().unwrap().set_blocking(value));
+
+	cell.set(1);
+}
@@ -0,0 +1,37 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::GlobalSignalsRuntime;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn tracks_both() {
+	let v = &Validator::new();
+
+	let a = Signal::cell(1);
+	let b = Signal::cell(2);
+	let lt = a.lt_signal(&b);
+	let eq = a.eq_signal(&b.to_dyn());
+	let _sub = Subscription::computed(|| v.push((lt.get(), eq.get())));
+	v.expect([(true, false)]);
+
+	a.set(2);
+	v.expect([(false, true)]);
+
+	b.set(3);
+	v.expect([(true, false)]);
+}
+
+#[test]
+fn orderings() {
+	let a = Signal::cell(1);
+	let b = Signal::cell(1);
+	assert!(a.le_signal(&b).get());
+	assert!(a.ge_signal(&b).get());
+	assert!(!a.gt_signal(&b).get());
+	assert!(!a.ne_signal(&b).get());
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use std::thread;
+
+use flourish::GlobalSignalsRuntime;
+
+type Effect<'a> = flourish::Effect<'a, GlobalSignalsRuntime>;
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn sets_only_on_match() {
+	let v = &Validator::new();
+
+	let cell = Signal::cell(1);
+	let _e = Effect::new(|| v.push(cell.get()), drop);
+	v.expect([1]);
+
+	assert_eq!(cell.compare_and_set(&1, 2), Ok(()));
+	v.expect([2]);
+
+	// A mismatch hands the value back and doesn't signal dependents.
+	assert_eq!(cell.compare_and_set(&1, 3), Err(3));
+	v.expect([]);
+	assert_eq!(cell.get(), 2);
+}
+
+#[test]
+fn concurrent_increments() {
+	let cell = Signal::cell(0_u32);
+
+	thread::scope(|s| {
+		for _ in 0..4 {
+			s.spawn(|| {
+				for _ in 0..100 {
+					loop {
+						let current = cell.get();
+						if cell.compare_and_set(&current, current + 1).is_ok() {
+							break;
+						}
+					}
+				}
+			});
+		}
+	});
+
+	assert_eq!(cell.get(), 400);
+}
@@ -0,0 +1,71 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::GlobalSignalsRuntime;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+static RUNS: Validator<i32> = Validator::new();
+
+#[test]
+fn unsubscribed_recomputes_on_read() {
+	let input = Signal::cell(1);
+	let (signal, evictor) = Signal::computed_evictable({
+		let input = input.to_owned();
+		move || {
+			let value = input.get();
+			RUNS.push(value);
+			value
+		}
+	});
+
+	// Evicting before the first read does nothing.
+	evictor.evict();
+	RUNS.expect([]);
+
+	assert_eq!(signal.get(), 1);
+	RUNS.expect([1]);
+
+	assert_eq!(signal.get(), 1);
+	RUNS.expect([]);
+
+	evictor.evict();
+	RUNS.expect([]);
+
+	assert_eq!(signal.get(), 1);
+	RUNS.expect([1]);
+
+	// Dependencies are still tracked after recomputing.
+	input.set(2);
+	assert_eq!(signal.get(), 2);
+	RUNS.expect([2]);
+
+	drop(signal);
+	evictor.evict();
+	RUNS.expect([]);
+}
+
+#[test]
+fn subscribed_recomputes_immediately() {
+	let v = &Validator::new();
+
+	let mut counter = 0;
+	let (signal, evictor) = Signal::computed_evictable(move || {
+		counter += 1;
+		counter
+	});
+
+	let sub = Subscription::computed(|| v.push(signal.get()));
+	v.expect([1]);
+
+	evictor.evict();
+	v.expect([2]);
+
+	drop(sub);
+	evictor.evict();
+	v.expect([]);
+	assert_eq!(signal.get(), 3);
+}
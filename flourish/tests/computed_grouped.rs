@@ -0,0 +1,88 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::{GlobalSignalsRuntime, SignalsRuntimeRef};
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn reruns_per_key_change() {
+	let v = &Validator::new();
+
+	let a = Signal::cell(1);
+	let b = Signal::cell(2);
+
+	let grouped = Signal::computed_grouped(
+		|| (a.get() + b.get()) / 10,
+		|&group| {
+			v.push(group);
+			group
+		},
+	);
+	let _sub = grouped.to_subscription();
+	v.expect([0]);
+
+	a.set(3);
+	b.set(4);
+	v.expect([]);
+
+	b.set(8);
+	v.expect([1]);
+	assert_eq!(grouped.get(), 1);
+	v.expect([]);
+}
+
+#[test]
+fn coalesces_members() {
+	let v = &Validator::new();
+
+	let a = Signal::cell(false);
+	let b = Signal::cell(false);
+
+	let grouped = Signal::computed_grouped(
+		|| a.get() || b.get(),
+		|&any| {
+			v.push(any);
+			any
+		},
+	);
+	let _sub = Subscription::computed(|| grouped.get());
+	v.expect([false]);
+
+	GlobalSignalsRuntime.hint_batched_updates(|| {
+		a.set(true);
+		b.set(true);
+	});
+	v.expect([true]);
+
+	a.set(false);
+	v.expect([]);
+}
+
+#[test]
+fn reads_in_fn_pin_are_untracked() {
+	let v = &Validator::new();
+
+	let member = Signal::cell(1);
+	let other = Signal::cell(10);
+
+	let grouped = Signal::computed_grouped(
+		|| member.get() > 0,
+		|&positive| {
+			let value = (positive, other.get());
+			v.push(value);
+			value
+		},
+	);
+	let _sub = grouped.to_subscription();
+	v.expect([(true, 10)]);
+
+	other.set(20);
+	v.expect([]);
+
+	member.set(-1);
+	v.expect([(false, 20)]);
+}
@@ -0,0 +1,31 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::GlobalSignalsRuntime;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn keeps_previous() {
+	let v = &Validator::new();
+
+	let input = Signal::cell(None);
+	let kept = Signal::computed_keep(|| input.get());
+	let sub = Subscription::computed(|| v.push(kept.get()));
+	v.expect([0]);
+
+	input.set(Some(1));
+	v.expect([1]);
+
+	input.set(None);
+	v.expect([]);
+	assert_eq!(kept.get(), 1);
+
+	input.set(Some(2));
+	v.expect([2]);
+
+	drop(sub);
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use std::sync::{
+	atomic::{AtomicUsize, Ordering},
+	Arc,
+};
+
+use flourish::GlobalSignalsRuntime;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn invalidate() {
+	let v = &Validator::new();
+
+	let external = Arc::new(AtomicUsize::new(1));
+	let (signal, invalidator) = Signal::computed_manually({
+		let external = Arc::clone(&external);
+		move || v.push(external.load(Ordering::Relaxed))
+	});
+	v.expect([]);
+
+	signal.get();
+	v.expect([1]);
+
+	external.store(2, Ordering::Relaxed);
+	signal.get();
+	v.expect([]);
+
+	invalidator.invalidate();
+	v.expect([]);
+	signal.get();
+	v.expect([2]);
+
+	let sub = Subscription::computed(move || signal.get());
+	v.expect([]);
+
+	external.store(3, Ordering::Relaxed);
+	invalidator.invalidate();
+	v.expect([3]);
+
+	drop(sub);
+	invalidator.invalidate();
+	v.expect([]);
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use std::num::NonZeroU64;
+
+use flourish::{GlobalCountingSignalsRuntime, GlobalSignalsRuntime};
+
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn observes_subscription() {
+	type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+
+	let v = &Validator::new();
+
+	let input = Signal::cell(1);
+	let observed = Signal::computed_reactive(|| input.get(), |status| v.push(status));
+	v.expect([]);
+
+	let sub = Subscription::computed(|| observed.get());
+	v.expect([true]);
+
+	input.set(2);
+	assert_eq!(sub.get(), 2);
+	v.expect([]);
+
+	drop(sub);
+	v.expect([false]);
+}
+
+#[test]
+fn counts() {
+	type Signal<T, S> = flourish::Signal<T, S, GlobalCountingSignalsRuntime>;
+
+	let v = &Validator::new();
+
+	let observed = Signal::computed_reactive(|| (), |status| v.push(status.map(NonZeroU64::get)));
+
+	let a = observed.to_subscription();
+	let b = observed.to_subscription();
+	v.expect([Some(1), Some(2)]);
+
+	drop((a, b));
+	v.expect([Some(1), None]);
+}
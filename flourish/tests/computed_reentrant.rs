@@ -0,0 +1,36 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::GlobalSignalsRuntime;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn memoized_recursion() {
+	let v = &Validator::new();
+
+	let offset = Signal::cell(0u64);
+	let sum = Signal::computed_reentrant(4u64, |&n, family| {
+		v.push(n);
+		match n {
+			0 => offset.get(),
+			n => n + family.get(n - 1) + family.get(n - 1),
+		}
+	});
+	assert_eq!(sum.get(), 26);
+	// Each key runs once per refresh despite being read twice.
+	v.expect([4, 3, 2, 1, 0]);
+
+	offset.set(1);
+	assert_eq!(sum.get(), 42);
+	v.expect([4, 3, 2, 1, 0]);
+}
+
+#[test]
+#[should_panic = "Cyclic read"]
+fn cycle_panics() {
+	let signal = Signal::computed_reentrant(0u8, |&n, family| -> u8 { family.get((n + 1) % 3) });
+	signal.get();
+}
@@ -0,0 +1,93 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use std::panic::{self, AssertUnwindSafe};
+
+use flourish::GlobalSignalsRuntime;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn keeps_last_good_value() {
+	let v = &Validator::new();
+
+	let input = Signal::cell(1);
+	let checked = Signal::computed_resilient(|| {
+		let input = input.get();
+		assert!(input > 0, "not positive");
+		input
+	});
+	let sub = Subscription::computed(|| v.push(checked.get()));
+	v.expect([1]);
+	assert!(!checked.last_error().is_errored());
+
+	input.set(0);
+	v.expect([]);
+	assert!(checked.last_error().is_errored());
+	assert_eq!(checked.get(), 1);
+
+	// The runtime is still usable, and the dependency is still recorded.
+	input.set(2);
+	v.expect([2]);
+	assert!(!checked.last_error().is_errored());
+
+	input.set(-1);
+	let payload = checked.last_error().take().unwrap();
+	assert_eq!(*payload.downcast::<&str>().unwrap(), "not positive");
+	assert!(!checked.last_error().is_errored());
+
+	drop(sub);
+}
+
+#[test]
+fn first_run_panics_at_read_site() {
+	let input = Signal::cell(0);
+	let checked = Signal::computed_resilient(|| {
+		let input = input.get();
+		assert!(input > 0, "not positive");
+		input
+	});
+
+	let read = panic::catch_unwind(AssertUnwindSafe(|| checked.get()));
+	assert!(read.is_err());
+	assert!(checked.last_error().is_errored());
+	assert!(checked.try_read().is_none());
+
+	// Without a `Default` placeholder, the first successful run seeds the value.
+	input.set(3);
+	assert_eq!(checked.get(), 3);
+	assert!(!checked.last_error().is_errored());
+}
+
+#[test]
+fn keeps_dependencies_on_panic() {
+	let v = &Validator::new();
+
+	let first = Signal::cell(1);
+	let second = Signal::cell(1);
+	let checked = Signal::computed_resilient(|| {
+		let first = first.get();
+		assert!(first > 0, "not positive");
+		first + second.get()
+	});
+	let sub = Subscription::computed(|| v.push(checked.get()));
+	v.expect([2]);
+
+	// This run stops before reading `second`.
+	first.set(0);
+	v.expect([]);
+	assert!(checked.last_error().take().is_some());
+
+	// `second` is still a dependency, so this refreshes (and panics again).
+	second.set(2);
+	v.expect([]);
+	assert!(checked.last_error().is_errored());
+
+	first.set(1);
+	v.expect([3]);
+
+	drop(sub);
+}
@@ -0,0 +1,89 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use std::{
+	collections::BTreeSet,
+	sync::{Arc, Mutex},
+};
+
+use flourish::{GSRSymbol, GlobalSignalsRuntime};
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn notifies_all_by_default() {
+	let v = &Validator::new();
+
+	let cell = Signal::cell(1);
+	let selective = Signal::computed_selective(|| cell.get(), |_| true);
+	let a = Subscription::computed(|| v.push(("a", selective.get())));
+	let b = Subscription::computed(|| v.push(("b", selective.get())));
+	v.expect([("a", 1), ("b", 1)]);
+
+	cell.set(2);
+	v.expect([("a", 2), ("b", 2)]);
+
+	drop((a, b));
+}
+
+#[test]
+fn skips_filtered_dependents() {
+	let v = &Validator::new();
+	let asked = Arc::new(Mutex::new(Vec::<GSRSymbol>::new()));
+	let muted = Arc::new(Mutex::new(BTreeSet::<GSRSymbol>::new()));
+
+	let cell = Signal::cell(1);
+	let selective = Signal::computed_selective(|| cell.get(), {
+		let asked = Arc::clone(&asked);
+		let muted = Arc::clone(&muted);
+		move |dependent| {
+			asked.lock().unwrap().push(dependent);
+			!muted.lock().unwrap().contains(&dependent)
+		}
+	});
+	let a = Subscription::computed(|| v.push(("a", selective.get())));
+	let b = Subscription::computed(|| v.push(("b", selective.get())));
+	v.expect([("a", 1), ("b", 1)]);
+
+	cell.set(2);
+	v.expect([("a", 2), ("b", 2)]);
+
+	// Dependents are asked about in creation order.
+	let b_symbol = *asked.lock().unwrap().iter().max().unwrap();
+	assert_eq!(asked.lock().unwrap().len(), 2);
+	muted.lock().unwrap().insert(b_symbol);
+
+	cell.set(3);
+	v.expect([("a", 3)]);
+	assert_eq!(selective.get(), 3);
+
+	drop((a, b));
+}
+
+#[test]
+fn filter_does_not_record_dependencies() {
+	let v = &Validator::new();
+
+	let other = Signal::cell(0);
+	let cell = Signal::cell(1);
+	let selective = Signal::computed_selective(|| cell.get(), {
+		let other = other.to_owned();
+		move |_| other.get() == 0
+	});
+	let sub = Subscription::computed(|| v.push(selective.get()));
+	v.expect([1]);
+
+	cell.set(2);
+	v.expect([2]);
+
+	other.set(1);
+	v.expect([]);
+
+	cell.set(3);
+	v.expect([]);
+
+	drop(sub);
+}
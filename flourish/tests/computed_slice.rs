@@ -0,0 +1,51 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::GlobalSignalsRuntime;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn reads_as_slice() {
+	let v = &Validator::new();
+
+	let count = Signal::cell(2);
+	let numbers = Signal::computed_slice({
+		let count = count.to_owned();
+		move || {
+			let count = count.get();
+			v.push(count);
+			(0..count).collect::<Vec<_>>()
+		}
+	});
+	v.expect([]);
+
+	assert_eq!(&*numbers.read(), [0, 1]);
+	assert_eq!(&*numbers.read_exclusive(), [0, 1]);
+	v.expect([2]);
+
+	count.set(3);
+	assert_eq!(numbers.read().len(), 3);
+	v.expect([3]);
+}
+
+#[test]
+fn subscribed_dependents_update() {
+	let v = &Validator::new();
+
+	let count = Signal::cell(1);
+	let numbers = Signal::computed_slice({
+		let count = count.to_owned();
+		move || vec![(); count.get()]
+	});
+	let sub = Subscription::computed(|| v.push(numbers.read().len()));
+	v.expect([1]);
+
+	count.set(4);
+	v.expect([4]);
+
+	drop(sub);
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::GlobalSignalsRuntime;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn stores_but_halts() {
+	let v = &Validator::new();
+
+	let input = Signal::cell(0);
+	let rounded = Signal::computed_store_distinct(|| input.get(), |a, b| a / 10 == b / 10);
+	let sub = Subscription::computed(|| v.push(rounded.get() / 10));
+	v.expect([0]);
+
+	input.set(5);
+	v.expect([]);
+	assert_eq!(rounded.get(), 5);
+
+	input.set(12);
+	v.expect([1]);
+	assert_eq!(rounded.get(), 12);
+
+	drop(sub);
+}
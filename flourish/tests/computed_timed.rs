@@ -0,0 +1,32 @@
+#![cfg(all(feature = "global_signals_runtime", feature = "timing"))]
+
+use std::{sync::atomic::Ordering, thread, time::Duration};
+
+use flourish::GlobalSignalsRuntime;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+
+#[test]
+fn accumulates_across_runs() {
+	let input = Signal::cell(1);
+	let (signal, nanos) = Signal::computed_timed({
+		let input = input.to_owned();
+		move || {
+			thread::sleep(Duration::from_millis(2));
+			input.get()
+		}
+	});
+	assert_eq!(nanos.load(Ordering::Relaxed), 0);
+
+	assert_eq!(signal.get(), 1);
+	let first = nanos.load(Ordering::Relaxed);
+	assert!(first >= 2_000_000);
+
+	// Cached reads don't run `fn_pin`.
+	assert_eq!(signal.get(), 1);
+	assert_eq!(nanos.load(Ordering::Relaxed), first);
+
+	input.set(2);
+	assert_eq!(signal.get(), 2);
+	assert!(nanos.load(Ordering::Relaxed) >= first + 2_000_000);
+}
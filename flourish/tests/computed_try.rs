@@ -0,0 +1,53 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::GlobalSignalsRuntime;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn error_before_success() {
+	let input = Signal::cell(Err("none yet"));
+	let (result, last_ok) = Signal::computed_try(|| input.get());
+
+	assert_eq!(result.get(), Err("none yet"));
+	assert_eq!(last_ok.get(), None::<i32>);
+
+	input.set(Ok(1));
+	assert_eq!(result.get(), Ok(1));
+	assert_eq!(last_ok.get(), Some(1));
+}
+
+#[test]
+fn keeps_last_ok() {
+	let v = &Validator::new();
+
+	let input = Signal::cell(Ok(1));
+	let (result, last_ok) = Signal::computed_try(|| input.get());
+	let _result_sub = Subscription::computed(|| v.push(Err(result.get())));
+	let _last_ok_sub = Subscription::computed(|| v.push(Ok(last_ok.get())));
+	v.expect([Err(Ok(1)), Ok(Some(1))]);
+
+	input.set(Err("error"));
+	v.expect([Err(Err("error"))]);
+	assert_eq!(last_ok.get(), Some(1));
+
+	input.set(Err("other error"));
+	v.expect([Err(Err("other error"))]);
+
+	input.set(Ok(2));
+	v.expect([Err(Ok(2)), Ok(Some(2))]);
+}
+
+#[test]
+fn companion_keeps_result_alive() {
+	let input = Signal::cell(Ok::<_, ()>(1));
+	let (result, last_ok) = Signal::computed_try(|| input.get());
+	drop(result);
+
+	input.set(Ok(2));
+	assert_eq!(last_ok.get(), Some(2));
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use std::{sync::Mutex, time::Duration};
+
+use flourish::GlobalSignalsRuntime;
+use futures_channel::oneshot;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn expiry_and_reset() {
+	let v = &Validator::new();
+	let timers = &Mutex::new(Vec::new());
+
+	let a = Signal::cell(1);
+	let ttl = Signal::computed_ttl(
+		|| v.push(a.get()),
+		Duration::from_secs(1),
+		|ttl| {
+			assert_eq!(ttl, Duration::from_secs(1));
+			let (sender, receiver) = oneshot::channel::<()>();
+			timers.lock().unwrap().push(sender);
+			async move {
+				receiver.await.ok();
+			}
+		},
+	);
+	let sub = ttl.to_subscription();
+	v.expect([1]);
+
+	// Expiry.
+	let first = timers.lock().unwrap().remove(0);
+	first.send(()).unwrap();
+	v.expect([1]);
+
+	// A dependency-triggered recomputation resets the timer.
+	a.set(2);
+	v.expect([2]);
+	let stale = timers.lock().unwrap().remove(0);
+	assert!(stale.is_canceled());
+	stale.send(()).ok();
+	v.expect([]);
+
+	drop(sub);
+	drop(ttl);
+	v.expect([]);
+}
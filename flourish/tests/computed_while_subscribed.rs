@@ -0,0 +1,152 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::GlobalSignalsRuntime;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn holds_value_while_unsubscribed() {
+	let v = &Validator::new();
+
+	let input = Signal::cell(1);
+	let gated = Signal::computed_while_subscribed(|| {
+		let value = input.get();
+		v.push(value);
+		value
+	});
+	v.expect([]);
+
+	let sub = Subscription::computed(|| gated.get());
+	v.expect([1]);
+
+	input.set(2);
+	assert_eq!(sub.get(), 2);
+	v.expect([2]);
+
+	drop(sub);
+	input.set(3);
+	input.set(4);
+	assert_eq!(gated.get(), 2);
+	assert_eq!(gated.get(), 2);
+	v.expect([]);
+}
+
+#[test]
+fn catches_up_on_resubscription() {
+	let v = &Validator::new();
+
+	let input = Signal::cell(1);
+	let gated = Signal::computed_while_subscribed(|| {
+		let value = input.get();
+		v.push(value);
+		value
+	});
+
+	let sub = gated.to_subscription();
+	v.expect([1]);
+	drop(sub);
+
+	input.set(2);
+	v.expect([]);
+
+	let sub = gated.to_subscription();
+	v.expect([2]);
+	assert_eq!(sub.get(), 2);
+	v.expect([]);
+
+	input.set(3);
+	v.expect([3]);
+	drop(sub);
+
+	// Recomputes once, even without a change since unsubscribing.
+	let sub = gated.to_subscription();
+	v.expect([3]);
+	drop(sub);
+}
+
+#[test]
+fn never_subscribed() {
+	let v = &Validator::new();
+
+	let input = Signal::cell(1);
+	let gated = Signal::computed_while_subscribed(|| {
+		let value = input.get();
+		v.push(value);
+		value
+	});
+
+	assert_eq!(gated.get(), 1);
+	v.expect([1]);
+
+	input.set(2);
+	assert_eq!(gated.get(), 1);
+	input.set(3);
+	assert_eq!(gated.get(), 1);
+	v.expect([]);
+
+	let sub = Subscription::computed(|| gated.get());
+	assert_eq!(sub.get(), 3);
+	v.expect([3]);
+}
+
+#[test]
+fn counting_runtime() {
+	use flourish::GlobalCountingSignalsRuntime;
+
+	type Signal<T, S> = flourish::Signal<T, S, GlobalCountingSignalsRuntime>;
+
+	let v = &Validator::new();
+
+	let input = Signal::cell(1);
+	let gated = Signal::computed_while_subscribed(|| {
+		let value = input.get();
+		v.push(value);
+		value
+	});
+
+	let a = gated.to_subscription();
+	let b = gated.to_subscription();
+	v.expect([1]);
+
+	// Still subscribed through `b`.
+	drop(a);
+	input.set(2);
+	v.expect([2]);
+
+	drop(b);
+	input.set(3);
+	assert_eq!(gated.get(), 2);
+	v.expect([]);
+
+	let _sub = gated.to_subscription();
+	v.expect([3]);
+}
+
+#[test]
+fn not_fresh_while_unsubscribed() {
+	let input = Signal::cell(1);
+	let gated = Signal::computed_while_subscribed(|| input.get());
+
+	// The first read computes the value and records dependencies.
+	let (value, fresh) = gated.read_with_freshness();
+	assert_eq!((*value, fresh), (1, true));
+	drop(value);
+
+	// The dependency changed, but the gated computation is skipped.
+	input.set(2);
+	let (value, fresh) = gated.read_with_freshness();
+	assert_eq!((*value, fresh), (1, false));
+	drop(value);
+
+	let sub = gated.to_subscription();
+	assert_eq!(sub.get(), 2);
+	drop(sub);
+
+	input.set(3);
+	let (value, fresh) = gated.read_with_freshness();
+	assert_eq!((*value, fresh), (2, false));
+}
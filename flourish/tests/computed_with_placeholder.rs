@@ -0,0 +1,38 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::GlobalSignalsRuntime;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn subscribed_sees_placeholder_once() {
+	let v = &Validator::new();
+	let runs = &Validator::new();
+
+	let input = Signal::cell(1);
+	let s = Signal::computed_with_placeholder(0, || {
+		runs.push(());
+		input.get() * 10
+	});
+	let _sub = Subscription::computed(|| v.push(s.get()));
+	v.expect([0, 10]);
+	runs.expect([()]);
+
+	input.set(2);
+	v.expect([20]);
+	runs.expect([()]);
+}
+
+#[test]
+fn read_is_real_value() {
+	let input = Signal::cell(1);
+	let s = Signal::computed_with_placeholder(0, || input.get() * 10);
+	assert_eq!(s.get(), 10);
+
+	input.set(2);
+	assert_eq!(s.get(), 20);
+}
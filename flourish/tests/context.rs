@@ -0,0 +1,31 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::GlobalSignalsRuntime;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn depend_and_ignore() {
+	let v = &Validator::new();
+
+	let a = Signal::cell(1);
+	let b = Signal::cell(());
+	let c = Signal::computed_with_context(|cx| {
+		cx.depend(&b);
+		// A nested computation's reads are detached too.
+		let value = cx.ignore(|| Signal::computed(|| a.get()).get());
+		v.push(value)
+	});
+	let _sub = Subscription::computed(|| c.touch());
+	v.expect([1]);
+
+	a.set(2);
+	v.expect([]);
+
+	b.set(());
+	v.expect([2]);
+}
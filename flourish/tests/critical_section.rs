@@ -0,0 +1,69 @@
+#![cfg(all(feature = "critical_section", not(feature = "global_signals_runtime")))]
+//! Run with `--no-default-features --features std,critical_section` to use the *critical-section* backend.
+
+use std::thread;
+
+use critical_section as _; // Links the `std` implementation.
+use flourish::{GlobalSignalsRuntime, Propagation, SignalsRuntimeRef};
+
+type Effect<'a> = flourish::Effect<'a, GlobalSignalsRuntime>;
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn propagation() {
+	let v = &Validator::new();
+
+	let a = &Signal::cell(1);
+	let b = Signal::computed(|| a.get() * 2);
+	let e = Effect::new(move || v.push(b.get()), drop);
+	v.expect([2]);
+
+	a.set(2);
+	v.expect([4]);
+
+	a.replace_blocking(3);
+	v.expect([6]);
+
+	drop(e);
+	a.set(4);
+	v.expect([]);
+}
+
+#[test]
+fn batched() {
+	let v = &Validator::new();
+
+	let (a, b) = (&Signal::cell(1), &Signal::cell(1));
+	let _e = Effect::new(move || v.push(a.get() + b.get()), drop);
+	v.expect([2]);
+
+	GlobalSignalsRuntime.hint_batched_updates(|| {
+		a.set(2);
+		b.set(2);
+	});
+	v.expect([4]);
+}
+
+#[test]
+fn threads() {
+	let a = Signal::cell(0);
+	let b = Signal::computed(|| a.get());
+	let _sub = b.to_subscription();
+
+	thread::scope(|s| {
+		for _ in 0..4 {
+			s.spawn(|| {
+				for _ in 0..100 {
+					a.update(|value| {
+						*value += 1;
+						Propagation::Propagate
+					});
+				}
+			});
+		}
+	});
+	assert_eq!(b.get(), 400);
+}
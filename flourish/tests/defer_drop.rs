@@ -0,0 +1,35 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use std::{
+	sync::mpsc::{channel, Sender},
+	thread::{self, ThreadId},
+	time::Duration,
+};
+
+use flourish::GlobalSignalsRuntime;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+
+struct Reporter(u8, Sender<(u8, ThreadId)>);
+impl Drop for Reporter {
+	fn drop(&mut self) {
+		self.1.send((self.0, thread::current().id())).unwrap();
+	}
+}
+
+#[test]
+fn set_defer_drop() {
+	let (sender, receiver) = channel();
+
+	let a = Signal::cell(Reporter(1, sender.clone()));
+	a.set_defer_drop(Reporter(2, sender.clone()));
+	a.set_defer_drop(Reporter(3, sender));
+	assert_eq!(a.read().0, 3);
+
+	let timeout = Duration::from_secs(10);
+	let (first, first_thread) = receiver.recv_timeout(timeout).unwrap();
+	let (second, second_thread) = receiver.recv_timeout(timeout).unwrap();
+	assert_eq!((first, second), (1, 2));
+	assert_ne!(first_thread, thread::current().id());
+	assert_eq!(first_thread, second_thread);
+}
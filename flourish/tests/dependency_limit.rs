@@ -0,0 +1,25 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::GlobalSignalsRuntime;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+
+#[test]
+fn within_limit() {
+	let inputs = [Signal::cell(1), Signal::cell(2)];
+	let sum =
+		Signal::computed_with_dependency_limit(|| inputs.iter().map(|i| i.get()).sum::<i32>(), 2);
+	assert_eq!(sum.get(), 3);
+
+	inputs[0].set(5);
+	assert_eq!(sum.get(), 7);
+}
+
+#[test]
+#[cfg_attr(debug_assertions, should_panic = "recorded more than 2 dependencies")]
+fn exceeding_limit() {
+	let inputs = [Signal::cell(1), Signal::cell(2), Signal::cell(3)];
+	let sum =
+		Signal::computed_with_dependency_limit(|| inputs.iter().map(|i| i.get()).sum::<i32>(), 2);
+	assert_eq!(sum.get(), 6);
+}
@@ -0,0 +1,146 @@
+#![cfg(all(feature = "global_signals_runtime", feature = "futures"))]
+
+use std::{
+	collections::HashMap,
+	pin::pin,
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+	},
+	task::{Context, Poll, Wake, Waker},
+};
+
+use flourish::{CollectionDelta, GlobalSignalsRuntime};
+use futures_core::Stream;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+struct CountingWaker(AtomicUsize);
+
+impl Wake for CountingWaker {
+	fn wake(self: Arc<Self>) {
+		self.0.fetch_add(1, Ordering::Relaxed);
+	}
+}
+
+#[test]
+fn vec() {
+	let cell = Signal::cell(vec![1, 2, 3]);
+	let mut deltas = pin!(cell.diff_collection());
+	let wakes = Arc::new(CountingWaker(AtomicUsize::new(0)));
+	let waker = Waker::from(Arc::clone(&wakes));
+	let mut cx = Context::from_waker(&waker);
+
+	assert_eq!(
+		deltas.as_mut().poll_next(&mut cx),
+		Poll::Ready(Some(CollectionDelta {
+			added: vec![(0, 1), (1, 2), (2, 3)],
+			removed: vec![],
+			changed: vec![],
+		}))
+	);
+	assert_eq!(deltas.as_mut().poll_next(&mut cx), Poll::Pending);
+
+	cell.set(vec![1, 5]);
+	assert_eq!(wakes.0.load(Ordering::Relaxed), 1);
+	assert_eq!(
+		deltas.as_mut().poll_next(&mut cx),
+		Poll::Ready(Some(CollectionDelta {
+			added: vec![],
+			removed: vec![(2, 3)],
+			changed: vec![(1, 2, 5)],
+		}))
+	);
+	assert_eq!(deltas.as_mut().poll_next(&mut cx), Poll::Pending);
+}
+
+#[test]
+fn empty_initial() {
+	let cell = Signal::cell(Vec::<u8>::new());
+	let mut deltas = pin!(cell.diff_collection());
+	let mut cx = Context::from_waker(Waker::noop());
+
+	assert_eq!(
+		deltas.as_mut().poll_next(&mut cx),
+		Poll::Ready(Some(CollectionDelta::default()))
+	);
+	assert_eq!(deltas.as_mut().poll_next(&mut cx), Poll::Pending);
+}
+
+#[test]
+fn map() {
+	let cell = Signal::cell(HashMap::from([("a", 1), ("b", 2)]));
+	let mut deltas = pin!(cell.diff_collection());
+	let mut cx = Context::from_waker(Waker::noop());
+
+	let Poll::Ready(Some(mut initial)) = deltas.as_mut().poll_next(&mut cx) else {
+		panic!("Expected the initial delta.");
+	};
+	initial.added.sort_unstable();
+	assert_eq!(
+		initial,
+		CollectionDelta {
+			added: vec![("a", 1), ("b", 2)],
+			removed: vec![],
+			changed: vec![],
+		}
+	);
+
+	cell.set(HashMap::from([("b", 3), ("c", 4)]));
+	assert_eq!(
+		deltas.as_mut().poll_next(&mut cx),
+		Poll::Ready(Some(CollectionDelta {
+			added: vec![("c", 4)],
+			removed: vec![("a", 1)],
+			changed: vec![("b", 2, 3)],
+		}))
+	);
+}
+
+#[test]
+fn coalesces_against_yielded() {
+	let cell = Signal::cell(vec![1]);
+	let mut deltas = pin!(cell.diff_collection());
+	let mut cx = Context::from_waker(Waker::noop());
+	assert!(deltas.as_mut().poll_next(&mut cx).is_ready());
+
+	cell.set(vec![2]);
+	cell.set(vec![2, 3]);
+	assert_eq!(
+		deltas.as_mut().poll_next(&mut cx),
+		Poll::Ready(Some(CollectionDelta {
+			added: vec![(1, 3)],
+			removed: vec![],
+			changed: vec![(0, 1, 2)],
+		}))
+	);
+
+	// Changes that cancel out aren't yielded.
+	cell.set(vec![4]);
+	cell.set(vec![2, 3]);
+	assert_eq!(deltas.as_mut().poll_next(&mut cx), Poll::Pending);
+}
+
+#[test]
+fn drop_unsubscribes() {
+	let v = &Validator::new();
+
+	let cell = Signal::cell(1);
+	let items = Signal::computed(|| {
+		v.push(());
+		vec![cell.get()]
+	});
+
+	let deltas = items.diff_collection();
+	v.expect([()]);
+
+	cell.set(2);
+	v.expect([()]);
+
+	drop(deltas);
+	cell.set(3);
+	v.expect([]);
+}
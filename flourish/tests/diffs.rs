@@ -0,0 +1,79 @@
+#![cfg(all(feature = "global_signals_runtime", feature = "futures"))]
+
+use std::{
+	pin::pin,
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+	},
+	task::{Context, Poll, Wake, Waker},
+};
+
+use flourish::GlobalSignalsRuntime;
+use futures_core::Stream;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+struct CountingWaker(AtomicUsize);
+
+impl Wake for CountingWaker {
+	fn wake(self: Arc<Self>) {
+		self.0.fetch_add(1, Ordering::Relaxed);
+	}
+}
+
+#[test]
+fn pairs() {
+	let cell = Signal::cell(1);
+	let mut diffs = pin!(cell.diffs());
+	let wakes = Arc::new(CountingWaker(AtomicUsize::new(0)));
+	let waker = Waker::from(Arc::clone(&wakes));
+	let mut cx = Context::from_waker(&waker);
+
+	assert_eq!(diffs.as_mut().poll_next(&mut cx), Poll::Pending);
+
+	cell.set(2);
+	assert_eq!(wakes.0.load(Ordering::Relaxed), 1);
+	assert_eq!(diffs.as_mut().poll_next(&mut cx), Poll::Ready(Some((1, 2))));
+	assert_eq!(diffs.as_mut().poll_next(&mut cx), Poll::Pending);
+
+	cell.set(3);
+	assert_eq!(diffs.as_mut().poll_next(&mut cx), Poll::Ready(Some((2, 3))));
+}
+
+#[test]
+fn coalesces() {
+	let cell = Signal::cell(1);
+	let mut diffs = pin!(cell.diffs());
+	let mut cx = Context::from_waker(Waker::noop());
+
+	cell.set(2);
+	cell.set(3);
+	cell.set(4);
+	assert_eq!(diffs.as_mut().poll_next(&mut cx), Poll::Ready(Some((3, 4))));
+	assert_eq!(diffs.as_mut().poll_next(&mut cx), Poll::Pending);
+}
+
+#[test]
+fn drop_unsubscribes() {
+	let v = &Validator::new();
+
+	let cell = Signal::cell(1);
+	let doubled = Signal::computed(|| {
+		v.push(());
+		cell.get() * 2
+	});
+
+	let diffs = doubled.diffs();
+	v.expect([()]);
+
+	cell.set(2);
+	v.expect([()]);
+
+	drop(diffs);
+	cell.set(3);
+	v.expect([]);
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::GlobalSignalsRuntime;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn formats_value() {
+	let cell = Signal::cell(String::from("a\"b"));
+	assert_eq!(cell.display().to_string(), "a\"b");
+	assert_eq!(format!("{:?}", cell.debug_value()), r#""a\"b""#);
+
+	cell.set(String::from("c"));
+	assert_eq!(cell.display().to_string(), "c");
+}
+
+#[test]
+fn doesnt_record_dependencies() {
+	let v = &Validator::new();
+
+	let logged = Signal::cell(1);
+	let tracked = Signal::cell(10);
+	let _sub = Subscription::computed(|| {
+		v.push(format!("{} {:?}", logged.display(), logged.debug_value()));
+		tracked.touch();
+	});
+	v.expect(["1 1".to_string()]);
+
+	logged.set(2);
+	v.expect([]);
+
+	tracked.set(11);
+	v.expect(["2 2".to_string()]);
+}
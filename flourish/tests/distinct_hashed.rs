@@ -0,0 +1,98 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use std::{
+	hash::{Hash, Hasher},
+	sync::atomic::{AtomicUsize, Ordering},
+};
+
+use flourish::GlobalSignalsRuntime;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn halts_on_equal() {
+	let v = &Validator::new();
+
+	let input = Signal::cell(1);
+	let hashed = Signal::distinct_hashed(|| vec![input.get() / 2; 3]);
+	let _sub = Subscription::computed(|| v.push(hashed.read().clone()));
+	v.expect([vec![0; 3]]);
+
+	input.set(0);
+	v.expect([]);
+
+	input.set(2);
+	v.expect([vec![1; 3]]);
+
+	input.set(3);
+	v.expect([]);
+}
+
+/// Hashes to the same value regardless of content, and counts comparisons.
+#[derive(Debug, Clone)]
+struct Colliding(i32);
+
+static COMPARISONS: AtomicUsize = AtomicUsize::new(0);
+
+impl Hash for Colliding {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		0.hash(state);
+	}
+}
+
+impl PartialEq for Colliding {
+	fn eq(&self, other: &Self) -> bool {
+		COMPARISONS.fetch_add(1, Ordering::Relaxed);
+		self.0 == other.0
+	}
+}
+
+impl Eq for Colliding {}
+
+#[test]
+fn compares_on_collision() {
+	let v = &Validator::new();
+
+	let input = Signal::cell(1);
+	let hashed = Signal::distinct_hashed(|| Colliding(input.get()));
+	let _sub = Subscription::computed(|| v.push(hashed.read().clone()));
+	v.expect([Colliding(1)]);
+
+	input.set(2);
+	v.expect([Colliding(2)]);
+
+	let comparisons = COMPARISONS.load(Ordering::Relaxed);
+	input.set(2);
+	assert_eq!(COMPARISONS.load(Ordering::Relaxed), comparisons + 1);
+	v.expect([]);
+}
+
+#[test]
+fn distinct_hashes_skip_comparison() {
+	#[derive(Debug, Clone)]
+	struct Uncomparable(i32);
+
+	impl Hash for Uncomparable {
+		fn hash<H: Hasher>(&self, state: &mut H) {
+			self.0.hash(state);
+		}
+	}
+
+	impl PartialEq for Uncomparable {
+		fn eq(&self, _: &Self) -> bool {
+			panic!("Compared despite distinct hashes.");
+		}
+	}
+
+	let input = Signal::cell(1);
+	let hashed = Signal::distinct_hashed(|| Uncomparable(input.get()));
+	let _sub = hashed.to_subscription();
+
+	input.set(2);
+	input.set(3);
+	assert_eq!(hashed.read().0, 3);
+}
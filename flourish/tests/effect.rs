@@ -1,6 +1,11 @@
 #![cfg(feature = "global_signals_runtime")]
 
-use flourish::{GlobalSignalsRuntime, Propagation};
+use std::{
+	mem,
+	sync::{Arc, Mutex},
+};
+
+use flourish::{shadow_clone, GlobalSignalsRuntime, Propagation};
 
 type Effect<'a> = flourish::Effect<'a, GlobalSignalsRuntime>;
 type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
@@ -50,3 +55,93 @@ fn effect_drop_is_distinct() {
 	constructions.expect([]);
 	destructions.expect([()]);
 }
+
+#[test]
+fn skip_initial() {
+	let v = &Validator::new();
+
+	let a = Signal::cell(1);
+	let b = Signal::cell(10);
+	let e = Effect::new_skip_initial(|| a.get(), |value| v.push((value, b.get())));
+	v.expect([]);
+
+	a.set(2);
+	v.expect([(2, 10)]);
+
+	// `effect_fn_pin` isn't a dependency detection scope.
+	b.set(20);
+	v.expect([]);
+
+	a.set(3);
+	v.expect([(3, 20)]);
+
+	drop(e);
+	a.set(4);
+	v.expect([]);
+}
+
+#[test]
+fn dependencies() {
+	let a = Signal::cell(true);
+	let b = Signal::cell(1);
+	let c = Signal::cell(2);
+	let e = Effect::new(|| if a.get() { b.get() } else { c.get() }, drop);
+
+	let before = e.dependencies();
+	assert_eq!(before.len(), 2);
+	assert!(before.is_sorted());
+
+	a.set(false);
+	let after = e.dependencies();
+	assert_eq!(after.len(), 2);
+	assert_eq!(before[0], after[0]);
+	assert!(before[1] < after[1]);
+}
+
+#[test]
+fn new_on() {
+	let seen = Arc::new(Mutex::new(Vec::new()));
+	let take_seen = || mem::take(&mut *seen.lock().unwrap());
+
+	let queue = Arc::new(Mutex::new(Vec::<Box<dyn Send + FnOnce()>>::new()));
+	let run_queued = || {
+		let queued = mem::take(&mut *queue.lock().unwrap());
+		queued.into_iter().for_each(|f| f());
+	};
+
+	let a = Signal::cell(1);
+	let b = Signal::cell(10);
+	let e = Effect::new_on(
+		{
+			let queue = Arc::clone(&queue);
+			move |f| queue.lock().unwrap().push(f)
+		},
+		{
+			shadow_clone!(a, b, seen);
+			move || {
+				let a = a.get();
+				shadow_clone!(b, seen);
+				move || seen.lock().unwrap().push(a + b.get())
+			}
+		},
+	);
+	assert_eq!(take_seen(), []);
+	assert_eq!(e.dependencies().len(), 1);
+
+	run_queued();
+	assert_eq!(take_seen(), [11]);
+
+	b.set(20);
+	run_queued();
+	assert_eq!(take_seen(), []);
+
+	a.set(2);
+	assert_eq!(take_seen(), []);
+	run_queued();
+	assert_eq!(take_seen(), [22]);
+
+	drop(e);
+	a.set(3);
+	run_queued();
+	assert_eq!(take_seen(), []);
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::{GlobalSignalsRuntime, SignalsRuntimeRef};
+
+type Effect<'a> = flourish::Effect<'a, GlobalSignalsRuntime>;
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn runs_once_per_batch() {
+	let v = &Validator::new();
+
+	let a = Signal::cell(1);
+	let b = Signal::cell(10);
+	let _effect = Effect::new_coalesced(|| v.push(a.get() + b.get()));
+	v.expect([11]);
+
+	GlobalSignalsRuntime.hint_batched_updates(|| {
+		a.set(2);
+		b.set(20);
+		a.set(3);
+	});
+	v.expect([23]);
+
+	b.set(30);
+	v.expect([33]);
+}
+
+#[test]
+fn stops_when_dropped() {
+	let v = &Validator::new();
+
+	let a = Signal::cell(1);
+	let effect = Effect::new_coalesced(|| v.push(a.get()));
+	v.expect([1]);
+
+	drop(effect);
+	a.set(2);
+	v.expect([]);
+}
+
+#[test]
+fn sees_settled_state() {
+	let v = &Validator::new();
+
+	let a = Signal::cell(1);
+	let b = Signal::cell(10);
+	// Created first, so a plain effect would refresh before `derive` and see `b` lagging behind.
+	let _effect = Effect::new_coalesced(|| v.push((a.get(), b.get())));
+	let _derive = Effect::new(|| b.set_if_distinct(a.get() * 10), drop);
+	v.expect([(1, 10)]);
+
+	a.set(2);
+	v.expect([(2, 20)]);
+
+	a.set(3);
+	v.expect([(3, 30)]);
+}
+
+#[test]
+fn tracks_dependencies_again() {
+	let v = &Validator::new();
+
+	let a = Signal::cell(1);
+	let b = Signal::cell(10);
+	let _effect = Effect::new_coalesced(|| v.push(a.get() + b.get()));
+	v.expect([11]);
+
+	a.set(2);
+	v.expect([12]);
+
+	b.set(20);
+	v.expect([22]);
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::GlobalSignalsRuntime;
+
+type Effect<'a> = flourish::Effect<'a, GlobalSignalsRuntime>;
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn runs_only_on_key_change() {
+	let v = &Validator::new();
+
+	let a = Signal::cell(1);
+	let b = Signal::cell(10);
+	let effect = Effect::watch_keyed(|| (a.get() + b.get()) % 2, |&key| v.push(key));
+	v.expect([1]);
+
+	a.set(3);
+	v.expect([]);
+
+	b.set(11);
+	v.expect([0]);
+
+	a.set(6);
+	v.expect([1]);
+
+	drop(effect);
+	a.set(7);
+	v.expect([]);
+}
+
+#[test]
+fn body_is_untracked() {
+	let v = &Validator::new();
+
+	let key = Signal::cell(0);
+	let other = Signal::cell(0);
+	let _effect = Effect::watch_keyed(|| key.get(), |&key| v.push((key, other.get())));
+	v.expect([(0, 0)]);
+
+	other.set(1);
+	v.expect([]);
+
+	key.set(1);
+	v.expect([(1, 1)]);
+}
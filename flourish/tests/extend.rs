@@ -0,0 +1,44 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::{extend::ExtendSignalExt, GlobalSignalsRuntime, SignalsRuntimeRef};
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn appends_in_place() {
+	let v = &Validator::new();
+
+	let text = Signal::cell(String::from("a"));
+	let _sub = Subscription::computed(|| v.push(text.get_clone()));
+	v.expect(["a".to_string()]);
+
+	text.push('b');
+	v.expect(["ab".to_string()]);
+
+	text.extend("cd".chars());
+	v.expect(["abcd".to_string()]);
+
+	text.extend("".chars());
+	v.expect([]);
+}
+
+#[test]
+fn batched_pushes_coalesce() {
+	let v = &Validator::new();
+
+	let items = Signal::cell(Vec::new());
+	let _sub = Subscription::computed(|| v.push(items.read().len()));
+	v.expect([0]);
+
+	GlobalSignalsRuntime.hint_batched_updates(|| {
+		for i in 0..10 {
+			items.push(i);
+		}
+		items.extend(10..20);
+	});
+	v.expect([20]);
+}
@@ -0,0 +1,99 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::{GlobalSignalsRuntime, Propagation};
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _block_on;
+use _block_on::assert_ready;
+
+mod _validator;
+use _validator::Validator;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Point {
+	x: i32,
+	y: i32,
+}
+
+#[test]
+fn reads_and_writes_through() {
+	let point = Signal::cell(Point { x: 1, y: 2 });
+	let x = point.field(|point| &point.x, |point, x| point.x = x);
+
+	assert_eq!(x.get(), 1);
+
+	x.set(3);
+	assert_eq!(*point.read(), Point { x: 3, y: 2 });
+	assert_eq!(x.get(), 3);
+
+	x.update_dyn(Box::new(|x| {
+		*x += 1;
+		Propagation::Propagate
+	}));
+	assert_eq!(*point.read(), Point { x: 4, y: 2 });
+
+	assert_eq!(x.replace_blocking(5), 4);
+	assert_eq!(x.set_if_distinct_blocking(5), Err(5));
+	assert_eq!(*point.read(), Point { x: 5, y: 2 });
+
+	point.set(Point { x: 6, y: 7 });
+	assert_eq!(x.get(), 6);
+}
+
+#[test]
+fn propagates_only_field_changes() {
+	let v = &Validator::new();
+
+	let point = Signal::cell(Point { x: 1, y: 2 });
+	let x = point.field(|point| &point.x, |point, x| point.x = x);
+	let y = point.field(|point| &point.y, |point, y| point.y = y);
+
+	let _sub_x = Subscription::computed(|| v.push(("x", x.get())));
+	let _sub_y = Subscription::computed(|| v.push(("y", y.get())));
+	v.expect([("x", 1), ("y", 2)]);
+
+	y.set(3);
+	v.expect([("y", 3)]);
+
+	x.set(4);
+	v.expect([("x", 4)]);
+
+	point.update(|point| {
+		point.y = 5;
+		Propagation::Propagate
+	});
+	v.expect([("y", 5)]);
+}
+
+#[test]
+fn set_if_distinct_compares_field() {
+	let v = &Validator::new();
+
+	let point = Signal::cell(Point { x: 1, y: 2 });
+	let x = point.field(|point| &point.x, |point, x| point.x = x);
+
+	let _sub = Subscription::computed(|| v.push(point.read().clone()));
+	v.expect([Point { x: 1, y: 2 }]);
+
+	x.set_if_distinct(1);
+	v.expect([]);
+
+	x.set_if_distinct(2);
+	v.expect([Point { x: 2, y: 2 }]);
+}
+
+#[test]
+fn eager() {
+	let point = Signal::cell(Point { x: 1, y: 2 });
+	let y = point.field(|point| &point.y, |point, y| point.y = y);
+
+	assert_eq!(assert_ready(Box::into_pin(y.replace_eager_dyn(3))), Ok(2));
+	assert_eq!(*point.read(), Point { x: 1, y: 3 });
+
+	assert_eq!(
+		assert_ready(Box::into_pin(y.set_if_distinct_eager_dyn(3))),
+		Ok(Err(3))
+	);
+}
@@ -1,6 +1,6 @@
 #![cfg(feature = "global_signals_runtime")]
 
-use flourish::{GlobalSignalsRuntime, Propagation};
+use flourish::{GlobalSignalsRuntime, Propagation, SignalsRuntimeRef};
 
 type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
 type Effect<'a> = flourish::Effect<'a, GlobalSignalsRuntime>;
@@ -92,3 +92,54 @@ fn no_skip_if_not_exclusive() {
 	drop(a);
 	seen.expect([]);
 }
+
+#[test]
+fn flush_out_not_downgraded_by_later_propagate() {
+	let seen = &Validator::new();
+
+	let flushing = Signal::cell(0);
+	let propagating = Signal::cell(0);
+	let s = Signal::computed(|| seen.push((flushing.get(), propagating.get())));
+	s.get();
+	seen.expect([(0, 0)]);
+
+	// Both updates run before `s` is refreshed, in creation order.
+	GlobalSignalsRuntime.hint_batched_updates(|| {
+		flushing.update(|value| {
+			*value = 1;
+			Propagation::FlushOut
+		});
+		propagating.update(|value| {
+			*value = 1;
+			Propagation::Propagate
+		});
+	});
+	seen.expect([(1, 1)]);
+
+	drop(s);
+}
+
+#[test]
+fn propagate_upgraded_by_later_flush_out() {
+	let seen = &Validator::new();
+
+	let propagating = Signal::cell(0);
+	let flushing = Signal::cell(0);
+	let s = Signal::computed(|| seen.push((propagating.get(), flushing.get())));
+	s.get();
+	seen.expect([(0, 0)]);
+
+	GlobalSignalsRuntime.hint_batched_updates(|| {
+		propagating.update(|value| {
+			*value = 1;
+			Propagation::Propagate
+		});
+		flushing.update(|value| {
+			*value = 1;
+			Propagation::FlushOut
+		});
+	});
+	seen.expect([(1, 1)]);
+
+	drop(s);
+}
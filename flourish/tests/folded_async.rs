@@ -0,0 +1,67 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use std::{
+	future::Future,
+	pin::Pin,
+	sync::{Arc, Mutex},
+};
+
+use flourish::{GlobalSignalsRuntime, Propagation};
+use futures_channel::oneshot;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Effect<'a> = flourish::Effect<'a, GlobalSignalsRuntime>;
+
+mod _block_on;
+use _block_on::assert_ready;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn commit_and_cancel() {
+	let v = &Validator::new();
+	let a = Signal::cell(1);
+	let spawned = Arc::new(Mutex::new(
+		Vec::<Pin<Box<dyn Send + Future<Output = ()>>>>::new(),
+	));
+	let senders = Arc::new(Mutex::new(Vec::new()));
+	let run_next = || assert_ready(spawned.lock().unwrap().remove(0));
+
+	let folded = Signal::folded_async(
+		0,
+		{
+			let a = a.clone();
+			let senders = Arc::clone(&senders);
+			move |value| {
+				*value = a.get();
+				let (sender, receiver) = oneshot::channel();
+				senders.lock().unwrap().push(sender);
+				async move { receiver.await.unwrap_or(Propagation::Halt) }
+			}
+		},
+		{
+			let spawned = Arc::clone(&spawned);
+			move |task| spawned.lock().unwrap().push(task)
+		},
+	);
+	let _e = Effect::new(|| v.push(folded.get()), drop);
+	v.expect([1]);
+
+	// The mutation is visible, but not propagated yet.
+	a.set(2);
+	v.expect([]);
+	assert_eq!(folded.get(), 2);
+
+	// The first fold was cancelled by the second.
+	let first = senders.lock().unwrap().remove(0);
+	assert!(first.send(Propagation::Propagate).is_ok());
+	run_next();
+	v.expect([]);
+
+	// The second fold commits.
+	let second = senders.lock().unwrap().remove(0);
+	assert!(second.send(Propagation::Propagate).is_ok());
+	run_next();
+	v.expect([2]);
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::GlobalSignalsRuntime;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn mirrors_until_dropped() {
+	let v = &Validator::new();
+
+	let input = Signal::cell(1);
+	let source = Subscription::computed(|| input.get() + 1);
+	let mirror = Signal::cell(0);
+	let observer = Subscription::computed(|| v.push(mirror.get()));
+	v.expect([0]);
+
+	let forwarding = source.forward_to(mirror.as_dyn_cell());
+	v.expect([2]);
+
+	input.set(2);
+	v.expect([3]);
+
+	drop(forwarding);
+	input.set(3);
+	v.expect([]);
+	assert_eq!(mirror.get(), 3);
+
+	drop(observer);
+}
@@ -0,0 +1,114 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use std::thread;
+
+use flourish::GlobalSignalsRuntime;
+use futures_channel::mpsc;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn sends_changes() {
+	let cell = Signal::cell(1);
+	let (tx, mut rx) = mpsc::channel(8);
+	let _forwarding = cell.forward_to_sender(tx);
+
+	cell.set(2);
+	cell.set(3);
+
+	assert_eq!(rx.try_recv().unwrap(), 1);
+	assert_eq!(rx.try_recv().unwrap(), 2);
+	assert_eq!(rx.try_recv().unwrap(), 3);
+	assert!(rx.try_recv().is_err());
+}
+
+#[test]
+fn drops_on_full() {
+	let cell = Signal::cell(1);
+	// Capacity is the buffer plus one slot per sender.
+	let (tx, mut rx) = mpsc::channel(0);
+	let _forwarding = cell.forward_to_sender(tx);
+
+	cell.set(2);
+	cell.set(3);
+
+	assert_eq!(rx.try_recv().unwrap(), 1);
+	assert!(rx.try_recv().is_err());
+}
+
+#[test]
+fn dormant_after_receiver_drop() {
+	let v = &Validator::new();
+
+	let cell = Signal::cell(1);
+	let reads = Signal::computed(|| {
+		v.push(());
+		cell.get()
+	});
+	let (tx, rx) = mpsc::channel(8);
+	let _forwarding = reads.forward_to_sender(tx);
+	v.expect([()]);
+
+	drop(rx);
+	cell.set(2);
+	// `reads` refreshes once more while subscribed, then the effect notices the closed channel and drops it.
+	v.expect([()]);
+
+	cell.set(3);
+	v.expect([]);
+
+	let _sub = Subscription::computed(|| reads.get());
+	v.expect([()]);
+}
+
+#[test]
+fn blocking_waits_for_capacity() {
+	let cell = Signal::cell(0);
+	let (tx, mut rx) = mpsc::channel(0);
+	let _forwarding = cell.forward_to_sender_blocking(tx);
+
+	let receiver = thread::spawn(move || {
+		let mut received = Vec::new();
+		while received.last() != Some(&3) {
+			if let Ok(value) = rx.try_recv() {
+				received.push(value);
+			} else {
+				thread::yield_now();
+			}
+		}
+		received
+	});
+
+	for i in 1..=3 {
+		cell.set(i);
+	}
+
+	assert_eq!(receiver.join().unwrap(), [0, 1, 2, 3]);
+}
+
+#[test]
+fn blocking_doesnt_stall_runtime() {
+	let cell = Signal::cell(0);
+	let (tx, mut rx) = mpsc::channel(0);
+	let _forwarding = cell.forward_to_sender_blocking(tx);
+
+	// Nothing is received yet, but updates still go through right away.
+	for i in 1..=3 {
+		cell.set(i);
+		assert_eq!(cell.get(), i);
+	}
+
+	let mut received = Vec::new();
+	while received.last() != Some(&3) {
+		if let Ok(value) = rx.try_recv() {
+			received.push(value);
+		} else {
+			thread::yield_now();
+		}
+	}
+	assert_eq!(received, [0, 1, 2, 3]);
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use std::{
+	sync::{Arc, Mutex},
+	task::{Poll, Waker},
+};
+
+use flourish::GlobalSignalsRuntime;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+/// A minimal single-slot channel that wakes its receiver.
+#[derive(Default)]
+struct Slot {
+	value: Option<i32>,
+	waker: Option<Waker>,
+}
+
+type Shared = Arc<Mutex<Slot>>;
+
+fn send(slot: &Shared, value: Option<i32>) {
+	let waker = {
+		let mut slot = slot.lock().unwrap();
+		slot.value = value;
+		slot.waker.take()
+	};
+	if let Some(waker) = waker {
+		waker.wake();
+	}
+}
+
+#[test]
+fn propagates_ready_values() {
+	let v = &Validator::new();
+
+	let slot = Shared::default();
+	let polls = Signal::from_poll_fn({
+		let slot = Arc::clone(&slot);
+		move |cx| {
+			let mut slot = slot.lock().unwrap();
+			slot.waker = Some(cx.waker().clone());
+			match slot.value.take() {
+				Some(value) => Poll::Ready(value),
+				None => Poll::Pending,
+			}
+		}
+	});
+	let _sub = Subscription::computed(|| v.push(polls.get()));
+	v.expect([None]);
+
+	send(&slot, Some(1));
+	v.expect([Some(1)]);
+
+	// Wakeups without a new value keep the last one and don't propagate.
+	send(&slot, None);
+	v.expect([]);
+	assert_eq!(polls.get(), Some(1));
+
+	send(&slot, Some(2));
+	v.expect([Some(2)]);
+}
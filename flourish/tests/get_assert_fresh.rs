@@ -0,0 +1,39 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::GlobalSignalsRuntime;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn fresh_reads() {
+	let v = &Validator::new();
+
+	let cell = Signal::cell(1);
+	let doubled = Signal::computed(|| cell.get() * 2);
+	assert_eq!(cell.get_assert_fresh(), 1);
+	assert_eq!(doubled.get_assert_fresh(), 2);
+
+	let sub = Subscription::computed(|| v.push(doubled.get_assert_fresh()));
+	v.expect([2]);
+
+	cell.set(2);
+	v.expect([4]);
+	assert_eq!(doubled.get_assert_fresh(), 4);
+
+	drop(sub);
+}
+
+#[test]
+#[cfg_attr(debug_assertions, should_panic = "while it was stale")]
+fn stale_read() {
+	let cell = Signal::cell(1);
+	let doubled = Signal::computed(|| cell.get() * 2);
+	assert_eq!(doubled.get(), 2);
+
+	cell.set(2);
+	assert_eq!(doubled.get_assert_fresh(), 4);
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::{GlobalSignalsRuntime, Propagation};
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn uninitialized_until_updated() {
+	let cell = Signal::cell_reactive_uninitialized(0, |_, _| Propagation::Halt);
+	assert_eq!(cell.get_initialized(), None);
+	assert_eq!(cell.get(), 0);
+
+	// Even an update that doesn't propagate counts.
+	cell.update_blocking(|_| (Propagation::Halt, ()));
+	assert_eq!(cell.get_initialized(), Some(0));
+
+	cell.set(1);
+	assert_eq!(cell.get_initialized(), Some(1));
+}
+
+#[test]
+fn set_after_subscription() {
+	let v = &Validator::new();
+
+	let cell = Signal::cell_reactive_uninitialized(0, |_, subscribed| {
+		v.push(Err(subscribed));
+		Propagation::Halt
+	});
+	let sub = Subscription::computed(|| v.push(Ok(cell.get_initialized())));
+	v.expect([Err(true), Ok(None)]);
+
+	// This is where a loader would store its result.
+	cell.set(5);
+	v.expect([Ok(Some(5))]);
+
+	drop(sub);
+	v.expect([Err(false)]);
+}
+
+#[test]
+fn others_are_always_initialized() {
+	let cell = Signal::cell(1);
+	let reactive = Signal::cell_reactive(2, |_, _| Propagation::Halt);
+	let computed = Signal::computed(|| cell.get() + reactive.get());
+
+	assert_eq!(cell.get_initialized(), Some(1));
+	assert_eq!(reactive.get_initialized(), Some(2));
+	assert_eq!(computed.get_initialized(), Some(3));
+	assert_eq!(computed.as_dyn().get_initialized(), Some(3));
+}
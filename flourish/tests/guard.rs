@@ -0,0 +1,56 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use std::{borrow::Borrow, ops::Deref};
+
+use flourish::{GlobalSignalsRuntime, Guard};
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+
+fn len_as_ref(value: impl AsRef<String>) -> usize {
+	value.as_ref().len()
+}
+
+fn len_borrow(value: impl Borrow<String>) -> usize {
+	value.borrow().len()
+}
+
+#[test]
+fn as_ref_borrow_and_deref() {
+	let a = Signal::cell("abc".to_string());
+
+	// `Guard<T>` doesn't imply `AsRef<T>`, so opaque guards go through `dyn Guard<T>`.
+	assert_eq!(len_as_ref(&*a.read_dyn()), 3);
+	assert_eq!(len_borrow(a.read()), 3);
+	assert_eq!(a.read().len(), 3);
+	assert_eq!(&**a.read(), "abc");
+
+	let guard = a.read_dyn();
+	assert_eq!((*guard).as_ref(), "abc");
+	assert_eq!(AsRef::<String>::as_ref(&*guard), "abc");
+	assert_eq!(len_as_ref(&*guard), 3);
+}
+
+/// Custom guards don't need to implement [`AsRef`].
+struct PlainGuard(String);
+
+impl Deref for PlainGuard {
+	type Target = String;
+
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+impl Borrow<String> for PlainGuard {
+	fn borrow(&self) -> &String {
+		&self.0
+	}
+}
+
+impl Guard<String> for PlainGuard {}
+
+#[test]
+fn custom_guard_as_ref() {
+	let guard: Box<dyn Guard<String>> = Box::new(PlainGuard("abcd".to_string()));
+	assert_eq!(len_as_ref(&*guard), 4);
+}
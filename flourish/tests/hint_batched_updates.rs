@@ -1,9 +1,10 @@
 #![cfg(feature = "global_signals_runtime")]
 
-use flourish::{GlobalSignalsRuntime, SignalsRuntimeRef};
+use flourish::{GlobalSignalsRuntime, Propagation, SignalsRuntimeRef};
 
 type Effect<'a> = flourish::Effect<'a, GlobalSignalsRuntime>;
 type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
 
 mod _validator;
 use _validator::Validator;
@@ -31,3 +32,43 @@ fn deduplication() {
 	});
 	validator.expect([()]);
 }
+
+#[test]
+fn update_blocking() {
+	let validator = &Validator::new();
+
+	let a = Signal::cell(0);
+	let b = Signal::cell(0);
+	let _sub = Subscription::computed(|| validator.push((a.get(), b.get())));
+	validator.expect([(0, 0)]);
+
+	GlobalSignalsRuntime.hint_batched_updates(|| {
+		a.set_blocking(1);
+		assert_eq!(a.get(), 1);
+		assert_eq!(b.replace_blocking(2), 0);
+		validator.expect([]);
+	});
+	validator.expect([(1, 2)]);
+}
+
+#[test]
+fn update_blocking_after_enqueued() {
+	let a = Signal::cell(0);
+
+	GlobalSignalsRuntime.hint_batched_updates(|| {
+		a.set(1);
+		a.update_blocking(|value| (Propagation::Propagate, *value += 1));
+		assert_eq!(a.get(), 2);
+	});
+	assert_eq!(a.get(), 2);
+}
+
+#[test]
+#[should_panic = "while propagating another update"]
+fn update_blocking_in_recording_context() {
+	let a = Signal::cell(0);
+
+	GlobalSignalsRuntime.hint_batched_updates(|| {
+		Signal::computed(|| a.set_blocking(1)).get();
+	});
+}
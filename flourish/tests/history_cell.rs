@@ -0,0 +1,77 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::GlobalSignalsRuntime;
+
+type HistoryCell<'a, T> = flourish::HistoryCell<'a, T, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn undo_redo() {
+	let v = &Validator::new();
+
+	let cell = HistoryCell::new(1, 10);
+	let _sub = Subscription::computed(|| v.push(cell.get()));
+	v.expect([1]);
+	assert!(!cell.can_undo());
+	assert!(!cell.can_redo());
+
+	cell.set(2);
+	cell.update(|value| *value *= 10);
+	v.expect([2, 20]);
+
+	assert!(cell.undo());
+	assert!(cell.undo());
+	v.expect([2, 1]);
+
+	// At the bottom of the stack, this is a no-op.
+	assert!(!cell.undo());
+	v.expect([]);
+
+	assert!(cell.redo());
+	v.expect([2]);
+	assert!(cell.can_undo());
+	assert!(cell.can_redo());
+
+	// A new commit clears the redo stack.
+	cell.set(3);
+	v.expect([3]);
+	assert!(!cell.can_redo());
+	assert!(!cell.redo());
+	v.expect([]);
+
+	assert!(cell.undo());
+	assert!(cell.undo());
+	assert!(!cell.undo());
+	v.expect([2, 1]);
+}
+
+#[test]
+fn capacity() {
+	let cell = HistoryCell::new(0, 2);
+	for i in 1..=5 {
+		cell.set(i);
+	}
+
+	assert!(cell.undo());
+	assert!(cell.undo());
+	assert!(!cell.undo());
+	assert_eq!(cell.get(), 3);
+
+	// Redo isn't capped separately, as it only holds undone values.
+	assert!(cell.redo());
+	assert!(cell.redo());
+	assert!(!cell.redo());
+	assert_eq!(cell.get(), 5);
+}
+
+#[test]
+fn no_history() {
+	let cell = HistoryCell::new("a", 0);
+	cell.set("b");
+	assert!(!cell.can_undo());
+	assert!(!cell.undo());
+	assert_eq!(cell.get(), "b");
+}
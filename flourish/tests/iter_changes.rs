@@ -0,0 +1,47 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use std::{sync::mpsc, thread};
+
+use flourish::GlobalSignalsRuntime;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+
+#[test]
+fn current_then_changes() {
+	let cell = Signal::cell(1);
+	let mut changes = cell.iter_changes();
+	assert_eq!(changes.next(), Some(1));
+
+	cell.set(2);
+	assert_eq!(changes.next(), Some(2));
+
+	// Unconsumed changes coalesce.
+	cell.set(3);
+	cell.set(4);
+	assert_eq!(changes.next(), Some(4));
+}
+
+#[test]
+fn blocks_until_changed() {
+	let cell = Signal::cell(0);
+	let (tx, rx) = mpsc::channel();
+
+	let consumer = thread::spawn({
+		let cell = cell.clone();
+		move || {
+			for value in cell.iter_changes() {
+				tx.send(value).unwrap();
+				if value == 2 {
+					break;
+				}
+			}
+		}
+	});
+
+	assert_eq!(rx.recv().unwrap(), 0);
+	cell.set(1);
+	assert_eq!(rx.recv().unwrap(), 1);
+	cell.set(2);
+	assert_eq!(rx.recv().unwrap(), 2);
+	consumer.join().unwrap();
+}
@@ -0,0 +1,27 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::{GlobalSignalsRuntime, Propagation};
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn leak() {
+	let v = &Validator::new();
+
+	let a = Signal::cell_reactive((), |_value, status| {
+		v.push(status);
+		Propagation::Halt
+	});
+	let leaked = a.to_subscription().leak();
+	v.expect([true]);
+
+	drop(a);
+	v.expect([]);
+
+	// Still subscribed intrinsically.
+	drop(leaked.to_subscription());
+	v.expect([]);
+}
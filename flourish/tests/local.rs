@@ -0,0 +1,48 @@
+#![cfg(feature = "local_signals_runtime")]
+
+use std::{cell::Cell, rc::Rc};
+
+use flourish::local::LocalSignalsRuntime;
+
+type Signal<T, S> = flourish::local::Signal<T, S, LocalSignalsRuntime>;
+type Subscription<T, S> = flourish::local::Subscription<T, S, LocalSignalsRuntime>;
+
+#[test]
+fn unsend_values() {
+	let seen = Rc::new(Cell::new(0));
+
+	let a = Signal::cell(Rc::new(1));
+	let sub = Subscription::computed({
+		let (a, seen) = (a.clone(), Rc::clone(&seen));
+		move || seen.set(*a.get_clone())
+	});
+	assert_eq!(seen.get(), 1);
+
+	a.set(Rc::new(2));
+	assert_eq!(seen.get(), 2);
+
+	drop(sub);
+}
+
+#[test]
+fn local_aliases() {
+	use flourish::{LocalSignal, LocalSubscription};
+
+	let seen = Rc::new(Cell::new(0));
+
+	let a = LocalSignal::cell(Rc::new(1));
+	let b = LocalSignal::computed({
+		let a = a.clone();
+		move || Rc::new(*a.get_clone() * 2)
+	});
+	let sub = LocalSubscription::computed({
+		let seen = Rc::clone(&seen);
+		move || seen.set(*b.get_clone())
+	});
+	assert_eq!(seen.get(), 2);
+
+	a.set(Rc::new(3));
+	assert_eq!(seen.get(), 6);
+
+	drop(sub);
+}
@@ -0,0 +1,71 @@
+#![cfg(feature = "global_signals_runtime")]
+
+//! Manual processing is runtime-wide, so this file contains only one test.
+
+use flourish::{GlobalSignalsRuntime, SignalsRuntimeRef};
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn spans_budgeted_calls() {
+	let v = &Validator::new();
+
+	let a = Signal::cell(1);
+	let b = Signal::computed(|| {
+		let b = a.get() * 10;
+		v.push(("b", b));
+		b
+	});
+	let _c = Subscription::computed(|| v.push(("c", b.get())));
+	let _d = Subscription::computed(|| v.push(("d", a.get())));
+	v.expect([("b", 10), ("c", 10), ("d", 1)]);
+
+	GlobalSignalsRuntime.set_manual_processing(true);
+
+	// The update is applied, but nothing is refreshed yet.
+	a.set(2);
+	assert_eq!(a.get(), 2);
+	v.expect([]);
+
+	static IDLE: Validator<&str> = Validator::new();
+	GlobalSignalsRuntime.on_idle(|| IDLE.push("idle"));
+	IDLE.expect([]);
+
+	// One refresh per frame, in dependency order.
+	assert!(GlobalSignalsRuntime.process_budgeted(1));
+	v.expect([("b", 20)]);
+
+	assert!(GlobalSignalsRuntime.process_budgeted(1));
+	v.expect([("c", 20)]);
+	IDLE.expect([]);
+
+	// Updates between frames are picked up by the next call, and `d` is refreshed only once.
+	a.set(3);
+	v.expect([]);
+
+	// Blocking updates are applied right away too, even though `d` is still stale.
+	a.set_blocking(3);
+	assert_eq!(a.get(), 3);
+	v.expect([]);
+
+	assert!(GlobalSignalsRuntime.process_budgeted(1));
+	assert!(GlobalSignalsRuntime.process_budgeted(1));
+	assert!(!GlobalSignalsRuntime.process_budgeted(1));
+	v.expect([("b", 30), ("c", 30), ("d", 3)]);
+	IDLE.expect(["idle"]);
+
+	assert!(!GlobalSignalsRuntime.process_budgeted(0));
+
+	// Leaving manual mode processes everything that's left.
+	a.set(4);
+	v.expect([]);
+	GlobalSignalsRuntime.set_manual_processing(false);
+	v.expect([("b", 40), ("c", 40), ("d", 4)]);
+
+	a.set(5);
+	v.expect([("b", 50), ("c", 50), ("d", 5)]);
+}
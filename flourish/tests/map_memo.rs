@@ -0,0 +1,41 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use std::sync::Arc;
+
+use flourish::GlobalSignalsRuntime;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn reuses_output_for_equal_input() {
+	let v = &Validator::new();
+
+	let input = Signal::cell(1);
+	let mapped = input.to_owned().map_memo(|value| {
+		v.push(*value);
+		value * 10
+	});
+	v.expect([]);
+
+	let first = mapped.get_clone();
+	assert_eq!(*first, 10);
+	v.expect([1]);
+
+	input.set(1);
+	let second = mapped.get_clone();
+	assert!(Arc::ptr_eq(&first, &second));
+	v.expect([]);
+
+	input.set(2);
+	let third = mapped.get_clone();
+	assert_eq!(*third, 20);
+	assert!(!Arc::ptr_eq(&second, &third));
+	v.expect([2]);
+
+	input.set(1);
+	assert_eq!(*mapped.get_clone(), 10);
+	v.expect([1]);
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::{GlobalSignalsRuntime, Propagation};
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+static LOADS: Validator<bool> = Validator::new();
+
+#[test]
+fn subscribes_source() {
+	let v = &Validator::new();
+
+	let source = Signal::cell_reactive(0, |_, subscribed| {
+		LOADS.push(subscribed);
+		Propagation::Halt
+	});
+	let weak = source.downgrade();
+	LOADS.expect([]);
+
+	let mapped = source.mapped_subscription(|value| {
+		v.push(*value);
+		value + 1
+	});
+	LOADS.expect([true]);
+	v.expect([0]);
+	assert_eq!(mapped.get(), 1);
+
+	// The subscription chain keeps the source alive.
+	let source = weak.upgrade().expect("alive");
+	source.set(1);
+	v.expect([1]);
+	assert_eq!(mapped.get(), 2);
+	drop(source);
+
+	drop(mapped);
+	LOADS.expect([false]);
+	assert!(weak.upgrade().is_none());
+}
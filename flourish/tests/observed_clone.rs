@@ -0,0 +1,38 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use std::num::NonZeroU64;
+
+use flourish::{GlobalCountingSignalsRuntime, Propagation};
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalCountingSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn subscribes_once() {
+	let v = &Validator::new();
+
+	let cell = Signal::cell_reactive(1, |_value, status| {
+		v.push(status.map(NonZeroU64::get));
+		Propagation::Halt
+	});
+
+	let subscription = cell.to_subscription();
+	v.expect([Some(1)]);
+
+	let (observer, handle) = subscription.observed_clone();
+	v.expect([Some(2)]);
+
+	drop(handle);
+	v.expect([]);
+
+	drop(subscription);
+	v.expect([Some(1)]);
+
+	cell.set(2);
+	assert_eq!(observer.get(), 2);
+
+	drop(observer);
+	v.expect([None]);
+}
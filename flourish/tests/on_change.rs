@@ -0,0 +1,130 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use std::sync::{Arc, Mutex};
+
+use flourish::GlobalSignalsRuntime;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+type Log = Arc<Mutex<Vec<(&'static str, i32)>>>;
+type Observer = Box<dyn Send + FnMut(&i32)>;
+
+fn log() -> (Log, impl Fn(&'static str) -> Observer) {
+	let log = Arc::new(Mutex::new(Vec::new()));
+	(log.clone(), move |name| {
+		let log = log.clone();
+		Box::new(move |value: &i32| log.lock().unwrap().push((name, *value)))
+	})
+}
+
+fn take(log: &Mutex<Vec<(&'static str, i32)>>) -> Vec<(&'static str, i32)> {
+	std::mem::take(&mut *log.lock().unwrap())
+}
+
+#[test]
+fn fans_out_changes() {
+	let (log, observer) = log();
+	let cell = Signal::cell(1);
+	let sub = Subscription::computed({
+		let cell = cell.to_owned();
+		move || cell.get() * 2
+	});
+
+	let observers = sub.observers();
+	let a = observers.on_change(observer("a"));
+	let b = observers.on_change(observer("b"));
+	assert_eq!(take(&log), []);
+
+	cell.set(2);
+	assert_eq!(take(&log), [("a", 4), ("b", 4)]);
+
+	drop(a);
+	cell.set(3);
+	assert_eq!(take(&log), [("b", 6)]);
+
+	drop(b);
+}
+
+#[test]
+fn idles_without_observers() {
+	let (log, observer) = log();
+	let cell = Signal::cell(1);
+	let sub = Subscription::computed({
+		let cell = cell.to_owned();
+		move || cell.get()
+	});
+
+	let observers = sub.observers();
+	drop(observers.on_change(observer("a")));
+	cell.set(2);
+	assert_eq!(take(&log), []);
+	assert_eq!(sub.get(), 2);
+
+	let b = observers.on_change(observer("b"));
+	cell.set(3);
+	assert_eq!(take(&log), [("b", 3)]);
+
+	drop(b);
+}
+
+#[test]
+fn ends_with_observers() {
+	let (log, observer) = log();
+	let cell = Signal::cell(1);
+	let sub = Subscription::computed({
+		let cell = cell.to_owned();
+		move || cell.get()
+	});
+
+	let observers = sub.observers();
+	let a = observers.on_change(observer("a"));
+	drop(observers);
+	cell.set(2);
+	assert_eq!(take(&log), []);
+
+	drop((a, sub));
+}
+
+#[test]
+fn outlive_subscription() {
+	let (log, observer) = log();
+	let cell = Signal::cell(1);
+	let sub = Subscription::computed({
+		let cell = cell.to_owned();
+		move || cell.get()
+	});
+
+	let observers = sub.observers();
+	let a = observers.on_change(observer("a"));
+	drop(sub.into_dyn());
+	cell.set(2);
+	assert_eq!(take(&log), [("a", 2)]);
+
+	drop(a);
+}
+
+#[test]
+fn observer_removes_itself() {
+	let log = Arc::new(Mutex::new(Vec::new()));
+	let cell = Signal::cell(1);
+	let sub = Subscription::computed({
+		let cell = cell.to_owned();
+		move || cell.get()
+	});
+
+	let observers = sub.observers();
+	let handle = Arc::new(Mutex::new(None));
+	*handle.lock().unwrap() = Some(observers.on_change({
+		let log = log.clone();
+		let handle = handle.clone();
+		move |value: &i32| {
+			log.lock().unwrap().push(*value);
+			drop(handle.lock().unwrap().take());
+		}
+	}));
+
+	cell.set(2);
+	cell.set(3);
+	assert_eq!(*log.lock().unwrap(), [2]);
+}
@@ -0,0 +1,101 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use std::panic::AssertUnwindSafe;
+
+use flourish::{GlobalSignalsRuntime, SignalsRuntimeRef};
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn immediate_if_idle() {
+	let v: &'static _ = Box::leak(Box::new(Validator::new()));
+
+	GlobalSignalsRuntime.on_idle(|| v.push(()));
+	v.expect([()]);
+}
+
+#[test]
+fn after_propagation() {
+	let v: &'static _ = Box::leak(Box::new(Validator::new()));
+
+	let a = Signal::cell(1);
+	let _registering = Subscription::computed(|| {
+		let a = a.get();
+		v.push(("registering", a));
+		if a == 2 {
+			GlobalSignalsRuntime.on_idle(|| v.push(("idle", 0)));
+		}
+	});
+	let _later = Subscription::computed(|| v.push(("later", a.get())));
+	v.expect([("registering", 1), ("later", 1)]);
+
+	a.set(2);
+	v.expect([("registering", 2), ("later", 2), ("idle", 0)]);
+
+	a.set(3);
+	v.expect([("registering", 3), ("later", 3)]);
+}
+
+#[test]
+fn fires_again_after_new_work() {
+	let v: &'static _ = Box::leak(Box::new(Validator::new()));
+
+	let a: &'static _ = Box::leak(Box::new(Signal::cell(0)));
+	let _sub = Subscription::computed(|| {
+		let value = a.get();
+		v.push(("computed", value));
+		if value > 0 {
+			GlobalSignalsRuntime.on_idle(move || {
+				v.push(("idle", value));
+				if value < 2 {
+					a.set(value + 1);
+				}
+			});
+		}
+	});
+	v.expect([("computed", 0)]);
+
+	a.set(1);
+	v.expect([("computed", 1), ("idle", 1), ("computed", 2), ("idle", 2)]);
+}
+
+#[test]
+fn outside_critical_section() {
+	let v: &'static _ = Box::leak(Box::new(Validator::new()));
+
+	let a: &'static _ = Box::leak(Box::new(Signal::cell(1)));
+	let _sub = Subscription::computed(|| {
+		if a.get() == 2 {
+			GlobalSignalsRuntime.on_idle(|| {
+				// Would deadlock if the runtime was still locked by this thread.
+				v.push(std::thread::spawn(|| a.get()).join().unwrap());
+			});
+		}
+	});
+
+	a.set(2);
+	v.expect([2]);
+}
+
+#[test]
+fn panic_requeues_remaining() {
+	let v: &'static _ = Box::leak(Box::new(Validator::new()));
+
+	let a = Signal::cell(1);
+	let _sub = Subscription::computed(|| {
+		if a.get() == 2 {
+			GlobalSignalsRuntime.on_idle(|| panic!("idle"));
+			GlobalSignalsRuntime.on_idle(|| v.push("remaining"));
+		}
+	});
+
+	std::panic::catch_unwind(AssertUnwindSafe(|| a.set(2))).unwrap_err();
+	v.expect([]);
+
+	a.set(3);
+	v.expect(["remaining"]);
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::GlobalSignalsRuntime;
+
+type OptimisticCell<'a, T> = flourish::OptimisticCell<'a, T, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn rollback_reverts_and_propagates() {
+	let v = &Validator::new();
+
+	let cell = OptimisticCell::new(1);
+	let _sub = Subscription::computed(|| v.push(cell.get()));
+	v.expect([1]);
+
+	cell.set(2);
+	cell.set(3);
+	v.expect([2, 3]);
+	assert!(cell.is_pending());
+	assert_eq!(cell.confirmed().get(), 1);
+
+	assert!(cell.rollback());
+	v.expect([1]);
+	assert!(!cell.is_pending());
+
+	// Nothing pending, so this doesn't propagate.
+	assert!(!cell.rollback());
+	v.expect([]);
+}
+
+#[test]
+fn confirm() {
+	let v = &Validator::new();
+
+	let cell = OptimisticCell::new(1);
+	let _sub = Subscription::computed(|| v.push(("optimistic", cell.get())));
+	let _confirmed = Subscription::computed(|| v.push(("confirmed", cell.confirmed().get())));
+	v.expect([("optimistic", 1), ("confirmed", 1)]);
+
+	cell.set(2);
+	v.expect([("optimistic", 2)]);
+
+	assert!(cell.confirm());
+	v.expect([("optimistic", 2), ("confirmed", 2)]);
+	assert!(!cell.is_pending());
+
+	assert!(!cell.confirm());
+	v.expect([]);
+
+	cell.set(3);
+	v.expect([("optimistic", 3)]);
+	assert!(cell.rollback());
+	v.expect([("optimistic", 2)]);
+}
+
+#[test]
+fn set_confirmed_is_shadowed_while_pending() {
+	let v = &Validator::new();
+
+	let cell = OptimisticCell::new(1);
+	let _sub = Subscription::computed(|| v.push(cell.get()));
+	v.expect([1]);
+
+	cell.set_confirmed(2);
+	v.expect([2]);
+
+	cell.set(3);
+	v.expect([3]);
+
+	cell.set_confirmed(4);
+	v.expect([]);
+
+	assert!(cell.rollback());
+	v.expect([4]);
+}
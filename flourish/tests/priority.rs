@@ -0,0 +1,115 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::{GlobalSignalsRuntime, Propagation, SignalsRuntimeRef};
+
+type Effect<'a> = flourish::Effect<'a, GlobalSignalsRuntime>;
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+fn enqueue(
+	v: &'static Validator<(&'static str, u8)>,
+	id: <GlobalSignalsRuntime as SignalsRuntimeRef>::Symbol,
+	name: &'static str,
+	n: u8,
+) {
+	GlobalSignalsRuntime.update_or_enqueue(id, move || {
+		v.push((name, n));
+		Propagation::Halt
+	});
+}
+
+#[test]
+fn higher_priority_first() {
+	static V: Validator<(&str, u8)> = Validator::new();
+
+	let a = GlobalSignalsRuntime.next_id();
+	let b = GlobalSignalsRuntime.next_id();
+	let c = GlobalSignalsRuntime.next_id();
+	GlobalSignalsRuntime.set_priority(a, -1);
+	GlobalSignalsRuntime.set_priority(c, 1);
+
+	GlobalSignalsRuntime.hint_batched_updates(|| {
+		for n in 1..=2 {
+			enqueue(&V, a, "a", n);
+			enqueue(&V, b, "b", n);
+			enqueue(&V, c, "c", n);
+		}
+		V.expect([]);
+	});
+	V.expect([("c", 1), ("c", 2), ("b", 1), ("b", 2), ("a", 1), ("a", 2)]);
+
+	// Changing the priority also reorders already-queued updates.
+	GlobalSignalsRuntime.hint_batched_updates(|| {
+		enqueue(&V, a, "a", 3);
+		enqueue(&V, c, "c", 3);
+		GlobalSignalsRuntime.set_priority(a, 2);
+	});
+	V.expect([("a", 3), ("c", 3)]);
+
+	for id in [a, b, c] {
+		GlobalSignalsRuntime.purge(id);
+	}
+}
+
+#[test]
+fn equal_priority_keeps_order() {
+	static V: Validator<(&str, u8)> = Validator::new();
+
+	let a = GlobalSignalsRuntime.next_id();
+	let b = GlobalSignalsRuntime.next_id();
+	GlobalSignalsRuntime.set_priority(a, 1);
+	GlobalSignalsRuntime.set_priority(b, 1);
+
+	// Each signal's updates apply in submission order, and signals in creation order.
+	GlobalSignalsRuntime.hint_batched_updates(|| {
+		enqueue(&V, b, "b", 1);
+		enqueue(&V, a, "a", 1);
+		enqueue(&V, b, "b", 2);
+		enqueue(&V, a, "a", 2);
+	});
+	V.expect([("a", 1), ("a", 2), ("b", 1), ("b", 2)]);
+
+	for id in [a, b] {
+		GlobalSignalsRuntime.purge(id);
+	}
+}
+
+#[test]
+fn dependencies_first() {
+	let v = &Validator::new();
+
+	let a = Signal::cell(1);
+	let b = Signal::computed(|| {
+		v.push("b");
+		a.get() + 1
+	});
+	// Created before `c`, so this would refresh before it without priorities.
+	let d = Subscription::computed(|| {
+		v.push("d");
+		a.get() + b.get()
+	});
+	let c = Subscription::computed(|| {
+		v.push("c");
+		a.get() + b.get()
+	});
+	v.expect(["d", "b", "c"]);
+
+	GlobalSignalsRuntime.set_priority(symbol_of(|| c.get()), 1);
+	GlobalSignalsRuntime.set_priority(symbol_of(|| d.get()), -1);
+	v.expect([]);
+
+	// `c` is due first, but its stale dependency `b` refreshes before it. `d` comes last.
+	a.set(2);
+	v.expect(["b", "c", "d"]);
+	assert_eq!((c.get(), d.get()), (5, 5));
+}
+
+/// Subscriptions don't expose their symbol, so this reads it from a probe's dependencies.
+fn symbol_of<T: Send>(
+	read: impl Send + FnMut() -> T,
+) -> <GlobalSignalsRuntime as SignalsRuntimeRef>::Symbol {
+	Effect::new(read, drop).dependencies()[0]
+}
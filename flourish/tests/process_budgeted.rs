@@ -0,0 +1,78 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::{GlobalSignalsRuntime, SignalsRuntimeRef};
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn idle() {
+	assert!(!GlobalSignalsRuntime.process_budgeted(0));
+}
+
+#[test]
+fn partial_passes() {
+	let v = &Validator::new();
+
+	let a = Signal::cell(1);
+	let _x = Subscription::computed(|| v.push(("x", a.get())));
+	let _y = Subscription::computed(|| v.push(("y", a.get())));
+	let _z = Subscription::computed(|| v.push(("z", a.get())));
+	v.expect([("x", 1), ("y", 1), ("z", 1)]);
+
+	GlobalSignalsRuntime.hint_batched_updates(|| {
+		a.set(2);
+		v.expect([]);
+
+		assert!(GlobalSignalsRuntime.process_budgeted(1));
+		v.expect([("x", 2)]);
+
+		assert!(!GlobalSignalsRuntime.process_budgeted(2));
+		v.expect([("y", 2), ("z", 2)]);
+	});
+	v.expect([]);
+}
+
+#[test]
+fn remainder_at_batch_end() {
+	let v = &Validator::new();
+
+	let a = Signal::cell(1);
+	let _x = Subscription::computed(|| v.push(("x", a.get())));
+	let _y = Subscription::computed(|| v.push(("y", a.get())));
+	v.expect([("x", 1), ("y", 1)]);
+
+	GlobalSignalsRuntime.hint_batched_updates(|| {
+		a.set(2);
+		assert!(GlobalSignalsRuntime.process_budgeted(1));
+		v.expect([("x", 2)]);
+	});
+	v.expect([("y", 2)]);
+}
+
+#[test]
+fn dependency_order() {
+	let v = &Validator::new();
+
+	let a = Signal::cell(1);
+	let b = Signal::computed(|| {
+		let b = a.get() * 10;
+		v.push(("b", b));
+		b
+	});
+	let _c = Subscription::computed(|| v.push(("c", b.get())));
+	v.expect([("b", 10), ("c", 10)]);
+
+	GlobalSignalsRuntime.hint_batched_updates(|| {
+		a.set(2);
+		assert!(GlobalSignalsRuntime.process_budgeted(1));
+		v.expect([("b", 20)]);
+
+		assert!(!GlobalSignalsRuntime.process_budgeted(1));
+		v.expect([("c", 20)]);
+	});
+	v.expect([]);
+}
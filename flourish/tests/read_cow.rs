@@ -0,0 +1,31 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::GlobalSignalsRuntime;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn borrowed_and_owned() {
+	let cell = Signal::cell(vec![1, 2, 3]);
+
+	assert_eq!(cell.read_cow().len(), 3);
+
+	let owned: Vec<i32> = cell.read_cow().into_owned();
+	assert_eq!(owned, [1, 2, 3]);
+}
+
+#[test]
+fn into_owned_records_dependency() {
+	let v = &Validator::new();
+
+	let cell = Signal::cell(String::from("a"));
+	let _sub = Subscription::computed(|| v.push(cell.read_cow().into_owned()));
+	v.expect(["a".to_string()]);
+
+	cell.set(String::from("b"));
+	v.expect(["b".to_string()]);
+}
@@ -0,0 +1,28 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::GlobalSignalsRuntime;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+
+#[test]
+fn reuses_allocation() {
+	let a = Signal::cell(1);
+	let mut buf = None;
+
+	a.read_exclusive_dyn_into(&mut buf);
+	assert_eq!(***buf.as_ref().unwrap(), 1);
+	let address = &**buf.as_ref().unwrap() as *const _ as *const () as usize;
+
+	// Would deadlock iff the previous guard were still held.
+	a.read_exclusive_dyn_into(&mut buf);
+	assert_eq!(***buf.as_ref().unwrap(), 1);
+	assert_eq!(
+		&**buf.as_ref().unwrap() as *const _ as *const () as usize,
+		address
+	);
+
+	buf = None;
+	a.set(2);
+	a.read_dyn_into(&mut buf);
+	assert_eq!(***buf.as_ref().unwrap(), 2);
+}
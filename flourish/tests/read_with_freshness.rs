@@ -0,0 +1,87 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::{GlobalSignalsRuntime, StaleRead};
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+#[test]
+fn computed() {
+	let cell = Signal::cell(1);
+	let doubled = Signal::computed(|| cell.get() * 2);
+
+	let (value, fresh) = doubled.read_with_freshness();
+	assert_eq!((*value, fresh), (2, true));
+	drop(value);
+
+	let (value, fresh) = doubled.read_with_freshness();
+	assert_eq!((*value, fresh), (2, false));
+	drop(value);
+
+	cell.set(2);
+	let (value, fresh) = doubled.read_with_freshness();
+	assert_eq!((*value, fresh), (4, true));
+	drop(value);
+
+	let (value, fresh) = doubled.read_with_freshness();
+	assert_eq!((*value, fresh), (4, false));
+}
+
+#[test]
+fn subscribed() {
+	let cell = Signal::cell(1);
+	let sub = Subscription::computed(|| cell.get() + 1);
+
+	// Refreshed eagerly by the runtime rather than by the read.
+	cell.set(2);
+	let (value, fresh) = sub.read_with_freshness();
+	assert_eq!((*value, fresh), (3, false));
+}
+
+#[test]
+fn uncached() {
+	let cell = Signal::cell(1);
+	let uncached = Signal::computed_uncached(|| cell.get());
+
+	for _ in 0..2 {
+		let (value, fresh) = uncached.read_with_freshness();
+		assert_eq!((*value, fresh), (1, true));
+	}
+}
+
+#[test]
+fn cell() {
+	let cell = Signal::cell(1);
+	let (value, fresh) = cell.read_with_freshness();
+	assert_eq!((*value, fresh), (1, false));
+}
+
+#[test]
+fn return_stale() {
+	let cell = Signal::cell(1);
+	let lazy = Signal::computed_with_stale_read(|| cell.get(), StaleRead::ReturnStale);
+
+	let (value, fresh) = lazy.read_with_freshness();
+	assert_eq!((*value, fresh), (1, true));
+	drop(value);
+
+	cell.set(2);
+	let (value, fresh) = lazy.read_with_freshness();
+	assert_eq!((*value, fresh), (1, false));
+}
+
+#[test]
+fn evictable() {
+	let cell = Signal::cell(1);
+	let (signal, evictor) = Signal::computed_evictable({
+		let cell = cell.clone();
+		move || cell.get()
+	});
+
+	assert!(signal.read_with_freshness().1);
+	assert!(!signal.read_with_freshness().1);
+
+	evictor.evict();
+	assert!(signal.read_with_freshness().1);
+	assert!(!signal.read_with_freshness().1);
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use std::{ptr, sync::Barrier, thread};
+
+use flourish::GlobalSignalsRuntime;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type SignalRegistry<K, T> = flourish::SignalRegistry<K, T, GlobalSignalsRuntime>;
+
+#[test]
+fn deduplicates_and_prunes() {
+	let registry = SignalRegistry::<&str, usize>::new();
+
+	let a = registry.get_or_create("a", || Signal::computed(|| 1).into_dyn_static());
+	let b = registry.get_or_create("b", || Signal::computed(|| 2).into_dyn_static());
+	assert!(ptr::addr_eq(&*registry.get("a").unwrap(), &*a));
+	assert_eq!(b.get(), 2);
+
+	drop(b);
+	assert!(registry.get("b").is_none());
+	let b = registry.get_or_create("b", || Signal::computed(|| 3).into_dyn_static());
+	assert_eq!(b.get(), 3);
+
+	drop((a, b));
+	assert!(registry.get("a").is_none());
+	// `get` pruned all dead entries.
+	assert_eq!(format!("{registry:?}"), "{}");
+}
+
+#[test]
+fn concurrent_get_or_create() {
+	let registry = SignalRegistry::<u8, u8>::new();
+	let barrier = Barrier::new(8);
+
+	let signals = thread::scope(|s| {
+		let handles: Vec<_> = (0..8)
+			.map(|_| {
+				s.spawn(|| {
+					barrier.wait();
+					registry.get_or_create(0, || Signal::cell(0).into_dyn_static())
+				})
+			})
+			.collect();
+		handles
+			.into_iter()
+			.map(|handle| handle.join().unwrap())
+			.collect::<Vec<_>>()
+	});
+
+	for signal in &signals {
+		assert!(ptr::addr_eq(&**signal, &*signals[0]));
+	}
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::{result::ResultSignalExt, GlobalSignalsRuntime};
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn map_ok() {
+	let v = &Validator::new();
+
+	let a = Signal::cell(Ok::<_, &str>(1));
+	let mapped = a.to_owned().map_ok(|value| {
+		v.push(*value);
+		value + 1
+	});
+	assert_eq!(mapped.get(), Ok(2));
+	v.expect([1]);
+
+	a.set(Err("error"));
+	assert_eq!(mapped.get(), Err("error"));
+	v.expect([]);
+}
+
+#[test]
+fn map_err() {
+	let v = &Validator::new();
+
+	let a = Signal::cell(Err::<i32, _>("error"));
+	let mapped = a.to_owned().map_err(|error| {
+		v.push(*error);
+		error.len()
+	});
+	assert_eq!(mapped.get(), Err(5));
+	v.expect(["error"]);
+
+	a.set(Ok(1));
+	assert_eq!(mapped.get(), Ok(1));
+	v.expect([]);
+}
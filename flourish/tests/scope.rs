@@ -0,0 +1,60 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::{GlobalSignalsRuntime, Propagation};
+
+type Scope<'a> = flourish::Scope<'a, GlobalSignalsRuntime>;
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn unsubscribes_on_drop() {
+	let v = &Validator::new();
+
+	let a = Signal::cell_reactive((), |_, subscribed| {
+		v.push(("a", subscribed));
+		Propagation::Halt
+	});
+	let b = Signal::cell_reactive(1, |_, subscribed| {
+		v.push(("b", subscribed));
+		Propagation::Halt
+	});
+	let c = Signal::computed(|| b.get() + 1);
+
+	let scope = Scope::new();
+	a.subscribe_scoped(&scope);
+	v.expect([("a", true)]);
+
+	let sub_c = c.subscribe_scoped(&scope);
+	v.expect([("b", true)]);
+	assert_eq!(sub_c.get(), 2);
+
+	// Already subscribed, so no notification.
+	let sub_b = b.subscribe_scoped(&scope);
+	v.expect([]);
+
+	b.set(2);
+	assert_eq!((sub_b.get(), sub_c.get()), (2, 3));
+
+	drop(scope);
+	v.expect([("a", false), ("b", false)]);
+}
+
+#[test]
+fn empty() {
+	drop(Scope::new());
+}
+
+#[test]
+fn earlier_references_stay_valid() {
+	let cells = [1, 2, 3, 4].map(Signal::cell);
+
+	let scope = Scope::new();
+	let subscriptions = cells.each_ref().map(|cell| cell.subscribe_scoped(&scope));
+
+	// Read only after all were added.
+	for (subscription, expected) in subscriptions.into_iter().zip(1..) {
+		assert_eq!(subscription.get(), expected);
+	}
+}
@@ -1,6 +1,6 @@
 #![cfg(feature = "global_signals_runtime")]
 
-use flourish::{shadow_clone, GlobalSignalsRuntime};
+use flourish::{shadow_clone, GlobalSignalsRuntime, MaybeReplaced, MaybeSet};
 
 type Effect<'a> = flourish::Effect<'a, GlobalSignalsRuntime>;
 type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
@@ -36,3 +36,45 @@ fn set() {
 
 	v.expect([("_sub_a", "aa"), ("_sub_b", "aa")]);
 }
+
+#[test]
+fn set_if_distinct_reporting() {
+	let v = &Validator::new();
+
+	let a = Signal::cell("a");
+	let _sub = Subscription::computed({
+		shadow_clone!(a);
+		move || v.push(a.get())
+	});
+	v.expect(["a"]);
+
+	assert_eq!(a.set_if_distinct_reporting("a"), MaybeSet::Unchanged("a"));
+	v.expect([]);
+
+	assert_eq!(a.set_if_distinct_reporting("b"), MaybeSet::Set);
+	v.expect(["b"]);
+}
+
+#[test]
+fn swap_if_distinct_blocking() {
+	let v = &Validator::new();
+
+	let a = Signal::cell("a");
+	let _sub = Subscription::computed({
+		shadow_clone!(a);
+		move || v.push(a.get())
+	});
+	v.expect(["a"]);
+
+	assert_eq!(
+		a.swap_if_distinct_blocking("a"),
+		MaybeReplaced::Unchanged("a")
+	);
+	v.expect([]);
+
+	assert_eq!(
+		a.swap_if_distinct_blocking("b"),
+		MaybeReplaced::Replaced("a")
+	);
+	v.expect(["b"]);
+}
@@ -0,0 +1,40 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::{set_all, GlobalSignalsRuntime};
+
+type Effect<'a> = flourish::Effect<'a, GlobalSignalsRuntime>;
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn single_propagation() {
+	let v = &Validator::new();
+
+	let a = Signal::cell(1);
+	let b = Signal::cell(2);
+	let _e = Effect::new(|| v.push((a.get(), b.get())), drop);
+	v.expect([(1, 2)]);
+
+	set_all([(a.as_dyn_cell(), 3), (b.as_dyn_cell(), 4)]);
+	v.expect([(3, 4)]);
+}
+
+#[test]
+fn last_write_wins() {
+	let v = &Validator::new();
+
+	let a = Signal::cell(1);
+	let _e = Effect::new(|| v.push(a.get()), drop);
+	v.expect([1]);
+
+	set_all([(a.as_dyn_cell(), 2), (a.as_dyn_cell(), 3)]);
+	v.expect([3]);
+	assert_eq!(a.get(), 3);
+}
+
+#[test]
+fn empty() {
+	set_all::<u8, GlobalSignalsRuntime>([]);
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::GlobalSignalsRuntime;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type SignalBuilder<I> = flourish::SignalBuilder<I, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn tracks_inputs() {
+	let v = &Validator::new();
+
+	let a = Signal::cell(1);
+	let b = Signal::cell(10);
+	let sum = SignalBuilder::new()
+		.input(&a)
+		.input(b.as_dyn())
+		.computed(|(((), a), b)| a.get() + b.get());
+	let _sub = Subscription::computed(|| v.push(sum.get()));
+	v.expect([11]);
+
+	a.set(2);
+	v.expect([12]);
+
+	b.set(20);
+	v.expect([22]);
+}
+
+#[test]
+fn unread_inputs_are_not_dependencies() {
+	let v = &Validator::new();
+
+	let a = Signal::cell(1);
+	let b = Signal::cell(10);
+	let only_a = SignalBuilder::with_runtime(GlobalSignalsRuntime)
+		.input(&a)
+		.input_unchecked(&b)
+		.computed(|(((), a), _)| a.get());
+	let _sub = Subscription::computed(|| v.push(only_a.get()));
+	v.expect([1]);
+
+	b.set(20);
+	v.expect([]);
+}
+
+#[test]
+fn keeps_inputs_alive() {
+	let a = Signal::cell(1);
+	let doubled = SignalBuilder::new()
+		.input(&a)
+		.computed(|((), a)| a.get() * 2);
+	let weak = a.downgrade();
+	drop(a);
+
+	assert_eq!(doubled.get(), 2);
+	assert!(weak.upgrade().is_some());
+}
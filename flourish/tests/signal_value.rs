@@ -0,0 +1,49 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use flourish::{signal_value, GlobalSignalsRuntime, SignalDyn};
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+static CLONES: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, PartialEq, Eq)]
+struct Counted(u8);
+
+impl Clone for Counted {
+	fn clone(&self) -> Self {
+		CLONES.fetch_add(1, Ordering::Relaxed);
+		Self(self.0)
+	}
+}
+
+fn generic<T: Send + Sync + Clone>(signal: &SignalDyn<'_, T, GlobalSignalsRuntime>) -> T {
+	signal_value!(signal)
+}
+
+#[test]
+fn copy_or_clone() {
+	let copied = Signal::cell(1);
+	let cloned = Signal::cell(Counted(2));
+
+	assert_eq!(signal_value!(copied), 1);
+	assert_eq!(generic(copied.as_dyn()), 1);
+
+	assert_eq!(signal_value!(&cloned), Counted(2));
+	assert_eq!(CLONES.load(Ordering::Relaxed), 1);
+
+	assert_eq!(generic(cloned.as_dyn()), Counted(2));
+	assert_eq!(CLONES.load(Ordering::Relaxed), 2);
+}
+
+#[test]
+fn records_dependency() {
+	let a = Signal::cell(1);
+	let sub = Subscription::computed(|| signal_value!(a) + 1);
+	assert_eq!(sub.get(), 2);
+
+	a.set(2);
+	assert_eq!(sub.get(), 3);
+}
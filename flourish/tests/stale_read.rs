@@ -0,0 +1,68 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::{GlobalSignalsRuntime, StaleRead};
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn refresh_matches_computed() {
+	let v = &Validator::new();
+
+	let input = Signal::cell(1);
+	let signal = Signal::computed_with_stale_read(
+		|| {
+			let value = input.get();
+			v.push(value);
+			value
+		},
+		StaleRead::default(),
+	);
+	v.expect([]);
+
+	assert_eq!(signal.get(), 1);
+	v.expect([1]);
+
+	input.set(2);
+	assert_eq!(signal.get(), 2);
+	v.expect([2]);
+}
+
+#[test]
+fn return_stale_until_subscribed() {
+	let v = &Validator::new();
+
+	let input = Signal::cell(1);
+	let signal = Signal::computed_with_stale_read(
+		|| {
+			let value = input.get();
+			v.push(value);
+			value
+		},
+		StaleRead::ReturnStale,
+	);
+
+	// The first read still computes.
+	assert_eq!(signal.get(), 1);
+	v.expect([1]);
+
+	input.set(2);
+	assert_eq!(signal.get(), 1);
+	v.expect([]);
+
+	let sub = Subscription::computed(|| signal.get());
+	assert_eq!(sub.get(), 2);
+	v.expect([2]);
+
+	input.set(3);
+	assert_eq!(signal.get(), 3);
+	v.expect([3]);
+
+	drop(sub);
+	input.set(4);
+	assert_eq!(signal.get(), 3);
+	v.expect([]);
+}
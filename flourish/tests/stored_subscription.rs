@@ -0,0 +1,50 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::{GlobalSignalsRuntime, StoredSubscription};
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn heterogeneous_and_unsubscribes_on_drop() {
+	let v = &Validator::new();
+
+	let input = Signal::cell(1);
+	let stored: Vec<StoredSubscription> = vec![
+		Subscription::computed(|| v.push(format!("int {}", input.get()))).into_stored(),
+		Subscription::computed(|| {
+			let text = input.get().to_string();
+			v.push(format!("text {text}"));
+			text
+		})
+		.into(),
+	];
+	v.expect(["int 1".to_string(), "text 1".to_string()]);
+
+	input.set(2);
+	v.expect(["int 2".to_string(), "text 2".to_string()]);
+
+	drop(stored);
+	input.set(3);
+	v.expect([]);
+}
+
+#[test]
+fn keeps_shared_signal_subscribed() {
+	let v = &Validator::new();
+
+	let input = Signal::cell(1);
+	let signal = Signal::computed(|| v.push(input.get()));
+	let stored = signal.to_subscription().into_stored();
+	v.expect([1]);
+
+	input.set(2);
+	v.expect([2]);
+
+	drop(stored);
+	input.set(3);
+	v.expect([]);
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use std::{pin::pin, thread};
+
+use flourish::{GlobalSignalsRuntime, Propagation};
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+
+mod _block_on;
+use _block_on::{assert_pending, assert_ready};
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn loaded_on_subscribe() {
+	let v = &Validator::new();
+
+	let a = Signal::cell_reactive_mut(None, |value, subscribed| {
+		v.push(subscribed);
+		if subscribed {
+			*value = Some(1);
+			Propagation::Propagate
+		} else {
+			Propagation::Halt
+		}
+	});
+	v.expect([]);
+
+	let first = a.subscribe_first_ready(Option::is_some);
+	v.expect([true]);
+
+	let (sub, first) = assert_ready(first);
+	assert_eq!(first, Some(1));
+
+	drop(sub);
+	v.expect([false]);
+}
+
+#[test]
+fn already_present() {
+	let a = Signal::cell(1);
+	let (_sub, first) = assert_ready(a.subscribe_first());
+	assert_eq!(first, 1);
+}
+
+#[test]
+fn loaded_from_other_thread() {
+	let a = Signal::cell(None);
+
+	let mut first = pin!(a.subscribe_first_ready(Option::is_some));
+	assert_pending(first.as_mut());
+
+	thread::scope(|s| {
+		s.spawn(|| a.set(Some(1)));
+	});
+
+	let (sub, first) = assert_ready(first);
+	assert_eq!(first, Some(1));
+	assert_eq!(sub.get(), Some(1));
+}
+
+#[test]
+fn skips_values_until_ready() {
+	let a = Signal::cell(0);
+
+	let mut first = pin!(a.subscribe_first_ready(|&value| value >= 2));
+	assert_pending(first.as_mut());
+
+	a.set(1);
+	assert_pending(first.as_mut());
+
+	a.set(2);
+	a.set(3);
+	let (_sub, first) = assert_ready(first);
+	assert_eq!(first, 2);
+}
@@ -99,3 +99,49 @@ fn lifecycle() {
 	drop(s);
 	v.expect([]);
 }
+
+#[test]
+fn unsubscribe_one_of_two() {
+	let v = &Validator::new();
+
+	let a = Signal::cell_reactive((), |_value, status| {
+		v.push(status);
+		Propagation::Halt
+	});
+	let s1 = a.to_subscription();
+	let s2 = a.to_subscription();
+	v.expect([true]);
+
+	let a1 = s1.unsubscribe();
+	v.expect([]);
+
+	let a2 = s2.unsubscribe();
+	v.expect([false]);
+
+	let s = a1.into_subscription();
+	v.expect([true]);
+
+	drop((a, a2, s));
+	v.expect([false]);
+}
+
+#[test]
+fn clone_arc_does_not_subscribe() {
+	let v = &Validator::new();
+
+	let a = Signal::cell_reactive((), |_value, status| {
+		v.push(status);
+		Propagation::Halt
+	});
+	let s = a.to_subscription();
+	v.expect([true]);
+
+	let arc = s.clone_arc();
+	v.expect([]);
+
+	drop(s);
+	v.expect([false]);
+
+	drop((a, arc));
+	v.expect([]);
+}
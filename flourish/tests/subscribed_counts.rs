@@ -0,0 +1,76 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use std::num::NonZeroU64;
+
+use flourish::{shadow_clone, GlobalCountingSignalsRuntime, Propagation};
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalCountingSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalCountingSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn intrinsic() {
+	let v = &Validator::new();
+
+	let a = Signal::cell_reactive((), |_value, status| {
+		v.push(status.map(NonZeroU64::get));
+		Propagation::Halt
+	});
+	v.expect([]);
+
+	let s1 = a.to_subscription();
+	v.expect([Some(1)]);
+
+	let s2 = a.to_subscription();
+	v.expect([Some(2)]);
+
+	let s3 = a.to_subscription();
+	v.expect([Some(3)]);
+
+	drop(s2);
+	v.expect([Some(2)]);
+
+	drop(s1);
+	v.expect([Some(1)]);
+
+	drop(a);
+	v.expect([]);
+
+	drop(s3);
+	v.expect([None]);
+}
+
+#[test]
+fn dependents() {
+	let v = &Validator::new();
+
+	let a = Signal::cell_reactive((), |_value, status| {
+		v.push(status.map(NonZeroU64::get));
+		Propagation::Halt
+	});
+	v.expect([]);
+
+	let s1 = Subscription::computed({
+		shadow_clone!(a);
+		move || a.get()
+	});
+	v.expect([Some(1)]);
+
+	let s2 = Subscription::computed({
+		shadow_clone!(a);
+		move || a.get()
+	});
+	v.expect([Some(2)]);
+
+	// A dependent's own subscriptions aren't counted again.
+	let s1_again = s1.to_subscription();
+	v.expect([]);
+
+	drop((s1, s1_again));
+	v.expect([Some(1)]);
+
+	drop(s2);
+	v.expect([None]);
+}
@@ -0,0 +1,27 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::GlobalSignalsRuntime;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn takes_and_propagates() {
+	let v = &Validator::new();
+
+	let cell = Signal::cell(5);
+	let sub = Subscription::computed(|| v.push(cell.get()));
+	v.expect([5]);
+
+	assert_eq!(cell.take(), 5);
+	v.expect([0]);
+
+	// Still propagates when the value was already the default.
+	assert_eq!(cell.take(), 0);
+	v.expect([0]);
+
+	drop(sub);
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use std::num::NonZeroU64;
+
+use flourish::{GlobalCountingSignalsRuntime, Propagation};
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalCountingSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn writes_through() {
+	let subscription = Signal::cell(1).into_subscription().into_dyn_cell();
+	let cell = subscription.to_cell();
+
+	cell.set(2);
+	assert_eq!(subscription.get(), 2);
+
+	drop(subscription);
+	cell.set(3);
+	assert_eq!(cell.get(), 3);
+}
+
+#[test]
+fn doesnt_subscribe() {
+	let v = &Validator::new();
+
+	let subscription = Signal::cell_reactive(0, |_value, status| {
+		v.push(status.map(NonZeroU64::get));
+		Propagation::Halt
+	})
+	.into_subscription()
+	.into_dyn_cell();
+	v.expect([Some(1)]);
+
+	let cell = subscription.to_cell();
+	v.expect([]);
+
+	cell.set(1);
+	v.expect([]);
+
+	drop(subscription);
+	v.expect([None]);
+
+	drop(cell);
+	v.expect([]);
+}
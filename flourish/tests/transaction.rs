@@ -0,0 +1,193 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use std::{
+	ops::ControlFlow,
+	panic::{catch_unwind, AssertUnwindSafe},
+};
+
+use flourish::{GlobalSignalsRuntime, SignalsRuntimeRef};
+
+type Effect<'a> = flourish::Effect<'a, GlobalSignalsRuntime>;
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn commit() {
+	let v = &Validator::new();
+
+	let a = Signal::cell(1);
+	let b = Signal::cell(2);
+	let _e = Effect::new(|| v.push((a.get(), b.get())), drop);
+	v.expect([(1, 2)]);
+
+	let flow = GlobalSignalsRuntime.transaction(|| {
+		a.set(3);
+		b.set(4);
+		// Staged updates aren't visible yet.
+		assert_eq!(a.get(), 1);
+		v.expect([]);
+		ControlFlow::<(), _>::Continue("done")
+	});
+	assert_eq!(flow, ControlFlow::Continue("done"));
+	v.expect([(3, 4)]);
+}
+
+#[test]
+fn rollback() {
+	let v = &Validator::new();
+
+	let a = Signal::cell(1);
+	let b = Signal::cell(2);
+	let _e = Effect::new(|| v.push((a.get(), b.get())), drop);
+	v.expect([(1, 2)]);
+
+	let flow = GlobalSignalsRuntime.transaction(|| {
+		a.set(3);
+		b.set(4);
+		ControlFlow::<_, ()>::Break("invalid")
+	});
+	assert_eq!(flow, ControlFlow::Break("invalid"));
+	v.expect([]);
+	assert_eq!((a.get(), b.get()), (1, 2));
+
+	// The runtime is still usable.
+	a.set(5);
+	v.expect([(5, 2)]);
+}
+
+#[test]
+fn nested() {
+	let v = &Validator::new();
+
+	let a = Signal::cell(1);
+	let b = Signal::cell(2);
+	let _e = Effect::new(|| v.push((a.get(), b.get())), drop);
+	v.expect([(1, 2)]);
+
+	let _ = GlobalSignalsRuntime.transaction(|| {
+		a.set(3);
+		let _ = GlobalSignalsRuntime.transaction(|| {
+			b.set(4);
+			ControlFlow::<()>::Break(())
+		});
+		let _ = GlobalSignalsRuntime.transaction(|| {
+			a.set(5);
+			ControlFlow::<()>::Continue(())
+		});
+		v.expect([]);
+		ControlFlow::<()>::Continue(())
+	});
+	v.expect([(5, 2)]);
+}
+
+#[test]
+fn panic_rolls_back() {
+	let v = &Validator::new();
+
+	let a = Signal::cell(1);
+	let _e = Effect::new(|| v.push(a.get()), drop);
+	v.expect([1]);
+
+	assert!(catch_unwind(AssertUnwindSafe(|| {
+		GlobalSignalsRuntime.transaction(|| -> ControlFlow<()> {
+			a.set(2);
+			panic!();
+		})
+	}))
+	.is_err());
+	v.expect([]);
+
+	a.set(3);
+	v.expect([3]);
+}
+
+#[test]
+fn blocking_write_panics() {
+	let v = &Validator::new();
+
+	let a = Signal::cell(1);
+	let _e = Effect::new(|| v.push(a.get()), drop);
+	v.expect([1]);
+
+	let set_blocking = catch_unwind(AssertUnwindSafe(|| {
+		GlobalSignalsRuntime.transaction(|| -> ControlFlow<()> {
+			a.set(2);
+			a.set_blocking(3);
+			ControlFlow::Continue(())
+		})
+	}));
+	let message = *set_blocking.unwrap_err().downcast::<&str>().unwrap();
+	assert!(message.contains("within a transaction"), "{message}");
+	let replace_blocking = catch_unwind(AssertUnwindSafe(|| {
+		GlobalSignalsRuntime.transaction(|| -> ControlFlow<()> {
+			a.replace_blocking(4);
+			ControlFlow::Continue(())
+		})
+	}));
+	assert!(replace_blocking.is_err());
+
+	// Both transactions were rolled back entirely.
+	v.expect([]);
+	assert_eq!(a.get(), 1);
+
+	a.set_blocking(5);
+	v.expect([5]);
+}
+
+#[test]
+fn nested_commit_within_rollback() {
+	let v = &Validator::new();
+
+	let a = Signal::cell(1);
+	let _e = Effect::new(|| v.push(a.get()), drop);
+	v.expect([1]);
+
+	let _ = GlobalSignalsRuntime.transaction(|| {
+		let _ = GlobalSignalsRuntime.transaction(|| {
+			a.set(2);
+			ControlFlow::<()>::Continue(())
+		});
+		ControlFlow::<()>::Break(())
+	});
+	v.expect([]);
+	assert_eq!(a.get(), 1);
+}
+
+#[test]
+fn observers_partway() {
+	let v = &Validator::new();
+
+	let a = Signal::cell(1);
+	let b = Signal::cell(2);
+	let sum = Signal::computed(|| a.get() + b.get());
+	let _e = Effect::new(|| v.push(("effect", sum.get())), drop);
+	v.expect([("effect", 3)]);
+
+	let _ = GlobalSignalsRuntime.transaction(|| {
+		a.set(10);
+		let _ = GlobalSignalsRuntime.transaction(|| {
+			b.set(20);
+			ControlFlow::<()>::Continue(())
+		});
+
+		// Neither the committed inner nor the pending outer updates are visible yet.
+		v.push(("partway", sum.get()));
+		v.push(("partway", a.get() + b.get()));
+		ControlFlow::<()>::Continue(())
+	});
+	v.expect([("partway", 3), ("partway", 3), ("effect", 30)]);
+}
+
+#[test]
+fn reads_within_signal_are_recorded() {
+	let a = Signal::cell(1);
+	let c = Signal::computed(|| {
+		GlobalSignalsRuntime.transaction(|| ControlFlow::<(), _>::Continue(a.get()))
+	});
+	assert_eq!(c.get(), ControlFlow::Continue(1));
+
+	a.set(2);
+	assert_eq!(c.get(), ControlFlow::Continue(2));
+}
@@ -0,0 +1,123 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use std::{
+	sync::{mpsc, OnceLock},
+	thread,
+};
+
+use flourish::{GlobalSignalsRuntime, SignalsRuntimeRef};
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn try_read() {
+	let a = Signal::cell(1);
+	assert_eq!(a.try_read().as_deref(), Some(&1));
+
+	let exclusive = a.read_exclusive();
+	assert!(a.try_read().is_none());
+	drop(exclusive);
+
+	let b = Signal::computed(|| a.get() + 1);
+	assert_eq!(b.get(), 2);
+	assert_eq!(b.try_read().as_deref(), Some(&2));
+}
+
+#[test]
+fn no_evaluation() {
+	let v = &Validator::new();
+
+	let a = Signal::cell(1);
+	let b = Signal::computed(|| {
+		v.push(a.get());
+		a.get()
+	});
+	assert!(b.try_read().is_none());
+	v.expect([]);
+
+	assert_eq!(b.get(), 1);
+	v.expect([1]);
+
+	let _sub = b.to_subscription();
+	GlobalSignalsRuntime.hint_batched_updates(|| {
+		a.set(2);
+		// Applies the update without refreshing `b`.
+		assert!(GlobalSignalsRuntime.process_budgeted(0));
+		assert!(b.try_read().is_none());
+	});
+	v.expect([2]);
+	assert_eq!(b.try_read().as_deref(), Some(&2));
+
+	let uncached = Signal::computed_uncached(|| {
+		v.push(0);
+		0
+	});
+	assert!(uncached.try_read().is_none());
+	v.expect([]);
+}
+
+#[test]
+fn reentrant() {
+	let a = Signal::computed(|| 1);
+	assert_eq!(a.get(), 1);
+
+	let exclusive = a.read_exclusive();
+	assert!(a.try_read().is_none());
+	drop(exclusive);
+
+	assert_eq!(a.try_read().as_deref(), Some(&1));
+}
+
+#[test]
+fn mid_update() {
+	let v: &'static _ = Box::leak(Box::new(Validator::new()));
+	let probe: &'static OnceLock<Box<dyn Send + Sync + Fn() -> bool>> =
+		Box::leak(Box::new(OnceLock::new()));
+
+	let trigger: &'static _ = Box::leak(Box::new(Signal::cell(0)));
+	let b: &'static _ = Box::leak(Box::new(Subscription::computed(move || {
+		let value = trigger.get();
+		if let Some(probe) = probe.get() {
+			v.push(probe());
+		}
+		value
+	})));
+	probe
+		.set(Box::new(|| {
+			GlobalSignalsRuntime.run_detached(|| b.try_read().is_some())
+		}))
+		.ok()
+		.unwrap();
+	assert_eq!(b.try_read().as_deref(), Some(&0));
+
+	trigger.set(1);
+	v.expect([false]);
+	assert_eq!(b.try_read().as_deref(), Some(&1));
+}
+
+#[test]
+fn cross_thread() {
+	let a = Signal::computed(|| 1);
+	assert_eq!(a.get(), 1);
+
+	let (entered_tx, entered_rx) = mpsc::channel();
+	let (release_tx, release_rx) = mpsc::channel();
+	let busy = thread::spawn(move || {
+		GlobalSignalsRuntime.hint_batched_updates(|| {
+			entered_tx.send(()).unwrap();
+			release_rx.recv().unwrap();
+		})
+	});
+
+	// The runtime is busy on the other thread, so this doesn't wait for it.
+	entered_rx.recv().unwrap();
+	assert!(a.try_read().is_none());
+
+	release_tx.send(()).unwrap();
+	busy.join().unwrap();
+	assert_eq!(a.try_read().as_deref(), Some(&1));
+}
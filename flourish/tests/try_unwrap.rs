@@ -0,0 +1,33 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::{GlobalSignalsRuntime, SignalArc};
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+
+#[test]
+fn unique() {
+	let a = Signal::shared(1);
+	let Ok(unmanaged) = a.try_unwrap() else {
+		panic!()
+	};
+	assert_eq!(SignalArc::new(unmanaged).get(), 1);
+}
+
+#[test]
+fn shared() {
+	let a = Signal::shared(1);
+
+	let b = a.clone();
+	let Err(a) = a.try_unwrap() else { panic!() };
+	drop(b);
+
+	let weak = a.downgrade();
+	let Err(a) = a.try_unwrap() else { panic!() };
+	drop(weak);
+
+	let sub = a.to_subscription();
+	let Err(a) = a.try_unwrap() else { panic!() };
+	drop(sub);
+
+	assert!(a.try_unwrap().is_ok());
+}
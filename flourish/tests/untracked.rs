@@ -0,0 +1,38 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::{untracked, GlobalSignalsRuntime};
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn nests_and_restores_tracking() {
+	let v = &Validator::new();
+
+	let before = Signal::cell(1);
+	let outer = Signal::cell(2);
+	let inner = Signal::cell(3);
+	let after = Signal::cell(4);
+
+	let sub = Subscription::computed(|| {
+		let before = before.get();
+		let (outer, inner) = untracked(|| (outer.get(), untracked(|| inner.get())));
+		v.push((before, outer, inner, after.get()));
+	});
+	v.expect([(1, 2, 3, 4)]);
+
+	outer.set(20);
+	inner.set(30);
+	v.expect([]);
+
+	before.set(10);
+	v.expect([(10, 20, 30, 4)]);
+
+	after.set(40);
+	v.expect([(10, 20, 30, 40)]);
+
+	drop(sub);
+}
@@ -38,3 +38,9 @@ fn via_into() {
 	let _: SignalWeakDyn<_> = weak.into();
 	let _: SubscriptionDyn<_> = sub.into();
 }
+
+#[test]
+fn into_dyn_static() {
+	let arc: SignalArcDyn<'static, _> = Signal::cell(0).into_dyn_static();
+	assert_eq!(arc.get(), 0);
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::GlobalSignalsRuntime;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Point {
+	x: i32,
+	y: i32,
+}
+
+#[test]
+fn propagates_once_per_update() {
+	let v = &Validator::new();
+
+	let point = Signal::cell(Point { x: 1, y: 2 });
+	let _sub = Subscription::computed(|| v.push(point.read().clone()));
+	v.expect([Point { x: 1, y: 2 }]);
+
+	point.update_if_changed(|point| {
+		point.x = 3;
+		point.y = 4;
+	});
+	v.expect([Point { x: 3, y: 4 }]);
+}
+
+#[test]
+fn halts_if_unchanged() {
+	let v = &Validator::new();
+
+	let point = Signal::cell(Point { x: 1, y: 2 });
+	let _sub = Subscription::computed(|| v.push(point.read().clone()));
+	v.expect([Point { x: 1, y: 2 }]);
+
+	point.update_if_changed(|point| point.x = 1);
+	v.expect([]);
+
+	// Changed and changed back within the same update.
+	point.update_if_changed(|point| {
+		point.x = 5;
+		point.x = 1;
+	});
+	v.expect([]);
+}
@@ -0,0 +1,69 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::{GlobalSignalsRuntime, MaybeSet};
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+type Validated<'a, T, V> = flourish::Validated<'a, T, V, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn rejects_invalid() {
+	let v = &Validator::new();
+
+	let even = Validated::new(0, |value: &i32| value % 2 == 0).unwrap();
+	let sub = Subscription::computed(|| v.push(even.get()));
+	v.expect([0]);
+
+	assert_eq!(even.set(1), MaybeSet::Unchanged(1));
+	v.expect([]);
+
+	assert_eq!(even.set(2), MaybeSet::Set);
+	v.expect([2]);
+
+	drop(sub);
+}
+
+#[test]
+fn validates_initial_value() {
+	assert!(matches!(
+		Validated::new(1, |value: &i32| value % 2 == 0),
+		Err(1)
+	));
+}
+
+#[derive(Debug, PartialEq)]
+struct Odd(i32);
+
+fn even(value: &i32) -> Result<(), Odd> {
+	if value % 2 == 0 {
+		Ok(())
+	} else {
+		Err(Odd(*value))
+	}
+}
+
+#[test]
+fn structured_errors() {
+	let v = &Validator::new();
+
+	let cell = Signal::cell_validated(0, even).unwrap();
+	let sub = Subscription::computed(|| v.push(cell.get()));
+	v.expect([0]);
+
+	assert_eq!(cell.try_set(1), Err((1, Odd(1))));
+	v.expect([]);
+	assert_eq!(cell.get(), 0);
+
+	assert_eq!(cell.try_set(2), Ok(()));
+	v.expect([2]);
+
+	drop(sub);
+}
+
+#[test]
+fn structured_errors_rejects_initial() {
+	assert_eq!(Signal::cell_validated(1, even).err(), Some((1, Odd(1))));
+}
@@ -0,0 +1,78 @@
+#![cfg(all(feature = "global_signals_runtime", feature = "tokio"))]
+
+use flourish::GlobalSignalsRuntime;
+use tokio::{runtime::Runtime, sync::watch, task};
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+static RUNS: Validator<i32> = Validator::new();
+
+fn runtime() -> Runtime {
+	tokio::runtime::Builder::new_current_thread()
+		.build()
+		.unwrap()
+}
+
+#[test]
+fn to_watch_follows_signal() {
+	let runtime = runtime();
+	let _guard = runtime.enter();
+
+	let cell = Signal::cell(1);
+	let mut rx = cell.to_watch();
+	assert!(!rx.has_changed().unwrap());
+	assert_eq!(*rx.borrow(), 1);
+
+	cell.set(2);
+	assert!(rx.has_changed().unwrap());
+	assert_eq!(*rx.borrow_and_update(), 2);
+}
+
+#[test]
+fn to_watch_idles_without_receivers() {
+	let runtime = runtime();
+	let _guard = runtime.enter();
+
+	let cell = Signal::cell(1);
+	let signal = Signal::computed({
+		let cell = cell.to_owned();
+		move || {
+			let value = cell.get();
+			RUNS.push(value);
+			value
+		}
+	});
+	let rx = signal.to_watch();
+	RUNS.expect([1]);
+
+	cell.set(2);
+	RUNS.expect([2]);
+
+	drop(rx);
+	runtime.block_on(task::yield_now());
+	cell.set(3);
+	RUNS.expect([]);
+}
+
+#[test]
+fn from_watch_follows_sender() {
+	let runtime = runtime();
+	let _guard = runtime.enter();
+
+	let (tx, rx) = watch::channel(1);
+	let mirror = Signal::from_watch(rx);
+	assert_eq!(mirror.get(), 1);
+
+	tx.send_replace(2);
+	runtime.block_on(task::yield_now());
+	assert_eq!(mirror.get(), 2);
+
+	// The task ends once the signal is gone.
+	drop(mirror);
+	tx.send_replace(3);
+	runtime.block_on(task::yield_now());
+	assert!(tx.is_closed());
+}
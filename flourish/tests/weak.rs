@@ -0,0 +1,30 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use flourish::{GlobalSignalsRuntime, Propagation};
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn upgrade_subscription() {
+	let v = &Validator::new();
+
+	let a = Signal::cell_reactive((), |_value, status| {
+		v.push(status);
+		Propagation::Halt
+	});
+	let weak = a.downgrade();
+	v.expect([]);
+
+	let sub = weak.upgrade_subscription().unwrap();
+	v.expect([true]);
+
+	drop(sub);
+	v.expect([false]);
+
+	drop(a);
+	assert!(weak.upgrade_subscription().is_none());
+	v.expect([]);
+}
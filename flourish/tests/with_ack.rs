@@ -0,0 +1,90 @@
+#![cfg(all(feature = "global_signals_runtime", feature = "futures"))]
+
+use std::{
+	pin::pin,
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+	},
+	task::{Context, Poll, Wake, Waker},
+};
+
+use flourish::GlobalSignalsRuntime;
+use futures_core::Stream;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+struct CountingWaker(AtomicUsize);
+
+impl Wake for CountingWaker {
+	fn wake(self: Arc<Self>) {
+		self.0.fetch_add(1, Ordering::Relaxed);
+	}
+}
+
+#[test]
+fn paused_until_acked() {
+	let cell = Signal::cell(1);
+	let sub = Subscription::computed(|| cell.get());
+	let mut values = pin!(sub.with_ack());
+
+	let wakes = Arc::new(CountingWaker(AtomicUsize::new(0)));
+	let waker = Waker::from(Arc::clone(&wakes));
+	let mut cx = Context::from_waker(&waker);
+
+	let Poll::Ready(Some((1, ack))) = values.as_mut().poll_next(&mut cx) else {
+		panic!("Expected the initial value.");
+	};
+
+	cell.set(2);
+	assert!(values.as_mut().poll_next(&mut cx).is_pending());
+	assert_eq!(wakes.0.load(Ordering::Relaxed), 0);
+
+	drop(ack);
+	assert_eq!(wakes.0.load(Ordering::Relaxed), 1);
+	let Poll::Ready(Some((2, ack))) = values.as_mut().poll_next(&mut cx) else {
+		panic!("Expected the next value.");
+	};
+
+	ack.ack();
+	assert!(values.as_mut().poll_next(&mut cx).is_pending());
+
+	cell.set(3);
+	assert_eq!(wakes.0.load(Ordering::Relaxed), 2);
+	assert!(matches!(
+		values.as_mut().poll_next(&mut cx),
+		Poll::Ready(Some((3, _)))
+	));
+}
+
+#[test]
+fn doesnt_block_propagation() {
+	let v = &Validator::new();
+
+	let cell = Signal::cell(1);
+	let sub = Subscription::computed(|| cell.get());
+	let _other = Subscription::computed(|| v.push(sub.get()));
+	v.expect([1]);
+
+	let mut values = pin!(sub.with_ack());
+	let mut cx = Context::from_waker(Waker::noop());
+	let Poll::Ready(Some((1, ack))) = values.as_mut().poll_next(&mut cx) else {
+		panic!("Expected the initial value.");
+	};
+
+	// While paused, other dependents still update and changes coalesce.
+	cell.set(2);
+	cell.set(3);
+	v.expect([2, 3]);
+	assert!(values.as_mut().poll_next(&mut cx).is_pending());
+
+	drop(ack);
+	assert!(matches!(
+		values.as_mut().poll_next(&mut cx),
+		Poll::Ready(Some((3, _)))
+	));
+}
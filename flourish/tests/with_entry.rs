@@ -0,0 +1,50 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use std::collections::{BTreeMap, HashMap};
+
+use flourish::{GlobalSignalsRuntime, Propagation};
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn inserts_and_modifies() {
+	let v = &Validator::new();
+
+	let map = Signal::cell(HashMap::from([("a", 1)]));
+	let _sub = Subscription::computed(|| v.push(map.read().get("b").copied()));
+	v.expect([None]);
+
+	map.with_entry("b", |value| {
+		assert_eq!(*value, 0);
+		*value = 2;
+		Propagation::Propagate
+	});
+	v.expect([Some(2)]);
+
+	map.with_entry("b", |value| {
+		*value += 1;
+		Propagation::Propagate
+	});
+	v.expect([Some(3)]);
+	assert_eq!(map.read()["a"], 1);
+}
+
+#[test]
+fn halt_keeps_insertion() {
+	let v = &Validator::new();
+
+	let map = Signal::cell(BTreeMap::<u8, u8>::new());
+	let _sub = Subscription::computed(|| v.push(map.read().len()));
+	v.expect([0]);
+
+	map.with_entry(1, |_| Propagation::Halt);
+	v.expect([]);
+	assert_eq!(map.read().get(&1), Some(&0));
+
+	map.with_entry(2, |_| Propagation::Propagate);
+	v.expect([2]);
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "global_signals_runtime")]
+
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use flourish::GlobalSignalsRuntime;
+
+type Signal<T, S> = flourish::Signal<T, S, GlobalSignalsRuntime>;
+type Subscription<T, S> = flourish::Subscription<T, S, GlobalSignalsRuntime>;
+
+mod _validator;
+use _validator::Validator;
+
+#[test]
+fn maps_value() {
+	let cell = Signal::cell(vec![1, 2, 3]);
+	assert_eq!(cell.with_ref(|v| v.len()), 3);
+}
+
+#[test]
+fn records_dependency() {
+	let v = &Validator::new();
+
+	let cell = Signal::cell(String::from("a"));
+	let sub = Subscription::computed(|| v.push(cell.with_ref(|s| s.len())));
+	v.expect([1]);
+
+	cell.set_blocking("abc".to_string());
+	v.expect([3]);
+
+	drop(sub);
+}
+
+#[test]
+fn releases_on_panic() {
+	let cell = Signal::cell(1);
+
+	assert!(catch_unwind(AssertUnwindSafe(|| cell.with_ref(|_| panic!()))).is_err());
+
+	// Would deadlock if the exclusive borrow were still held.
+	cell.set_blocking(2);
+	assert_eq!(cell.with_ref(|&v| v), 2);
+}
@@ -2,13 +2,21 @@
 #![warn(missing_docs)]
 #![warn(unreachable_pub)]
 #![cfg_attr(feature = "_doc", doc = include_str!("../README.md"))]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
 #[cfg(all(
-	feature = "global_signals_runtime",
+	any(feature = "global_signals_runtime", feature = "critical_section"),
 	feature = "forbid_global_signals_runtime"
 ))]
-compile_error!("A dependent enabled the `global_signals_runtime` feature, but another forbid this with the `forbid_global_signals_runtime` feature. Please do not enable `global_signals_runtime` in libraries.");
+compile_error!("A dependent enabled the `global_signals_runtime` or `critical_section` feature, but another forbid this with the `forbid_global_signals_runtime` feature. Please do not enable `global_signals_runtime` or `critical_section` in libraries.");
+
+#[cfg(not(any(feature = "std", feature = "spin")))]
+compile_error!("Without the `std` feature, please enable the `spin` feature to provide locking.");
 
 pub mod raw;
 pub mod runtime;
 pub mod slot;
+
+mod sync;
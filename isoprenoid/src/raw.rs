@@ -13,19 +13,22 @@
 //! [pin-project-lite]: https://crates.io/crates/pin-project-lite
 //! [Pinning in plain English]: https://blog.schichler.dev/posts/Pinning-in-plain-English/
 
-use core::{
-	fmt::{self, Debug, Formatter},
-	marker::PhantomPinned,
-	pin::Pin,
+use alloc::{
+	boxed::Box,
+	collections::{btree_map::Entry, BTreeMap},
+	sync::Arc,
 };
-use std::{
+use core::{
 	any::TypeId,
-	collections::{btree_map::Entry, BTreeMap},
+	fmt::{self, Debug, Formatter},
 	future::Future,
+	marker::PhantomPinned,
 	mem::{self, MaybeUninit},
-	sync::{Arc, Mutex},
+	pin::Pin,
 };
 
+use crate::sync::Mutex;
+
 use once_slot::OnceSlot;
 
 use crate::{
@@ -98,14 +101,34 @@ impl<SR: SignalsRuntimeRef> SignalId<SR> {
 		self.runtime.update_blocking(self.id, f)
 	}
 
-	fn refresh(&self) {
-		self.runtime.refresh(self.id);
+	fn refresh_reporting(&self) -> Option<Propagation> {
+		self.runtime.refresh_reporting(self.id)
 	}
 
 	fn stop(&self) {
 		self.runtime.stop(self.id)
 	}
 
+	fn set_priority(&self, priority: i32) {
+		self.runtime.set_priority(self.id, priority);
+	}
+
+	#[cfg(debug_assertions)]
+	fn set_dependency_limit(&self, limit: usize) {
+		self.runtime.set_dependency_limit(self.id, limit);
+	}
+
+	fn set_notification_filter(
+		&self,
+		should_notify: Box<dyn 'static + Send + Sync + Fn(SR::Symbol) -> bool>,
+	) {
+		self.runtime.set_notification_filter(self.id, should_notify);
+	}
+
+	fn is_stale(&self) -> bool {
+		self.runtime.is_stale(self.id)
+	}
+
 	fn purge(&self) {
 		self.runtime.purge(self.id)
 	}
@@ -180,6 +203,14 @@ impl<Eager: Sync + ?Sized, Lazy: Sync, SR: SignalsRuntimeRef> RawSignal<Eager, L
 		&mut self.eager
 	}
 
+	/// Gives pinned shared access to the contained `Eager`.
+	///
+	/// Unlike [`project_or_init`](`RawSignal::project_or_init`), this neither records a dependency nor initialises the `Lazy` state.
+	#[must_use]
+	pub fn eager(self: Pin<&Self>) -> Pin<&Eager> {
+		unsafe { Pin::new_unchecked(&self.get_ref().eager) }
+	}
+
 	/// This method borrows the pin-projected `Eager` and `Lazy` values,
 	/// marking this [`RawSignal`] as dependency of the surrounding context.
 	///
@@ -199,10 +230,24 @@ impl<Eager: Sync + ?Sized, Lazy: Sync, SR: SignalsRuntimeRef> RawSignal<Eager, L
 		self: Pin<&Self>,
 		init: impl for<'b> FnOnce(Pin<&'b Eager>, Slot<'b, Lazy>) -> Token<'b>,
 	) -> (Pin<&Eager>, Pin<&Lazy>) {
+		let (eager, lazy, _) = self.project_or_init_reporting::<C>(init);
+		(eager, lazy)
+	}
+
+	/// Like [`project_or_init`](`RawSignal::project_or_init`), but also returns whether this call (re)computed the `Lazy` state.
+	///
+	/// That's the case iff this call ran `init`, or ran [`C::UPDATE`](`Callbacks::UPDATE`) (as reported by
+	/// [`SignalsRuntimeRef::refresh_reporting`]) and it returned anything other than [`Propagation::Halt`].
+	pub fn project_or_init_reporting<C: Callbacks<Eager, Lazy, SR>>(
+		self: Pin<&Self>,
+		init: impl for<'b> FnOnce(Pin<&'b Eager>, Slot<'b, Lazy>) -> Token<'b>,
+	) -> (Pin<&Eager>, Pin<&Lazy>, bool) {
 		self.handle.runtime.record_dependency(self.handle.id);
+		let mut initialised = false;
 		unsafe {
 			let eager = Pin::new_unchecked(&self.eager);
 			let lazy = self.lazy.get_or_write(|cell| {
+				initialised = true;
 				self.handle.start(
 					|| {
 						let mut lazy = MaybeUninit::uninit();
@@ -284,11 +329,56 @@ impl<Eager: Sync + ?Sized, Lazy: Sync, SR: SignalsRuntimeRef> RawSignal<Eager, L
 					)
 				}
 			});
-			self.handle.refresh();
-			mem::transmute((eager, Pin::new_unchecked(lazy)))
+			let refreshed = self
+				.handle
+				.refresh_reporting()
+				.is_some_and(|propagation| propagation != Propagation::Halt);
+			let (eager, lazy) = mem::transmute((eager, Pin::new_unchecked(lazy)));
+			(eager, lazy, initialised || refreshed)
+		}
+	}
+
+	/// Like [`project_or_init`](`RawSignal::project_or_init`), but iff the `Lazy` state is already initialised,
+	/// this [`RawSignal`] isn't refreshed first, even if it's stale.
+	///
+	/// This still marks this [`RawSignal`] as dependency of the surrounding context.
+	pub fn project_or_init_stale<C: Callbacks<Eager, Lazy, SR>>(
+		self: Pin<&Self>,
+		init: impl for<'b> FnOnce(Pin<&'b Eager>, Slot<'b, Lazy>) -> Token<'b>,
+	) -> (Pin<&Eager>, Pin<&Lazy>) {
+		let this = self.get_ref();
+		match this.lazy.get() {
+			Some(lazy) => {
+				this.handle.runtime.record_dependency(this.handle.id);
+				unsafe { (Pin::new_unchecked(&this.eager), Pin::new_unchecked(lazy)) }
+			}
+			None => self.project_or_init::<C>(init),
 		}
 	}
 
+	/// Iff the `Lazy` state is initialised and this [`RawSignal`] is neither stale nor currently being updated,
+	/// marks it as dependency of the surrounding context and borrows the pin-projected `Eager` and `Lazy` values.
+	///
+	/// Unlike [`project_or_init`](`RawSignal::project_or_init`), this never initialises or refreshes this [`RawSignal`],
+	/// so no callbacks run as part of this call except for subscription changes caused by
+	/// [`record_dependency`](`SignalsRuntimeRef::record_dependency`).
+	///
+	/// This also returns [`None`] where the runtime is busy on another thread.
+	/// See [`SignalsRuntimeRef::try_record_fresh_dependency`].
+	#[must_use]
+	pub fn project_if_fresh(self: Pin<&Self>) -> Option<(Pin<&Eager>, Pin<&Lazy>)> {
+		let this = self.get_ref();
+		let lazy = this.lazy.get()?;
+		if !this
+			.handle
+			.runtime
+			.try_record_fresh_dependency(this.handle.id)
+		{
+			return None;
+		}
+		unsafe { Some((Pin::new_unchecked(&this.eager), Pin::new_unchecked(lazy))) }
+	}
+
 	/// Increases this [`RawSignal`]'s intrinsic subscription count.
 	pub fn subscribe(&self) {
 		self.handle.subscribe()
@@ -535,11 +625,46 @@ impl<Eager: Sync + ?Sized, Lazy: Sync, SR: SignalsRuntimeRef> RawSignal<Eager, L
 		self.handle.runtime.clone()
 	}
 
+	/// The [`SignalsRuntimeRef::Symbol`] that identifies this [`RawSignal`] in its signals runtime.
+	pub fn id(&self) -> SR::Symbol {
+		self.handle.id
+	}
+
 	/// Wraps [`SignalsRuntimeRef::stop`].
 	pub fn stop(&self) {
 		self.handle.stop();
 	}
 
+	/// Wraps [`SignalsRuntimeRef::set_priority`].
+	pub fn set_priority(&self, priority: i32) {
+		self.handle.set_priority(priority);
+	}
+
+	/// Wraps [`SignalsRuntimeRef::set_dependency_limit`].
+	#[cfg(debug_assertions)]
+	pub fn set_dependency_limit(&self, limit: usize) {
+		self.handle.set_dependency_limit(limit);
+	}
+
+	/// Wraps [`SignalsRuntimeRef::set_notification_filter`].
+	pub fn set_notification_filter(
+		&self,
+		should_notify: Box<dyn 'static + Send + Sync + Fn(SR::Symbol) -> bool>,
+	) {
+		self.handle.set_notification_filter(should_notify);
+	}
+
+	/// Wraps [`SignalsRuntimeRef::is_stale`].
+	pub fn is_stale(&self) -> bool {
+		self.handle.is_stale()
+	}
+
+	/// Whether the `Lazy` state has been initialised, i.e. whether [`project_or_init`](`RawSignal::project_or_init`)
+	/// (or a variant of it) has run `init` before.
+	pub fn is_initialised(&self) -> bool {
+		self.lazy.get().is_some()
+	}
+
 	/// Instructs the signals runtime to release all resources associated with this [`RawSignal`],
 	/// then, if initialised, drops the `Lazy` after calling `before_deinit`.
 	///
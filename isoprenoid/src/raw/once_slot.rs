@@ -1,4 +1,6 @@
-use std::{cell::OnceCell, sync::Mutex};
+use core::cell::OnceCell;
+
+use crate::sync::Mutex;
 
 #[derive(Debug)]
 pub(super) struct OnceSlot<T> {
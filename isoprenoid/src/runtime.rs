@@ -2,15 +2,19 @@
 //!
 //! # Features
 //!
-//! Enable the `global_signals_runtime` Cargo feature for [`GlobalSignalsRuntime`] to implement [`SignalsRuntimeRef`].
+//! Enable the `global_signals_runtime` Cargo feature for [`GlobalSignalsRuntime`] to implement [`SignalsRuntimeRef`].  
+//! Without `std`, enable the `critical_section` Cargo feature for this instead.
 
-use core::{self};
-use std::{
+use alloc::boxed::Box;
+#[cfg(any(feature = "global_signals_runtime", feature = "critical_section"))]
+use alloc::vec::Vec;
+use core::{
 	self,
 	fmt::{self, Debug, Formatter},
 	future::Future,
 	mem,
 	num::NonZeroU64,
+	ops::ControlFlow,
 };
 
 /// Embedded in signals to refer to a specific signals runtime.
@@ -221,6 +225,50 @@ pub unsafe trait SignalsRuntimeRef: Send + Sync + Clone {
 	/// `f` **must** be dropped or consumed before the next matching [`stop`](`SignalsRuntimeRef::stop`) call returns.
 	fn update_or_enqueue(&self, id: Self::Symbol, f: impl 'static + Send + FnOnce() -> Propagation);
 
+	/// Like [`update_or_enqueue`](`SignalsRuntimeRef::update_or_enqueue`), but returns an [`UpdateTicket`]
+	/// that can be passed to [`cancel_update`](`SignalsRuntimeRef::cancel_update`) to remove just this update from the queue.
+	///
+	/// # Returns
+	///
+	/// [`None`] iff the runtime doesn't support cancelling individual updates.
+	///
+	/// # Safety
+	///
+	/// See [`update_or_enqueue`](`SignalsRuntimeRef::update_or_enqueue`).
+	///
+	/// The default implementation calls [`update_or_enqueue`](`SignalsRuntimeRef::update_or_enqueue`) and returns [`None`].
+	#[inline]
+	fn update_or_enqueue_ticketed(
+		&self,
+		id: Self::Symbol,
+		f: impl 'static + Send + FnOnce() -> Propagation,
+	) -> Option<UpdateTicket<Self::Symbol>> {
+		self.update_or_enqueue(id, f);
+		None
+	}
+
+	/// Removes the update identified by `ticket` from the queue and drops it *without* running it.
+	///
+	/// Other updates submitted for the same [`Symbol`](`SignalsRuntimeRef::Symbol`) are unaffected.
+	///
+	/// # Returns
+	///
+	/// Whether the update was still queued and has been cancelled.
+	///
+	/// # Logic
+	///
+	/// Cancelling an update that is already running, has completed or was otherwise dropped
+	/// (e.g. by [`stop`](`SignalsRuntimeRef::stop`)) **must** be a no-op that returns `false`.
+	///
+	/// The runtime **may** (but **should not**) always return `false` without cancelling anything.
+	///
+	/// The default implementation always returns `false`.
+	#[inline]
+	fn cancel_update(&self, ticket: UpdateTicket<Self::Symbol>) -> bool {
+		let _ = ticket;
+		false
+	}
+
 	/// **Immediately** submits `f` to run exclusively for `id` *without* recording dependencies.
 	///
 	/// Dropping the resulting [`Future`] cancels the scheduled update iff possible.
@@ -278,6 +326,27 @@ pub unsafe trait SignalsRuntimeRef: Send + Sync + Clone {
 	/// [`update`][`CallbackTable::update`] callback before this method returns.
 	fn refresh(&self, id: Self::Symbol);
 
+	/// Like [`refresh`](`SignalsRuntimeRef::refresh`), but returns the [`Propagation`] returned by
+	/// the [`update`][`CallbackTable::update`] callback iff this call ran it.
+	///
+	/// # Logic
+	///
+	/// The result **should** be accurate even while other threads refresh `id`.
+	///
+	/// The default implementation checks [`is_stale`](`SignalsRuntimeRef::is_stale`) before calling
+	/// [`refresh`](`SignalsRuntimeRef::refresh`) and returns [`Propagation::Propagate`] iff `id` was stale,
+	/// so it's only advisory.
+	///
+	/// # Safety
+	///
+	/// Same as for [`refresh`](`SignalsRuntimeRef::refresh`).
+	#[inline]
+	fn refresh_reporting(&self, id: Self::Symbol) -> Option<Propagation> {
+		let stale = self.is_stale(id);
+		self.refresh(id);
+		stale.then_some(Propagation::Propagate)
+	}
+
 	/// Removes existing callbacks, dependency relations (in either direction) associated with `id`.
 	///
 	/// Ones that are scheduled as a result of this are not necessarily removed!
@@ -317,31 +386,264 @@ pub unsafe trait SignalsRuntimeRef: Send + Sync + Clone {
 	fn hint_batched_updates<T>(&self, f: impl FnOnce() -> T) -> T {
 		f()
 	}
+
+	/// Runs `f` as transaction:
+	/// Updates submitted through [`update_or_enqueue`](`SignalsRuntimeRef::update_or_enqueue`) or
+	/// [`update_eager`](`SignalsRuntimeRef::update_eager`) while `f` runs are staged and then applied as one batch
+	/// iff `f` returns [`ControlFlow::Continue`].
+	///
+	/// On [`ControlFlow::Break`] (or if `f` panics), staged updates are dropped without running,
+	/// so dependents never observe them. Reads within `f` don't observe them either.
+	///
+	/// Transactions nest: Committing an inner transaction stages its updates in the outer one.
+	///
+	/// # Panics
+	///
+	/// Blocking updates can't be staged, as their result is needed immediately.
+	/// Calls to [`update_blocking`](`SignalsRuntimeRef::update_blocking`) within `f` **should** panic,
+	/// which rolls back the transaction like any other panic.
+	///
+	/// # Logic
+	///
+	/// The runtime **must not** refresh signals because of staged updates before `f` returns.  
+	/// Staged updates for `id` **must** be dropped when [`stop`](`SignalsRuntimeRef::stop`) is called with `id`.
+	///
+	/// Like [`hint_batched_updates`](`SignalsRuntimeRef::hint_batched_updates`), this function **may** act as
+	/// "exclusivity context" for nested calls to [`update_blocking`](`SignalsRuntimeRef::update_blocking`).
+	///
+	/// The default implementation can't stage updates:
+	/// It only calls `f` through [`hint_batched_updates`](`SignalsRuntimeRef::hint_batched_updates`)
+	/// and panics if `f` returns [`ControlFlow::Break`], as it can't roll anything back.
+	/// Runtimes **should** override it.
+	fn transaction<B, C>(&self, f: impl FnOnce() -> ControlFlow<B, C>) -> ControlFlow<B, C> {
+		let flow = self.hint_batched_updates(f);
+		assert!(
+			flow.is_continue(),
+			"This signals runtime can't roll back transactions."
+		);
+		flow
+	}
+
+	/// Hints to the signals runtime that `id` should be refreshed before stale signals
+	/// with a lower `priority`. The initial priority of each signal is `0`.
+	///
+	/// Note that the runtime **may** ignore this completely.
+	///
+	/// # Logic
+	///
+	/// The runtime **must not** refresh a signal before its stale dependencies because of this hint.
+	///
+	/// [`purge`](`SignalsRuntimeRef::purge`) **should** reset the priority of `id`.
+	#[inline]
+	fn set_priority(&self, id: Self::Symbol, priority: i32) {
+		let _ = (id, priority);
+	}
+
+	/// Hints to the signals runtime that a single run of `id` is expected to record
+	/// at most `limit` dependencies.
+	///
+	/// This is a debugging aid against accidental over-subscription, only available with `debug_assertions`.
+	/// Note that the runtime **may** ignore this completely.
+	///
+	/// # Panics
+	///
+	/// [`record_dependency`](`SignalsRuntimeRef::record_dependency`) **may** panic
+	/// iff it would record more than `limit` dependencies for `id` during one run.
+	///
+	/// # Logic
+	///
+	/// [`purge`](`SignalsRuntimeRef::purge`) **should** remove the limit of `id`.
+	#[cfg(debug_assertions)]
+	#[inline]
+	fn set_dependency_limit(&self, id: Self::Symbol, limit: usize) {
+		let _ = (id, limit);
+	}
+
+	/// Hints to the signals runtime that when `id` propagates a change, it should only
+	/// mark those of its dependents stale for which `should_notify` returns `true`.
+	///
+	/// By default, all dependents are notified.
+	/// Note that the runtime **may** ignore this completely.
+	///
+	/// `should_notify` **should** be cheap and **should not** update any signals.
+	///
+	/// # Logic
+	///
+	/// Dependents that aren't notified keep their current value until they're marked stale otherwise.
+	///
+	/// [`purge`](`SignalsRuntimeRef::purge`) **should** remove the filter of `id`.
+	#[inline]
+	fn set_notification_filter(
+		&self,
+		id: Self::Symbol,
+		should_notify: Box<dyn 'static + Send + Sync + Fn(Self::Symbol) -> bool>,
+	) {
+		let _ = (id, should_notify);
+	}
+
+	/// Whether `id` is currently marked stale, i.e. waiting to be refreshed.
+	///
+	/// This is meant for debugging and **should** be cheap.
+	/// The default implementation always returns `false`.
+	#[inline]
+	fn is_stale(&self, id: Self::Symbol) -> bool {
+		let _ = id;
+		false
+	}
+
+	/// Like [`record_dependency`](`SignalsRuntimeRef::record_dependency`), but only iff `id` isn't [stale](`SignalsRuntimeRef::is_stale`)
+	/// and the runtime can be accessed without waiting.
+	///
+	/// Returns whether the dependency was recorded.
+	///
+	/// # Logic
+	///
+	/// This method **should not** wait for other threads.  
+	/// Checking staleness and recording the dependency **should** happen atomically.  
+	/// It **should** also fail while `id`'s [`update`](`CallbackTable::update`) callback runs.
+	///
+	/// The default implementation calls [`is_stale`](`SignalsRuntimeRef::is_stale`) and then
+	/// [`record_dependency`](`SignalsRuntimeRef::record_dependency`), so it blocks whenever those do.
+	/// It can't tell whether `id` is being updated.
+	#[inline]
+	fn try_record_fresh_dependency(&self, id: Self::Symbol) -> bool {
+		if self.is_stale(id) {
+			return false;
+		}
+		self.record_dependency(id);
+		true
+	}
+
+	/// Within the context of a signal's update, keeps the dependencies it recorded in its previous run,
+	/// in addition to those it has recorded during this one.
+	///
+	/// This lets a signal that abandons a run partway (for example after catching a panic)
+	/// still be refreshed when anything it depended on before changes.  
+	/// Note that the runtime **may** ignore this, in which case only the dependencies recorded during this run are kept.
+	///
+	/// # Logic
+	///
+	/// Outside of a context that records dependencies, this **should** do nothing.
+	///
+	/// The default implementation does nothing.
+	#[inline]
+	fn retain_dependencies(&self) {}
+
+	/// Drops `value` later, outside of any critical section of the signals runtime.
+	///
+	/// This is useful for values with expensive [`Drop`] implementations that would otherwise
+	/// be dropped inside an update.
+	///
+	/// Note that the runtime **may** drop `value` immediately instead.
+	///
+	/// # Logic
+	///
+	/// The runtime **should** drop values deferred from the same thread in the order they were deferred.  
+	/// The runtime **may** leak deferred values that are pending when the process exits.
+	#[inline]
+	fn defer_drop<T: 'static + Send>(&self, value: T) {
+		drop(value);
+	}
+
+	/// Calls `f` once the signals runtime is idle, i.e. once all pending updates and refreshes have been processed.
+	///
+	/// This lets custom event loops find out when reactive work is done.
+	///
+	/// # Logic
+	///
+	/// Iff the runtime is already idle, it **should** call `f` immediately.  
+	/// Otherwise, it **should** call `f` once it next becomes idle, outside of any exclusivity context.
+	///
+	/// Updates submitted by `f` are processed as usual.
+	/// Callbacks registered while the runtime isn't idle (again) **should** run the next time it is.
+	///
+	/// The default implementation calls `f` immediately.
+	#[inline]
+	fn on_idle(&self, f: impl 'static + Send + FnOnce()) {
+		f();
+	}
+
+	/// Applies pending updates and refreshes at most `max_refreshes` due stale signals, deferring the rest.
+	///
+	/// This lets custom event loops spread reactive work over several frames or ticks.  
+	/// Use [`set_manual_processing`](`SignalsRuntimeRef::set_manual_processing`) to keep the runtime
+	/// from processing deferred refreshes on its own after each update.
+	///
+	/// # Returns
+	///
+	/// Whether pending updates or due stale signals remain after this call.
+	///
+	/// # Logic
+	///
+	/// The runtime **must** refresh signals in an order consistent with their dependencies,
+	/// so that a partial pass never exposes a dependent refreshed from a stale dependency.  
+	/// Deferred refreshes **should** be processed by the next (budgeted or automatic) processing of pending work.
+	///
+	/// The runtime **may** do nothing inside exclusivity contexts, and **may** refresh
+	/// signals outside the budget where this is needed to read them.
+	///
+	/// The default implementation does nothing and returns `false`.
+	#[inline]
+	fn process_budgeted(&self, max_refreshes: usize) -> bool {
+		let _ = max_refreshes;
+		false
+	}
+
+	/// Sets whether due stale signals are refreshed only through [`process_budgeted`](`SignalsRuntimeRef::process_budgeted`).
+	///
+	/// While `manual` is `true`, updates are still applied and propagated as stale-marks,
+	/// but subscribed signals and effects are refreshed only when processed explicitly (or when read).  
+	/// This includes [`update_blocking`](`SignalsRuntimeRef::update_blocking`), which doesn't treat such stale signals as an ongoing propagation.
+	///
+	/// # Logic
+	///
+	/// The runtime **should** process all pending work when `manual` is set to `false`.  
+	/// The runtime **may** ignore this setting, in which case it processes pending work automatically.
+	///
+	/// The default implementation does nothing.
+	#[inline]
+	fn set_manual_processing(&self, manual: bool) {
+		let _ = manual;
+	}
 }
 
-#[cfg(feature = "global_signals_runtime")]
+#[cfg(any(feature = "global_signals_runtime", feature = "critical_section"))]
 mod a_signals_runtime;
 
-#[cfg(feature = "global_signals_runtime")]
+#[cfg(any(feature = "global_signals_runtime", feature = "critical_section"))]
 static ISOPRENOID_GLOBAL_SIGNALS_RUNTIME: a_signals_runtime::ASignalsRuntime =
 	a_signals_runtime::ASignalsRuntime::new();
 
+#[cfg(any(feature = "global_signals_runtime", feature = "critical_section"))]
+static ISOPRENOID_GLOBAL_COUNTING_SIGNALS_RUNTIME: a_signals_runtime::ASignalsRuntime<
+	ACountingCallbackTableTypes,
+> = a_signals_runtime::ASignalsRuntime::new();
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub(crate) struct ASymbol(pub(crate) NonZeroU64);
 
+#[derive(Debug)]
 pub(crate) enum ACallbackTableTypes {}
 
 impl CallbackTableTypes for ACallbackTableTypes {
 	type SubscribedStatus = bool;
 }
 
+#[derive(Debug)]
+pub(crate) enum ACountingCallbackTableTypes {}
+
+impl CallbackTableTypes for ACountingCallbackTableTypes {
+	type SubscribedStatus = Option<NonZeroU64>;
+}
+
 /// A plain [`SignalsRuntimeRef`] implementation that represents a static signals runtime.
 ///
 /// 🚧 This implementation is currently not optimised. 🚧
 ///
 /// # Features
 ///
-/// Enable the `global_signals_runtime` Cargo feature to implement [`SignalsRuntimeRef`] for this type.
+/// Enable the `global_signals_runtime` Cargo feature to implement [`SignalsRuntimeRef`] for this type.  
+/// Without `std`, enable the `critical_section` Cargo feature instead. Its critical section then guards each runtime operation.
 ///
 /// # Logic
 ///
@@ -358,13 +660,32 @@ impl CallbackTableTypes for ACallbackTableTypes {
 ///
 /// [`SignalsRuntimeRef::Symbol`]s associated with the [`GlobalSignalsRuntime`] are ordered.  
 /// Given [`GSRSymbol`]s `a` and `b`, `b` can depend on `a` only iff `a` < `b` (by creation order).
+///
+/// [`SignalsRuntimeRef::update_blocking`] panics when called while another update or a signal callback runs.
+/// Directly within [`SignalsRuntimeRef::hint_batched_updates`] it's allowed, though: It then first applies
+/// earlier enqueued updates of the same signal and its propagation is coalesced with the rest of the batch.
+///
+/// # Budgeted Processing
+///
+/// By default, this runtime refreshes all due stale signals at the end of each top-level update or batch,
+/// so [`SignalsRuntimeRef::process_budgeted`] only defers work within [`SignalsRuntimeRef::hint_batched_updates`].
+///
+/// After [`SignalsRuntimeRef::set_manual_processing`]`(true)`, updates are still applied immediately (or at the end
+/// of the batch), but due stale signals are refreshed only by [`SignalsRuntimeRef::process_budgeted`] (or when read).
+/// Remaining work is then carried over between calls, for example from one frame to the next.  
+/// [`SignalsRuntimeRef::on_idle`] callbacks run once a call leaves no due stale signals.
+///
+/// Manual processing is a runtime-wide setting that affects all threads.
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct GlobalSignalsRuntime;
 
 impl Debug for GlobalSignalsRuntime {
 	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-		if cfg!(feature = "global_signals_runtime") {
-			#[cfg(feature = "global_signals_runtime")]
+		if cfg!(any(
+			feature = "global_signals_runtime",
+			feature = "critical_section"
+		)) {
+			#[cfg(any(feature = "global_signals_runtime", feature = "critical_section"))]
 			Debug::fmt(&ISOPRENOID_GLOBAL_SIGNALS_RUNTIME, f)?;
 			Ok(())
 		} else {
@@ -373,7 +694,7 @@ impl Debug for GlobalSignalsRuntime {
 				fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
 					write!(
 						f,
-						"(unavailable without `isoprenoid/global_signals_runtime` feature)"
+						"(unavailable without `isoprenoid/global_signals_runtime` or `isoprenoid/critical_section` feature)"
 					)
 				}
 			}
@@ -385,6 +706,62 @@ impl Debug for GlobalSignalsRuntime {
 	}
 }
 
+#[cfg(any(feature = "global_signals_runtime", feature = "critical_section"))]
+/// **The feature `"global_signals_runtime"` or `"critical_section"` is required to enable this implementation.**
+impl GlobalSignalsRuntime {
+	/// A snapshot of the signals that `id` currently depends on, in creation order.
+	///
+	/// This is meant for debugging. The snapshot is taken in a single critical section,
+	/// so it's consistent, but it **may** be outdated by the time this method returns.
+	pub fn dependencies(&self, id: GSRSymbol) -> Vec<GSRSymbol> {
+		ISOPRENOID_GLOBAL_SIGNALS_RUNTIME
+			.dependencies(id.0)
+			.into_iter()
+			.map(GSRSymbol)
+			.collect()
+	}
+
+	/// How often the [`GlobalSignalsRuntime`]'s critical section was entered
+	/// with and without waiting on another thread, since the start of the process.
+	///
+	/// Reentrant entries on the same thread count as uncontended.
+	///
+	/// ```
+	/// # #![cfg(feature = "contention_stats")] // isoprenoid feature
+	/// use isoprenoid::runtime::{GlobalSignalsRuntime, SignalsRuntimeRef};
+	///
+	/// let before = GlobalSignalsRuntime.contention_stats();
+	/// let id = GlobalSignalsRuntime.next_id();
+	/// assert!(GlobalSignalsRuntime.dependencies(id).is_empty());
+	/// let after = GlobalSignalsRuntime.contention_stats();
+	/// assert!(after.uncontended + after.contended > before.uncontended + before.contended);
+	/// ```
+	///
+	/// # Features
+	///
+	/// **The feature `"contention_stats"` is required to enable this method.**  
+	/// Without it, the runtime doesn't count anything.
+	#[cfg(feature = "contention_stats")]
+	pub fn contention_stats(&self) -> ContentionStats {
+		let (uncontended, contended) = ISOPRENOID_GLOBAL_SIGNALS_RUNTIME.contention_stats();
+		ContentionStats {
+			uncontended,
+			contended,
+		}
+	}
+}
+
+/// Counts of critical section entries, as returned by [`GlobalSignalsRuntime::contention_stats`].
+#[cfg(feature = "contention_stats")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct ContentionStats {
+	/// How often the lock was acquired right away.
+	pub uncontended: u64,
+	/// How often the lock was held by another thread, so that acquiring it blocked.
+	pub contended: u64,
+}
+
 /// A [`SignalsRuntimeRef::Symbol`] associated with the [`GlobalSignalsRuntime`].
 ///
 /// Given [`GSRSymbol`]s `a` and `b`, `b` can depend on `a` only iff `a` < `b` (by creation order).
@@ -411,8 +788,8 @@ impl CallbackTableTypes for GlobalCallbackTableTypes {
 	type SubscribedStatus = bool;
 }
 
-#[cfg(feature = "global_signals_runtime")]
-/// **The feature `"global_signals_runtime"` is required to enable this implementation.**
+#[cfg(any(feature = "global_signals_runtime", feature = "critical_section"))]
+/// **The feature `"global_signals_runtime"` or `"critical_section"` is required to enable this implementation.**
 unsafe impl SignalsRuntimeRef for GlobalSignalsRuntime {
 	type Symbol = GSRSymbol;
 	type CallbackTableTypes = GlobalCallbackTableTypes;
@@ -465,7 +842,22 @@ unsafe impl SignalsRuntimeRef for GlobalSignalsRuntime {
 		id: Self::Symbol,
 		f: impl 'static + Send + FnOnce() -> Propagation,
 	) {
-		(&ISOPRENOID_GLOBAL_SIGNALS_RUNTIME).update_or_enqueue(id.0, f)
+		(&ISOPRENOID_GLOBAL_SIGNALS_RUNTIME).update_or_enqueue(id.0, f);
+	}
+
+	fn update_or_enqueue_ticketed(
+		&self,
+		id: Self::Symbol,
+		f: impl 'static + Send + FnOnce() -> Propagation,
+	) -> Option<UpdateTicket<Self::Symbol>> {
+		(&ISOPRENOID_GLOBAL_SIGNALS_RUNTIME)
+			.update_or_enqueue_ticketed(id.0, f)
+			.map(|ticket| UpdateTicket::new(GSRSymbol(ticket.symbol), ticket.serial))
+	}
+
+	fn cancel_update(&self, ticket: UpdateTicket<Self::Symbol>) -> bool {
+		(&ISOPRENOID_GLOBAL_SIGNALS_RUNTIME)
+			.cancel_update(UpdateTicket::new(ticket.symbol.0, ticket.serial))
 	}
 
 	fn update_eager<'f, T: 'f + Send, F: 'f + Send + FnOnce() -> (Propagation, T)>(
@@ -490,6 +882,10 @@ unsafe impl SignalsRuntimeRef for GlobalSignalsRuntime {
 		(&ISOPRENOID_GLOBAL_SIGNALS_RUNTIME).refresh(id.0)
 	}
 
+	fn refresh_reporting(&self, id: Self::Symbol) -> Option<Propagation> {
+		(&ISOPRENOID_GLOBAL_SIGNALS_RUNTIME).refresh_reporting(id.0)
+	}
+
 	fn purge(&self, id: Self::Symbol) {
 		(&ISOPRENOID_GLOBAL_SIGNALS_RUNTIME).purge(id.0)
 	}
@@ -497,6 +893,311 @@ unsafe impl SignalsRuntimeRef for GlobalSignalsRuntime {
 	fn hint_batched_updates<T>(&self, f: impl FnOnce() -> T) -> T {
 		(&ISOPRENOID_GLOBAL_SIGNALS_RUNTIME).hint_batched_updates(f)
 	}
+
+	fn transaction<B, C>(&self, f: impl FnOnce() -> ControlFlow<B, C>) -> ControlFlow<B, C> {
+		(&ISOPRENOID_GLOBAL_SIGNALS_RUNTIME).transaction(f)
+	}
+
+	fn set_priority(&self, id: Self::Symbol, priority: i32) {
+		(&ISOPRENOID_GLOBAL_SIGNALS_RUNTIME).set_priority(id.0, priority);
+	}
+
+	#[cfg(debug_assertions)]
+	fn set_dependency_limit(&self, id: Self::Symbol, limit: usize) {
+		(&ISOPRENOID_GLOBAL_SIGNALS_RUNTIME).set_dependency_limit(id.0, limit);
+	}
+
+	fn set_notification_filter(
+		&self,
+		id: Self::Symbol,
+		should_notify: Box<dyn 'static + Send + Sync + Fn(Self::Symbol) -> bool>,
+	) {
+		(&ISOPRENOID_GLOBAL_SIGNALS_RUNTIME).set_notification_filter(
+			id.0,
+			Box::new(move |symbol| should_notify(GSRSymbol(symbol))),
+		);
+	}
+
+	fn is_stale(&self, id: Self::Symbol) -> bool {
+		(&ISOPRENOID_GLOBAL_SIGNALS_RUNTIME).is_stale(id.0)
+	}
+
+	fn try_record_fresh_dependency(&self, id: Self::Symbol) -> bool {
+		(&ISOPRENOID_GLOBAL_SIGNALS_RUNTIME).try_record_fresh_dependency(id.0)
+	}
+
+	fn retain_dependencies(&self) {
+		(&ISOPRENOID_GLOBAL_SIGNALS_RUNTIME).retain_dependencies();
+	}
+
+	fn defer_drop<T: 'static + Send>(&self, value: T) {
+		(&ISOPRENOID_GLOBAL_SIGNALS_RUNTIME).defer_drop(value);
+	}
+
+	fn on_idle(&self, f: impl 'static + Send + FnOnce()) {
+		(&ISOPRENOID_GLOBAL_SIGNALS_RUNTIME).on_idle(f);
+	}
+
+	fn process_budgeted(&self, max_refreshes: usize) -> bool {
+		(&ISOPRENOID_GLOBAL_SIGNALS_RUNTIME).process_budgeted(max_refreshes)
+	}
+
+	fn set_manual_processing(&self, manual: bool) {
+		(&ISOPRENOID_GLOBAL_SIGNALS_RUNTIME).set_manual_processing(manual);
+	}
+}
+
+/// A static signals runtime like [`GlobalSignalsRuntime`], but one that reports subscriber counts.
+///
+/// Signals associated with it are notified through [`CallbackTable::on_subscribed_change`] on *each* change of
+/// their subscriber count, with the new total as [`Option<NonZeroU64>`] ([`None`] once the last subscriber is removed).
+///
+/// Dependencies are still subscribed to only once per dependent,
+/// so a signal's count is the number of its distinct subscribed dependents
+/// plus the number of its own intrinsic subscriptions.
+///
+/// This runtime is separate from the [`GlobalSignalsRuntime`], so signals of one can't depend on signals of the other.
+///
+/// # Features
+///
+/// Enable the `global_signals_runtime` Cargo feature to implement [`SignalsRuntimeRef`] for this type.  
+/// Without `std`, enable the `critical_section` Cargo feature instead. Its critical section then guards each runtime operation.
+///
+/// # Logic
+///
+/// Other than for subscription notifications, this runtime behaves exactly like the [`GlobalSignalsRuntime`].
+///
+/// # Panics
+///
+/// Given [`GCSRSymbol`]s `a` and `b`, `b` can depend on `a` only iff `a` < `b` (by creation order).
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GlobalCountingSignalsRuntime;
+
+impl Debug for GlobalCountingSignalsRuntime {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		if cfg!(any(
+			feature = "global_signals_runtime",
+			feature = "critical_section"
+		)) {
+			#[cfg(any(feature = "global_signals_runtime", feature = "critical_section"))]
+			Debug::fmt(&ISOPRENOID_GLOBAL_COUNTING_SIGNALS_RUNTIME, f)?;
+			Ok(())
+		} else {
+			struct Unavailable;
+			impl Debug for Unavailable {
+				fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+					write!(
+						f,
+						"(unavailable without `isoprenoid/global_signals_runtime` or `isoprenoid/critical_section` feature)"
+					)
+				}
+			}
+
+			f.debug_struct("GlobalCountingSignalsRuntime")
+				.field("state", &Unavailable)
+				.finish_non_exhaustive()
+		}
+	}
+}
+
+#[cfg(any(feature = "global_signals_runtime", feature = "critical_section"))]
+/// **The feature `"global_signals_runtime"` or `"critical_section"` is required to enable this implementation.**
+impl GlobalCountingSignalsRuntime {
+	/// A snapshot of the signals that `id` currently depends on, in creation order.
+	///
+	/// See [`GlobalSignalsRuntime::dependencies`].
+	pub fn dependencies(&self, id: GCSRSymbol) -> Vec<GCSRSymbol> {
+		ISOPRENOID_GLOBAL_COUNTING_SIGNALS_RUNTIME
+			.dependencies(id.0)
+			.into_iter()
+			.map(GCSRSymbol)
+			.collect()
+	}
+}
+
+/// A [`SignalsRuntimeRef::Symbol`] associated with the [`GlobalCountingSignalsRuntime`].
+///
+/// Given [`GCSRSymbol`]s `a` and `b`, `b` can depend on `a` only iff `a` < `b` (by creation order).
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GCSRSymbol(pub(crate) ASymbol);
+
+impl Debug for GCSRSymbol {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("GCSRSymbol").field(&self.0 .0).finish()
+	}
+}
+
+mod global_counting_callback_table_types {
+	use super::ACountingCallbackTableTypes;
+
+	#[allow(unreachable_pub)]
+	#[repr(transparent)]
+	pub struct GlobalCountingCallbackTableTypes(ACountingCallbackTableTypes);
+}
+use global_counting_callback_table_types::GlobalCountingCallbackTableTypes;
+
+impl CallbackTableTypes for GlobalCountingCallbackTableTypes {
+	//SAFETY: Everything here must be the same as for `ACountingCallbackTableTypes`!
+	type SubscribedStatus = Option<NonZeroU64>;
+}
+
+#[cfg(any(feature = "global_signals_runtime", feature = "critical_section"))]
+/// **The feature `"global_signals_runtime"` or `"critical_section"` is required to enable this implementation.**
+unsafe impl SignalsRuntimeRef for GlobalCountingSignalsRuntime {
+	type Symbol = GCSRSymbol;
+	type CallbackTableTypes = GlobalCountingCallbackTableTypes;
+
+	fn next_id(&self) -> GCSRSymbol {
+		GCSRSymbol((&ISOPRENOID_GLOBAL_COUNTING_SIGNALS_RUNTIME).next_id())
+	}
+
+	fn record_dependency(&self, id: Self::Symbol) {
+		(&ISOPRENOID_GLOBAL_COUNTING_SIGNALS_RUNTIME).record_dependency(id.0);
+	}
+
+	unsafe fn start<T, D: ?Sized>(
+		&self,
+		id: Self::Symbol,
+		f: impl FnOnce() -> T,
+		callback_table: *const CallbackTable<D, Self::CallbackTableTypes>,
+		callback_data: *const D,
+	) -> T {
+		(&ISOPRENOID_GLOBAL_COUNTING_SIGNALS_RUNTIME).start(
+			id.0,
+			f,
+			//SAFETY: `GlobalCountingCallbackTableTypes` is deeply transmute-compatible and ABI-compatible to `ACountingCallbackTableTypes`.
+			mem::transmute::<
+				*const CallbackTable<D, GlobalCountingCallbackTableTypes>,
+				*const CallbackTable<D, ACountingCallbackTableTypes>,
+			>(callback_table),
+			callback_data,
+		)
+	}
+
+	fn stop(&self, id: Self::Symbol) {
+		(&ISOPRENOID_GLOBAL_COUNTING_SIGNALS_RUNTIME).stop(id.0);
+	}
+
+	fn update_dependency_set<T>(&self, id: Self::Symbol, f: impl FnOnce() -> T) -> T {
+		(&ISOPRENOID_GLOBAL_COUNTING_SIGNALS_RUNTIME).update_dependency_set(id.0, f)
+	}
+
+	fn subscribe(&self, id: Self::Symbol) {
+		(&ISOPRENOID_GLOBAL_COUNTING_SIGNALS_RUNTIME).subscribe(id.0);
+	}
+
+	fn unsubscribe(&self, id: Self::Symbol) {
+		(&ISOPRENOID_GLOBAL_COUNTING_SIGNALS_RUNTIME).unsubscribe(id.0);
+	}
+
+	fn update_or_enqueue(
+		&self,
+		id: Self::Symbol,
+		f: impl 'static + Send + FnOnce() -> Propagation,
+	) {
+		(&ISOPRENOID_GLOBAL_COUNTING_SIGNALS_RUNTIME).update_or_enqueue(id.0, f);
+	}
+
+	fn update_or_enqueue_ticketed(
+		&self,
+		id: Self::Symbol,
+		f: impl 'static + Send + FnOnce() -> Propagation,
+	) -> Option<UpdateTicket<Self::Symbol>> {
+		(&ISOPRENOID_GLOBAL_COUNTING_SIGNALS_RUNTIME)
+			.update_or_enqueue_ticketed(id.0, f)
+			.map(|ticket| UpdateTicket::new(GCSRSymbol(ticket.symbol), ticket.serial))
+	}
+
+	fn cancel_update(&self, ticket: UpdateTicket<Self::Symbol>) -> bool {
+		(&ISOPRENOID_GLOBAL_COUNTING_SIGNALS_RUNTIME)
+			.cancel_update(UpdateTicket::new(ticket.symbol.0, ticket.serial))
+	}
+
+	fn update_eager<'f, T: 'f + Send, F: 'f + Send + FnOnce() -> (Propagation, T)>(
+		&self,
+		id: Self::Symbol,
+		f: F,
+	) -> Self::UpdateEager<'f, T, F> {
+		(&ISOPRENOID_GLOBAL_COUNTING_SIGNALS_RUNTIME).update_eager(id.0, f)
+	}
+
+	type UpdateEager<'f, T: 'f, F: 'f> = private::DetachedFuture<'f, Result<T, F>>;
+
+	fn update_blocking<T>(&self, id: Self::Symbol, f: impl FnOnce() -> (Propagation, T)) -> T {
+		(&ISOPRENOID_GLOBAL_COUNTING_SIGNALS_RUNTIME).update_blocking(id.0, f)
+	}
+
+	fn run_detached<T>(&self, f: impl FnOnce() -> T) -> T {
+		(&ISOPRENOID_GLOBAL_COUNTING_SIGNALS_RUNTIME).run_detached(f)
+	}
+
+	fn refresh(&self, id: Self::Symbol) {
+		(&ISOPRENOID_GLOBAL_COUNTING_SIGNALS_RUNTIME).refresh(id.0);
+	}
+
+	fn refresh_reporting(&self, id: Self::Symbol) -> Option<Propagation> {
+		(&ISOPRENOID_GLOBAL_COUNTING_SIGNALS_RUNTIME).refresh_reporting(id.0)
+	}
+
+	fn purge(&self, id: Self::Symbol) {
+		(&ISOPRENOID_GLOBAL_COUNTING_SIGNALS_RUNTIME).purge(id.0);
+	}
+
+	fn hint_batched_updates<T>(&self, f: impl FnOnce() -> T) -> T {
+		(&ISOPRENOID_GLOBAL_COUNTING_SIGNALS_RUNTIME).hint_batched_updates(f)
+	}
+
+	fn transaction<B, C>(&self, f: impl FnOnce() -> ControlFlow<B, C>) -> ControlFlow<B, C> {
+		(&ISOPRENOID_GLOBAL_COUNTING_SIGNALS_RUNTIME).transaction(f)
+	}
+
+	fn set_priority(&self, id: Self::Symbol, priority: i32) {
+		(&ISOPRENOID_GLOBAL_COUNTING_SIGNALS_RUNTIME).set_priority(id.0, priority);
+	}
+
+	#[cfg(debug_assertions)]
+	fn set_dependency_limit(&self, id: Self::Symbol, limit: usize) {
+		(&ISOPRENOID_GLOBAL_COUNTING_SIGNALS_RUNTIME).set_dependency_limit(id.0, limit);
+	}
+
+	fn set_notification_filter(
+		&self,
+		id: Self::Symbol,
+		should_notify: Box<dyn 'static + Send + Sync + Fn(Self::Symbol) -> bool>,
+	) {
+		(&ISOPRENOID_GLOBAL_COUNTING_SIGNALS_RUNTIME).set_notification_filter(
+			id.0,
+			Box::new(move |symbol| should_notify(GCSRSymbol(symbol))),
+		);
+	}
+
+	fn is_stale(&self, id: Self::Symbol) -> bool {
+		(&ISOPRENOID_GLOBAL_COUNTING_SIGNALS_RUNTIME).is_stale(id.0)
+	}
+
+	fn try_record_fresh_dependency(&self, id: Self::Symbol) -> bool {
+		(&ISOPRENOID_GLOBAL_COUNTING_SIGNALS_RUNTIME).try_record_fresh_dependency(id.0)
+	}
+
+	fn retain_dependencies(&self) {
+		(&ISOPRENOID_GLOBAL_COUNTING_SIGNALS_RUNTIME).retain_dependencies();
+	}
+
+	fn defer_drop<T: 'static + Send>(&self, value: T) {
+		(&ISOPRENOID_GLOBAL_COUNTING_SIGNALS_RUNTIME).defer_drop(value);
+	}
+
+	fn on_idle(&self, f: impl 'static + Send + FnOnce()) {
+		(&ISOPRENOID_GLOBAL_COUNTING_SIGNALS_RUNTIME).on_idle(f);
+	}
+
+	fn process_budgeted(&self, max_refreshes: usize) -> bool {
+		(&ISOPRENOID_GLOBAL_COUNTING_SIGNALS_RUNTIME).process_budgeted(max_refreshes)
+	}
+
+	fn set_manual_processing(&self, manual: bool) {
+		(&ISOPRENOID_GLOBAL_COUNTING_SIGNALS_RUNTIME).set_manual_processing(manual);
+	}
 }
 
 /// The `unsafe` at-runtime version of [`Callbacks`](`crate::raw::Callbacks`),
@@ -562,7 +1263,7 @@ impl<T: ?Sized, CTT: ?Sized + CallbackTableTypes> Eq for CallbackTable<T, CTT> {
 
 impl<T: ?Sized, CTT: ?Sized + CallbackTableTypes> PartialOrd for CallbackTable<T, CTT> {
 	#[allow(unpredictable_function_pointer_comparisons)] // Used only for interning.
-	fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+	fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
 		match self.update.partial_cmp(&other.update) {
 			Some(core::cmp::Ordering::Equal) => {}
 			ord => return ord,
@@ -574,7 +1275,7 @@ impl<T: ?Sized, CTT: ?Sized + CallbackTableTypes> PartialOrd for CallbackTable<T
 
 impl<T: ?Sized, CTT: ?Sized + CallbackTableTypes> Ord for CallbackTable<T, CTT> {
 	#[allow(unpredictable_function_pointer_comparisons)] // Used only for interning.
-	fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+	fn cmp(&self, other: &Self) -> core::cmp::Ordering {
 		match self.update.cmp(&other.update) {
 			core::cmp::Ordering::Equal => {}
 			ord => return ord,
@@ -588,10 +1289,41 @@ pub trait CallbackTableTypes: 'static {
 	/// A status indicating "how subscribed" a signal now is.
 	///
 	/// [`GlobalSignalsRuntime`] notifies only for the first and removal of the last subscription for each signal,
-	/// so it uses a [`bool`], but other runtimes may notify with the direct or total subscriber count or a more complex measure.
+	/// so it uses a [`bool`], but other runtimes may notify with the direct or total subscriber count or a more complex measure.  
+	/// [`GlobalCountingSignalsRuntime`] notifies for each change with the total count as [`Option<NonZeroU64>`].
 	type SubscribedStatus;
 }
 
+/// A [`CallbackTableTypes::SubscribedStatus`] that runtime-agnostic code can interpret.
+///
+/// Implemented for the statuses of [`GlobalSignalsRuntime`] and [`GlobalCountingSignalsRuntime`].
+pub trait SubscriberCount {
+	/// The number of subscribers this status represents.
+	///
+	/// Statuses that only distinguish whether a signal is subscribed report `0` or `1`.
+	fn subscriber_count(&self) -> u64;
+
+	/// Whether this status represents any subscribers.
+	#[inline]
+	fn is_subscribed(&self) -> bool {
+		self.subscriber_count() > 0
+	}
+}
+
+impl SubscriberCount for bool {
+	#[inline]
+	fn subscriber_count(&self) -> u64 {
+		(*self).into()
+	}
+}
+
+impl SubscriberCount for Option<NonZeroU64> {
+	#[inline]
+	fn subscriber_count(&self) -> u64 {
+		self.map_or(0, NonZeroU64::get)
+	}
+}
+
 impl<T: ?Sized, CTT: ?Sized + CallbackTableTypes> CallbackTable<T, CTT> {
 	/// "Type-erases" the pointed-to callback table against the data type `T` by replacing it with `()` in the signature.
 	///
@@ -608,6 +1340,35 @@ impl<T: ?Sized, CTT: ?Sized + CallbackTableTypes> CallbackTable<T, CTT> {
 	}
 }
 
+/// Identifies an update submitted through [`SignalsRuntimeRef::update_or_enqueue_ticketed`],
+/// so that it can be [cancelled](`SignalsRuntimeRef::cancel_update`).
+///
+/// Tickets are meaningful only towards the runtime that issued them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UpdateTicket<Symbol> {
+	symbol: Symbol,
+	serial: u64,
+}
+
+impl<Symbol> UpdateTicket<Symbol> {
+	/// Creates a new [`UpdateTicket`] for an update of `symbol`.
+	///
+	/// `serial` **should** tell apart updates submitted to the same runtime instance.
+	pub const fn new(symbol: Symbol, serial: u64) -> Self {
+		Self { symbol, serial }
+	}
+
+	/// The [`Symbol`](`SignalsRuntimeRef::Symbol`) of the signal the update was submitted for.
+	pub fn symbol(&self) -> &Symbol {
+		&self.symbol
+	}
+
+	/// The serial number the issuing runtime assigned to the update.
+	pub const fn serial(&self) -> u64 {
+		self.serial
+	}
+}
+
 /// A return value used by [`CallbackTable`]/[`Callbacks`](`crate::raw::Callbacks`) callbacks
 /// to indicate whether to flag dependent signals as stale and optionally also refresh ones not currently subscribed.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -630,7 +1391,8 @@ pub enum Propagation {
 }
 
 mod private {
-	use std::{
+	use alloc::boxed::Box;
+	use core::{
 		future::Future,
 		pin::Pin,
 		task::{Context, Poll},
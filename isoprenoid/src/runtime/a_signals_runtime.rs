@@ -1,39 +1,246 @@
-use std::{
+use alloc::{
+	boxed::Box,
+	collections::{BTreeMap, BTreeSet, VecDeque},
+	sync::Arc,
+	vec,
+	vec::Vec,
+};
+use core::{
 	borrow::{Borrow, BorrowMut as _},
 	cell::{RefCell, RefMut},
-	collections::{BTreeMap, BTreeSet, VecDeque},
+	cmp::Reverse,
 	fmt::{self, Debug, Formatter},
-	mem,
-	sync::{atomic::Ordering, Arc, Mutex},
+	mem::{self, ManuallyDrop},
+	num::NonZeroU64,
+	ops::{ControlFlow, Deref},
+	sync::atomic::{AtomicU64, AtomicUsize, Ordering},
 };
+#[cfg(feature = "std")]
+use std::{sync::mpsc, sync::OnceLock, thread};
 
-use core::sync::atomic::AtomicU64;
+#[cfg(feature = "global_signals_runtime")]
 use parking_lot::{ReentrantMutex, ReentrantMutexGuard};
 use scopeguard::{guard, ScopeGuard};
 use unwind_safe::try_eval;
 
-use super::{private, ACallbackTableTypes, ASymbol, CallbackTable, Propagation, SignalsRuntimeRef};
+use crate::sync::Mutex;
+#[cfg(not(feature = "global_signals_runtime"))]
+use crate::sync::{ReentrantMutex, ReentrantMutexGuard};
+
+use super::{
+	private, ACallbackTableTypes, ACountingCallbackTableTypes, ASymbol, CallbackTable,
+	CallbackTableTypes, Propagation, SignalsRuntimeRef, UpdateTicket,
+};
+
+/// How subscriber counts are reported through [`CallbackTable::on_subscribed_change`].
+pub(crate) trait SubscribedStatusMode: CallbackTableTypes + Debug {
+	/// Whether each change of a signal's subscriber count is reported,
+	/// rather than only its first subscriber and the removal of its last.
+	const EVERY_CHANGE: bool;
+
+	fn status(subscribers: u64) -> Self::SubscribedStatus;
+}
+
+impl SubscribedStatusMode for ACallbackTableTypes {
+	const EVERY_CHANGE: bool = false;
+
+	fn status(subscribers: u64) -> bool {
+		subscribers > 0
+	}
+}
+
+impl SubscribedStatusMode for ACountingCallbackTableTypes {
+	const EVERY_CHANGE: bool = true;
+
+	fn status(subscribers: u64) -> Option<NonZeroU64> {
+		NonZeroU64::new(subscribers)
+	}
+}
 
 #[derive(Debug)]
-pub(crate) struct ASignalsRuntime {
+pub(crate) struct ASignalsRuntime<CTT: SubscribedStatusMode = ACallbackTableTypes> {
 	source_counter: AtomicU64,
-	critical_mutex: ReentrantMutex<RefCell<ASignalsRuntime_>>,
+	update_counter: AtomicU64,
+	critical_mutex: ReentrantMutex<RefCell<ASignalsRuntime_<CTT>>>,
+	/// How many [`CriticalGuard`]s the thread holding `critical_mutex` has open.
+	/// Only changed while holding `critical_mutex`.
+	critical_depth: AtomicUsize,
+	#[cfg(feature = "contention_stats")]
+	uncontended_locks: AtomicU64,
+	#[cfg(feature = "contention_stats")]
+	contended_locks: AtomicU64,
+	/// Lazily started. [`None`] iff threads aren't available.
+	#[cfg(feature = "std")]
+	drop_queue: OnceLock<Option<DropQueue>>,
+}
+
+#[cfg(feature = "std")]
+type DropQueue = Mutex<mpsc::Sender<Box<dyn Send>>>;
+type Update = Box<dyn 'static + Send + FnOnce() -> Propagation>;
+type NotificationFilter = Arc<dyn 'static + Send + Sync + Fn(ASymbol) -> bool>;
+type IdleCallback = Box<dyn 'static + Send + FnOnce()>;
+
+unsafe impl<CTT: SubscribedStatusMode> Sync for ASignalsRuntime<CTT> {}
+
+/// A (reentrant) lock on [`ASignalsRuntime::critical_mutex`].
+///
+/// Releasing the outermost guard releases the mutex and then calls due idle callbacks.
+struct CriticalGuard<'a, CTT: SubscribedStatusMode> {
+	runtime: &'a ASignalsRuntime<CTT>,
+	lock: ManuallyDrop<ReentrantMutexGuard<'a, RefCell<ASignalsRuntime_<CTT>>>>,
 }
 
-unsafe impl Sync for ASignalsRuntime {}
+impl<'a, CTT: SubscribedStatusMode> Deref for CriticalGuard<'a, CTT> {
+	type Target = RefCell<ASignalsRuntime_<CTT>>;
+
+	fn deref(&self) -> &Self::Target {
+		&self.lock
+	}
+}
+
+impl<'a, CTT: SubscribedStatusMode> Drop for CriticalGuard<'a, CTT> {
+	fn drop(&mut self) {
+		let outermost = self.runtime.critical_depth.fetch_sub(1, Ordering::Relaxed) == 1;
+		let due = if outermost {
+			let mut borrow = RefCell::borrow_mut(&self.lock);
+			let due = mem::take(&mut borrow.due_idle_callbacks);
+			#[cfg(feature = "std")]
+			if thread::panicking() {
+				// Don't call back into user code while unwinding.
+				borrow.idle_callbacks.splice(0..0, due);
+				Vec::new()
+			} else {
+				due
+			}
+			#[cfg(not(feature = "std"))]
+			due
+		} else {
+			Vec::new()
+		};
+
+		// SAFETY: Not used again.
+		unsafe { ManuallyDrop::drop(&mut self.lock) };
+
+		// If a callback panics, the remaining ones run the next time the runtime is idle.
+		let mut due = guard(due.into_iter(), |remaining| {
+			if remaining.len() > 0 {
+				let lock = self.runtime.lock_critical();
+				(*lock).borrow_mut().idle_callbacks.splice(0..0, remaining);
+			}
+		});
+		for f in &mut *due {
+			f();
+		}
+	}
+}
 
-struct ASignalsRuntime_ {
+struct ASignalsRuntime_<CTT: SubscribedStatusMode> {
 	context_stack: Vec<Option<(ASymbol, BTreeSet<ASymbol>)>>,
-	callbacks: BTreeMap<ASymbol, (*const CallbackTable<(), ACallbackTableTypes>, *const ())>,
+	/// Whether the bottom [`None`] frame of `context_stack` was pushed by [`SignalsRuntimeRef::hint_batched_updates`].
+	batched: bool,
+	callbacks: BTreeMap<ASymbol, (*const CallbackTable<(), CTT>, *const ())>,
 	///FIXME: This is not-at-all a fair queue.
-	update_queue: BTreeMap<ASymbol, VecDeque<Box<dyn 'static + Send + FnOnce() -> Propagation>>>,
+	update_queue: UpdateQueue,
+	/// Updates submitted during open transactions, innermost last.
+	staged_updates: Vec<Vec<(UpdateTicket<ASymbol>, Update)>>,
 	stale_queue: BTreeSet<Stale>,
+	/// Only non-zero priorities are stored.
+	priorities: BTreeMap<ASymbol, i32>,
+	/// The entries of `priorities`, highest priority first and then in creation order.
+	priority_order: BTreeSet<(Reverse<i32>, ASymbol)>,
+	#[cfg(debug_assertions)]
+	dependency_limits: BTreeMap<ASymbol, usize>,
+	/// Only signals that opted into selective notification have an entry here.
+	notification_filters: BTreeMap<ASymbol, NotificationFilter>,
 	interdependencies: Interdependencies,
+	/// Made due (and removed) at the end of [`ASignalsRuntime::process_pending`].
+	idle_callbacks: Vec<IdleCallback>,
+	/// Called (and removed) once the outermost [`CriticalGuard`] is released.
+	due_idle_callbacks: Vec<IdleCallback>,
+	/// Set through [`SignalsRuntimeRef::set_manual_processing`].
+	manual_processing: bool,
 }
 
+/// Pending updates, grouped by symbol.
+///
+/// Groups are taken highest priority first and then in creation order.
+/// Updates within a group are applied in submission order.
+struct UpdateQueue {
+	/// Each group carries its signal's priority.
+	/// Each update is tagged with its [`UpdateTicket::serial`].
+	groups: BTreeMap<ASymbol, (i32, VecDeque<(u64, Update)>)>,
+	/// Exactly the keys of `groups`, in the order they're taken.
+	order: BTreeSet<(Reverse<i32>, ASymbol)>,
+}
+
+impl UpdateQueue {
+	const fn new() -> Self {
+		Self {
+			groups: BTreeMap::new(),
+			order: BTreeSet::new(),
+		}
+	}
+
+	fn push(&mut self, symbol: ASymbol, priority: i32, serial: u64, update: Update) {
+		self.groups
+			.entry(symbol)
+			.or_insert_with(|| {
+				self.order.insert((Reverse(priority), symbol));
+				(priority, VecDeque::new())
+			})
+			.1
+			.push_back((serial, update));
+	}
+
+	/// Removes the next update, dropping any empty groups in front of it.
+	fn pop_next(&mut self) -> Option<(ASymbol, Update)> {
+		while let Some(&(_, symbol)) = self.order.first() {
+			if let Some((_, update)) = self
+				.groups
+				.get_mut(&symbol)
+				.expect("unreachable")
+				.1
+				.pop_front()
+			{
+				return Some((symbol, update));
+			}
+			drop(self.remove(symbol));
+		}
+		None
+	}
+
+	fn get_mut(&mut self, symbol: ASymbol) -> Option<&mut VecDeque<(u64, Update)>> {
+		self.groups.get_mut(&symbol).map(|(_, group)| group)
+	}
+
+	fn remove(&mut self, symbol: ASymbol) -> Option<VecDeque<(u64, Update)>> {
+		let (priority, group) = self.groups.remove(&symbol)?;
+		assert!(self.order.remove(&(Reverse(priority), symbol)));
+		Some(group)
+	}
+
+	fn set_priority(&mut self, symbol: ASymbol, priority: i32) {
+		if let Some((old, _)) = self.groups.get_mut(&symbol) {
+			assert!(self.order.remove(&(Reverse(*old), symbol)));
+			*old = priority;
+			self.order.insert((Reverse(priority), symbol));
+		}
+	}
+
+	fn is_empty(&self) -> bool {
+		self.groups.is_empty()
+	}
+
+	fn keys(&self) -> impl '_ + Debug + Iterator<Item = &ASymbol> {
+		self.groups.keys()
+	}
+}
+
+/// Compares and orders by `symbol` only, so that [`ASignalsRuntime_::stale_queue`] holds at most one entry per signal.
 #[derive(Debug, Clone, Copy, Eq)]
 struct Stale {
 	symbol: ASymbol,
+	/// Once set, this is never cleared until the entry is taken.
 	flush: bool,
 }
 
@@ -44,13 +251,13 @@ impl Borrow<ASymbol> for Stale {
 }
 
 impl PartialOrd for Stale {
-	fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+	fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
 		Some(self.cmp(other))
 	}
 }
 
 impl Ord for Stale {
-	fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+	fn cmp(&self, other: &Self) -> core::cmp::Ordering {
 		self.symbol.cmp(&other.symbol)
 	}
 }
@@ -61,15 +268,38 @@ impl PartialEq for Stale {
 	}
 }
 
-impl Debug for ASignalsRuntime_ {
+impl<CTT: SubscribedStatusMode> ASignalsRuntime_<CTT> {
+	fn priority(&self, symbol: ASymbol) -> i32 {
+		self.priorities.get(&symbol).copied().unwrap_or(0)
+	}
+}
+
+impl<CTT: SubscribedStatusMode> Debug for ASignalsRuntime_<CTT> {
 	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
 		f.debug_struct("ASignalsRuntime_")
 			.field("context_stack", &self.context_stack)
+			.field("batched", &self.batched)
 			.field("callbacks", &self.callbacks)
 			.field("update_queue", &self.update_queue.keys())
+			.field(
+				"staged_updates",
+				&self
+					.staged_updates
+					.iter()
+					.map(|staged| staged.iter().map(|(ticket, _)| ticket).collect::<Vec<_>>())
+					.collect::<Vec<_>>(),
+			)
 			.field("stale_queue", &self.stale_queue)
+			.field("priorities", &self.priorities)
+			.field("priority_order", &self.priority_order)
+			.field(
+				"notification_filters",
+				&self.notification_filters.keys().collect::<Vec<_>>(),
+			)
 			//FIXME: This could be a lot nicer, for example by printing a dependency graph (if a feature to do so is enabled).
 			.field("interdependencies", &self.interdependencies)
+			.field("idle_callbacks", &self.idle_callbacks.len())
+			.field("manual_processing", &self.manual_processing)
 			.finish()
 	}
 }
@@ -117,58 +347,168 @@ impl Interdependencies {
 	}
 }
 
-impl ASignalsRuntime {
+impl<CTT: SubscribedStatusMode> ASignalsRuntime<CTT> {
 	pub(crate) const fn new() -> Self {
 		Self {
 			source_counter: AtomicU64::new(0),
+			update_counter: AtomicU64::new(0),
 			critical_mutex: ReentrantMutex::new(RefCell::new(ASignalsRuntime_ {
 				context_stack: Vec::new(),
+				batched: false,
 				callbacks: BTreeMap::new(),
-				update_queue: BTreeMap::new(),
+				update_queue: UpdateQueue::new(),
+				staged_updates: Vec::new(),
 				stale_queue: BTreeSet::new(),
+				priorities: BTreeMap::new(),
+				priority_order: BTreeSet::new(),
+				#[cfg(debug_assertions)]
+				dependency_limits: BTreeMap::new(),
+				notification_filters: BTreeMap::new(),
 				interdependencies: Interdependencies::new(),
+				idle_callbacks: Vec::new(),
+				due_idle_callbacks: Vec::new(),
+				manual_processing: false,
 			})),
+			critical_depth: AtomicUsize::new(0),
+			#[cfg(feature = "contention_stats")]
+			uncontended_locks: AtomicU64::new(0),
+			#[cfg(feature = "contention_stats")]
+			contended_locks: AtomicU64::new(0),
+			#[cfg(feature = "std")]
+			drop_queue: OnceLock::new(),
 		}
 	}
 
+	/// Enters the critical section.
+	///
+	/// With the `"contention_stats"` feature, this first tries to lock without blocking
+	/// and counts whether that succeeded. Otherwise, it's a plain [`ReentrantMutex::lock`].
+	#[inline]
+	fn lock_critical(&self) -> CriticalGuard<'_, CTT> {
+		#[cfg(feature = "contention_stats")]
+		let lock = if let Some(lock) = self.critical_mutex.try_lock() {
+			self.uncontended_locks.fetch_add(1, Ordering::Relaxed);
+			lock
+		} else {
+			self.contended_locks.fetch_add(1, Ordering::Relaxed);
+			self.critical_mutex.lock()
+		};
+		#[cfg(not(feature = "contention_stats"))]
+		let lock = self.critical_mutex.lock();
+		self.critical_depth.fetch_add(1, Ordering::Relaxed);
+		CriticalGuard {
+			runtime: self,
+			lock: ManuallyDrop::new(lock),
+		}
+	}
+
+	/// Returns the numbers of uncontended and contended critical section entries, in that order.
+	#[cfg(feature = "contention_stats")]
+	pub(crate) fn contention_stats(&self) -> (u64, u64) {
+		(
+			self.uncontended_locks.load(Ordering::Relaxed),
+			self.contended_locks.load(Ordering::Relaxed),
+		)
+	}
+
+	pub(crate) fn dependencies(&self, id: ASymbol) -> Vec<ASymbol> {
+		let lock = self.lock_critical();
+		let borrow = (*lock).borrow();
+		borrow
+			.interdependencies
+			.all_by_dependent
+			.get(&id)
+			.into_iter()
+			.flatten()
+			.copied()
+			.collect()
+	}
+
 	fn peek_stale<'a>(
 		&self,
-		borrow: RefMut<'a, ASignalsRuntime_>,
-	) -> (Option<Stale>, RefMut<'a, ASignalsRuntime_>) {
+		borrow: RefMut<'a, ASignalsRuntime_<CTT>>,
+	) -> (Option<Stale>, RefMut<'a, ASignalsRuntime_<CTT>>) {
 		//FIXME: This is very inefficient!
 
-		(
+		let is_due = |&Stale { ref symbol, flush }: &Stale| {
+			flush
+				|| !borrow
+					.interdependencies
+					.subscribers_by_dependency
+					.get(symbol)
+					.expect("unreachable")
+					.is_empty()
+		};
+
+		if borrow.priorities.is_empty() {
+			let stale = borrow.stale_queue.iter().copied().find(is_due);
+			return (stale, borrow);
+		}
+
+		// Highest priority first, then creation order.
+		let prioritised = |positive: bool| {
 			borrow
-				.stale_queue
+				.priority_order
 				.iter()
-				.copied()
-				.find(|&Stale { ref symbol, flush }| {
-					flush
-						|| !borrow
-							.interdependencies
-							.subscribers_by_dependency
-							.get(symbol)
-							.expect("unreachable")
-							.is_empty()
-				}),
-			borrow,
-		)
+				.filter(move |(Reverse(priority), _)| (*priority > 0) == positive)
+				.filter_map(|(_, symbol)| borrow.stale_queue.get(symbol).copied())
+				.find(is_due)
+		};
+		let Some(candidate) = prioritised(true)
+			.or_else(|| {
+				borrow
+					.stale_queue
+					.iter()
+					.copied()
+					.filter(|stale| !borrow.priorities.contains_key(&stale.symbol))
+					.find(is_due)
+			})
+			.or_else(|| prioritised(false))
+		else {
+			return (None, borrow);
+		};
+
+		// Priorities must not break dependency order, so refresh the earliest-created
+		// due (transitive) dependency of the candidate first, if there is one.
+		let mut earliest = candidate;
+		let mut pending = vec![candidate.symbol];
+		let mut seen = BTreeSet::new();
+		while let Some(symbol) = pending.pop() {
+			for &dependency in borrow
+				.interdependencies
+				.all_by_dependent
+				.get(&symbol)
+				.into_iter()
+				.flatten()
+			{
+				if seen.insert(dependency) {
+					if let Some(stale) = borrow.stale_queue.get(&dependency).copied() {
+						if stale.symbol < earliest.symbol && is_due(&stale) {
+							earliest = stale;
+						}
+					}
+					pending.push(dependency);
+				}
+			}
+		}
+
+		(Some(earliest), borrow)
 	}
 
 	fn subscribe_to_with<'a>(
 		&self,
 		dependency: ASymbol,
 		dependent: ASymbol,
-		lock: &'a ReentrantMutexGuard<'a, RefCell<ASignalsRuntime_>>,
-		mut borrow: RefMut<'a, ASignalsRuntime_>,
-	) -> RefMut<'a, ASignalsRuntime_> {
+		lock: &'a CriticalGuard<'a, CTT>,
+		mut borrow: RefMut<'a, ASignalsRuntime_<CTT>>,
+	) -> RefMut<'a, ASignalsRuntime_<CTT>> {
 		let subscribers = borrow
 			.interdependencies
 			.subscribers_by_dependency
 			.entry(dependency)
 			.or_default();
 
-		if if dependency == dependent {
+		let changed = if dependency == dependent {
 			subscribers.intrinsic = subscribers
 				.intrinsic
 				.checked_add(1)
@@ -176,9 +516,11 @@ impl ASignalsRuntime {
 			true
 		} else {
 			subscribers.extrinsic.insert(dependent)
-		} && subscribers.total() == 1
-		{
-			// First subscriber, so propagate upwards and then call the handler!
+		};
+		let total = subscribers.total();
+
+		if changed && total == 1 {
+			// First subscriber, so propagate upwards!
 
 			for transitive_dependency in borrow
 				.interdependencies
@@ -191,7 +533,10 @@ impl ASignalsRuntime {
 			{
 				borrow = self.subscribe_to_with(transitive_dependency, dependency, lock, borrow);
 			}
+		}
 
+		if changed && (total == 1 || CTT::EVERY_CHANGE) {
+			// Then call the handler.
 			if let Some(&(callback_table, data)) = borrow.callbacks.get(&dependency) {
 				unsafe {
 					if let CallbackTable {
@@ -209,14 +554,16 @@ impl ASignalsRuntime {
 						borrow.context_stack.push(None);
 						drop(borrow);
 						let propagation =
-							try_eval(|| on_subscribed_change(data, true)).finally(|()| {
-								let mut borrow = (**lock).borrow_mut();
-								assert_eq!(borrow.context_stack.pop(), Some(None));
-								assert_eq!(
-									borrow.context_stack.pop(),
-									Some(Some((dependency, BTreeSet::new())))
-								);
-							});
+							try_eval(|| on_subscribed_change(data, CTT::status(total))).finally(
+								|()| {
+									let mut borrow = (**lock).borrow_mut();
+									assert_eq!(borrow.context_stack.pop(), Some(None));
+									assert_eq!(
+										borrow.context_stack.pop(),
+										Some(Some((dependency, BTreeSet::new())))
+									);
+								},
+							);
 						borrow = (**lock).borrow_mut();
 						borrow = match propagation {
 							Propagation::Halt => borrow,
@@ -238,15 +585,15 @@ impl ASignalsRuntime {
 		&self,
 		dependency: ASymbol,
 		dependent: ASymbol,
-		lock: &'a ReentrantMutexGuard<'a, RefCell<ASignalsRuntime_>>,
-		mut borrow: RefMut<'a, ASignalsRuntime_>,
-	) -> RefMut<'a, ASignalsRuntime_> {
+		lock: &'a CriticalGuard<'a, CTT>,
+		mut borrow: RefMut<'a, ASignalsRuntime_<CTT>>,
+	) -> RefMut<'a, ASignalsRuntime_<CTT>> {
 		let subscribers = borrow
 			.interdependencies
 			.subscribers_by_dependency
 			.entry(dependency)
 			.or_default();
-		if if dependency == dependent {
+		let changed = if dependency == dependent {
 			subscribers.intrinsic = subscribers
 				.intrinsic
 				.checked_sub(1)
@@ -254,9 +601,11 @@ impl ASignalsRuntime {
 			true
 		} else {
 			subscribers.extrinsic.remove(&dependent)
-		} && subscribers.total() == 0
-		{
-			// Removed last subscriber, so propagate upwards and then call the handler!
+		};
+		let total = subscribers.total();
+
+		if changed && total == 0 {
+			// Removed last subscriber, so propagate upwards!
 
 			for transitive_dependency in borrow
 				.interdependencies
@@ -270,7 +619,10 @@ impl ASignalsRuntime {
 				borrow =
 					self.unsubscribe_from_with(transitive_dependency, dependency, lock, borrow);
 			}
+		}
 
+		if changed && (total == 0 || CTT::EVERY_CHANGE) {
+			// Then call the handler.
 			if let Some(&(callback_table, data)) = borrow.callbacks.get(&dependency) {
 				unsafe {
 					if let CallbackTable {
@@ -288,14 +640,16 @@ impl ASignalsRuntime {
 						borrow.context_stack.push(None);
 						drop(borrow);
 						let propagation =
-							try_eval(|| on_subscribed_change(data, false)).finally(|()| {
-								let mut borrow = (**lock).borrow_mut();
-								assert_eq!(borrow.context_stack.pop(), Some(None));
-								assert_eq!(
-									borrow.context_stack.pop(),
-									Some(Some((dependency, BTreeSet::new())))
-								);
-							});
+							try_eval(|| on_subscribed_change(data, CTT::status(total))).finally(
+								|()| {
+									let mut borrow = (**lock).borrow_mut();
+									assert_eq!(borrow.context_stack.pop(), Some(None));
+									assert_eq!(
+										borrow.context_stack.pop(),
+										Some(Some((dependency, BTreeSet::new())))
+									);
+								},
+							);
 						borrow = (**lock).borrow_mut();
 						borrow = match propagation {
 							Propagation::Halt => borrow,
@@ -316,14 +670,27 @@ impl ASignalsRuntime {
 
 	fn process_pending<'a>(
 		&self,
-		lock: &'a ReentrantMutexGuard<'a, RefCell<ASignalsRuntime_>>,
-		mut borrow: RefMut<'a, ASignalsRuntime_>,
-	) -> RefMut<'a, ASignalsRuntime_> {
+		lock: &'a CriticalGuard<'a, CTT>,
+		borrow: RefMut<'a, ASignalsRuntime_<CTT>>,
+	) -> RefMut<'a, ASignalsRuntime_<CTT>> {
 		if !borrow.context_stack.is_empty() {
 			return borrow;
 		}
+		// In manual mode, updates are still applied so that cells don't lag behind.
+		let budget = borrow.manual_processing.then_some(0);
+		self.drain(lock, borrow, budget).1
+	}
 
-		loop {
+	/// Applies queued updates and refreshes due stale signals, the latter at most `budget` times (if [`Some`]).
+	///
+	/// Returns whether due stale signals remain.
+	fn drain<'a>(
+		&self,
+		lock: &'a CriticalGuard<'a, CTT>,
+		mut borrow: RefMut<'a, ASignalsRuntime_<CTT>>,
+		mut budget: Option<usize>,
+	) -> (bool, RefMut<'a, ASignalsRuntime_<CTT>>) {
+		let remaining = loop {
 			while let Some((symbol, update)) = {
 				let next_update;
 				(next_update, borrow) = self.next_update(lock, borrow);
@@ -354,6 +721,13 @@ impl ASignalsRuntime {
 			let stale;
 			(stale, borrow) = self.peek_stale(borrow);
 			if let Some(Stale { symbol, flush: _ }) = stale {
+				// Updates are applied regardless, so that stopping here leaves only stale signals,
+				// which are refreshed in dependency order later (or when read).
+				match &mut budget {
+					Some(0) => break true,
+					Some(budget) => *budget -= 1,
+					None => (),
+				}
 				try_eval(|| {
 					borrow.context_stack.push(None);
 					drop(borrow);
@@ -365,39 +739,44 @@ impl ASignalsRuntime {
 				});
 				borrow = (**lock).borrow_mut();
 			} else {
-				break;
+				break false;
 			}
+		};
+
+		// Idle now. The callbacks run once the critical section is left.
+		if !remaining && borrow.context_stack.is_empty() {
+			let idle_callbacks = mem::take(&mut borrow.idle_callbacks);
+			borrow.due_idle_callbacks.extend(idle_callbacks);
 		}
 
-		borrow
+		(remaining, borrow)
 	}
 
 	fn next_update<'a>(
 		&self,
-		_lock: &'a ReentrantMutexGuard<'a, RefCell<ASignalsRuntime_>>,
-		mut borrow: RefMut<'a, ASignalsRuntime_>,
+		_lock: &'a CriticalGuard<'a, CTT>,
+		mut borrow: RefMut<'a, ASignalsRuntime_<CTT>>,
 	) -> (
 		Option<(ASymbol, Box<dyn 'static + Send + FnOnce() -> Propagation>)>,
-		RefMut<'a, ASignalsRuntime_>,
+		RefMut<'a, ASignalsRuntime_<CTT>>,
 	) {
-		while let Some(mut first_group) = borrow.update_queue.first_entry() {
-			if let Some(update) = first_group.get_mut().pop_front() {
-				return (Some((*first_group.key(), update)), borrow);
-			} else {
-				drop(first_group.remove())
-			}
-		}
-		(None, borrow)
+		let next = borrow.update_queue.pop_next();
+		(next, borrow)
 	}
 
+	/// Marks the dependents of `id` stale, flushing them out iff `flush`.
+	///
+	/// Within a pass, [`Propagation::FlushOut`] takes precedence over [`Propagation::Propagate`]
+	/// regardless of order: Flushing *replaces* a plain stale entry for the same symbol,
+	/// while plain propagation only *inserts* and so leaves a flushing entry untouched.
 	fn mark_dependencies_stale<'a>(
 		&self,
 		id: ASymbol,
-		lock: &'a ReentrantMutexGuard<'a, RefCell<ASignalsRuntime_>>,
-		mut borrow: RefMut<'a, ASignalsRuntime_>,
+		lock: &'a CriticalGuard<'a, CTT>,
+		mut borrow: RefMut<'a, ASignalsRuntime_<CTT>>,
 		flush: bool,
-	) -> RefMut<'a, ASignalsRuntime_> {
-		let dependents = borrow
+	) -> RefMut<'a, ASignalsRuntime_<CTT>> {
+		let mut dependents = borrow
 			.interdependencies
 			.all_by_dependency
 			.entry(id)
@@ -406,8 +785,19 @@ impl ASignalsRuntime {
 			.copied()
 			.collect::<Vec<_>>();
 
+		if let Some(should_notify) = borrow.notification_filters.get(&id).cloned() {
+			borrow.context_stack.push(None);
+			drop(borrow);
+			try_eval(|| dependents.retain(|&dependent| should_notify(dependent))).finally(|()| {
+				let mut borrow = (**lock).borrow_mut();
+				assert_eq!(borrow.context_stack.pop(), Some(None));
+			});
+			borrow = (**lock).borrow_mut();
+		}
+
 		if flush {
 			for symbol in dependents {
+				// Upgrades a plain stale entry.
 				if borrow
 					.stale_queue
 					.replace(Stale { symbol, flush })
@@ -425,6 +815,7 @@ impl ASignalsRuntime {
 			}
 		} else {
 			for symbol in dependents {
+				// Never downgrades a flushing entry, as `Stale` compares only `symbol`.
 				if borrow.stale_queue.insert(Stale { symbol, flush })
 					&& borrow
 						.interdependencies
@@ -445,9 +836,9 @@ impl ASignalsRuntime {
 		&self,
 		id: ASymbol,
 		recorded_dependencies: BTreeSet<ASymbol>,
-		lock: &'a ReentrantMutexGuard<'a, RefCell<ASignalsRuntime_>>,
-		mut borrow: RefMut<'a, ASignalsRuntime_>,
-	) -> RefMut<'a, ASignalsRuntime_> {
+		lock: &'a CriticalGuard<'a, CTT>,
+		mut borrow: RefMut<'a, ASignalsRuntime_<CTT>>,
+	) -> RefMut<'a, ASignalsRuntime_<CTT>> {
 		let prior_dependencies = borrow
 			.interdependencies
 			.all_by_dependent
@@ -488,9 +879,9 @@ impl ASignalsRuntime {
 	}
 }
 
-unsafe impl SignalsRuntimeRef for &ASignalsRuntime {
+unsafe impl<CTT: SubscribedStatusMode> SignalsRuntimeRef for &ASignalsRuntime<CTT> {
 	type Symbol = ASymbol;
-	type CallbackTableTypes = ACallbackTableTypes;
+	type CallbackTableTypes = CTT;
 
 	fn next_id(&self) -> Self::Symbol {
 		ASymbol(
@@ -502,7 +893,7 @@ unsafe impl SignalsRuntimeRef for &ASignalsRuntime {
 	}
 
 	fn record_dependency(&self, id: Self::Symbol) {
-		let lock = self.critical_mutex.lock();
+		let lock = self.lock_critical();
 		let mut borrow = (*lock).borrow_mut();
 		if let Some(Some((ref context_id, recorded_dependencies))) =
 			&mut borrow.context_stack.last_mut()
@@ -512,6 +903,24 @@ unsafe impl SignalsRuntimeRef for &ASignalsRuntime {
 			if id >= context_id {
 				panic!("Tried to depend on later-created signal. To prevent loops, this isn't possible for now.");
 			}
+			// Checked before recording, so that the dependency sets stay symmetrical while unwinding.
+			#[cfg(debug_assertions)]
+			let recorded_dependencies = {
+				let new_count =
+					(!recorded_dependencies.contains(&id)).then(|| recorded_dependencies.len() + 1);
+				if let (Some(count), Some(&limit)) =
+					(new_count, borrow.dependency_limits.get(&context_id))
+				{
+					if count > limit {
+						drop(borrow);
+						panic!("Signal {context_id:?} recorded more than {limit} dependencies in one run.");
+					}
+				}
+				let Some(Some((_, recorded_dependencies))) = borrow.context_stack.last_mut() else {
+					unreachable!()
+				};
+				recorded_dependencies
+			};
 			recorded_dependencies.insert(id);
 
 			if !borrow
@@ -554,7 +963,7 @@ unsafe impl SignalsRuntimeRef for &ASignalsRuntime {
 		callback_table: *const CallbackTable<D, Self::CallbackTableTypes>,
 		callback_data: *const D,
 	) -> T {
-		let lock = self.critical_mutex.lock();
+		let lock = self.lock_critical();
 		let mut borrow = (*lock).borrow_mut();
 
 		if borrow.callbacks.contains_key(&id) {
@@ -590,12 +999,12 @@ unsafe impl SignalsRuntimeRef for &ASignalsRuntime {
 		});
 		borrow = (*lock).borrow_mut();
 
-		if borrow
+		let total = borrow
 			.interdependencies
 			.subscribers_by_dependency
 			.get(&id)
-			.is_some_and(|subs| !subs.is_empty())
-		{
+			.map_or(0, Subscribers::total);
+		if total > 0 {
 			// Subscribed, so run the callback for that.
 			let propagation = try_eval(|| {
 				// Important guard frame against `stop` and `purge`!
@@ -608,7 +1017,7 @@ unsafe impl SignalsRuntimeRef for &ASignalsRuntime {
 						..
 					} = &*callback_table
 					{
-						let propagation = on_subscribed_change(callback_data, true);
+						let propagation = on_subscribed_change(callback_data, CTT::status(total));
 						propagation
 					} else {
 						Propagation::Halt
@@ -637,7 +1046,7 @@ unsafe impl SignalsRuntimeRef for &ASignalsRuntime {
 	}
 
 	fn stop(&self, id: Self::Symbol) {
-		let lock = self.critical_mutex.lock();
+		let lock = self.lock_critical();
 		let mut borrow = (*lock).borrow_mut();
 
 		if borrow
@@ -654,14 +1063,17 @@ unsafe impl SignalsRuntimeRef for &ASignalsRuntime {
 		// This can unblock futures.
 		// Note that this could schedule more work for `id`!
 		// This method only guarantees _previous_ updates have been stopped.
-		drop(borrow.update_queue.remove(&id));
+		drop(borrow.update_queue.remove(id));
+		for staged in &mut borrow.staged_updates {
+			staged.retain(|(ticket, _)| ticket.symbol != id);
+		}
 
 		// There may have been side-effects.
 		self.process_pending(&lock, borrow);
 	}
 
 	fn update_dependency_set<T>(&self, id: Self::Symbol, f: impl FnOnce() -> T) -> T {
-		let lock = self.critical_mutex.lock();
+		let lock = self.lock_critical();
 		let mut borrow = (*lock).borrow_mut();
 
 		let t = try_eval(|| {
@@ -684,7 +1096,7 @@ unsafe impl SignalsRuntimeRef for &ASignalsRuntime {
 	}
 
 	fn subscribe(&self, id: Self::Symbol) {
-		let lock = self.critical_mutex.lock();
+		let lock = self.lock_critical();
 		let mut borrow = (*lock).borrow_mut();
 
 		borrow = self.subscribe_to_with(id, id, &lock, borrow);
@@ -693,7 +1105,7 @@ unsafe impl SignalsRuntimeRef for &ASignalsRuntime {
 	}
 
 	fn unsubscribe(&self, id: Self::Symbol) {
-		let lock = self.critical_mutex.lock();
+		let lock = self.lock_critical();
 		let mut borrow = (*lock).borrow_mut();
 
 		borrow = self.unsubscribe_from_with(id, id, &lock, borrow);
@@ -706,14 +1118,54 @@ unsafe impl SignalsRuntimeRef for &ASignalsRuntime {
 		id: Self::Symbol,
 		f: impl 'static + Send + FnOnce() -> Propagation,
 	) {
-		let lock = self.critical_mutex.lock();
+		self.update_or_enqueue_ticketed(id, f);
+	}
+
+	fn update_or_enqueue_ticketed(
+		&self,
+		id: Self::Symbol,
+		f: impl 'static + Send + FnOnce() -> Propagation,
+	) -> Option<UpdateTicket<Self::Symbol>> {
+		let ticket = UpdateTicket::new(id, self.update_counter.fetch_add(1, Ordering::Relaxed));
+		let lock = self.lock_critical();
 		let mut borrow = (*lock).borrow_mut();
-		borrow
-			.update_queue
-			.entry(id)
-			.or_default()
-			.push_back(Box::new(f));
+		if let Some(staged) = borrow.staged_updates.last_mut() {
+			staged.push((ticket, Box::new(f)));
+		} else {
+			let priority = borrow.priority(id);
+			borrow
+				.update_queue
+				.push(id, priority, ticket.serial, Box::new(f));
+		}
 		self.process_pending(&lock, borrow);
+		Some(ticket)
+	}
+
+	fn cancel_update(&self, ticket: UpdateTicket<Self::Symbol>) -> bool {
+		let lock = self.lock_critical();
+		let mut borrow = (*lock).borrow_mut();
+
+		let mut cancelled = None;
+		if let Some(group) = borrow.update_queue.get_mut(ticket.symbol) {
+			if let Some(index) = group
+				.iter()
+				.position(|(serial, _)| *serial == ticket.serial)
+			{
+				cancelled = group.remove(index).map(|(_, update)| update);
+			}
+		}
+		if cancelled.is_none() {
+			for staged in &mut borrow.staged_updates {
+				if let Some(index) = staged.iter().position(|(staged, _)| *staged == ticket) {
+					cancelled = Some(staged.remove(index).1);
+					break;
+				}
+			}
+		}
+		drop(borrow);
+
+		// Dropped outside of `borrow`, since updates may own arbitrary values.
+		cancelled.map(drop).is_some()
 	}
 
 	fn update_eager<'f, T: 'f + Send, F: 'f + Send + FnOnce() -> (Propagation, T)>(
@@ -738,8 +1190,11 @@ unsafe impl SignalsRuntimeRef for &ASignalsRuntime {
 				let setter_lock = Arc::clone(&setter_lock);
 				guard(f, move |f| {
 					if let Some(mut setter_lock) = setter_lock.lock().expect("unreachable").take() {
-						*setter_lock =
-							Some(Err(f.lock().expect("unreachable").borrow_mut().take())).into();
+						*setter_lock = Mutex::new(Some(Err(f
+							.lock()
+							.expect("unreachable")
+							.borrow_mut()
+							.take())));
 					}
 				})
 			};
@@ -752,7 +1207,7 @@ unsafe impl SignalsRuntimeRef for &ASignalsRuntime {
 					f_guard.borrow_mut().take(),
 				) {
 					let (propagation, t) = f();
-					*setter_lock = Some(Ok(t)).into();
+					*setter_lock = Mutex::new(Some(Ok(t)));
 					propagation
 				} else {
 					Propagation::Halt
@@ -768,7 +1223,7 @@ unsafe impl SignalsRuntimeRef for &ASignalsRuntime {
 			>(update)
 		});
 
-		let lock = self.critical_mutex.lock();
+		let lock = self.lock_critical();
 		let borrow = (*lock).borrow_mut();
 		self.process_pending(&lock, borrow);
 
@@ -796,21 +1251,62 @@ unsafe impl SignalsRuntimeRef for &ASignalsRuntime {
 		// This is indirected because the nested function's text size may be relatively large.
 		//BLOCKED: Avoid the heap allocation once the `Allocator` API is stabilised.
 
-		fn update_blocking<T>(
-			this: &ASignalsRuntime,
+		fn update_blocking<T, CTT: SubscribedStatusMode>(
+			this: &ASignalsRuntime<CTT>,
 			id: ASymbol,
 			f: Box<dyn '_ + FnOnce() -> (Propagation, T)>,
 		) -> T {
-			let lock = this.critical_mutex.lock();
+			let lock = this.lock_critical();
 			let borrow = (*lock).borrow_mut();
 
+			if !borrow.staged_updates.is_empty() {
+				drop(borrow);
+				panic!("Called `update_blocking` (via `set_blocking` or `replace_blocking`?) within a transaction. Blocking updates can't be staged, so submit this update through `update_or_enqueue` (via `set` or `update`?) instead.");
+			}
+
 			let (stale, mut borrow) = this.peek_stale(borrow);
 			let has_stale = stale.is_some();
 
-			if !(borrow.context_stack.is_empty() && !has_stale) {
+			// Batch frames don't record dependencies, so the update can run right away.
+			// Propagation is then coalesced with the rest of the batch.
+			let in_batch = borrow.batched && borrow.context_stack.len() == 1;
+			// In manual mode, stale signals are expected between `process_budgeted` calls.
+			// Their refreshes are left for the next call, same as for enqueued updates.
+			if !(in_batch
+				|| borrow.context_stack.is_empty() && (!has_stale || borrow.manual_processing))
+			{
 				panic!("Called `update_blocking` (via `change_blocking` or `replace_blocking`?) while propagating another update. This would deadlock with a better queue.");
 			}
 
+			if in_batch {
+				// Apply updates for `id` that were enqueued earlier in the batch first, to keep them in order.
+				while let Some((_, update)) = borrow
+					.update_queue
+					.get_mut(id)
+					.and_then(VecDeque::pop_front)
+				{
+					let propagation = try_eval(|| {
+						borrow.context_stack.push(None);
+						drop(borrow);
+						update()
+					})
+					.finally(|()| {
+						let mut borrow = (*lock).borrow_mut();
+						assert_eq!(borrow.context_stack.pop(), Some(None));
+					});
+					borrow = (*lock).borrow_mut();
+					borrow = match propagation {
+						Propagation::Propagate => {
+							this.mark_dependencies_stale(id, &lock, borrow, false)
+						}
+						Propagation::Halt => borrow,
+						Propagation::FlushOut => {
+							this.mark_dependencies_stale(id, &lock, borrow, true)
+						}
+					};
+				}
+			}
+
 			let (propagation, t) = f();
 			borrow = match propagation {
 				Propagation::Propagate => this.mark_dependencies_stale(id, &lock, borrow, false),
@@ -824,7 +1320,7 @@ unsafe impl SignalsRuntimeRef for &ASignalsRuntime {
 	}
 
 	fn run_detached<T>(&self, f: impl FnOnce() -> T) -> T {
-		let lock = self.critical_mutex.lock();
+		let lock = self.lock_critical();
 		let mut borrow = (*lock).borrow_mut();
 		let t = try_eval(|| {
 			borrow.context_stack.push(None);
@@ -841,8 +1337,13 @@ unsafe impl SignalsRuntimeRef for &ASignalsRuntime {
 	}
 
 	fn refresh(&self, id: Self::Symbol) {
-		let lock = self.critical_mutex.lock();
+		self.refresh_reporting(id);
+	}
+
+	fn refresh_reporting(&self, id: Self::Symbol) -> Option<Propagation> {
+		let lock = self.lock_critical();
 		let mut borrow = (*lock).borrow_mut();
+		let mut reported = None;
 		if let Some(Stale { symbol: _, flush }) = borrow.stale_queue.take(&id) {
 			if let Some(&(callback_table, data)) = borrow.callbacks.get(&id) {
 				if let &CallbackTable {
@@ -860,6 +1361,7 @@ unsafe impl SignalsRuntimeRef for &ASignalsRuntime {
 						assert_eq!(borrow.context_stack.pop(), Some(None));
 					});
 					borrow = (*lock).borrow_mut();
+					reported = Some(propagation);
 					match propagation {
 						Propagation::Propagate => {
 							borrow = self.mark_dependencies_stale(id, &lock, borrow, flush)
@@ -881,10 +1383,11 @@ unsafe impl SignalsRuntimeRef for &ASignalsRuntime {
 			}
 		}
 		self.process_pending(&lock, borrow);
+		reported
 	}
 
 	fn purge(&self, id: Self::Symbol) {
-		let lock = self.critical_mutex.lock();
+		let lock = self.lock_critical();
 		let mut borrow = (*lock).borrow_mut();
 
 		if borrow
@@ -934,7 +1437,10 @@ unsafe impl SignalsRuntimeRef for &ASignalsRuntime {
 		// This can unblock futures.
 		// Note that this could schedule more work for `id`!
 		// This method only guarantees _previous_ updates have been stopped.
-		drop(borrow.update_queue.remove(&id));
+		drop(borrow.update_queue.remove(id));
+		for staged in &mut borrow.staged_updates {
+			staged.retain(|(ticket, _)| ticket.symbol != id);
+		}
 
 		let interdependencies = &mut borrow.interdependencies;
 		for collection in [
@@ -952,23 +1458,73 @@ unsafe impl SignalsRuntimeRef for &ASignalsRuntime {
 			.is_some_and(|subscribers| !subscribers.is_empty()));
 
 		borrow.stale_queue.remove(&id);
+		if let Some(priority) = borrow.priorities.remove(&id) {
+			borrow.priority_order.remove(&(Reverse(priority), id));
+		}
+		#[cfg(debug_assertions)]
+		borrow.dependency_limits.remove(&id);
+		borrow.notification_filters.remove(&id);
 
 		self.process_pending(&lock, borrow);
 	}
 
+	fn transaction<B, C>(&self, f: impl FnOnce() -> ControlFlow<B, C>) -> ControlFlow<B, C> {
+		// Like in `hint_batched_updates`, the context stack blocks updates while `f` runs.
+		// Within a signal's context, that's already the case and reads must still be recorded.
+		let lock = self.lock_critical();
+		let mut borrow = (*lock).borrow_mut();
+		borrow.staged_updates.push(Vec::new());
+		let detached = borrow.context_stack.is_empty();
+		if detached {
+			borrow.context_stack.push(None);
+		}
+		drop(borrow);
+
+		// Dropped outside of `borrow` (also while unwinding), since updates may own arbitrary values.
+		let mut staged = None;
+		let flow = try_eval(f).finally(|()| {
+			let mut borrow = (*lock).borrow_mut();
+			if detached {
+				assert_eq!(borrow.context_stack.pop(), Some(None));
+			}
+			staged = borrow.staged_updates.pop();
+		});
+		let staged = staged.expect("unreachable");
+
+		if flow.is_continue() {
+			let mut borrow = (*lock).borrow_mut();
+			if let Some(outer) = borrow.staged_updates.last_mut() {
+				outer.extend(staged);
+			} else {
+				for (ticket, update) in staged {
+					let priority = borrow.priority(ticket.symbol);
+					borrow
+						.update_queue
+						.push(ticket.symbol, priority, ticket.serial, update);
+				}
+			}
+			self.process_pending(&lock, borrow);
+		} else {
+			drop(staged);
+		}
+		flow
+	}
+
 	fn hint_batched_updates<T>(&self, f: impl FnOnce() -> T) -> T {
 		// Ensures that the context stack is not empty while `f` runs, blocking updates.
-		let lock = self.critical_mutex.lock();
+		let lock = self.lock_critical();
 		let mut borrow = (*lock).borrow_mut();
 		if borrow.context_stack.is_empty() {
 			let t = try_eval(|| {
 				borrow.context_stack.push(None);
+				borrow.batched = true;
 				drop(borrow);
 				f()
 			})
 			.finally(|()| {
 				let mut borrow = (*lock).borrow_mut();
 				assert_eq!(borrow.context_stack.pop(), Some(None));
+				borrow.batched = false;
 			});
 			borrow = (*lock).borrow_mut();
 			self.process_pending(&lock, borrow);
@@ -978,4 +1534,152 @@ unsafe impl SignalsRuntimeRef for &ASignalsRuntime {
 			f()
 		}
 	}
+
+	fn process_budgeted(&self, max_refreshes: usize) -> bool {
+		let lock = self.lock_critical();
+		let borrow = (*lock).borrow_mut();
+		// Like `update_blocking`, this is allowed directly within `hint_batched_updates`.
+		if borrow.context_stack.is_empty() || (borrow.batched && borrow.context_stack.len() == 1) {
+			self.drain(&lock, borrow, Some(max_refreshes)).0
+		} else {
+			let (stale, borrow) = self.peek_stale(borrow);
+			stale.is_some() || !borrow.update_queue.is_empty()
+		}
+	}
+
+	fn set_manual_processing(&self, manual: bool) {
+		let lock = self.lock_critical();
+		let mut borrow = (*lock).borrow_mut();
+		borrow.manual_processing = manual;
+		self.process_pending(&lock, borrow);
+	}
+
+	fn set_priority(&self, id: Self::Symbol, priority: i32) {
+		let lock = self.lock_critical();
+		let mut borrow = (*lock).borrow_mut();
+		let old = if priority == 0 {
+			borrow.priorities.remove(&id)
+		} else {
+			borrow.priorities.insert(id, priority)
+		};
+		if let Some(old) = old {
+			borrow.priority_order.remove(&(Reverse(old), id));
+		}
+		if priority != 0 {
+			borrow.priority_order.insert((Reverse(priority), id));
+		}
+		borrow.update_queue.set_priority(id, priority);
+	}
+
+	#[cfg(debug_assertions)]
+	fn set_dependency_limit(&self, id: Self::Symbol, limit: usize) {
+		let lock = self.lock_critical();
+		let mut borrow = (*lock).borrow_mut();
+		borrow.dependency_limits.insert(id, limit);
+	}
+
+	fn set_notification_filter(
+		&self,
+		id: Self::Symbol,
+		should_notify: Box<dyn 'static + Send + Sync + Fn(Self::Symbol) -> bool>,
+	) {
+		let lock = self.lock_critical();
+		let mut borrow = (*lock).borrow_mut();
+		borrow
+			.notification_filters
+			.insert(id, Arc::from(should_notify));
+	}
+
+	fn is_stale(&self, id: Self::Symbol) -> bool {
+		let lock = self.lock_critical();
+		let borrow = (*lock).borrow();
+		borrow.stale_queue.contains(&id)
+	}
+
+	fn try_record_fresh_dependency(&self, id: Self::Symbol) -> bool {
+		// Succeeds without waiting iff this thread already holds the lock, too.
+		let Some(lock) = self.critical_mutex.try_lock() else {
+			return false;
+		};
+		let borrow = (*lock).borrow();
+		// Also fails while `id` is being refreshed (on this thread), as its value may be in flux.
+		if borrow.stale_queue.contains(&id)
+			|| borrow
+				.context_stack
+				.iter()
+				.flatten()
+				.any(|(stack_id, _)| *stack_id == id)
+		{
+			return false;
+		}
+		drop(borrow);
+		// Reentrant, so this doesn't wait either.
+		self.record_dependency(id);
+		true
+	}
+
+	fn retain_dependencies(&self) {
+		let lock = self.lock_critical();
+		let mut borrow = (*lock).borrow_mut();
+		let ASignalsRuntime_ {
+			context_stack,
+			interdependencies,
+			..
+		} = &mut *borrow;
+		if let Some(Some((id, recorded_dependencies))) = context_stack.last_mut() {
+			// These are still wired up, so they only need to survive `shrink_dependencies`.
+			if let Some(prior_dependencies) = interdependencies.all_by_dependent.get(id) {
+				recorded_dependencies.extend(prior_dependencies.iter().copied());
+			}
+		}
+	}
+
+	#[cfg(feature = "std")]
+	fn defer_drop<T: 'static + Send>(&self, value: T) {
+		let drop_queue = self.drop_queue.get_or_init(|| {
+			let (sender, receiver) = mpsc::channel::<Box<dyn Send>>();
+			thread::Builder::new()
+				.name("isoprenoid drop queue".to_string())
+				.spawn(move || {
+					for garbage in receiver {
+						drop(garbage);
+					}
+				})
+				.ok()
+				.map(|_| Mutex::new(sender))
+		});
+
+		// Falls back to dropping immediately, e.g. where threads aren't supported.
+		if let Some(drop_queue) = drop_queue {
+			let sender = drop_queue.lock().expect("unreachable");
+			if let Err(mpsc::SendError(value)) = sender.send(Box::new(value)) {
+				drop(sender);
+				drop(value);
+			}
+		}
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn defer_drop<T: 'static + Send>(&self, value: T) {
+		// Without threads, there's nowhere else to drop `value`.
+		drop(value);
+	}
+
+	fn on_idle(&self, f: impl 'static + Send + FnOnce()) {
+		let lock = self.lock_critical();
+		let mut borrow = (*lock).borrow_mut();
+		let idle = borrow.context_stack.is_empty() && {
+			// Top-level calls always process pending work before returning,
+			// except for due stale signals while processing manually.
+			let stale;
+			(stale, borrow) = self.peek_stale(borrow);
+			stale.is_none()
+		};
+		// Either way, `f` is called only outside of the critical section.
+		if idle {
+			borrow.due_idle_callbacks.push(Box::new(f));
+		} else {
+			borrow.idle_callbacks.push(Box::new(f));
+		}
+	}
 }
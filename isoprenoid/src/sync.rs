@@ -0,0 +1,123 @@
+//! Locks that are available without `std`.
+
+#[cfg(feature = "std")]
+pub(crate) use std::sync::Mutex;
+
+#[cfg(not(feature = "std"))]
+pub(crate) use spin_mutex::Mutex;
+
+#[cfg(all(feature = "critical_section", not(feature = "global_signals_runtime")))]
+pub(crate) use critical_section_mutex::{ReentrantMutex, ReentrantMutexGuard};
+
+#[cfg(not(feature = "std"))]
+mod spin_mutex {
+	use core::{
+		convert::Infallible,
+		fmt::{self, Debug, Formatter},
+	};
+
+	/// A [`spin::Mutex`] with the subset of `std::sync::Mutex`'s API that this crate uses.
+	///
+	/// Spinlocks aren't poisoned by panics, so locking them can't fail.
+	pub(crate) struct Mutex<T: ?Sized>(spin::Mutex<T>);
+
+	#[derive(Debug)]
+	pub(crate) struct WouldBlock;
+
+	impl<T> Mutex<T> {
+		pub(crate) const fn new(value: T) -> Self {
+			Self(spin::Mutex::new(value))
+		}
+
+		pub(crate) fn into_inner(self) -> Result<T, Infallible> {
+			Ok(self.0.into_inner())
+		}
+	}
+
+	impl<T: ?Sized> Mutex<T> {
+		pub(crate) fn lock(&self) -> Result<spin::MutexGuard<'_, T>, Infallible> {
+			Ok(self.0.lock())
+		}
+
+		pub(crate) fn try_lock(&self) -> Result<spin::MutexGuard<'_, T>, WouldBlock> {
+			self.0.try_lock().ok_or(WouldBlock)
+		}
+	}
+
+	impl<T: ?Sized + Debug> Debug for Mutex<T> {
+		fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+			Debug::fmt(&self.0, f)
+		}
+	}
+}
+
+#[cfg(all(feature = "critical_section", not(feature = "global_signals_runtime")))]
+mod critical_section_mutex {
+	use core::{
+		fmt::{self, Debug, Formatter},
+		marker::PhantomData,
+		ops::Deref,
+	};
+
+	use critical_section::RestoreState;
+
+	/// A reentrant lock with the subset of `parking_lot::ReentrantMutex`'s API that this crate uses.
+	///
+	/// Locking it enters the global critical section, which nests on the same thread.
+	pub(crate) struct ReentrantMutex<T>(T);
+
+	// SAFETY: `T` is only accessible from inside the global critical section.
+	unsafe impl<T: Send> Sync for ReentrantMutex<T> {}
+
+	/// Leaves the critical section when dropped.
+	///
+	/// Guards must drop in reverse order of their creation, which holds for the scoped use in this crate.
+	pub(crate) struct ReentrantMutexGuard<'a, T> {
+		mutex: &'a ReentrantMutex<T>,
+		restore_state: RestoreState,
+		_not_send: PhantomData<*const ()>,
+	}
+
+	impl<T> ReentrantMutex<T> {
+		pub(crate) const fn new(value: T) -> Self {
+			Self(value)
+		}
+
+		pub(crate) fn lock(&self) -> ReentrantMutexGuard<'_, T> {
+			ReentrantMutexGuard {
+				mutex: self,
+				// SAFETY: Released (in nesting order) when the guard drops.
+				restore_state: unsafe { critical_section::acquire() },
+				_not_send: PhantomData,
+			}
+		}
+
+		/// Entering the critical section can't be attempted without waiting, so this always locks.
+		pub(crate) fn try_lock(&self) -> Option<ReentrantMutexGuard<'_, T>> {
+			Some(self.lock())
+		}
+	}
+
+	impl<T: Debug> Debug for ReentrantMutex<T> {
+		fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+			f.debug_tuple("ReentrantMutex")
+				.field(&*self.lock())
+				.finish()
+		}
+	}
+
+	impl<T> Deref for ReentrantMutexGuard<'_, T> {
+		type Target = T;
+
+		fn deref(&self) -> &Self::Target {
+			&self.mutex.0
+		}
+	}
+
+	impl<T> Drop for ReentrantMutexGuard<'_, T> {
+		fn drop(&mut self) {
+			// SAFETY: Acquired in `ReentrantMutex::lock`.
+			unsafe { critical_section::release(self.restore_state) }
+		}
+	}
+}